@@ -0,0 +1,184 @@
+use proc_macro_error::abort;
+use syn::meta::ParseNestedMeta;
+use syn::{parenthesized, token, Attribute, Expr, Field, Path, Type};
+
+/// Default tolerance values given by `#[nearly(eps = ..., ulps = ..., rel = ...)]`.
+#[derive(Default)]
+pub(crate) struct Defaults {
+    pub(crate) eps: Option<Expr>,
+    pub(crate) ulps: Option<Expr>,
+    pub(crate) rel: Option<Expr>,
+}
+
+impl Defaults {
+    /// Parses a default tolerance value. Returns false if `meta` is no default tolerance value.
+    fn parse(&mut self, meta: &ParseNestedMeta) -> syn::Result<bool> {
+        let value = if meta.path.is_ident("eps") {
+            &mut self.eps
+        } else if meta.path.is_ident("ulps") {
+            &mut self.ulps
+        } else if meta.path.is_ident("rel") {
+            &mut self.rel
+        } else {
+            return Ok(false);
+        };
+
+        *value = Some(meta.value()?.parse()?);
+        Ok(true)
+    }
+
+    fn is_empty(&self) -> bool {
+        self.eps.is_none() && self.ulps.is_none() && self.rel.is_none()
+    }
+}
+
+fn parse_attrs(
+    attrs: &[Attribute],
+    mut parse: impl FnMut(&ParseNestedMeta) -> syn::Result<bool>,
+    expected: &str,
+) {
+    for attr in attrs.iter().filter(|it| it.path().is_ident("nearly")) {
+        let result = attr.parse_nested_meta(|meta| {
+            if parse(&meta)? {
+                return Ok(());
+            }
+            Err(meta.error(format!(
+                "unsupported nearly attribute, expected {}",
+                expected
+            )))
+        });
+
+        if let Err(err) = result {
+            abort!(err.span(), "{}", err);
+        }
+    }
+}
+
+/// The options of a struct or enum given by `#[nearly(...)]` attributes.
+#[derive(Default)]
+pub(crate) struct ContainerAttrs {
+    /// The default tolerance of the type.
+    pub(crate) defaults: Defaults,
+    /// The other types the type is compared with.
+    pub(crate) rhs: Vec<Type>,
+    /// The tolerance is a generated struct with a field for each field of the type.
+    pub(crate) named_tolerance: bool,
+}
+
+impl ContainerAttrs {
+    pub(crate) fn from_attrs(attrs: &[Attribute]) -> Self {
+        let mut container_attrs = ContainerAttrs::default();
+        parse_attrs(
+            attrs,
+            |meta| {
+                if meta.path.is_ident("named_tolerance") {
+                    container_attrs.named_tolerance = true;
+                    return Ok(true);
+                }
+                if meta.path.is_ident("rhs") {
+                    container_attrs.rhs.push(meta.value()?.parse()?);
+                    return Ok(true);
+                }
+                container_attrs.defaults.parse(meta)
+            },
+            "`named_tolerance`, `rhs`, `eps`, `ulps` or `rel`",
+        );
+        container_attrs
+    }
+}
+
+/// The options of a field given by `#[nearly(...)]` attributes.
+#[derive(Default)]
+pub(crate) struct FieldAttrs {
+    /// The field is not part of any nearly comparison.
+    pub(crate) skip: bool,
+    /// The field is compared exactly by [PartialEq] and [PartialOrd].
+    pub(crate) exact: bool,
+    /// The path of a module providing the nearly comparison functions of the field.
+    pub(crate) with: Option<Path>,
+    /// The default tolerance of the field.
+    pub(crate) defaults: Defaults,
+    /// The types of the field in the other types the type is compared with. The first element is
+    /// the other type given by `#[nearly(rhs(Other) = ...)]`, or none for all other types.
+    pub(crate) rhs: Vec<(Option<Type>, Type)>,
+}
+
+impl FieldAttrs {
+    pub(crate) fn from_field(field: &Field) -> Self {
+        let mut attrs = FieldAttrs::default();
+        parse_attrs(
+            &field.attrs,
+            |meta| {
+                if meta.path.is_ident("skip") {
+                    attrs.skip = true;
+                    return Ok(true);
+                }
+                if meta.path.is_ident("exact") {
+                    attrs.exact = true;
+                    return Ok(true);
+                }
+                if meta.path.is_ident("with") {
+                    attrs.with = Some(meta.value()?.parse()?);
+                    return Ok(true);
+                }
+                if meta.path.is_ident("rhs") {
+                    let rhs = if meta.input.peek(token::Paren) {
+                        let content;
+                        parenthesized!(content in meta.input);
+                        Some(content.parse()?)
+                    } else {
+                        None
+                    };
+                    attrs.rhs.push((rhs, meta.value()?.parse()?));
+                    return Ok(true);
+                }
+                attrs.defaults.parse(meta)
+            },
+            "`skip`, `exact`, `with`, `rhs`, `eps`, `ulps` or `rel`",
+        );
+
+        if attrs.skip && attrs.exact {
+            abort!(field, "a field cannot be both `skip` and `exact`");
+        }
+        if attrs.skip && !attrs.rhs.is_empty() {
+            abort!(field, "`rhs` cannot be used for a `skip` field");
+        }
+        if !attrs.uses_tolerance() && attrs.with.is_some() {
+            abort!(field, "`with` cannot be used for a `skip` or `exact` field");
+        }
+        if !attrs.uses_tolerance() && !attrs.defaults.is_empty() {
+            abort!(
+                field,
+                "a default tolerance cannot be set for a `skip` or `exact` field"
+            );
+        }
+
+        attrs
+    }
+
+    /// Returns whether the field is compared based on a tolerance.
+    pub(crate) fn uses_tolerance(&self) -> bool {
+        !self.skip && !self.exact
+    }
+
+    /// Returns whether the field has a default tolerance value.
+    pub(crate) fn has_defaults(&self) -> bool {
+        !self.defaults.is_empty()
+    }
+
+    /// Returns the type of the field in `rhs` if it is given by an attribute.
+    ///
+    /// A type given for `rhs` explicitly takes precedence over a type given for all other types.
+    pub(crate) fn rhs_ty(&self, rhs: &Type) -> Option<&Type> {
+        self.rhs
+            .iter()
+            .find(|(other, _)| other.as_ref() == Some(rhs))
+            .or_else(|| self.rhs.iter().find(|(other, _)| other.is_none()))
+            .map(|(_, ty)| ty)
+    }
+}
+
+/// Returns whether the field is compared based on a tolerance.
+pub(crate) fn uses_tolerance(field: &Field) -> bool {
+    FieldAttrs::from_field(field).uses_tolerance()
+}
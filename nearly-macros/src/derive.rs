@@ -1,12 +1,14 @@
+use crate::attr::{uses_tolerance, ContainerAttrs, Defaults, FieldAttrs};
 use proc_macro::TokenStream;
 use proc_macro_error::{abort, abort_call_site};
 use quote::{format_ident, quote, ToTokens};
 use syn::{
-    parse_macro_input,
+    ext::IdentExt,
+    parse_macro_input, parse_quote,
     Data::{self, Enum, Struct, Union},
-    DataEnum, DataStruct, DeriveInput,
+    DataEnum, DataStruct, DeriveInput, Expr, Field, Fields,
     Fields::{Named, Unit, Unnamed},
-    FieldsNamed, FieldsUnnamed, Ident, Index, Type, Variant,
+    GenericParam, Generics, Ident, Index, Lifetime, LifetimeParam, Member, Type,
 };
 
 #[allow(clippy::enum_variant_names)]
@@ -14,10 +16,13 @@ use syn::{
 pub(crate) enum DeriveTrait {
     NearlyEqEps,
     NearlyEqUlps,
+    NearlyEqRel,
     NearlyEqTol,
     NearlyEq,
+    NearlyEqReport,
     NearlyOrdEps,
     NearlyOrdUlps,
+    NearlyOrdRel,
     NearlyOrdTol,
     NearlyOrd,
 }
@@ -45,14 +50,22 @@ impl Cmp {
 enum TraitFn {
     Eps(Cmp),
     Ulps(Cmp),
+    Rel(Cmp),
     Tol(Cmp),
 }
 
 impl TraitFn {
+    fn cmp(&self) -> &Cmp {
+        match self {
+            TraitFn::Eps(cmp) | TraitFn::Ulps(cmp) | TraitFn::Rel(cmp) | TraitFn::Tol(cmp) => cmp,
+        }
+    }
+
     fn fn_ident(&self) -> Ident {
         match self {
             TraitFn::Eps(cmp) => format_ident!("nearly_{}_eps", cmp.fn_ident()),
             TraitFn::Ulps(cmp) => format_ident!("nearly_{}_ulps", cmp.fn_ident()),
+            TraitFn::Rel(cmp) => format_ident!("nearly_{}_rel", cmp.fn_ident()),
             TraitFn::Tol(cmp) => format_ident!("nearly_{}_tol", cmp.fn_ident()),
         }
     }
@@ -62,465 +75,1486 @@ fn tol_from_trait(derive_trait: DeriveTrait) -> Ident {
     match derive_trait {
         DeriveTrait::NearlyEqEps => format_ident!("EpsTolerance"),
         DeriveTrait::NearlyEqUlps => format_ident!("UlpsTolerance"),
+        DeriveTrait::NearlyEqRel => format_ident!("RelTolerance"),
         _ => panic!("invalid derive trait"),
     }
 }
 
-fn types_from_named_fields(fields: &FieldsNamed) -> Vec<&Type> {
-    fields.named.iter().map(|it| &it.ty).collect()
-}
-
-fn types_from_unnamed_fields(fields: &FieldsUnnamed) -> Vec<&Type> {
-    fields.unnamed.iter().map(|it| &it.ty).collect()
+fn default_from_trait(defaults: &Defaults, derive_trait: DeriveTrait) -> Option<&Expr> {
+    match derive_trait {
+        DeriveTrait::NearlyEqEps => defaults.eps.as_ref(),
+        DeriveTrait::NearlyEqUlps => defaults.ulps.as_ref(),
+        DeriveTrait::NearlyEqRel => defaults.rel.as_ref(),
+        _ => panic!("invalid derive trait"),
+    }
 }
 
-fn idents_from_named_fields(fields: &FieldsNamed) -> Vec<&Ident> {
-    fields
-        .named
-        .iter()
-        .map(|it| match &it.ident {
-            Some(ident) => ident,
-            None => abort!(it, "Invalid ident of named struct field"),
-        })
-        .collect()
+fn tol_fields_from_fields(fields: &Fields) -> Vec<&Field> {
+    fields.iter().filter(|it| uses_tolerance(it)).collect()
 }
 
-fn indices_from_unnamed_fields(fields: &FieldsUnnamed) -> Vec<Index> {
-    let num_fields = fields.unnamed.iter().count();
-    (0..num_fields)
-        .map(|i| Index {
-            index: i as u32,
+fn member_from_field(index: usize, field: &Field) -> Member {
+    match &field.ident {
+        Some(ident) => Member::Named(ident.clone()),
+        None => Member::Unnamed(Index {
+            index: index as u32,
             span: proc_macro2::Span::call_site(),
-        })
-        .collect()
+        }),
+    }
 }
 
-fn tol_output(data: &Data, derive_trait: DeriveTrait) -> proc_macro2::TokenStream {
-    match data {
-        Struct(data_struct) => struct_tol_output(data_struct, derive_trait),
-        Enum(data_enum) => enum_tol_output(data_enum, derive_trait),
-        Union(_data_union) => abort_call_site!("trait NearlyEqEps cannot be derived for unions"),
+fn tol_output(
+    input: &DeriveInput,
+    rhs: Option<&Type>,
+    derive_trait: DeriveTrait,
+) -> proc_macro2::TokenStream {
+    let container_attrs = ContainerAttrs::from_attrs(&input.attrs);
+    let default = default_from_trait(&container_attrs.defaults, derive_trait);
+
+    match &input.data {
+        // the tolerance against another type is never a named tolerance, since the tolerance
+        // struct holds the tolerances of the fields against the fields of the type itself
+        Struct(data_struct) if container_attrs.named_tolerance && rhs.is_none() => {
+            named_tol_output(input, data_struct, default, derive_trait)
+        }
+        Struct(data_struct) => struct_tol_output(data_struct, rhs, default, derive_trait),
+        Enum(data_enum) => enum_tol_output(data_enum, default, derive_trait),
+        Union(_data_union) => abort_call_site!("nearly traits cannot be derived for unions"),
     }
 }
 
-fn fn_output(data: &Data, ident: &Ident, trait_fn: TraitFn) -> proc_macro2::TokenStream {
-    match data {
-        Struct(data_struct) => struct_fn_output(data_struct, trait_fn),
-        Enum(data_enum) => enum_fn_output(data_enum, ident, trait_fn),
-        Union(_data_union) => abort_call_site!("trait NearlyEqEps cannot be derived for unions"),
+fn fn_output(
+    input: &DeriveInput,
+    rhs: Option<&Type>,
+    trait_fn: TraitFn,
+) -> proc_macro2::TokenStream {
+    let container_attrs = ContainerAttrs::from_attrs(&input.attrs);
+    let named_tolerance = container_attrs.named_tolerance && rhs.is_none();
+
+    match &input.data {
+        Struct(data_struct) => struct_fn_output(data_struct, named_tolerance, rhs, trait_fn),
+        Enum(data_enum) => enum_fn_output(data_enum, &input.ident, trait_fn),
+        Union(_data_union) => abort_call_site!("nearly traits cannot be derived for unions"),
     }
 }
 
 fn struct_tol_output(
     data_struct: &DataStruct,
+    rhs: Option<&Type>,
+    default: Option<&Expr>,
     derive_trait: DeriveTrait,
 ) -> proc_macro2::TokenStream {
-    match &data_struct.fields {
-        Named(fields) => struct_named_fields_tol_output(fields, derive_trait),
-        Unnamed(fields) => struct_unnamed_fields_tol_output(fields, derive_trait),
-        Unit => struct_unit_tol_output(),
-    }
+    let tol_fields = tol_fields_from_fields(&data_struct.fields);
+    tol_impl(&tol_fields, rhs, default, derive_trait)
+}
+
+fn struct_fn_output(
+    data_struct: &DataStruct,
+    named_tolerance: bool,
+    rhs: Option<&Type>,
+    trait_fn: TraitFn,
+) -> proc_macro2::TokenStream {
+    let tol_fields = tol_fields_from_fields(&data_struct.fields);
+    fn_impl_struct(
+        &data_struct.fields,
+        &tol_fields,
+        named_tolerance,
+        rhs,
+        trait_fn,
+    )
 }
 
-fn struct_fn_output(data_struct: &DataStruct, trait_fn: TraitFn) -> proc_macro2::TokenStream {
-    match &data_struct.fields {
-        Named(fields) => struct_named_fields_fn_output(fields, trait_fn),
-        Unnamed(fields) => struct_unnamed_fields_fn_output(fields, trait_fn),
-        Unit => struct_unit_fn_output(),
+/// Returns the ident of the tolerance struct generated for `#[nearly(named_tolerance)]`.
+fn tol_struct_ident(ident: &Ident, derive_trait: DeriveTrait) -> Ident {
+    match derive_trait {
+        DeriveTrait::NearlyEqEps => format_ident!("{}Eps", ident),
+        DeriveTrait::NearlyEqUlps => format_ident!("{}Ulps", ident),
+        DeriveTrait::NearlyEqRel => format_ident!("{}Rel", ident),
+        _ => panic!("invalid derive trait"),
     }
 }
 
-fn struct_named_fields_tol_output(
-    fields: &FieldsNamed,
+fn named_tol_output(
+    input: &DeriveInput,
+    data_struct: &DataStruct,
+    default: Option<&Expr>,
     derive_trait: DeriveTrait,
 ) -> proc_macro2::TokenStream {
-    let types = types_from_named_fields(fields);
-    tol_impl(&types, derive_trait)
-}
+    let tol_fields = tol_fields_from_fields(&data_struct.fields);
 
-fn struct_named_fields_fn_output(
-    fields: &FieldsNamed,
-    trait_fn: TraitFn,
-) -> proc_macro2::TokenStream {
-    let idents = idents_from_named_fields(fields);
-    let types = types_from_named_fields(fields);
-    fn_impl_struct(&types, &idents, trait_fn)
+    if tol_fields.is_empty() {
+        return unit_tol_output();
+    }
+
+    let tol_ident = tol_struct_ident(&input.ident, derive_trait);
+    let (_, ty_generics, _) = input.generics.split_for_impl();
+    let default = match default {
+        Some(default) => quote!(#default),
+        None => {
+            let members = tol_fields
+                .iter()
+                .enumerate()
+                .map(|(i, field)| member_from_field(i, field));
+            let defaults = tol_fields
+                .iter()
+                .map(|field| field_default(field, None, derive_trait));
+            quote!(#tol_ident { #(#members: #defaults),* })
+        }
+    };
+
+    quote!(
+        type T = #tol_ident #ty_generics;
+        const DEFAULT: Self::T = #default;
+    )
 }
 
-fn struct_unnamed_fields_tol_output(
-    fields: &FieldsUnnamed,
+/// Returns the tolerance struct generated for `#[nearly(named_tolerance)]`.
+///
+/// The struct has a field for each field compared based on a tolerance. It implements [Clone],
+/// [Copy], [Debug] and [Default], which returns the default tolerance of the derived type.
+fn tol_struct_output(
+    input: &DeriveInput,
+    rhs: Option<&Type>,
     derive_trait: DeriveTrait,
 ) -> proc_macro2::TokenStream {
-    let types = types_from_unnamed_fields(fields);
-    tol_impl(&types, derive_trait)
-}
+    let container_attrs = ContainerAttrs::from_attrs(&input.attrs);
+    if !container_attrs.named_tolerance || rhs.is_some() {
+        return quote!();
+    }
 
-fn struct_unnamed_fields_fn_output(
-    fields: &FieldsUnnamed,
-    trait_fn: TraitFn,
-) -> proc_macro2::TokenStream {
-    let indices = indices_from_unnamed_fields(fields);
-    let types = types_from_unnamed_fields(fields);
-    fn_impl_struct(&types, &indices, trait_fn)
+    let data_struct = match &input.data {
+        Struct(data_struct) => data_struct,
+        _ => abort_call_site!("`named_tolerance` is only supported for structs"),
+    };
+    let tol_fields = tol_fields_from_fields(&data_struct.fields);
+    if tol_fields.is_empty() {
+        return quote!();
+    }
+
+    let DeriveInput {
+        ident,
+        vis,
+        generics,
+        data,
+        ..
+    } = input;
+    let tol = tol_from_trait(derive_trait);
+    let tol_ident = tol_struct_ident(ident, derive_trait);
+    let tol_generics = bounded_generics(generics, None, data, &[quote!(::nearly::#tol)], None);
+    let (impl_generics, ty_generics, where_clause) = tol_generics.split_for_impl();
+    let types = tol_fields.iter().map(|it| &it.ty);
+    let doc = format!("The [{}] values of [{}].", tol, ident);
+
+    let (struct_output, debug_output) = match &data_struct.fields {
+        Named(_) => {
+            let idents: Vec<&Option<Ident>> = tol_fields.iter().map(|it| &it.ident).collect();
+            let names = idents.iter().map(|it| it.to_token_stream().to_string());
+            (
+                quote!(
+                    #vis struct #tol_ident #impl_generics #where_clause {
+                        #(#vis #idents: <#types as ::nearly::#tol>::T),*
+                    }
+                ),
+                quote!(
+                    f.debug_struct(stringify!(#tol_ident))
+                        #(.field(#names, &self.#idents))*
+                        .finish()
+                ),
+            )
+        }
+        _ => {
+            let indices = (0..tol_fields.len()).map(Index::from);
+            (
+                quote!(
+                    #vis struct #tol_ident #impl_generics (
+                        #(#vis <#types as ::nearly::#tol>::T),*
+                    ) #where_clause;
+                ),
+                quote!(
+                    f.debug_tuple(stringify!(#tol_ident))
+                        #(.field(&self.#indices))*
+                        .finish()
+                ),
+            )
+        }
+    };
+
+    quote!(
+        #[doc = #doc]
+        #struct_output
+
+        #[automatically_derived]
+        impl #impl_generics ::core::clone::Clone for #tol_ident #ty_generics #where_clause {
+            fn clone(&self) -> Self {
+                *self
+            }
+        }
+
+        #[automatically_derived]
+        impl #impl_generics ::core::marker::Copy for #tol_ident #ty_generics #where_clause {}
+
+        #[automatically_derived]
+        impl #impl_generics ::core::fmt::Debug for #tol_ident #ty_generics #where_clause {
+            fn fmt(&self, f: &mut ::core::fmt::Formatter<'_>) -> ::core::fmt::Result {
+                #debug_output
+            }
+        }
+
+        #[automatically_derived]
+        impl #impl_generics ::core::default::Default for #tol_ident #ty_generics #where_clause {
+            fn default() -> Self {
+                <#ident #ty_generics as ::nearly::#tol>::DEFAULT
+            }
+        }
+    )
 }
 
-fn struct_unit_tol_output() -> proc_macro2::TokenStream {
+fn unit_tol_output() -> proc_macro2::TokenStream {
     quote!(
         type T = ();
         const DEFAULT: Self::T = ();
     )
 }
 
-fn struct_unit_fn_output() -> proc_macro2::TokenStream {
-    quote!(true)
-}
-
-fn unnamed_variants_from_data_enum(data_enum: &DataEnum) -> Vec<&Variant> {
+fn tol_fields_from_data_enum(data_enum: &DataEnum) -> Vec<&Field> {
     data_enum
         .variants
         .iter()
-        .filter(|it| matches!(it.fields, Unnamed(_)))
+        .flat_map(|it| tol_fields_from_fields(&it.fields))
         .collect()
 }
 
-fn idents_from_variants<'a>(variants: &'a [&Variant]) -> Vec<&'a Ident> {
-    variants.iter().map(|it| &it.ident).collect()
+fn enum_tol_output(
+    data_enum: &DataEnum,
+    default: Option<&Expr>,
+    derive_trait: DeriveTrait,
+) -> proc_macro2::TokenStream {
+    let tol_fields = tol_fields_from_data_enum(data_enum);
+    tol_impl(&tol_fields, None, default, derive_trait)
+}
+
+fn enum_fn_output(
+    data_enum: &DataEnum,
+    enum_ident: &Ident,
+    trait_fn: TraitFn,
+) -> proc_macro2::TokenStream {
+    let tol_fields = tol_fields_from_data_enum(data_enum);
+    fn_impl_enum(data_enum, &tol_fields, enum_ident, trait_fn)
+}
+
+/// Returns the type of the field of `rhs` a field is compared with and whether that field is a
+/// reference.
+///
+/// The type is given by `#[nearly(rhs = ...)]` of the field and is the type of the field itself
+/// by default. A reference is compared by the value it refers to, so its referenced type is
+/// returned.
+fn rhs_field_ty(field: &Field, rhs: &Type) -> (Type, bool) {
+    let attrs = FieldAttrs::from_field(field);
+    match attrs.rhs_ty(rhs) {
+        Some(Type::Reference(reference)) => (*reference.elem.clone(), true),
+        Some(ty) => (ty.clone(), false),
+        None => (field.ty.clone(), false),
+    }
 }
 
-fn types_from_variants<'a>(variants: &'a [&Variant]) -> Vec<&'a Type> {
-    variants
+/// Returns the pairs of the types of the fields and the types of the fields of `rhs` they are
+/// compared with. Without `rhs`, the fields are compared with fields of the same type.
+fn field_ty_pairs(fields: &[&Field], rhs: Option<&Type>) -> Vec<(Type, Type)> {
+    fields
         .iter()
-        .map(|it| match &it.fields {
-            Unnamed(f) => {
-                if let Some(first_field) = f.unnamed.first() {
-                    return &first_field.ty;
-                }
-                abort!(f.unnamed, "Invalid ident of unnamed enum field")
-            }
-            _ => abort!(it, "invalid enum field"),
+        .map(|field| match rhs {
+            Some(rhs) => (field.ty.clone(), rhs_field_ty(field, rhs).0),
+            None => (field.ty.clone(), field.ty.clone()),
         })
         .collect()
 }
 
-fn enum_tol_output(data_enum: &DataEnum, derive_trait: DeriveTrait) -> proc_macro2::TokenStream {
-    let variants = unnamed_variants_from_data_enum(data_enum);
-    let types = types_from_variants(&variants);
-    tol_impl(&types, derive_trait)
+/// Returns whether the tolerance of the fields is the tolerance of a single pair of field types
+/// instead of a tuple of the tolerances of each field.
+///
+/// This is the case if all fields are compared with the same pair of types and none has its own
+/// default tolerance.
+fn is_single_tolerance(tol_fields: &[&Field], rhs: Option<&Type>) -> bool {
+    field_ty_pairs(tol_fields, rhs)
+        .windows(2)
+        .all(|x| x[0] == x[1])
+        && tol_fields
+            .iter()
+            .all(|it| !FieldAttrs::from_field(it).has_defaults())
 }
 
-fn enum_fn_output(
-    data_enum: &DataEnum,
-    enum_ident: &Ident,
-    trait_fn: TraitFn,
-) -> proc_macro2::TokenStream {
-    let variants = unnamed_variants_from_data_enum(data_enum);
-    let idents = idents_from_variants(&variants);
-    let types = types_from_variants(&variants);
-    fn_impl_enum(&types, &idents, enum_ident, trait_fn)
+/// Returns the tolerance trait `tol` of a field compared with the field of `rhs`.
+fn field_tol_trait(field: &Field, rhs: Option<&Type>, tol: &Ident) -> proc_macro2::TokenStream {
+    let ty = &field.ty;
+    match rhs {
+        Some(rhs) => {
+            let (rhs_ty, _) = rhs_field_ty(field, rhs);
+            quote!(<#ty as ::nearly::#tol<#rhs_ty>>)
+        }
+        None => quote!(<#ty as ::nearly::#tol>),
+    }
 }
 
-fn all_types_equal(types: &[&Type]) -> bool {
-    types.windows(2).all(|x| x[0] == x[1])
+/// Returns the default tolerance of a field.
+fn field_default(
+    field: &Field,
+    rhs: Option<&Type>,
+    derive_trait: DeriveTrait,
+) -> proc_macro2::TokenStream {
+    let tol = tol_from_trait(derive_trait);
+    let tol_trait = field_tol_trait(field, rhs, &tol);
+    let attrs = FieldAttrs::from_field(field);
+    match default_from_trait(&attrs.defaults, derive_trait) {
+        Some(default) => quote!(#default),
+        None => quote!(#tol_trait::DEFAULT),
+    }
 }
 
-fn tol_impl(types: &[&Type], derive_trait: DeriveTrait) -> proc_macro2::TokenStream {
+fn tol_impl(
+    tol_fields: &[&Field],
+    rhs: Option<&Type>,
+    default: Option<&Expr>,
+    derive_trait: DeriveTrait,
+) -> proc_macro2::TokenStream {
     let tol = tol_from_trait(derive_trait);
 
-    if all_types_equal(types) {
-        let ty = types.first();
-        if ty.is_none() {
-            abort_call_site!("At least one element must have non unit type");
-        }
+    if tol_fields.is_empty() {
+        return unit_tol_output();
+    }
+
+    let tol_traits: Vec<proc_macro2::TokenStream> = tol_fields
+        .iter()
+        .map(|field| field_tol_trait(field, rhs, &tol))
+        .collect();
+
+    if is_single_tolerance(tol_fields, rhs) {
+        let tol_trait = &tol_traits[0];
+        let default = match default {
+            Some(default) => quote!(#default),
+            None => quote!(#tol_trait::DEFAULT),
+        };
         return quote!(
-            type T = <#ty as ::nearly::#tol>::T;
-            const DEFAULT: Self::T = <#ty as ::nearly::#tol>::DEFAULT;
+            type T = #tol_trait::T;
+            const DEFAULT: Self::T = #default;
         );
     }
 
+    let default = match default {
+        Some(default) => quote!(#default),
+        None => {
+            let defaults = tol_fields
+                .iter()
+                .map(|field| field_default(field, rhs, derive_trait));
+            quote!((#(#defaults),*))
+        }
+    };
+
     quote!(
-        type T = (#(<#types as ::nearly::#tol>::T),*);
-        const DEFAULT: Self::T = (#(<#types as ::nearly::#tol>::DEFAULT),*);
+        type T = (#(#tol_traits::T),*);
+        const DEFAULT: Self::T = #default;
     )
 }
 
-fn fn_impl_struct<T: ToTokens>(
-    types: &[&Type],
-    fields: &[T],
-    trait_fn: TraitFn,
+fn tol_arg(trait_fn: &TraitFn, member: Option<&Member>) -> proc_macro2::TokenStream {
+    match (trait_fn, member) {
+        (TraitFn::Eps(_), None) => quote!(&eps),
+        (TraitFn::Ulps(_), None) => quote!(&ulps),
+        (TraitFn::Rel(_), None) => quote!(&rel),
+        (TraitFn::Tol(_), None) => {
+            quote!(&(tol.eps, tol.ulps, tol.rel, tol.mode, tol.ulps_across_zero).into())
+        }
+        (TraitFn::Eps(_), Some(member)) => quote!(&eps.#member),
+        (TraitFn::Ulps(_), Some(member)) => quote!(&ulps.#member),
+        (TraitFn::Rel(_), Some(member)) => quote!(&rel.#member),
+        (TraitFn::Tol(_), Some(member)) => {
+            quote!(&(
+                tol.eps.#member,
+                tol.ulps.#member,
+                tol.rel.map(|rel| rel.#member),
+                tol.mode,
+                tol.ulps_across_zero,
+            ).into())
+        }
+    }
+}
+
+/// Returns the tolerance arguments for the fields compared based on a tolerance.
+///
+/// If `named_tolerance` is true, the arguments are taken from the fields of the tolerance struct.
+fn tol_args(
+    tol_fields: &[&Field],
+    named_tolerance: bool,
+    rhs: Option<&Type>,
+    trait_fn: &TraitFn,
+) -> Vec<proc_macro2::TokenStream> {
+    if !named_tolerance && is_single_tolerance(tol_fields, rhs) {
+        return tol_fields.iter().map(|_| tol_arg(trait_fn, None)).collect();
+    }
+
+    tol_fields
+        .iter()
+        .enumerate()
+        .map(|(i, field)| {
+            let member = if named_tolerance {
+                member_from_field(i, field)
+            } else {
+                Member::Unnamed(Index {
+                    index: i as u32,
+                    span: proc_macro2::Span::call_site(),
+                })
+            };
+            tol_arg(trait_fn, Some(&member))
+        })
+        .collect()
+}
+
+fn exact_cmp(
+    lhs: &proc_macro2::TokenStream,
+    rhs: &proc_macro2::TokenStream,
+    trait_fn: &TraitFn,
+) -> proc_macro2::TokenStream {
+    match trait_fn.cmp() {
+        Cmp::Eq => quote!(#lhs == #rhs),
+        Cmp::Lt => quote!(#lhs < #rhs),
+        Cmp::Le => quote!(#lhs <= #rhs),
+        Cmp::Gt => quote!(#lhs > #rhs),
+        Cmp::Ge => quote!(#lhs >= #rhs),
+    }
+}
+
+/// Returns the comparisons of all fields that are not skipped.
+///
+/// If no field is compared, the result is the comparison of equal values.
+/// `lhs` and `rhs` return the expressions to access a field by its index and the field itself.
+/// The tolerance arguments for fields compared based on a tolerance are taken from `tols`.
+/// Fields with a `with` module are compared by the function of that module.
+fn field_cmps(
+    fields: &Fields,
+    tols: &mut impl Iterator<Item = proc_macro2::TokenStream>,
+    trait_fn: &TraitFn,
+    lhs: impl Fn(usize, &Field) -> proc_macro2::TokenStream,
+    rhs: impl Fn(usize, &Field) -> proc_macro2::TokenStream,
 ) -> proc_macro2::TokenStream {
     let func = trait_fn.fn_ident();
 
-    if all_types_equal(types) {
-        return match trait_fn {
-            TraitFn::Eps(_) => {
-                quote!(#(self.#fields.#func(&other.#fields, &eps))&&*)
+    let cmps: Vec<proc_macro2::TokenStream> = fields
+        .iter()
+        .enumerate()
+        .filter_map(|(i, field)| {
+            let attrs = FieldAttrs::from_field(field);
+            if attrs.skip {
+                return None;
             }
-            TraitFn::Ulps(_) => {
-                quote!(#(self.#fields.#func(&other.#fields, &ulps))&&*)
+
+            let lhs = lhs(i, field);
+            let rhs = rhs(i, field);
+            if attrs.exact {
+                return Some(exact_cmp(&lhs, &rhs, trait_fn));
             }
-            TraitFn::Tol(_) => {
-                quote!(#(self.#fields.#func(&other.#fields, &(tol.eps, tol.ulps).into()))&&*)
+
+            let tol = tols.next();
+            match attrs.with {
+                Some(with) => Some(quote!(#with::#func(&#lhs, &#rhs, #tol))),
+                None => Some(quote!(#lhs.#func(&#rhs, #tol))),
             }
+        })
+        .collect();
+
+    // without any compared fields the values are equal, like the values of `()`
+    if cmps.is_empty() {
+        return match trait_fn.cmp() {
+            Cmp::Eq | Cmp::Le | Cmp::Ge => quote!(true),
+            Cmp::Lt | Cmp::Gt => quote!(false),
         };
     }
 
-    let indices: Vec<Index> = (0..types.len())
-        .map(|i| Index {
-            index: i as u32,
-            span: proc_macro2::Span::call_site(),
-        })
-        .collect();
+    quote!(#(#cmps)&&*)
+}
 
-    match trait_fn {
-        TraitFn::Eps(_) => {
-            quote!(#(self.#fields.#func(&other.#fields, &eps.#indices))&&*)
-        }
-        TraitFn::Ulps(_) => {
-            quote!(#(self.#fields.#func(&other.#fields, &ulps.#indices))&&*)
-        }
-        TraitFn::Tol(_) => {
-            quote!(#(self.#fields.#func(&other.#fields, &(tol.eps.#indices, tol.ulps.#indices).into()))&&*)
-        }
+/// Returns the expression to access the field of `other` a field of a struct is compared with.
+///
+/// A field of `rhs` that is a reference is dereferenced.
+fn other_field(index: usize, field: &Field, rhs: Option<&Type>) -> proc_macro2::TokenStream {
+    let member = member_from_field(index, field);
+    match rhs.map(|rhs| rhs_field_ty(field, rhs)) {
+        Some((_, true)) => quote!((*other.#member)),
+        _ => quote!(other.#member),
     }
 }
 
+fn fn_impl_struct(
+    fields: &Fields,
+    tol_fields: &[&Field],
+    named_tolerance: bool,
+    rhs: Option<&Type>,
+    trait_fn: TraitFn,
+) -> proc_macro2::TokenStream {
+    field_cmps(
+        fields,
+        &mut tol_args(tol_fields, named_tolerance, rhs, &trait_fn).into_iter(),
+        &trait_fn,
+        |i, field| {
+            let member = member_from_field(i, field);
+            quote!(self.#member)
+        },
+        |i, field| other_field(i, field, rhs),
+    )
+}
+
 fn fn_impl_enum(
-    types: &[&Type],
-    idents: &[&Ident],
+    data_enum: &DataEnum,
+    tol_fields: &[&Field],
     enum_ident: &Ident,
     trait_fn: TraitFn,
 ) -> proc_macro2::TokenStream {
-    let func = trait_fn.fn_ident();
-
-    let value_match = if all_types_equal(types) {
-        match trait_fn {
-            TraitFn::Eps(_) => {
-                quote!(#((#enum_ident::#idents(self_val), #enum_ident::#idents(other_val)) => {
-                    self_val.#func(&other_val, &eps)
-                })*)
+    let mut tols = tol_args(tol_fields, false, None, &trait_fn).into_iter();
+
+    let value_match = data_enum.variants.iter().map(|variant| {
+        let ident = &variant.ident;
+        let self_val = |i: usize| format_ident!("self_{}", i);
+        let other_val = |i: usize| format_ident!("other_{}", i);
+
+        let (self_elems, other_elems): (Vec<_>, Vec<_>) = variant
+            .fields
+            .iter()
+            .enumerate()
+            .map(|(i, field)| {
+                if FieldAttrs::from_field(field).skip {
+                    (quote!(_), quote!(_))
+                } else {
+                    let (self_val, other_val) = (self_val(i), other_val(i));
+                    (quote!(#self_val), quote!(#other_val))
+                }
+            })
+            .unzip();
+
+        let (self_pattern, other_pattern) = match &variant.fields {
+            Named(fields) => {
+                let idents: Vec<&Option<Ident>> = fields.named.iter().map(|it| &it.ident).collect();
+                (
+                    quote!(#enum_ident::#ident { #(#idents: #self_elems),* }),
+                    quote!(#enum_ident::#ident { #(#idents: #other_elems),* }),
+                )
             }
-            TraitFn::Ulps(_) => {
-                quote!(#((#enum_ident::#idents(self_val), #enum_ident::#idents(other_val)) => {
-                    self_val.#func(&other_val, &ulps)
-                })*)
+            Unnamed(_) => (
+                quote!(#enum_ident::#ident(#(#self_elems),*)),
+                quote!(#enum_ident::#ident(#(#other_elems),*)),
+            ),
+            Unit => (quote!(#enum_ident::#ident), quote!(#enum_ident::#ident)),
+        };
+
+        // the bindings are references, dereference them to access the fields like for structs
+        let cmps = field_cmps(
+            &variant.fields,
+            &mut tols,
+            &trait_fn,
+            |i, _| {
+                let self_val = self_val(i);
+                quote!((*#self_val))
+            },
+            |i, _| {
+                let other_val = other_val(i);
+                quote!((*#other_val))
+            },
+        );
+
+        quote!((#self_pattern, #other_pattern) => #cmps,)
+    });
+
+    quote!(
+        match (self, other) {
+            #(#value_match)*
+            #[allow(unreachable_patterns)]
+            _ => false,
+        }
+    )
+}
+
+/// Returns whether `tokens` contain `ident`.
+fn mentions(tokens: proc_macro2::TokenStream, ident: &Ident) -> bool {
+    tokens.into_iter().any(|it| match it {
+        proc_macro2::TokenTree::Ident(it) => it == *ident,
+        proc_macro2::TokenTree::Group(group) => mentions(group.stream(), ident),
+        _ => false,
+    })
+}
+
+/// Collects all lifetimes contained in `tokens`.
+fn lifetimes(tokens: proc_macro2::TokenStream, lifetimes: &mut Vec<Lifetime>) {
+    let mut tokens = tokens.into_iter().peekable();
+    while let Some(token) = tokens.next() {
+        match token {
+            proc_macro2::TokenTree::Punct(punct) if punct.as_char() == '\'' => {
+                if let Some(proc_macro2::TokenTree::Ident(ident)) = tokens.peek() {
+                    let lifetime = Lifetime {
+                        apostrophe: punct.span(),
+                        ident: ident.clone(),
+                    };
+                    if !lifetimes.contains(&lifetime) {
+                        lifetimes.push(lifetime);
+                    }
+                }
             }
-            TraitFn::Tol(_) => {
-                quote!(#((#enum_ident::#idents(self_val), #enum_ident::#idents(other_val)) => {
-                    self_val.#func(&other_val, &(tol.eps, tol.ulps).into())
-                })*)
+            proc_macro2::TokenTree::Group(group) => self::lifetimes(group.stream(), lifetimes),
+            _ => {}
+        }
+    }
+}
+
+/// Returns the generic arguments of the derived traits and the type the derived type is compared
+/// with.
+fn rhs_output(rhs: Option<&Type>) -> (proc_macro2::TokenStream, proc_macro2::TokenStream) {
+    match rhs {
+        Some(rhs) => (quote!(<#rhs>), quote!(#rhs)),
+        None => (quote!(), quote!(Self)),
+    }
+}
+
+fn fields_from_data(data: &Data) -> Vec<&Field> {
+    match data {
+        Struct(data_struct) => data_struct.fields.iter().collect(),
+        Enum(data_enum) => data_enum
+            .variants
+            .iter()
+            .flat_map(|it| it.fields.iter())
+            .collect(),
+        Union(_data_union) => abort_call_site!("nearly traits cannot be derived for unions"),
+    }
+}
+
+/// Returns `generics` with additional bounds.
+///
+/// The lifetimes of `rhs` that are not declared by `generics` are added as lifetime parameters.
+/// Every type parameter that is used by a field compared based on a tolerance is bound by the
+/// traits `bounds`. Every type of an exactly compared field that uses a type parameter is bound
+/// by the trait `exact_bounds`.
+///
+/// With `rhs`, the type of each field that uses a type parameter is bound instead, with the
+/// type of the field of `rhs` it is compared with as the generic argument of the traits.
+fn bounded_generics(
+    generics: &Generics,
+    rhs: Option<&Type>,
+    data: &Data,
+    bounds: &[proc_macro2::TokenStream],
+    exact_bounds: Option<proc_macro2::TokenStream>,
+) -> Generics {
+    let fields = fields_from_data(data);
+    let mut generics = generics.clone();
+
+    if let Some(rhs) = rhs {
+        let mut rhs_lifetimes = Vec::new();
+        lifetimes(rhs.to_token_stream(), &mut rhs_lifetimes);
+        for lifetime in rhs_lifetimes {
+            if lifetime.ident == "_"
+                || lifetime.ident == "static"
+                || generics.lifetimes().any(|it| it.lifetime == lifetime)
+            {
+                continue;
             }
+            generics
+                .params
+                .insert(0, GenericParam::Lifetime(LifetimeParam::new(lifetime)));
         }
-    } else {
-        let indices: Vec<Index> = (0..types.len())
-            .map(|i| Index {
-                index: i as u32,
-                span: proc_macro2::Span::call_site(),
-            })
-            .collect();
+    }
+
+    let params: Vec<Ident> = generics.type_params().map(|it| it.ident.clone()).collect();
+    let uses_param = |ty: &Type| {
+        params
+            .iter()
+            .any(|param| mentions(ty.to_token_stream(), param))
+    };
+    let where_clause = generics.make_where_clause();
 
-        match trait_fn {
-            TraitFn::Eps(_) => {
-                quote!(#((#enum_ident::#idents(self_val), #enum_ident::#idents(other_val)) => {
-                    self_val.#func(&other_val, &eps.#indices)
-                })*)
+    if let Some(rhs) = rhs {
+        let tol_fields: Vec<&Field> = fields
+            .iter()
+            .copied()
+            .filter(|it| uses_tolerance(it))
+            .collect();
+        for (ty, rhs_ty) in field_ty_pairs(&tol_fields, Some(rhs)) {
+            if uses_param(&ty) || uses_param(&rhs_ty) {
+                where_clause
+                    .predicates
+                    .push(parse_quote!(#ty: #(#bounds<#rhs_ty>)+*));
             }
-            TraitFn::Ulps(_) => {
-                quote!(#((#enum_ident::#idents(self_val), #enum_ident::#idents(other_val)) => {
-                    self_val.#func(&other_val, &ulps.#indices)
-                })*)
+        }
+    } else {
+        for param in &params {
+            if fields
+                .iter()
+                .filter(|it| uses_tolerance(it))
+                .any(|it| mentions(it.ty.to_token_stream(), param))
+            {
+                where_clause
+                    .predicates
+                    .push(parse_quote!(#param: #(#bounds)+*));
             }
-            TraitFn::Tol(_) => {
-                quote!(#((#enum_ident::#idents(self_val), #enum_ident::#idents(other_val)) => {
-                    self_val.#func(&other_val, &(tol.eps.#indices, tol.ulps.#indices).into())
-                })*)
+        }
+    }
+
+    if let Some(exact_bounds) = exact_bounds {
+        for field in fields.iter().filter(|it| FieldAttrs::from_field(it).exact) {
+            let ty = &field.ty;
+            let rhs_ty = match rhs {
+                Some(rhs) => rhs_field_ty(field, rhs).0,
+                None => ty.clone(),
+            };
+            if uses_param(ty) || uses_param(&rhs_ty) {
+                where_clause
+                    .predicates
+                    .push(parse_quote!(#ty: #exact_bounds<#rhs_ty>));
             }
         }
-    };
+    }
+
+    generics
+}
+
+fn derive_nearly_eq_eps(input: &DeriveInput, rhs: Option<&Type>) -> proc_macro2::TokenStream {
+    let DeriveInput {
+        ident,
+        data,
+        generics,
+        ..
+    } = input;
+    let (_, ty_generics, _) = generics.split_for_impl();
+    let (rhs_generics, rhs_ty) = rhs_output(rhs);
+    let tol_generics =
+        bounded_generics(generics, rhs, data, &[quote!(::nearly::EpsTolerance)], None);
+    let (tol_impl_generics, _, tol_where_clause) = tol_generics.split_for_impl();
+    let trait_generics = bounded_generics(
+        generics,
+        rhs,
+        data,
+        &[
+            quote!(::nearly::NearlyEqEps),
+            quote!(::nearly::EpsTolerance),
+        ],
+        Some(quote!(::core::cmp::PartialEq)),
+    );
+    let (trait_impl_generics, _, trait_where_clause) = trait_generics.split_for_impl();
+    let tol_output = tol_output(input, rhs, DeriveTrait::NearlyEqEps);
+    let tol_struct_output = tol_struct_output(input, rhs, DeriveTrait::NearlyEqEps);
+    let fn_output = fn_output(input, rhs, TraitFn::Eps(Cmp::Eq));
 
     quote!(
-        let self_tag = core::mem::discriminant(self);
-        let other_tag = core::mem::discriminant(other);
+        #tol_struct_output
 
-        self_tag == other_tag &&
-        match (self, other) {
-            #value_match
-            _ => true,
+        #[automatically_derived]
+        impl #tol_impl_generics ::nearly::EpsTolerance #rhs_generics for #ident #ty_generics #tol_where_clause {
+            #tol_output
+        }
+
+        #[automatically_derived]
+        impl #trait_impl_generics ::nearly::NearlyEqEps #rhs_generics for #ident #ty_generics #trait_where_clause {
+            fn nearly_eq_eps(&self, other: &#rhs_ty, eps: &::nearly::EpsToleranceType<Self, #rhs_ty>) -> bool {
+                #fn_output
+            }
         }
     )
 }
 
-fn derive_nearly_eq_eps(data: &Data, ident: &Ident) -> proc_macro2::TokenStream {
-    let tol_output = tol_output(data, DeriveTrait::NearlyEqEps);
-    let fn_output = fn_output(data, ident, TraitFn::Eps(Cmp::Eq));
+fn derive_nearly_eq_ulps(input: &DeriveInput, rhs: Option<&Type>) -> proc_macro2::TokenStream {
+    let DeriveInput {
+        ident,
+        data,
+        generics,
+        ..
+    } = input;
+    let (_, ty_generics, _) = generics.split_for_impl();
+    let (rhs_generics, rhs_ty) = rhs_output(rhs);
+    let tol_generics = bounded_generics(
+        generics,
+        rhs,
+        data,
+        &[quote!(::nearly::UlpsTolerance)],
+        None,
+    );
+    let (tol_impl_generics, _, tol_where_clause) = tol_generics.split_for_impl();
+    let trait_generics = bounded_generics(
+        generics,
+        rhs,
+        data,
+        &[
+            quote!(::nearly::NearlyEqUlps),
+            quote!(::nearly::UlpsTolerance),
+        ],
+        Some(quote!(::core::cmp::PartialEq)),
+    );
+    let (trait_impl_generics, _, trait_where_clause) = trait_generics.split_for_impl();
+    let tol_output = tol_output(input, rhs, DeriveTrait::NearlyEqUlps);
+    let tol_struct_output = tol_struct_output(input, rhs, DeriveTrait::NearlyEqUlps);
+    let fn_output = fn_output(input, rhs, TraitFn::Ulps(Cmp::Eq));
 
     quote!(
+        #tol_struct_output
+
         #[automatically_derived]
-        impl ::nearly::EpsTolerance for #ident {
+        impl #tol_impl_generics ::nearly::UlpsTolerance #rhs_generics for #ident #ty_generics #tol_where_clause {
             #tol_output
         }
 
         #[automatically_derived]
-        impl ::nearly::NearlyEqEps for #ident {
-            fn nearly_eq_eps(&self, other: &Self, eps: &::nearly::EpsToleranceType<Self>) -> bool {
+        impl #trait_impl_generics ::nearly::NearlyEqUlps #rhs_generics for #ident #ty_generics #trait_where_clause {
+            fn nearly_eq_ulps(&self, other: &#rhs_ty, ulps: &::nearly::UlpsToleranceType<Self, #rhs_ty>) -> bool {
                 #fn_output
             }
         }
     )
 }
 
-fn derive_nearly_eq_ulps(data: &Data, ident: &Ident) -> proc_macro2::TokenStream {
-    let tol_output = tol_output(data, DeriveTrait::NearlyEqUlps);
-    let fn_output = fn_output(data, ident, TraitFn::Ulps(Cmp::Eq));
+fn derive_nearly_eq_rel(input: &DeriveInput, rhs: Option<&Type>) -> proc_macro2::TokenStream {
+    let DeriveInput {
+        ident,
+        data,
+        generics,
+        ..
+    } = input;
+    let (_, ty_generics, _) = generics.split_for_impl();
+    let (rhs_generics, rhs_ty) = rhs_output(rhs);
+    let tol_generics =
+        bounded_generics(generics, rhs, data, &[quote!(::nearly::RelTolerance)], None);
+    let (tol_impl_generics, _, tol_where_clause) = tol_generics.split_for_impl();
+    let trait_generics = bounded_generics(
+        generics,
+        rhs,
+        data,
+        &[
+            quote!(::nearly::NearlyEqRel),
+            quote!(::nearly::RelTolerance),
+        ],
+        Some(quote!(::core::cmp::PartialEq)),
+    );
+    let (trait_impl_generics, _, trait_where_clause) = trait_generics.split_for_impl();
+    let tol_output = tol_output(input, rhs, DeriveTrait::NearlyEqRel);
+    let tol_struct_output = tol_struct_output(input, rhs, DeriveTrait::NearlyEqRel);
+    let fn_output = fn_output(input, rhs, TraitFn::Rel(Cmp::Eq));
 
     quote!(
+        #tol_struct_output
+
         #[automatically_derived]
-        impl ::nearly::UlpsTolerance for #ident {
+        impl #tol_impl_generics ::nearly::RelTolerance #rhs_generics for #ident #ty_generics #tol_where_clause {
             #tol_output
         }
 
         #[automatically_derived]
-        impl ::nearly::NearlyEqUlps for #ident {
-            fn nearly_eq_ulps(&self, other: &Self, ulps: &::nearly::UlpsToleranceType<Self>) -> bool {
+        impl #trait_impl_generics ::nearly::NearlyEqRel #rhs_generics for #ident #ty_generics #trait_where_clause {
+            fn nearly_eq_rel(&self, other: &#rhs_ty, rel: &::nearly::RelToleranceType<Self, #rhs_ty>) -> bool {
                 #fn_output
             }
         }
     )
 }
 
-fn derive_nearly_eq_tol(data: &Data, ident: &Ident) -> proc_macro2::TokenStream {
-    let eps_output = derive_nearly_eq_eps(data, ident);
-    let ulps_output = derive_nearly_eq_ulps(data, ident);
-    let fn_output = fn_output(data, ident, TraitFn::Tol(Cmp::Eq));
+fn derive_nearly_eq_tol(input: &DeriveInput, rhs: Option<&Type>) -> proc_macro2::TokenStream {
+    let DeriveInput {
+        ident,
+        data,
+        generics,
+        ..
+    } = input;
+    let (_, ty_generics, _) = generics.split_for_impl();
+    let (rhs_generics, rhs_ty) = rhs_output(rhs);
+    let trait_generics = bounded_generics(
+        generics,
+        rhs,
+        data,
+        &[
+            quote!(::nearly::NearlyEqTol),
+            quote!(::nearly::EpsTolerance),
+            quote!(::nearly::UlpsTolerance),
+            quote!(::nearly::RelTolerance),
+        ],
+        Some(quote!(::core::cmp::PartialEq)),
+    );
+    let (trait_impl_generics, _, trait_where_clause) = trait_generics.split_for_impl();
+    let eps_output = derive_nearly_eq_eps(input, rhs);
+    let ulps_output = derive_nearly_eq_ulps(input, rhs);
+    let rel_output = derive_nearly_eq_rel(input, rhs);
+    let fn_output = fn_output(input, rhs, TraitFn::Tol(Cmp::Eq));
 
     quote!(
         #eps_output
         #ulps_output
+        #rel_output
 
         #[automatically_derived]
-        impl ::nearly::NearlyEqTol for #ident {
-            fn nearly_eq_tol(&self, other: &Self, tol: &::nearly::Tolerance<Self>) -> bool {
+        impl #trait_impl_generics ::nearly::NearlyEqTol #rhs_generics for #ident #ty_generics #trait_where_clause {
+            fn nearly_eq_tol(&self, other: &#rhs_ty, tol: &::nearly::Tolerance<Self, #rhs_ty>) -> bool {
                 #fn_output
             }
         }
     )
 }
 
-fn derive_nearly_eq(data: &Data, ident: &Ident) -> proc_macro2::TokenStream {
-    let tol_output = derive_nearly_eq_tol(data, ident);
+fn derive_nearly_eq(input: &DeriveInput, rhs: Option<&Type>) -> proc_macro2::TokenStream {
+    let DeriveInput {
+        ident,
+        data,
+        generics,
+        ..
+    } = input;
+    let (_, ty_generics, _) = generics.split_for_impl();
+    let (rhs_generics, _) = rhs_output(rhs);
+    let trait_generics = bounded_generics(
+        generics,
+        rhs,
+        data,
+        &[
+            quote!(::nearly::NearlyEq),
+            quote!(::nearly::EpsTolerance),
+            quote!(::nearly::UlpsTolerance),
+            quote!(::nearly::RelTolerance),
+        ],
+        Some(quote!(::core::cmp::PartialEq)),
+    );
+    let (trait_impl_generics, _, trait_where_clause) = trait_generics.split_for_impl();
+    let tol_output = derive_nearly_eq_tol(input, rhs);
 
     quote!(
         #tol_output
 
         #[automatically_derived]
-        impl ::nearly::NearlyEq for #ident {}
+        impl #trait_impl_generics ::nearly::NearlyEq #rhs_generics for #ident #ty_generics #trait_where_clause {}
+    )
+}
+
+/// Returns the [ReportTolerance] of a field compared based on a tolerance.
+///
+/// If `member` is present, the field's tolerance is the `member` of the tolerance of the derived
+/// type. Otherwise, it is the tolerance of the derived type converted to the field's type.
+fn report_tol_arg(member: Option<&Member>) -> proc_macro2::TokenStream {
+    let member = member.map(|member| quote!(.#member));
+    quote!(
+        match *tol {
+            ::nearly::ReportTolerance::Eps(eps) => ::nearly::ReportTolerance::Eps(eps #member),
+            ::nearly::ReportTolerance::Ulps(ulps) => ::nearly::ReportTolerance::Ulps(ulps #member),
+            ::nearly::ReportTolerance::Rel(rel) => ::nearly::ReportTolerance::Rel(rel #member),
+            ::nearly::ReportTolerance::Tol(tol) => ::nearly::ReportTolerance::Tol(
+                (
+                    tol.eps #member,
+                    tol.ulps #member,
+                    tol.rel.map(|rel| rel #member),
+                    tol.mode,
+                    tol.ulps_across_zero,
+                )
+                    .into(),
+            ),
+            ::nearly::ReportTolerance::EpsAndRel(eps, rel) => {
+                ::nearly::ReportTolerance::EpsAndRel(eps #member, rel #member)
+            }
+            ::nearly::ReportTolerance::UlpsAndRel(ulps, rel) => {
+                ::nearly::ReportTolerance::UlpsAndRel(ulps #member, rel #member)
+            }
+        }
+    )
+}
+
+/// Returns the [ReportTolerance] arguments for the fields compared based on a tolerance.
+///
+/// See [tol_args].
+fn report_tol_args(
+    tol_fields: &[&Field],
+    named_tolerance: bool,
+    rhs: Option<&Type>,
+) -> Vec<proc_macro2::TokenStream> {
+    if !named_tolerance && is_single_tolerance(tol_fields, rhs) {
+        return tol_fields.iter().map(|_| report_tol_arg(None)).collect();
+    }
+
+    tol_fields
+        .iter()
+        .enumerate()
+        .map(|(i, field)| {
+            let member = if named_tolerance {
+                member_from_field(i, field)
+            } else {
+                Member::Unnamed(Index {
+                    index: i as u32,
+                    span: proc_macro2::Span::call_site(),
+                })
+            };
+            report_tol_arg(Some(&member))
+        })
+        .collect()
+}
+
+/// Returns the name of a field in the path of a mismatch.
+fn field_name(index: usize, field: &Field) -> String {
+    match &field.ident {
+        Some(ident) => ident.unraw().to_string(),
+        None => index.to_string(),
+    }
+}
+
+/// Returns the statements adding the mismatches of all fields that are not skipped to `report`.
+///
+/// Exactly compared fields and fields with a `with` module are reported as not equal.
+/// See [field_cmps].
+fn field_reports(
+    fields: &Fields,
+    tols: &mut impl Iterator<Item = proc_macro2::TokenStream>,
+    lhs: impl Fn(usize, &Field) -> proc_macro2::TokenStream,
+    rhs: impl Fn(usize, &Field) -> proc_macro2::TokenStream,
+) -> proc_macro2::TokenStream {
+    let reports = fields.iter().enumerate().filter_map(|(i, field)| {
+        let attrs = FieldAttrs::from_field(field);
+        if attrs.skip {
+            return None;
+        }
+
+        let name = field_name(i, field);
+        let lhs = lhs(i, field);
+        let rhs = rhs(i, field);
+        let compare = quote!(
+            report.field(#name, |report| {
+                report.compare(eq, || ::nearly::MismatchKind::NotEqual)
+            });
+        );
+
+        if attrs.exact {
+            return Some(quote!(
+                let eq = #lhs == #rhs;
+                #compare
+            ));
+        }
+
+        let tol = tols.next();
+        match attrs.with {
+            Some(with) => Some(quote!(
+                let eq = match #tol {
+                    ::nearly::ReportTolerance::Eps(eps) => #with::nearly_eq_eps(&#lhs, &#rhs, &eps),
+                    ::nearly::ReportTolerance::Ulps(ulps) => {
+                        #with::nearly_eq_ulps(&#lhs, &#rhs, &ulps)
+                    }
+                    ::nearly::ReportTolerance::Rel(rel) => #with::nearly_eq_rel(&#lhs, &#rhs, &rel),
+                    ::nearly::ReportTolerance::Tol(tol) => #with::nearly_eq_tol(&#lhs, &#rhs, &tol),
+                    ::nearly::ReportTolerance::EpsAndRel(eps, rel) => {
+                        #with::nearly_eq_eps(&#lhs, &#rhs, &eps)
+                            || #with::nearly_eq_rel(&#lhs, &#rhs, &rel)
+                    }
+                    ::nearly::ReportTolerance::UlpsAndRel(ulps, rel) => {
+                        #with::nearly_eq_ulps(&#lhs, &#rhs, &ulps)
+                            || #with::nearly_eq_rel(&#lhs, &#rhs, &rel)
+                    }
+                };
+                #compare
+            )),
+            None => Some(quote!(
+                let field_tol = #tol;
+                report.field(#name, |report| #lhs.report_nearly_eq(&#rhs, &field_tol, report));
+            )),
+        }
+    });
+
+    quote!(#({ #reports })*)
+}
+
+fn report_fn_output(input: &DeriveInput, rhs: Option<&Type>) -> proc_macro2::TokenStream {
+    let container_attrs = ContainerAttrs::from_attrs(&input.attrs);
+    let named_tolerance = container_attrs.named_tolerance && rhs.is_none();
+
+    match &input.data {
+        Struct(data_struct) => {
+            let tol_fields = tol_fields_from_fields(&data_struct.fields);
+            let tols = report_tol_args(&tol_fields, named_tolerance, rhs);
+            field_reports(
+                &data_struct.fields,
+                &mut tols.into_iter(),
+                |i, field| {
+                    let member = member_from_field(i, field);
+                    quote!(self.#member)
+                },
+                |i, field| other_field(i, field, rhs),
+            )
+        }
+        Enum(data_enum) => report_fn_enum(data_enum, &input.ident),
+        Union(_data_union) => abort_call_site!("nearly traits cannot be derived for unions"),
+    }
+}
+
+fn report_fn_enum(data_enum: &DataEnum, enum_ident: &Ident) -> proc_macro2::TokenStream {
+    let tol_fields = tol_fields_from_data_enum(data_enum);
+    let mut tols = report_tol_args(&tol_fields, false, None).into_iter();
+
+    let value_match = data_enum.variants.iter().map(|variant| {
+        let ident = &variant.ident;
+        let self_val = |i: usize| format_ident!("self_{}", i);
+        let other_val = |i: usize| format_ident!("other_{}", i);
+
+        let (self_elems, other_elems): (Vec<_>, Vec<_>) = variant
+            .fields
+            .iter()
+            .enumerate()
+            .map(|(i, field)| {
+                if FieldAttrs::from_field(field).skip {
+                    (quote!(_), quote!(_))
+                } else {
+                    let (self_val, other_val) = (self_val(i), other_val(i));
+                    (quote!(#self_val), quote!(#other_val))
+                }
+            })
+            .unzip();
+
+        let (self_pattern, other_pattern) = match &variant.fields {
+            Named(fields) => {
+                let idents: Vec<&Option<Ident>> = fields.named.iter().map(|it| &it.ident).collect();
+                (
+                    quote!(#enum_ident::#ident { #(#idents: #self_elems),* }),
+                    quote!(#enum_ident::#ident { #(#idents: #other_elems),* }),
+                )
+            }
+            Unnamed(_) => (
+                quote!(#enum_ident::#ident(#(#self_elems),*)),
+                quote!(#enum_ident::#ident(#(#other_elems),*)),
+            ),
+            Unit => (quote!(#enum_ident::#ident), quote!(#enum_ident::#ident)),
+        };
+
+        // the bindings are references, dereference them to access the fields like for structs
+        let reports = field_reports(
+            &variant.fields,
+            &mut tols,
+            |i, _| {
+                let self_val = self_val(i);
+                quote!((*#self_val))
+            },
+            |i, _| {
+                let other_val = other_val(i);
+                quote!((*#other_val))
+            },
+        );
+
+        // the variant is part of the path, since variants may share their field names
+        let compared = variant
+            .fields
+            .iter()
+            .any(|field| !FieldAttrs::from_field(field).skip);
+        if !compared {
+            return quote!((#self_pattern, #other_pattern) => {});
+        }
+        let name = ident.unraw().to_string();
+        quote!((#self_pattern, #other_pattern) => report.field(#name, |report| { #reports }),)
+    });
+
+    let variant_names = data_enum.variants.iter().map(|variant| {
+        let ident = &variant.ident;
+        let name = ident.unraw().to_string();
+        quote!(#enum_ident::#ident { .. } => #name,)
+    });
+    let variant_names = quote!(#(#variant_names)*);
+
+    quote!(
+        match (self, other) {
+            #(#value_match)*
+            #[allow(unreachable_patterns)]
+            _ => report.push(::nearly::MismatchKind::Variant {
+                lhs: match self { #variant_names },
+                rhs: match other { #variant_names },
+            }),
+        }
+    )
+}
+
+fn derive_nearly_eq_report(input: &DeriveInput, rhs: Option<&Type>) -> proc_macro2::TokenStream {
+    let DeriveInput {
+        ident,
+        data,
+        generics,
+        ..
+    } = input;
+    let (_, ty_generics, _) = generics.split_for_impl();
+    let (rhs_generics, rhs_ty) = rhs_output(rhs);
+    let trait_generics = bounded_generics(
+        generics,
+        rhs,
+        data,
+        &[
+            quote!(::nearly::NearlyEqReport),
+            quote!(::nearly::EpsTolerance),
+            quote!(::nearly::UlpsTolerance),
+            quote!(::nearly::RelTolerance),
+        ],
+        Some(quote!(::core::cmp::PartialEq)),
+    );
+    let (trait_impl_generics, _, trait_where_clause) = trait_generics.split_for_impl();
+    let fn_output = report_fn_output(input, rhs);
+
+    quote!(
+        #[automatically_derived]
+        impl #trait_impl_generics ::nearly::NearlyEqReport #rhs_generics for #ident #ty_generics #trait_where_clause {
+            fn report_nearly_eq(
+                &self,
+                other: &#rhs_ty,
+                tol: &::nearly::ReportTolerance<Self, #rhs_ty>,
+                report: &mut ::nearly::Report,
+            ) {
+                #fn_output
+            }
+        }
     )
 }
 
-fn derive_nearly_ord_eps(data: &Data, ident: &Ident) -> proc_macro2::TokenStream {
-    let lt_fn_output = fn_output(data, ident, TraitFn::Eps(Cmp::Lt));
-    let le_fn_output = fn_output(data, ident, TraitFn::Eps(Cmp::Le));
-    let gt_fn_output = fn_output(data, ident, TraitFn::Eps(Cmp::Gt));
-    let ge_fn_output = fn_output(data, ident, TraitFn::Eps(Cmp::Ge));
+fn derive_nearly_ord_eps(input: &DeriveInput, rhs: Option<&Type>) -> proc_macro2::TokenStream {
+    let DeriveInput {
+        ident,
+        data,
+        generics,
+        ..
+    } = input;
+    let (_, ty_generics, _) = generics.split_for_impl();
+    let (rhs_generics, rhs_ty) = rhs_output(rhs);
+    let trait_generics = bounded_generics(
+        generics,
+        rhs,
+        data,
+        &[
+            quote!(::nearly::NearlyOrdEps),
+            quote!(::nearly::EpsTolerance),
+        ],
+        Some(quote!(::core::cmp::PartialOrd)),
+    );
+    let (trait_impl_generics, _, trait_where_clause) = trait_generics.split_for_impl();
+    let lt_fn_output = fn_output(input, rhs, TraitFn::Eps(Cmp::Lt));
+    let le_fn_output = fn_output(input, rhs, TraitFn::Eps(Cmp::Le));
+    let gt_fn_output = fn_output(input, rhs, TraitFn::Eps(Cmp::Gt));
+    let ge_fn_output = fn_output(input, rhs, TraitFn::Eps(Cmp::Ge));
 
     quote!(
         #[automatically_derived]
-        impl ::nearly::NearlyOrdEps for #ident {
-            fn nearly_lt_eps(&self, other: &Self, eps: &::nearly::EpsToleranceType<Self>) -> bool {
+        impl #trait_impl_generics ::nearly::NearlyOrdEps #rhs_generics for #ident #ty_generics #trait_where_clause {
+            fn nearly_lt_eps(&self, other: &#rhs_ty, eps: &::nearly::EpsToleranceType<Self, #rhs_ty>) -> bool {
                 #lt_fn_output
             }
-            fn nearly_le_eps(&self, other: &Self, eps: &::nearly::EpsToleranceType<Self>) -> bool {
+            fn nearly_le_eps(&self, other: &#rhs_ty, eps: &::nearly::EpsToleranceType<Self, #rhs_ty>) -> bool {
                 #le_fn_output
             }
-            fn nearly_gt_eps(&self, other: &Self, eps: &::nearly::EpsToleranceType<Self>) -> bool {
+            fn nearly_gt_eps(&self, other: &#rhs_ty, eps: &::nearly::EpsToleranceType<Self, #rhs_ty>) -> bool {
                 #gt_fn_output
             }
-            fn nearly_ge_eps(&self, other: &Self, eps: &::nearly::EpsToleranceType<Self>) -> bool {
+            fn nearly_ge_eps(&self, other: &#rhs_ty, eps: &::nearly::EpsToleranceType<Self, #rhs_ty>) -> bool {
                 #ge_fn_output
             }
         }
     )
 }
 
-fn derive_nearly_ord_ulps(data: &Data, ident: &Ident) -> proc_macro2::TokenStream {
-    let lt_fn_output = fn_output(data, ident, TraitFn::Ulps(Cmp::Lt));
-    let le_fn_output = fn_output(data, ident, TraitFn::Ulps(Cmp::Le));
-    let gt_fn_output = fn_output(data, ident, TraitFn::Ulps(Cmp::Gt));
-    let ge_fn_output = fn_output(data, ident, TraitFn::Ulps(Cmp::Ge));
+fn derive_nearly_ord_ulps(input: &DeriveInput, rhs: Option<&Type>) -> proc_macro2::TokenStream {
+    let DeriveInput {
+        ident,
+        data,
+        generics,
+        ..
+    } = input;
+    let (_, ty_generics, _) = generics.split_for_impl();
+    let (rhs_generics, rhs_ty) = rhs_output(rhs);
+    let trait_generics = bounded_generics(
+        generics,
+        rhs,
+        data,
+        &[
+            quote!(::nearly::NearlyOrdUlps),
+            quote!(::nearly::UlpsTolerance),
+        ],
+        Some(quote!(::core::cmp::PartialOrd)),
+    );
+    let (trait_impl_generics, _, trait_where_clause) = trait_generics.split_for_impl();
+    let lt_fn_output = fn_output(input, rhs, TraitFn::Ulps(Cmp::Lt));
+    let le_fn_output = fn_output(input, rhs, TraitFn::Ulps(Cmp::Le));
+    let gt_fn_output = fn_output(input, rhs, TraitFn::Ulps(Cmp::Gt));
+    let ge_fn_output = fn_output(input, rhs, TraitFn::Ulps(Cmp::Ge));
 
     quote!(
         #[automatically_derived]
-        impl ::nearly::NearlyOrdUlps for #ident {
-            fn nearly_lt_ulps(&self, other: &Self, ulps: &::nearly::UlpsToleranceType<Self>) -> bool {
+        impl #trait_impl_generics ::nearly::NearlyOrdUlps #rhs_generics for #ident #ty_generics #trait_where_clause {
+            fn nearly_lt_ulps(&self, other: &#rhs_ty, ulps: &::nearly::UlpsToleranceType<Self, #rhs_ty>) -> bool {
                 #lt_fn_output
             }
-            fn nearly_le_ulps(&self, other: &Self, ulps: &::nearly::UlpsToleranceType<Self>) -> bool {
+            fn nearly_le_ulps(&self, other: &#rhs_ty, ulps: &::nearly::UlpsToleranceType<Self, #rhs_ty>) -> bool {
                 #le_fn_output
             }
-            fn nearly_gt_ulps(&self, other: &Self, ulps: &::nearly::UlpsToleranceType<Self>) -> bool {
+            fn nearly_gt_ulps(&self, other: &#rhs_ty, ulps: &::nearly::UlpsToleranceType<Self, #rhs_ty>) -> bool {
                 #gt_fn_output
             }
-            fn nearly_ge_ulps(&self, other: &Self, ulps: &::nearly::UlpsToleranceType<Self>) -> bool {
+            fn nearly_ge_ulps(&self, other: &#rhs_ty, ulps: &::nearly::UlpsToleranceType<Self, #rhs_ty>) -> bool {
                 #ge_fn_output
             }
         }
     )
 }
 
-fn derive_nearly_ord_tol(data: &Data, ident: &Ident) -> proc_macro2::TokenStream {
-    let eps_output = derive_nearly_ord_eps(data, ident);
-    let ulps_output = derive_nearly_ord_ulps(data, ident);
+fn derive_nearly_ord_rel(input: &DeriveInput, rhs: Option<&Type>) -> proc_macro2::TokenStream {
+    let DeriveInput {
+        ident,
+        data,
+        generics,
+        ..
+    } = input;
+    let (_, ty_generics, _) = generics.split_for_impl();
+    let (rhs_generics, rhs_ty) = rhs_output(rhs);
+    let trait_generics = bounded_generics(
+        generics,
+        rhs,
+        data,
+        &[
+            quote!(::nearly::NearlyOrdRel),
+            quote!(::nearly::RelTolerance),
+        ],
+        Some(quote!(::core::cmp::PartialOrd)),
+    );
+    let (trait_impl_generics, _, trait_where_clause) = trait_generics.split_for_impl();
+    let lt_fn_output = fn_output(input, rhs, TraitFn::Rel(Cmp::Lt));
+    let le_fn_output = fn_output(input, rhs, TraitFn::Rel(Cmp::Le));
+    let gt_fn_output = fn_output(input, rhs, TraitFn::Rel(Cmp::Gt));
+    let ge_fn_output = fn_output(input, rhs, TraitFn::Rel(Cmp::Ge));
 
-    let lt_fn_output = fn_output(data, ident, TraitFn::Tol(Cmp::Lt));
-    let le_fn_output = fn_output(data, ident, TraitFn::Tol(Cmp::Le));
-    let gt_fn_output = fn_output(data, ident, TraitFn::Tol(Cmp::Gt));
-    let ge_fn_output = fn_output(data, ident, TraitFn::Tol(Cmp::Ge));
+    quote!(
+        #[automatically_derived]
+        impl #trait_impl_generics ::nearly::NearlyOrdRel #rhs_generics for #ident #ty_generics #trait_where_clause {
+            fn nearly_lt_rel(&self, other: &#rhs_ty, rel: &::nearly::RelToleranceType<Self, #rhs_ty>) -> bool {
+                #lt_fn_output
+            }
+            fn nearly_le_rel(&self, other: &#rhs_ty, rel: &::nearly::RelToleranceType<Self, #rhs_ty>) -> bool {
+                #le_fn_output
+            }
+            fn nearly_gt_rel(&self, other: &#rhs_ty, rel: &::nearly::RelToleranceType<Self, #rhs_ty>) -> bool {
+                #gt_fn_output
+            }
+            fn nearly_ge_rel(&self, other: &#rhs_ty, rel: &::nearly::RelToleranceType<Self, #rhs_ty>) -> bool {
+                #ge_fn_output
+            }
+        }
+    )
+}
+
+fn derive_nearly_ord_tol(input: &DeriveInput, rhs: Option<&Type>) -> proc_macro2::TokenStream {
+    let DeriveInput {
+        ident,
+        data,
+        generics,
+        ..
+    } = input;
+    let (_, ty_generics, _) = generics.split_for_impl();
+    let (rhs_generics, rhs_ty) = rhs_output(rhs);
+    let trait_generics = bounded_generics(
+        generics,
+        rhs,
+        data,
+        &[
+            quote!(::nearly::NearlyOrdTol),
+            quote!(::nearly::EpsTolerance),
+            quote!(::nearly::UlpsTolerance),
+            quote!(::nearly::RelTolerance),
+        ],
+        Some(quote!(::core::cmp::PartialOrd)),
+    );
+    let (trait_impl_generics, _, trait_where_clause) = trait_generics.split_for_impl();
+    let eps_output = derive_nearly_ord_eps(input, rhs);
+    let ulps_output = derive_nearly_ord_ulps(input, rhs);
+    let rel_output = derive_nearly_ord_rel(input, rhs);
+
+    let lt_fn_output = fn_output(input, rhs, TraitFn::Tol(Cmp::Lt));
+    let le_fn_output = fn_output(input, rhs, TraitFn::Tol(Cmp::Le));
+    let gt_fn_output = fn_output(input, rhs, TraitFn::Tol(Cmp::Gt));
+    let ge_fn_output = fn_output(input, rhs, TraitFn::Tol(Cmp::Ge));
 
     quote!(
         #eps_output
         #ulps_output
+        #rel_output
 
         #[automatically_derived]
-        impl ::nearly::NearlyOrdTol for #ident {
-            fn nearly_lt_tol(&self, other: &Self, tol: &::nearly::Tolerance<Self>) -> bool {
+        impl #trait_impl_generics ::nearly::NearlyOrdTol #rhs_generics for #ident #ty_generics #trait_where_clause {
+            fn nearly_lt_tol(&self, other: &#rhs_ty, tol: &::nearly::Tolerance<Self, #rhs_ty>) -> bool {
                 #lt_fn_output
             }
-            fn nearly_le_tol(&self, other: &Self, tol: &::nearly::Tolerance<Self>) -> bool {
+            fn nearly_le_tol(&self, other: &#rhs_ty, tol: &::nearly::Tolerance<Self, #rhs_ty>) -> bool {
                 #le_fn_output
             }
-            fn nearly_gt_tol(&self, other: &Self, tol: &::nearly::Tolerance<Self>) -> bool {
+            fn nearly_gt_tol(&self, other: &#rhs_ty, tol: &::nearly::Tolerance<Self, #rhs_ty>) -> bool {
                 #gt_fn_output
             }
-            fn nearly_ge_tol(&self, other: &Self, tol: &::nearly::Tolerance<Self>) -> bool {
+            fn nearly_ge_tol(&self, other: &#rhs_ty, tol: &::nearly::Tolerance<Self, #rhs_ty>) -> bool {
                 #ge_fn_output
             }
         }
     )
 }
 
-fn derive_nearly_ord(data: &Data, ident: &Ident) -> proc_macro2::TokenStream {
-    let tol_output = derive_nearly_ord_tol(data, ident);
+fn derive_nearly_ord(input: &DeriveInput, rhs: Option<&Type>) -> proc_macro2::TokenStream {
+    let DeriveInput {
+        ident,
+        data,
+        generics,
+        ..
+    } = input;
+    let (_, ty_generics, _) = generics.split_for_impl();
+    let (rhs_generics, _) = rhs_output(rhs);
+    let trait_generics = bounded_generics(
+        generics,
+        rhs,
+        data,
+        &[
+            quote!(::nearly::NearlyOrd),
+            quote!(::nearly::EpsTolerance),
+            quote!(::nearly::UlpsTolerance),
+            quote!(::nearly::RelTolerance),
+        ],
+        Some(quote!(::core::cmp::PartialOrd)),
+    );
+    let (trait_impl_generics, _, trait_where_clause) = trait_generics.split_for_impl();
+    let tol_output = derive_nearly_ord_tol(input, rhs);
 
     quote!(
         #tol_output
 
         #[automatically_derived]
-        impl ::nearly::NearlyOrd for #ident {}
+        impl #trait_impl_generics ::nearly::NearlyOrd #rhs_generics for #ident #ty_generics #trait_where_clause {}
     )
 }
 
 pub(crate) fn nearly_eq(input: TokenStream, derive_trait: DeriveTrait) -> TokenStream {
-    let DeriveInput { ident, data, .. } = parse_macro_input!(input as DeriveInput);
-
-    let result: proc_macro2::TokenStream = match derive_trait {
-        DeriveTrait::NearlyEqEps => derive_nearly_eq_eps(&data, &ident),
-        DeriveTrait::NearlyEqUlps => derive_nearly_eq_ulps(&data, &ident),
-        DeriveTrait::NearlyEqTol => derive_nearly_eq_tol(&data, &ident),
-        DeriveTrait::NearlyEq => derive_nearly_eq(&data, &ident),
-        DeriveTrait::NearlyOrdEps => derive_nearly_ord_eps(&data, &ident),
-        DeriveTrait::NearlyOrdUlps => derive_nearly_ord_ulps(&data, &ident),
-        DeriveTrait::NearlyOrdTol => derive_nearly_ord_tol(&data, &ident),
-        DeriveTrait::NearlyOrd => derive_nearly_ord(&data, &ident),
+    let input = parse_macro_input!(input as DeriveInput);
+    let container_attrs = ContainerAttrs::from_attrs(&input.attrs);
+
+    if !container_attrs.rhs.is_empty() && !matches!(input.data, Struct(_)) {
+        abort_call_site!("`rhs` is only supported for structs");
+    }
+
+    for field in fields_from_data(&input.data) {
+        for (rhs, _) in &FieldAttrs::from_field(field).rhs {
+            let known = match rhs {
+                Some(rhs) => container_attrs.rhs.contains(rhs),
+                None => !container_attrs.rhs.is_empty(),
+            };
+            if !known {
+                abort!(
+                    field,
+                    "the `rhs` of a field requires a matching `rhs` of the struct"
+                );
+            }
+        }
+    }
+
+    let derive = |rhs: Option<&Type>| match derive_trait {
+        DeriveTrait::NearlyEqEps => derive_nearly_eq_eps(&input, rhs),
+        DeriveTrait::NearlyEqUlps => derive_nearly_eq_ulps(&input, rhs),
+        DeriveTrait::NearlyEqRel => derive_nearly_eq_rel(&input, rhs),
+        DeriveTrait::NearlyEqTol => derive_nearly_eq_tol(&input, rhs),
+        DeriveTrait::NearlyEq => derive_nearly_eq(&input, rhs),
+        DeriveTrait::NearlyEqReport => derive_nearly_eq_report(&input, rhs),
+        DeriveTrait::NearlyOrdEps => derive_nearly_ord_eps(&input, rhs),
+        DeriveTrait::NearlyOrdUlps => derive_nearly_ord_ulps(&input, rhs),
+        DeriveTrait::NearlyOrdRel => derive_nearly_ord_rel(&input, rhs),
+        DeriveTrait::NearlyOrdTol => derive_nearly_ord_tol(&input, rhs),
+        DeriveTrait::NearlyOrd => derive_nearly_ord(&input, rhs),
     };
 
+    let mut result = derive(None);
+    for rhs in &container_attrs.rhs {
+        result.extend(derive(Some(rhs)));
+    }
+
     result.into()
 }
@@ -4,6 +4,7 @@
 use proc_macro::TokenStream;
 use proc_macro_error::proc_macro_error;
 
+mod attr;
 mod derive;
 mod nearly;
 
@@ -22,49 +23,67 @@ pub fn debug_assert_nearly(input: TokenStream) -> TokenStream {
     nearly::nearly_macro(input, nearly::NearlyMacroType::DebugAssert)
 }
 
-#[proc_macro_derive(NearlyEqEps)]
+#[proc_macro_derive(NearlyEqEps, attributes(nearly))]
 #[proc_macro_error]
 pub fn nearly_eq_eps_derive(input: TokenStream) -> TokenStream {
     derive::nearly_eq(input, derive::DeriveTrait::NearlyEqEps)
 }
 
-#[proc_macro_derive(NearlyEqUlps)]
+#[proc_macro_derive(NearlyEqUlps, attributes(nearly))]
 #[proc_macro_error]
 pub fn nearly_eq_ulps_derive(input: TokenStream) -> TokenStream {
     derive::nearly_eq(input, derive::DeriveTrait::NearlyEqUlps)
 }
 
-#[proc_macro_derive(NearlyEqTol)]
+#[proc_macro_derive(NearlyEqRel, attributes(nearly))]
+#[proc_macro_error]
+pub fn nearly_eq_rel_derive(input: TokenStream) -> TokenStream {
+    derive::nearly_eq(input, derive::DeriveTrait::NearlyEqRel)
+}
+
+#[proc_macro_derive(NearlyEqTol, attributes(nearly))]
 #[proc_macro_error]
 pub fn nearly_eq_tol_derive(input: TokenStream) -> TokenStream {
     derive::nearly_eq(input, derive::DeriveTrait::NearlyEqTol)
 }
 
-#[proc_macro_derive(NearlyEq)]
+#[proc_macro_derive(NearlyEq, attributes(nearly))]
 #[proc_macro_error]
 pub fn nearly_eq_derive(input: TokenStream) -> TokenStream {
     derive::nearly_eq(input, derive::DeriveTrait::NearlyEq)
 }
 
-#[proc_macro_derive(NearlyOrdEps)]
+#[proc_macro_derive(NearlyEqReport, attributes(nearly))]
+#[proc_macro_error]
+pub fn nearly_eq_report_derive(input: TokenStream) -> TokenStream {
+    derive::nearly_eq(input, derive::DeriveTrait::NearlyEqReport)
+}
+
+#[proc_macro_derive(NearlyOrdEps, attributes(nearly))]
 #[proc_macro_error]
 pub fn nearly_ord_eps_derive(input: TokenStream) -> TokenStream {
     derive::nearly_eq(input, derive::DeriveTrait::NearlyOrdEps)
 }
 
-#[proc_macro_derive(NearlyOrdUlps)]
+#[proc_macro_derive(NearlyOrdUlps, attributes(nearly))]
 #[proc_macro_error]
 pub fn nearly_ord_ulps_derive(input: TokenStream) -> TokenStream {
     derive::nearly_eq(input, derive::DeriveTrait::NearlyOrdUlps)
 }
 
-#[proc_macro_derive(NearlyOrdTol)]
+#[proc_macro_derive(NearlyOrdRel, attributes(nearly))]
+#[proc_macro_error]
+pub fn nearly_ord_rel_derive(input: TokenStream) -> TokenStream {
+    derive::nearly_eq(input, derive::DeriveTrait::NearlyOrdRel)
+}
+
+#[proc_macro_derive(NearlyOrdTol, attributes(nearly))]
 #[proc_macro_error]
 pub fn nearly_ord_tol_derive(input: TokenStream) -> TokenStream {
     derive::nearly_eq(input, derive::DeriveTrait::NearlyOrdTol)
 }
 
-#[proc_macro_derive(NearlyOrd)]
+#[proc_macro_derive(NearlyOrd, attributes(nearly))]
 #[proc_macro_error]
 pub fn nearly_ord_derive(input: TokenStream) -> TokenStream {
     derive::nearly_eq(input, derive::DeriveTrait::NearlyOrd)
@@ -53,13 +53,17 @@ impl NearlyOp {
     }
 }
 
-#[derive(Debug)]
+#[derive(Clone, Debug)]
 enum NearlyTol {
     Default,
     Eps(Expr),
     Ulps(Expr),
+    Rel(Expr),
     Tol(Expr),
     EpsAndUlps(Expr, Expr),
+    EpsAndRel(Expr, Expr),
+    UlpsAndRel(Expr, Expr),
+    EpsAndUlpsAndRel(Expr, Expr, Expr),
 }
 
 impl NearlyTol {
@@ -67,8 +71,14 @@ impl NearlyTol {
         match self {
             NearlyTol::Eps(_) => "Eps",
             NearlyTol::Ulps(_) => "Ulps",
-            NearlyTol::EpsAndUlps(_, _) | NearlyTol::Tol(_) => "Tol",
+            NearlyTol::Rel(_) => "Rel",
+            NearlyTol::EpsAndUlps(_, _)
+            | NearlyTol::EpsAndUlpsAndRel(_, _, _)
+            | NearlyTol::Tol(_) => "Tol",
             NearlyTol::Default => "",
+            NearlyTol::EpsAndRel(_, _) | NearlyTol::UlpsAndRel(_, _) => {
+                unreachable!("combined relative tolerance has no single trait")
+            }
         }
     }
 
@@ -76,8 +86,30 @@ impl NearlyTol {
         match self {
             NearlyTol::Eps(_) => "_eps",
             NearlyTol::Ulps(_) => "_ulps",
-            NearlyTol::EpsAndUlps(_, _) | NearlyTol::Tol(_) => "_tol",
+            NearlyTol::Rel(_) => "_rel",
+            NearlyTol::EpsAndUlps(_, _)
+            | NearlyTol::EpsAndUlpsAndRel(_, _, _)
+            | NearlyTol::Tol(_) => "_tol",
             NearlyTol::Default => "",
+            NearlyTol::EpsAndRel(_, _) | NearlyTol::UlpsAndRel(_, _) => {
+                unreachable!("combined relative tolerance has no single function")
+            }
+        }
+    }
+
+    /// Splits an eps or ulps value combined with a relative tolerance into the eps or ulps value
+    /// and the relative tolerance.
+    ///
+    /// An eps, ulps and relative tolerance together are not split, since they form a `Tolerance`.
+    fn split_rel(&self) -> Option<(NearlyTol, NearlyTol)> {
+        match self {
+            NearlyTol::EpsAndRel(eps, rel) => {
+                Some((NearlyTol::Eps(eps.clone()), NearlyTol::Rel(rel.clone())))
+            }
+            NearlyTol::UlpsAndRel(ulps, rel) => {
+                Some((NearlyTol::Ulps(ulps.clone()), NearlyTol::Rel(rel.clone())))
+            }
+            _ => None,
         }
     }
 }
@@ -135,7 +167,12 @@ fn update_tolerance(tol: NearlyTol, ident: Ident, expr: Expr) -> Result<NearlyTo
         "eps" => match tol {
             NearlyTol::Default => NearlyTol::Eps(expr),
             NearlyTol::Ulps(ulps) => NearlyTol::EpsAndUlps(expr, ulps),
-            NearlyTol::Eps(_) | NearlyTol::EpsAndUlps(_, _) => {
+            NearlyTol::Rel(rel) => NearlyTol::EpsAndRel(expr, rel),
+            NearlyTol::UlpsAndRel(ulps, rel) => NearlyTol::EpsAndUlpsAndRel(expr, ulps, rel),
+            NearlyTol::Eps(_)
+            | NearlyTol::EpsAndUlps(_, _)
+            | NearlyTol::EpsAndRel(_, _)
+            | NearlyTol::EpsAndUlpsAndRel(_, _, _) => {
                 return Err(syn::Error::new(ident.span(), "multiple eps arguments"))
             }
             NearlyTol::Tol(_) => {
@@ -148,7 +185,12 @@ fn update_tolerance(tol: NearlyTol, ident: Ident, expr: Expr) -> Result<NearlyTo
         "ulps" => match tol {
             NearlyTol::Default => NearlyTol::Ulps(expr),
             NearlyTol::Eps(eps) => NearlyTol::EpsAndUlps(eps, expr),
-            NearlyTol::Ulps(_) | NearlyTol::EpsAndUlps(_, _) => {
+            NearlyTol::Rel(rel) => NearlyTol::UlpsAndRel(expr, rel),
+            NearlyTol::EpsAndRel(eps, rel) => NearlyTol::EpsAndUlpsAndRel(eps, expr, rel),
+            NearlyTol::Ulps(_)
+            | NearlyTol::EpsAndUlps(_, _)
+            | NearlyTol::UlpsAndRel(_, _)
+            | NearlyTol::EpsAndUlpsAndRel(_, _, _) => {
                 return Err(syn::Error::new(ident.span(), "multiple ulps arguments"))
             }
             NearlyTol::Tol(_) => {
@@ -158,15 +200,39 @@ fn update_tolerance(tol: NearlyTol, ident: Ident, expr: Expr) -> Result<NearlyTo
                 ))
             }
         },
+        "rel" => match tol {
+            NearlyTol::Default => NearlyTol::Rel(expr),
+            NearlyTol::Eps(eps) => NearlyTol::EpsAndRel(eps, expr),
+            NearlyTol::Ulps(ulps) => NearlyTol::UlpsAndRel(ulps, expr),
+            NearlyTol::EpsAndUlps(eps, ulps) => NearlyTol::EpsAndUlpsAndRel(eps, ulps, expr),
+            NearlyTol::Rel(_)
+            | NearlyTol::EpsAndRel(_, _)
+            | NearlyTol::UlpsAndRel(_, _)
+            | NearlyTol::EpsAndUlpsAndRel(_, _, _) => {
+                return Err(syn::Error::new(ident.span(), "multiple rel arguments"))
+            }
+            NearlyTol::Tol(_) => {
+                return Err(syn::Error::new(
+                    ident.span(),
+                    "rel argument not allowed alongside tol argument",
+                ))
+            }
+        },
         "tol" => match tol {
             NearlyTol::Default => NearlyTol::Tol(expr),
             NearlyTol::Tol(_) => {
                 return Err(syn::Error::new(ident.span(), "multiple tol arguments"))
             }
-            NearlyTol::Eps(_) | NearlyTol::Ulps(_) | NearlyTol::EpsAndUlps(_, _) => {
+            NearlyTol::Eps(_)
+            | NearlyTol::Ulps(_)
+            | NearlyTol::Rel(_)
+            | NearlyTol::EpsAndUlps(_, _)
+            | NearlyTol::EpsAndRel(_, _)
+            | NearlyTol::UlpsAndRel(_, _)
+            | NearlyTol::EpsAndUlpsAndRel(_, _, _) => {
                 return Err(syn::Error::new(
                     ident.span(),
-                    "tol argument not allowed alongside eps or ulps argument",
+                    "tol argument not allowed alongside eps, ulps or rel argument",
                 ))
             }
         },
@@ -188,115 +254,268 @@ fn fn_token_stream(op: &NearlyOp, tolerance: &NearlyTol) -> proc_macro2::TokenSt
     quote!(::nearly::#trait_ident::#fn_ident)
 }
 
-fn standard_macro_output(input: &NearlyMacroInput) -> proc_macro2::TokenStream {
-    let left = &input.left;
-    let right = &input.right;
-    let function = fn_token_stream(&input.op, &input.tolerance);
+fn standard_call(
+    op: &NearlyOp,
+    tolerance: &NearlyTol,
+    left: proc_macro2::TokenStream,
+    right: proc_macro2::TokenStream,
+) -> proc_macro2::TokenStream {
+    if tolerance.split_rel().is_some() {
+        return combined_call(op, tolerance, left, right);
+    }
 
-    match &input.tolerance {
-        NearlyTol::Eps(eps) => quote!(#function(&#left, &#right, &#eps)),
-        NearlyTol::Ulps(ulps) => quote!(#function(&#left, &#right, &#ulps)),
-        NearlyTol::Tol(tol) => quote!(#function(&#left, &#right, &#tol)),
+    let function = fn_token_stream(op, tolerance);
+
+    match tolerance {
+        NearlyTol::Eps(eps) => quote!(#function(#left, #right, &#eps)),
+        NearlyTol::Ulps(ulps) => quote!(#function(#left, #right, &#ulps)),
+        NearlyTol::Rel(rel) => quote!(#function(#left, #right, &#rel)),
+        NearlyTol::Tol(tol) => quote!(#function(#left, #right, &#tol)),
         NearlyTol::EpsAndUlps(eps, ulps) => {
-            quote!(#function(&#left, &#right, &(#eps, #ulps).into()))
+            quote!(#function(#left, #right, &(#eps, #ulps).into()))
+        }
+        NearlyTol::EpsAndUlpsAndRel(eps, ulps, rel) => quote!(#function(
+            #left,
+            #right,
+            &::nearly::Tolerance::new(#eps, #ulps).with_rel(#rel)
+        )),
+        NearlyTol::Default => quote!(#function(#left, #right)),
+        NearlyTol::EpsAndRel(_, _) | NearlyTol::UlpsAndRel(_, _) => {
+            unreachable!("combined relative tolerances are handled by combined_call")
         }
-        NearlyTol::Default => quote!(#function(&#left, &#right)),
     }
 }
 
-fn assert_macro_output(input: &NearlyMacroInput) -> proc_macro2::TokenStream {
+/// Combines the comparison of an eps or ulps value with a relative tolerance like a `Tolerance` in
+/// `ToleranceMode::Any` does. The values are nearly equal if they are nearly equal based on any of
+/// the tolerances, so `<` and `>` are only true if they are true for all of them, while `<=`, `>=`
+/// and `==` are true if they are true for any of them. Since `a != b` is the negation of
+/// `a == b`, the not equal comparison is true if the equal comparison is false for all tolerances.
+fn combined_call(
+    op: &NearlyOp,
+    tolerance: &NearlyTol,
+    left: proc_macro2::TokenStream,
+    right: proc_macro2::TokenStream,
+) -> proc_macro2::TokenStream {
+    let (tol, rel) = tolerance
+        .split_rel()
+        .expect("tolerance must be combined with a relative tolerance");
+
+    match op {
+        NearlyOp::Ne => {
+            let tol_call = standard_call(&NearlyOp::Eq, &tol, left.clone(), right.clone());
+            let rel_call = standard_call(&NearlyOp::Eq, &rel, left, right);
+            quote!(!(#tol_call || #rel_call))
+        }
+        NearlyOp::Lt | NearlyOp::Gt => {
+            let tol_call = standard_call(op, &tol, left.clone(), right.clone());
+            let rel_call = standard_call(op, &rel, left, right);
+            quote!((#tol_call && #rel_call))
+        }
+        NearlyOp::Eq | NearlyOp::Le | NearlyOp::Ge => {
+            let tol_call = standard_call(op, &tol, left.clone(), right.clone());
+            let rel_call = standard_call(op, &rel, left, right);
+            quote!((#tol_call || #rel_call))
+        }
+    }
+}
+
+fn standard_macro_output(input: &NearlyMacroInput) -> proc_macro2::TokenStream {
     let left = &input.left;
     let right = &input.right;
-    let function = fn_token_stream(&input.op, &input.tolerance);
-    let op_str = input.op.symbol();
 
     match &input.tolerance {
-        NearlyTol::Eps(eps) => {
+        NearlyTol::EpsAndRel(_, _) | NearlyTol::UlpsAndRel(_, _) => {
+            let call = standard_call(&input.op, &input.tolerance, quote!(left), quote!(right));
             quote!({
                 let left = &#left;
                 let right = &#right;
-                let eps = &#eps;
-                if !#function(left, right, eps) {
-                    panic!(
-                        r#"assertion `nearly (left {} right)` failed
-  left: {:?}
- right: {:?}
-   eps: {:?}"#,
-                        #op_str, left, right, eps
-                    )
-                }
+                #call
             })
         }
-        NearlyTol::Ulps(ulps) => {
-            quote!({
-                let left = &#left;
-                let right = &#right;
-                let ulps = &#ulps;
-                if !#function(left, right, ulps) {
-                    panic!(
-                        r#"assertion `nearly (left {} right)` failed
-  left: {:?}
- right: {:?}
-  ulps: {:?}"#,
-                        #op_str, left, right, ulps
-                    )
-                }
-            })
+        tolerance => standard_call(&input.op, tolerance, quote!(&#left), quote!(&#right)),
+    }
+}
+
+/// Creates the panic of a failed assertion comparing `left` and `right`.
+///
+/// `message` contains the tolerance lines of the panic message formatted with `args`. For `==`
+/// assertions, `report` is called on both values to create a diff of their mismatching parts. If
+/// a diff can be created, it is printed instead of both values as a whole.
+fn assert_panic(
+    op: &NearlyOp,
+    message: &str,
+    args: &[proc_macro2::TokenStream],
+    report: Option<proc_macro2::TokenStream>,
+) -> proc_macro2::TokenStream {
+    let op_str = op.symbol();
+    let header = "assertion `nearly (left {} right)` failed";
+
+    let values_message = format!("{}\n  left: {{:?}}\n right: {{:?}}{}", header, message);
+    let panic = quote!(panic!(#values_message, #op_str, left, right, #(#args),*));
+
+    let report = match (op, report) {
+        (NearlyOp::Eq, Some(report)) => report,
+        _ => return panic,
+    };
+
+    let diff_message = format!("{}{}\n  diff: {{}}", header, message);
+    quote!(
+        let diff = {
+            #[allow(unused_imports)]
+            use ::nearly::macro_support::{ReportDiff as _, ReportDiffFallback as _};
+            (&::nearly::macro_support::Reported(left, right)).#report
+        };
+        match diff {
+            Some(diff) => panic!(#diff_message, #op_str, #(#args,)* diff),
+            None => #panic,
         }
-        NearlyTol::Tol(tol) => {
-            quote!({
-                let left = &#left;
-                let right = &#right;
-                let tol = &#tol;
-                if !#function(left, right, tol) {
-                    panic!(
-                        r#"assertion `nearly (left {} right)` failed
-  left: {:?}
- right: {:?}
-   eps: {:?}
-  ulps: {:?}"#,
-                        #op_str, left, right, tol.eps, tol.ulps
-                    )
-                }
-            })
+    )
+}
+
+/// Creates the assert output for all tolerances including a relative tolerance.
+/// Besides the tolerance, the relative error observed between both values is printed on panic.
+fn rel_assert_macro_output(input: &NearlyMacroInput) -> proc_macro2::TokenStream {
+    let left = &input.left;
+    let right = &input.right;
+
+    let (eps, ulps, rel) = match &input.tolerance {
+        NearlyTol::Rel(rel) => (None, None, rel),
+        NearlyTol::EpsAndRel(eps, rel) => (Some(eps), None, rel),
+        NearlyTol::UlpsAndRel(ulps, rel) => (None, Some(ulps), rel),
+        NearlyTol::EpsAndUlpsAndRel(eps, ulps, rel) => (Some(eps), Some(ulps), rel),
+        _ => unreachable!("tolerance must contain a relative tolerance"),
+    };
+
+    let mut message = String::new();
+    let mut bindings = Vec::new();
+    let mut args = Vec::new();
+
+    if let Some(eps) = eps {
+        message.push_str("\n   eps: {:?}");
+        bindings.push(quote!(let eps = &#eps;));
+        args.push(quote!(eps));
+    }
+    if let Some(ulps) = ulps {
+        message.push_str("\n  ulps: {:?}");
+        bindings.push(quote!(let ulps = &#ulps;));
+        args.push(quote!(ulps));
+    }
+    message.push_str("\n   rel: {:?}{}");
+    bindings.push(quote!(let rel = &#rel;));
+    args.push(quote!(rel));
+    args.push(quote!(observed));
+
+    let tolerance = match (eps, ulps) {
+        (None, None) => NearlyTol::Rel(syn::parse_quote!(*rel)),
+        (Some(_), None) => NearlyTol::EpsAndRel(syn::parse_quote!(*eps), syn::parse_quote!(*rel)),
+        (None, Some(_)) => NearlyTol::UlpsAndRel(syn::parse_quote!(*ulps), syn::parse_quote!(*rel)),
+        (Some(_), Some(_)) => NearlyTol::EpsAndUlpsAndRel(
+            syn::parse_quote!(*eps),
+            syn::parse_quote!(*ulps),
+            syn::parse_quote!(*rel),
+        ),
+    };
+    let call = standard_call(&input.op, &tolerance, quote!(left), quote!(right));
+
+    let report = match (eps, ulps) {
+        (None, None) => quote!(report_rel(rel)),
+        (Some(_), None) => quote!(report_eps_rel(eps, rel)),
+        (None, Some(_)) => quote!(report_ulps_rel(ulps, rel)),
+        (Some(_), Some(_)) => quote!(report_eps_ulps_rel(eps, ulps, rel)),
+    };
+    let panic = assert_panic(&input.op, &message, &args, Some(report));
+
+    quote!({
+        let left = &#left;
+        let right = &#right;
+        #(#bindings)*
+        if !#call {
+            let observed = {
+                #[allow(unused_imports)]
+                use ::nearly::macro_support::{ObserveRelError as _, ObserveRelErrorFallback as _};
+                (&::nearly::macro_support::RelError(left, right)).observed_rel_error()
+            };
+            #panic
         }
-        NearlyTol::EpsAndUlps(eps, ulps) => {
-            quote!({
-                let left = &#left;
-                let right = &#right;
-                let eps = &#eps;
-                let ulps = &#ulps;
-                if !#function(left, right, &(*eps, *ulps).into()) {
-                    panic!(
-                        r#"assertion `nearly (left {} right)` failed
-  left: {:?}
- right: {:?}
-   eps: {:?}
-  ulps: {:?}"#,
-                        #op_str, left, right, eps, ulps
-                    )
-                }
-            })
+    })
+}
+
+fn assert_macro_output(input: &NearlyMacroInput) -> proc_macro2::TokenStream {
+    if let NearlyTol::Rel(_)
+    | NearlyTol::EpsAndRel(_, _)
+    | NearlyTol::UlpsAndRel(_, _)
+    | NearlyTol::EpsAndUlpsAndRel(_, _, _) = &input.tolerance
+    {
+        return rel_assert_macro_output(input);
+    }
+
+    let left = &input.left;
+    let right = &input.right;
+    let function = fn_token_stream(&input.op, &input.tolerance);
+
+    let (bindings, call, message, args, report) = match &input.tolerance {
+        NearlyTol::Eps(eps) => (
+            quote!(let eps = &#eps;),
+            quote!(#function(left, right, eps)),
+            "\n   eps: {:?}",
+            vec![quote!(eps)],
+            quote!(report_eps(eps)),
+        ),
+        NearlyTol::Ulps(ulps) => (
+            quote!(let ulps = &#ulps;),
+            quote!(#function(left, right, ulps)),
+            "\n  ulps: {:?}",
+            vec![quote!(ulps)],
+            quote!(report_ulps(ulps)),
+        ),
+        NearlyTol::Tol(tol) => (
+            quote!(let tol = &#tol;),
+            quote!(#function(left, right, tol)),
+            "\n   eps: {:?}\n  ulps: {:?}{}",
+            vec![
+                quote!(tol.eps),
+                quote!(tol.ulps),
+                quote!(::nearly::macro_support::ToleranceDetails(
+                    &tol.rel, &tol.mode
+                )),
+            ],
+            quote!(report_tol(tol)),
+        ),
+        NearlyTol::EpsAndUlps(eps, ulps) => (
+            quote!(let eps = &#eps; let ulps = &#ulps;),
+            quote!(#function(left, right, &(*eps, *ulps).into())),
+            "\n   eps: {:?}\n  ulps: {:?}",
+            vec![quote!(eps), quote!(ulps)],
+            quote!(report_eps_ulps(eps, ulps)),
+        ),
+        NearlyTol::Default => (
+            quote!(),
+            quote!(#function(left, right)),
+            "\n   eps: {:?}\n  ulps: {:?}",
+            vec![
+                quote!(::nearly::EpsTolerance::default_eps(left, right)),
+                quote!(::nearly::UlpsTolerance::default_ulps(left, right)),
+            ],
+            quote!(report_default()),
+        ),
+        NearlyTol::Rel(_)
+        | NearlyTol::EpsAndRel(_, _)
+        | NearlyTol::UlpsAndRel(_, _)
+        | NearlyTol::EpsAndUlpsAndRel(_, _, _) => {
+            unreachable!("relative tolerances are handled by rel_assert_macro_output")
         }
-        NearlyTol::Default => {
-            quote!({
-                let left = &#left;
-                let right = &#right;
-                if !#function(left, right) {
-                    panic!(
-                        r#"assertion `nearly (left {} right)` failed
-  left: {:?}
- right: {:?}
-   eps: {:?}
-  ulps: {:?}"#,
-                        #op_str, left, right,
-                        ::nearly::EpsTolerance::default_eps(left, right),
-                        ::nearly::UlpsTolerance::default_ulps(left, right)
-                    )
-                }
-            })
+    };
+    let panic = assert_panic(&input.op, message, &args, Some(report));
+
+    quote!({
+        let left = &#left;
+        let right = &#right;
+        #bindings
+        if !#call {
+            #panic
         }
-    }
+    })
 }
 
 fn debug_assert_macro_output(input: &NearlyMacroInput) -> proc_macro2::TokenStream {
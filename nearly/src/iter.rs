@@ -0,0 +1,137 @@
+use crate::nearly_eq::{NearlyEq, NearlyEqEps, NearlyEqRel, NearlyEqTol, NearlyEqUlps};
+use crate::tolerance::{
+    EpsTolerance, EpsToleranceType, RelTolerance, RelToleranceType, Tolerance, UlpsTolerance,
+    UlpsToleranceType,
+};
+
+/// Returns whether `lhs` and `rhs` yield the same number of elements and all elements are nearly
+/// equal based on `eq`.
+///
+/// The iterators are advanced in lockstep and the comparison stops at the first element that is
+/// not nearly equal.
+fn nearly_eq_iter_by<Lhs, Rhs>(
+    lhs: Lhs,
+    rhs: Rhs,
+    mut eq: impl FnMut(&Lhs::Item, &Rhs::Item) -> bool,
+) -> bool
+where
+    Lhs: IntoIterator,
+    Rhs: IntoIterator,
+{
+    let mut lhs = lhs.into_iter();
+    let mut rhs = rhs.into_iter();
+
+    loop {
+        match (lhs.next(), rhs.next()) {
+            (Some(a), Some(b)) => {
+                if !eq(&a, &b) {
+                    return false;
+                }
+            }
+            (None, None) => return true,
+            _ => return false,
+        }
+    }
+}
+
+/// An extension trait to compare the elements of two iterators by nearly equality.
+///
+/// Contrary to the implementations for collections, the iterators do not need to know their
+/// length in advance. The elements are compared pairwise while both iterators are advanced in
+/// lockstep. Nothing is collected, so this is also available without the `std` feature.
+/// Two iterators are nearly equal, if they yield the same number of elements and all pairs of
+/// elements are nearly equal. If one iterator ends before the other, they are not nearly equal.
+///
+/// The trait is implemented for all types implementing [IntoIterator].
+///
+/// ```
+/// use nearly::NearlyIterExt;
+///
+/// let a = [1.0, 2.0, 3.0];
+/// let b = [0.5, 1.0, 1.5];
+///
+/// assert!(a.iter().nearly_eq_iter_eps(b.iter(), &1.5));
+/// assert!(a.into_iter().nearly_eq_iter_eps(b.map(|v| v * 2.0), &0.001));
+/// assert!(!a.into_iter().nearly_eq_iter_eps(b.map(|v| v * 2.0).into_iter().take(2), &0.001));
+/// ```
+pub trait NearlyIterExt: IntoIterator + Sized {
+    /// Returns whether the elements of `self` are nearly equal to the elements of `other` based
+    /// on an absolute epsilon value `eps`.
+    fn nearly_eq_iter_eps<Rhs, LhsTol, RhsTol>(
+        self,
+        other: Rhs,
+        eps: &EpsToleranceType<LhsTol, RhsTol>,
+    ) -> bool
+    where
+        Rhs: IntoIterator,
+        Self::Item: NearlyEqEps<Rhs::Item, LhsTol, RhsTol>,
+        LhsTol: ?Sized + EpsTolerance<RhsTol>,
+        RhsTol: ?Sized,
+    {
+        nearly_eq_iter_by(self, other, |a, b| a.nearly_eq_eps(b, eps))
+    }
+
+    /// Returns whether the elements of `self` are nearly equal to the elements of `other` based
+    /// on an ulps value `ulps`.
+    fn nearly_eq_iter_ulps<Rhs, LhsTol, RhsTol>(
+        self,
+        other: Rhs,
+        ulps: &UlpsToleranceType<LhsTol, RhsTol>,
+    ) -> bool
+    where
+        Rhs: IntoIterator,
+        Self::Item: NearlyEqUlps<Rhs::Item, LhsTol, RhsTol>,
+        LhsTol: ?Sized + UlpsTolerance<RhsTol>,
+        RhsTol: ?Sized,
+    {
+        nearly_eq_iter_by(self, other, |a, b| a.nearly_eq_ulps(b, ulps))
+    }
+
+    /// Returns whether the elements of `self` are nearly equal to the elements of `other` based
+    /// on a relative epsilon value `rel`.
+    fn nearly_eq_iter_rel<Rhs, LhsTol, RhsTol>(
+        self,
+        other: Rhs,
+        rel: &RelToleranceType<LhsTol, RhsTol>,
+    ) -> bool
+    where
+        Rhs: IntoIterator,
+        Self::Item: NearlyEqRel<Rhs::Item, LhsTol, RhsTol>,
+        LhsTol: ?Sized + RelTolerance<RhsTol>,
+        RhsTol: ?Sized,
+    {
+        nearly_eq_iter_by(self, other, |a, b| a.nearly_eq_rel(b, rel))
+    }
+
+    /// Returns whether the elements of `self` are nearly equal to the elements of `other` based
+    /// on a tolerance `tol`.
+    ///
+    /// Each pair of elements is compared by [nearly_eq_tol](NearlyEqTol::nearly_eq_tol).
+    fn nearly_eq_iter_tol<Rhs, LhsTol, RhsTol>(
+        self,
+        other: Rhs,
+        tol: &Tolerance<LhsTol, RhsTol>,
+    ) -> bool
+    where
+        Rhs: IntoIterator,
+        Self::Item: NearlyEqTol<Rhs::Item, LhsTol, RhsTol>,
+        LhsTol: ?Sized + EpsTolerance<RhsTol> + UlpsTolerance<RhsTol> + RelTolerance<RhsTol>,
+        RhsTol: ?Sized,
+    {
+        nearly_eq_iter_by(self, other, |a, b| a.nearly_eq_tol(b, tol))
+    }
+
+    /// Returns whether the elements of `self` are nearly equal to the elements of `other` based
+    /// on the default tolerance for comparisons of the element types.
+    fn nearly_eq_iter<Rhs, LhsTol, RhsTol>(self, other: Rhs) -> bool
+    where
+        Rhs: IntoIterator,
+        Self::Item: NearlyEq<Rhs::Item, LhsTol, RhsTol>,
+        LhsTol: ?Sized + EpsTolerance<RhsTol> + UlpsTolerance<RhsTol> + RelTolerance<RhsTol>,
+        RhsTol: ?Sized,
+    {
+        self.nearly_eq_iter_tol(other, &Tolerance::<LhsTol, RhsTol>::default())
+    }
+}
+
+impl<T: IntoIterator> NearlyIterExt for T {}
@@ -0,0 +1,33 @@
+/// A wrapper to compare collections lexicographically by nearly ordering comparisons.
+///
+/// The nearly ordering traits implemented for collections compare the elements pairwise.
+/// A collection is nearly less than another collection, if every element is nearly less than
+/// its counterpart. Wrapping collections into [Lexicographic] compares them lexicographically
+/// instead, like [Ord](core::cmp::Ord) does for slices:
+///   - Nearly equal elements at the beginning of both collections are skipped.
+///   - The first pair of elements that is not nearly equal decides the ordering.
+///   - If all elements of the shorter collection are nearly equal to the elements of the longer
+///     collection, the shorter collection is less than the longer collection.
+///
+/// The nearly equality comparison is the same as for the wrapped collections.
+///
+/// ```
+/// use core::cmp::Ordering;
+/// use nearly::{nearly, Lexicographic, NearlyOrdEps};
+///
+/// let a = Lexicographic([1.0, 2.0, 3.0]);
+/// let b = Lexicographic([1.0, 2.5, 0.0]);
+///
+/// assert!(nearly!(a < b, eps = 0.1));
+/// assert!(!nearly!(a.0 < b.0, eps = 0.1));
+///
+/// let mut values = vec![vec![1.0, 3.0], vec![1.0, 2.0, 4.0], vec![1.0]];
+/// values.sort_by(|a, b| {
+///     Lexicographic(a.as_slice())
+///         .nearly_cmp_eps(&Lexicographic(b.as_slice()), &0.1)
+///         .unwrap_or(Ordering::Equal)
+/// });
+/// assert_eq!(values, vec![vec![1.0], vec![1.0, 2.0, 4.0], vec![1.0, 3.0]]);
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Default)]
+pub struct Lexicographic<T: ?Sized>(pub T);
@@ -36,13 +36,19 @@
 //! The tolerance used can be:
 //!   - `eps` for an absolute epsilon tolerance
 //!   - `ulps` for an ulps based tolerance
-//!   - `tol` for an absolute epsilon and ulps based tolerance
+//!   - `rel` for a relative epsilon tolerance
+//!   - `tol` for an absolute epsilon and ulps based tolerance with an optional relative epsilon
+//!     tolerance, which by default is true if any of them is true
 //!   - `default` for an absolute epsilon and ulps based tolerance using default values
 //!
+//! The `rel` tolerance can be combined with `eps` and `ulps`. Like for a `tol` in the default
+//! [ToleranceMode::Any], the values are then nearly equal if they are nearly equal based on any of
+//! the given tolerances. So `a < b` and `a > b` are only true if they are true for all of them.
+//!
 //! Here are some example calls:
 //!
 //! ```
-//! use nearly::{nearly, Tolerance};
+//! use nearly::{nearly, Tolerance, ToleranceMode};
 //!
 //! let a: f32 = 1.0 + 1.04 + 1.1;
 //! let b: f32 = 3.14;
@@ -53,10 +59,22 @@
 //! // use ulps based tolerance
 //! nearly!(a == b, ulps = 5);
 //!
+//! // use relative epsilon tolerance
+//! nearly!(a == b, rel = 0.0001);
+//!
+//! // use absolute epsilon and relative epsilon tolerance
+//! nearly!(a == b, eps = 0.001, rel = 0.0001);
+//!
 //! // use absolute epsilon and ulps based tolerance
 //! nearly!(a == b, eps = 0.001, ulps = 5);
 //! nearly!(a == b, tol = Tolerance::new(0.001, 5));
 //!
+//! // use absolute epsilon, ulps based and relative epsilon tolerance
+//! nearly!(a == b, tol = Tolerance::new(0.001, 5).with_rel(0.0001));
+//!
+//! // require the absolute epsilon and the ulps based tolerance to hold
+//! nearly!(a == b, tol = Tolerance::new(0.001, 5).with_mode(ToleranceMode::All));
+//!
 //! // use default absolute epsilon and default ulps based tolerance
 //! nearly!(a == b);
 //! ```
@@ -72,13 +90,17 @@
 //!
 //! assert_nearly!(a == b, eps = 0.001);
 //! assert_nearly!(a == b, ulps = 5);
+//! assert_nearly!(a == b, rel = 0.0001);
 //! assert_nearly!(a == b, eps = 0.001, ulps = 5);
+//! assert_nearly!(a == b, eps = 0.001, rel = 0.0001);
 //! assert_nearly!(a == b, tol = Tolerance::new(0.001, 5));
 //! assert_nearly!(a == b);
 //!
 //! debug_assert_nearly!(a == b, eps = 0.001);
 //! debug_assert_nearly!(a == b, ulps = 5);
+//! debug_assert_nearly!(a == b, rel = 0.0001);
 //! debug_assert_nearly!(a == b, eps = 0.001, ulps = 5);
+//! debug_assert_nearly!(a == b, eps = 0.001, rel = 0.0001);
 //! debug_assert_nearly!(a == b, tol = Tolerance::new(0.001, 5));
 //! debug_assert_nearly!(a == b);
 //! ```
@@ -97,8 +119,125 @@
 //! assert!(a.nearly_eq(&b));
 //! ```
 //!
+//! The ordering of two values can be determined at once. Values that are nearly equal result in
+//! [Ordering::Equal](core::cmp::Ordering::Equal), incomparable values like NaN result in `None`.
+//!
+//! ```
+//! use core::cmp::Ordering;
+//! use nearly::NearlyOrd;
+//!
+//! let a: f32 = 1.0 + 1.04 + 1.1;
+//! let b: f32 = 3.14;
+//!
+//! match a.nearly_cmp(&b) {
+//!     Some(Ordering::Less) => println!("a < b"),
+//!     Some(Ordering::Equal) => println!("a == b"),
+//!     Some(Ordering::Greater) => println!("a > b"),
+//!     None => println!("a and b are not comparable"),
+//! }
+//! assert_eq!(a.nearly_cmp(&b), Some(Ordering::Equal));
+//! assert_eq!(a.nearly_cmp(&f32::NAN), None);
+//! ```
+//!
+//! Collections are ordered by comparing all elements pairwise. To order collections
+//! lexicographically instead, wrap them into [Lexicographic]. Nearly equal leading elements are
+//! skipped and the first pair of elements that is not nearly equal decides.
+//!
+//! ```
+//! use nearly::{Lexicographic, NearlyOrd};
+//!
+//! let a = Lexicographic([1.0, 2.0, 3.0]);
+//! let b = Lexicographic([1.0, 2.5, 0.0]);
+//!
+//! assert!(a.nearly_lt(&b));
+//! assert!(!a.0.nearly_lt(&b.0));
+//! ```
+//!
+//! Collections are nearly equal if their elements are pairwise nearly equal. To ignore the order
+//! of the elements, wrap them into [Unordered]. Each element is matched to a distinct nearly
+//! equal element of the other collection. Sets like `HashSet` and `BTreeSet` are always compared
+//! this way.
+//!
+//! ```
+//! # #[cfg(feature = "std")]
+//! # {
+//! use nearly::{nearly, Unordered};
+//!
+//! let a = Unordered([1.0, 2.0, 3.0]);
+//! let b = Unordered([3.0, 1.0, 2.0]);
+//!
+//! assert!(nearly!(a == b, eps = 0.001));
+//! # }
+//! ```
+//!
+//! To use nearly comparisons with std APIs that rely on [PartialEq], [PartialOrd] or [Ord], wrap
+//! the values into [Nearly]. The tolerance is specified at the type level by a
+//! [ToleranceProvider]. For [f32] and [f64], NaN values can be ordered first or last to get a
+//! total ordering.
+//!
+//! ```
+//! use nearly::{DefaultTolerance, NanFirst, Nearly};
+//!
+//! let mut values = vec![0.3, f32::NAN, 0.1 + 0.2, 0.1];
+//! values.sort_by_key(|v| Nearly::<f32, DefaultTolerance, NanFirst>::new(*v));
+//! values.dedup_by(|a, b| Nearly::<f32>::new(*a) == Nearly::new(*b));
+//! assert!(values[0].is_nan());
+//! assert_eq!(values[1..], [0.1, 0.3]);
+//! ```
+//!
+//! An absolute epsilon tolerance does not fit values of very different magnitudes. For these
+//! cases, the [NearlyEqRel] and [NearlyOrdRel] traits provide a comparison based on a relative
+//! epsilon value. The relative epsilon value is scaled by the larger magnitude of both values.
+//!
+//! ```
+//! use nearly::NearlyEqRel;
+//!
+//! let a: f64 = 1.0e9;
+//! let b: f64 = 1.0e9 + 1.0;
+//! assert!(a.nearly_eq_rel(&b, &1e-6));
+//!
+//! let a: f64 = 1.0e-12;
+//! let b: f64 = 1.0e-12 + 1.0e-21;
+//! assert!(a.nearly_eq_rel(&b, &1e-6));
+//! ```
+//!
+//! The ulps based comparison of [NearlyEqUlps] never considers values with different signs to be
+//! nearly equal. For comparisons around zero, the [NearlyEqUlpsAcrossZero] trait measures the
+//! ulps distance of floating point values across zero instead. For all other types, e.g.
+//! collections or derived types, use a [Tolerance] with
+//! [with_ulps_across_zero](Tolerance::with_ulps_across_zero()), which is passed on to the
+//! floating point values they contain.
+//!
+//! ```
+//! use nearly::{nearly, NearlyEqUlps, NearlyEqUlpsAcrossZero, Tolerance};
+//!
+//! let a: f32 = 1e-45;
+//! let b: f32 = -1e-45;
+//! assert!(!a.nearly_eq_ulps(&b, &2));
+//! assert!(a.nearly_eq_ulps_across_zero(&b, &2));
+//!
+//! let a = [1.0f32, 1e-45];
+//! let b = [1.0f32, -1e-45];
+//! let tol = Tolerance::<f32>::new(0.0, 2);
+//! assert!(!nearly!(a == b, tol = tol));
+//! assert!(nearly!(a == b, tol = tol.with_ulps_across_zero(true)));
+//! ```
+//!
+//! The [Ulps] trait provides the underlying ulps utilities for [f32] and [f64]. This is useful to
+//! create values at the boundary of an ulps tolerance.
+//!
+//! ```
+//! use nearly::{nearly, Ulps};
+//!
+//! let a: f64 = 1.0;
+//! assert!(nearly!(a == a.step_ulps(5), ulps = 5));
+//! assert!(!nearly!(a == a.step_ulps(6), ulps = 5));
+//! assert_eq!(a.ulps_distance(a.step_ulps(-6)), Some(6));
+//! ```
+//!
 //! The nearly functionality is also implemented for a variety of other types holding floats like
-//! containers, maps, pointers or tuples. Here is an example of comparing two arrays of floats.
+//! containers, maps, options, results, pointers or tuples. Here is an example of comparing two
+//! arrays of floats.
 //!
 //! ```
 //! use nearly::nearly;
@@ -109,6 +248,104 @@
 //! nearly!(a <= b, eps = 0.001, ulps = 5);
 //! ```
 //!
+//! Sequences of different kinds like arrays, slices, `Vec`, `VecDeque`, `LinkedList` and boxed or
+//! shared slices are compared with each other elementwise. `HashMap` and `BTreeMap` are compared
+//! with each other by key.
+//!
+//! ```
+//! use nearly::nearly;
+//!
+//! let a: [f32; 2] = [1.1, 2.2];
+//! let b: &[f32] = &[1.1, 2.2];
+//!
+//! assert!(nearly!(a == b, eps = 0.001));
+//! ```
+//!
+//! Tuples of elements with the same type are compared elementwise with the same tolerance for
+//! each element. To compare each element with its own tolerance, wrap them into [PerElement].
+//! The tolerance is a tuple of the tolerances of the elements, so the elements may have different
+//! types.
+//!
+//! ```
+//! use nearly::{nearly, EpsTolerance, PerElement};
+//!
+//! let a: (f32, f32) = (1.0, 2.0);
+//! let b: (f32, f32) = (1.0001, 2.0001);
+//! assert!(nearly!(a == b, eps = 0.001));
+//!
+//! let a = PerElement((1.0f32, [2.0f64, 3.0]));
+//! let b = PerElement((1.0001f32, [2.0f64, 3.0001]));
+//! assert!(nearly!(a == b, eps = (0.001, 0.001)));
+//! assert!(!nearly!(a == b, eps = (0.001, 0.00001)));
+//! assert_eq!(<PerElement<(f32, f64)> as EpsTolerance>::DEFAULT, (1e-6, 1e-14));
+//! ```
+//!
+//! Options are nearly equal if both are `None` or if both are `Some` and their values are nearly
+//! equal. Like for [Option]'s [PartialOrd], `None` is less than any `Some`. Results compare their
+//! `Ok` values with the tolerance and their `Err` values exactly.
+//!
+//! ```
+//! use nearly::assert_nearly;
+//!
+//! let a: [f64; 2] = [1.0, 2.0];
+//!
+//! assert_nearly!(a.first() == Some(1.0));
+//! assert_nearly!(a.get(2) < Some(0.0));
+//! assert_nearly!(Ok::<f64, u8>(1.0) != Err(1));
+//! ```
+//!
+//! Values behind [Cell](core::cell::Cell), [RefCell](core::cell::RefCell), `Cow`, `Mutex` and
+//! `RwLock` are compared by their content, using the tolerance of the content:
+//!   - A `RefCell` is borrowed for the duration of the comparison. The comparison panics if the
+//!     `RefCell` is currently mutably borrowed.
+//!   - A `Mutex` is locked and a `RwLock` is locked for reading for the duration of the
+//!     comparison. Poisoned locks are compared anyway, since the comparison only reads the
+//!     content. Like locking a `Mutex` twice, comparing a `Mutex` with itself deadlocks.
+//!   - Weak pointers are upgraded and compared like options, i.e. dangling pointers are equal to
+//!     each other and less than pointers to a value.
+//!
+//! ```
+//! use core::cell::{Cell, RefCell};
+//! use nearly::assert_nearly;
+//!
+//! let a = Cell::new(1.0);
+//! let b = RefCell::new([1.0, 2.0]);
+//!
+//! assert_nearly!(a == Cell::new(1.0));
+//! assert_nearly!(b == RefCell::new([1.0, 2.0]), eps = 0.001);
+//! ```
+//!
+//! Iterators are compared elementwise by the functions of [NearlyIterExt] without collecting
+//! them first. They are nearly equal if they yield the same number of elements and all pairs of
+//! elements are nearly equal.
+//!
+//! ```
+//! use nearly::NearlyIterExt;
+//!
+//! let a: [f64; 3] = [1.0, 2.0, 3.0];
+//!
+//! assert!(a.iter().map(|v| v / 3.0).nearly_eq_iter_eps(a.map(|v| v * 0.333333), &0.001));
+//! assert!(!a.iter().nearly_eq_iter_eps(a.iter().skip(1), &0.001));
+//! ```
+//!
+//! To find out which parts of two values are not nearly equal, use the functions of
+//! [NearlyEqReport]. They return a [Report] with the path to each mismatching element, field or
+//! map key, the compared values, their difference and their ulps distance.
+//!
+//! ```
+//! # #[cfg(feature = "std")]
+//! # {
+//! use nearly::NearlyEqReport;
+//!
+//! let a = vec![1.0, 2.0, 3.0];
+//! let b = vec![1.0, 2.5, 3.0];
+//!
+//! let report = a.nearly_eq_eps_report(&b, &0.1);
+//! assert_eq!(report.mismatches().len(), 1);
+//! assert_eq!(report.first().unwrap().path.to_string(), "[1]");
+//! # }
+//! ```
+//!
 //! # Own types
 //!
 //! ## Derive the nearly traits
@@ -141,6 +378,8 @@
 //!     absolute epsilon tolerance
 //!   - [NearlyEqUlps](nearly_macros::NearlyEqUlps): enables nearly equality support with
 //!     ulps based tolerance
+//!   - [NearlyEqRel](nearly_macros::NearlyEqRel): enables nearly equality support with
+//!     relative epsilon tolerance
 //!   - [NearlyEqTol][nearly_macros::NearlyEqTol]: enables nearly equality support with
 //!     absolute epsilon and ulps based tolerances
 //!   - [NearlyEq](nearly_macros::NearlyEq): enables nearly equality support with
@@ -149,6 +388,8 @@
 //!     absolute epsilon tolerance
 //!   - [NearlyOrdUlps](nearly_macros::NearlyOrdUlps): enables nearly ordering support with
 //!     ulps based tolerance
+//!   - [NearlyOrdRel](nearly_macros::NearlyOrdRel): enables nearly ordering support with
+//!     relative epsilon tolerance
 //!   - [NearlyOrdTol][nearly_macros::NearlyOrdTol]: enables nearly ordering support with
 //!     absolute epsilon and ulps based tolerances
 //!   - [NearlyOrd](nearly_macros::NearlyOrd): enables nearly ordering support with
@@ -169,15 +410,17 @@
 //! }
 //! ```
 //!
-//! First we have to implement the [EpsTolerance] nad [UlpsTolerance]. The [EpsTolerance] specifies
-//! the type that is used for the epsilon tolerance during absolute difference comparisons as well
-//! as the default value for that tolerance. The [UlpsTolerance] specifies the type that is used for
-//! the ulps tolerance during ulps difference comparisons as well as the default value for that
-//! tolerance.
+//! First we have to implement the [EpsTolerance], [UlpsTolerance] and [RelTolerance]. The
+//! [EpsTolerance] specifies the type that is used for the epsilon tolerance during absolute
+//! difference comparisons as well as the default value for that tolerance. The [UlpsTolerance]
+//! specifies the type that is used for the ulps tolerance during ulps difference comparisons as
+//! well as the default value for that tolerance. The [RelTolerance] specifies the type that is
+//! used for the relative epsilon tolerance during relative difference comparisons as well as the
+//! default value for that tolerance.
 //!
 //! ```
 //! # struct Point { x: f32, y: f32 }
-//! use nearly::{EpsTolerance, UlpsTolerance};
+//! use nearly::{EpsTolerance, RelTolerance, UlpsTolerance};
 //!
 //! impl EpsTolerance for Point {
 //!     type T = f32;
@@ -188,6 +431,11 @@
 //!     type T = i32;
 //!     const DEFAULT: i32 = 10;
 //! }
+//!
+//! impl RelTolerance for Point {
+//!     type T = f32;
+//!     const DEFAULT: f32 = 0.0001;
+//! }
 //! ```
 //!
 //! After we have defined the tolerances, we have to implement the comparison traits.
@@ -198,13 +446,14 @@
 //! Fist let's implement the nearly equality traits:
 //!
 //! ```
-//! # use nearly::{EpsTolerance, UlpsTolerance};
+//! # use nearly::{EpsTolerance, RelTolerance, UlpsTolerance};
 //! # struct Point { x: f32, y: f32 }
 //! # impl EpsTolerance for Point { type T = f32; const DEFAULT: f32 = 0.0001; }
 //! # impl UlpsTolerance for Point { type T = i32; const DEFAULT: i32 = 10; }
+//! # impl RelTolerance for Point { type T = f32; const DEFAULT: f32 = 0.0001; }
 //! use nearly::{
-//!     EpsToleranceType, NearlyEq, NearlyEqEps, NearlyEqTol, NearlyEqUlps, Tolerance,
-//!     UlpsToleranceType
+//!     EpsToleranceType, NearlyEq, NearlyEqEps, NearlyEqRel, NearlyEqTol, NearlyEqUlps,
+//!     RelToleranceType, Tolerance, UlpsToleranceType
 //! };
 //!
 //! impl NearlyEqEps for Point {
@@ -219,10 +468,16 @@
 //!     }
 //! }
 //!
+//! impl NearlyEqRel for Point {
+//!     fn nearly_eq_rel(&self, other: &Self, rel: &RelToleranceType<Self>) -> bool {
+//!         self.x.nearly_eq_rel(&other.x, rel) && self.y.nearly_eq_rel(&other.y, rel)
+//!     }
+//! }
+//!
 //! impl NearlyEqTol for Point {
 //!     fn nearly_eq_tol(&self, other: &Self, tol: &Tolerance<Self>) -> bool {
-//!         self.x.nearly_eq_tol(&other.x, &(tol.eps, tol.ulps).into()) &&
-//!         self.y.nearly_eq_tol(&other.y, &(tol.eps, tol.ulps).into())
+//!         let tol = (tol.eps, tol.ulps, tol.rel, tol.mode, tol.ulps_across_zero).into();
+//!         self.x.nearly_eq_tol(&other.x, &tol) && self.y.nearly_eq_tol(&other.y, &tol)
 //!     }
 //! }
 //!
@@ -236,8 +491,8 @@
 //! # use nearly::{NearlyEq};
 //! # #[derive(NearlyEq)] struct Point { x: f32, y: f32 }
 //! use nearly::{
-//!     EpsToleranceType, NearlyOrd, NearlyOrdEps, NearlyOrdTol, NearlyOrdUlps, Tolerance,
-//!     UlpsToleranceType
+//!     EpsToleranceType, NearlyOrd, NearlyOrdEps, NearlyOrdRel, NearlyOrdTol, NearlyOrdUlps,
+//!     RelToleranceType, Tolerance, UlpsToleranceType
 //! };
 //!
 //! impl NearlyOrdEps for Point {
@@ -270,22 +525,37 @@
 //!     }
 //! }
 //!
+//! impl NearlyOrdRel for Point {
+//!     fn nearly_lt_rel(&self, other: &Self, rel: &RelToleranceType<Self>) -> bool {
+//!         self.x.nearly_lt_rel(&other.x, rel) && self.y.nearly_lt_rel(&other.y, rel)
+//!     }
+//!     fn nearly_le_rel(&self, other: &Self, rel: &RelToleranceType<Self>) -> bool {
+//!         self.x.nearly_le_rel(&other.x, rel) && self.y.nearly_le_rel(&other.y, rel)
+//!     }
+//!     fn nearly_gt_rel(&self, other: &Self, rel: &RelToleranceType<Self>) -> bool {
+//!         self.x.nearly_gt_rel(&other.x, rel) && self.y.nearly_gt_rel(&other.y, rel)
+//!     }
+//!     fn nearly_ge_rel(&self, other: &Self, rel: &RelToleranceType<Self>) -> bool {
+//!         self.x.nearly_ge_rel(&other.x, rel) && self.y.nearly_ge_rel(&other.y, rel)
+//!     }
+//! }
+//!
 //! impl NearlyOrdTol for Point {
 //!     fn nearly_lt_tol(&self, other: &Self, tol: &Tolerance<Self>) -> bool {
-//!         self.x.nearly_lt_tol(&other.x, &(tol.eps, tol.ulps).into()) &&
-//!         self.y.nearly_lt_tol(&other.y, &(tol.eps, tol.ulps).into())
+//!         let tol = (tol.eps, tol.ulps, tol.rel, tol.mode, tol.ulps_across_zero).into();
+//!         self.x.nearly_lt_tol(&other.x, &tol) && self.y.nearly_lt_tol(&other.y, &tol)
 //!     }
 //!     fn nearly_le_tol(&self, other: &Self, tol: &Tolerance<Self>) -> bool {
-//!         self.x.nearly_le_tol(&other.x, &(tol.eps, tol.ulps).into()) &&
-//!         self.y.nearly_le_tol(&other.y, &(tol.eps, tol.ulps).into())
+//!         let tol = (tol.eps, tol.ulps, tol.rel, tol.mode, tol.ulps_across_zero).into();
+//!         self.x.nearly_le_tol(&other.x, &tol) && self.y.nearly_le_tol(&other.y, &tol)
 //!     }
 //!     fn nearly_gt_tol(&self, other: &Self, tol: &Tolerance<Self>) -> bool {
-//!         self.x.nearly_gt_tol(&other.x, &(tol.eps, tol.ulps).into()) &&
-//!         self.y.nearly_gt_tol(&other.y, &(tol.eps, tol.ulps).into())
+//!         let tol = (tol.eps, tol.ulps, tol.rel, tol.mode, tol.ulps_across_zero).into();
+//!         self.x.nearly_gt_tol(&other.x, &tol) && self.y.nearly_gt_tol(&other.y, &tol)
 //!     }
 //!     fn nearly_ge_tol(&self, other: &Self, tol: &Tolerance<Self>) -> bool {
-//!         self.x.nearly_ge_tol(&other.x, &(tol.eps, tol.ulps).into()) &&
-//!         self.y.nearly_ge_tol(&other.y, &(tol.eps, tol.ulps).into())
+//!         let tol = (tol.eps, tol.ulps, tol.rel, tol.mode, tol.ulps_across_zero).into();
+//!         self.x.nearly_ge_tol(&other.x, &tol) && self.y.nearly_ge_tol(&other.y, &tol)
 //!     }
 //! }
 //!
@@ -308,8 +578,9 @@
 //!
 //! ```
 //! use nearly::{
-//!     assert_nearly, EpsTolerance, EpsToleranceType, NearlyEq, NearlyEqEps, NearlyEqTol,
-//!     NearlyEqUlps, Tolerance, UlpsTolerance, UlpsToleranceType
+//!     assert_nearly, EpsTolerance, EpsToleranceType, NearlyEq, NearlyEqEps, NearlyEqRel,
+//!     NearlyEqTol, NearlyEqUlps, RelTolerance, RelToleranceType, Tolerance, UlpsTolerance,
+//!     UlpsToleranceType
 //! };
 //!
 //! #[derive(Debug)]
@@ -340,6 +611,14 @@
 //!     const DEFAULT: Self::T = <T as UlpsTolerance>::DEFAULT;
 //! }
 //!
+//! impl<T> RelTolerance<B<T>> for A<T>
+//! where
+//!     T: RelTolerance,
+//! {
+//!     type T = <T as RelTolerance>::T;
+//!     const DEFAULT: Self::T = <T as RelTolerance>::DEFAULT;
+//! }
+//!
 //! impl<T> NearlyEqEps<B<T>> for A<T>
 //! where
 //!     T: NearlyEqEps + EpsTolerance,
@@ -358,18 +637,27 @@
 //!     }
 //! }
 //!
+//! impl<T> NearlyEqRel<B<T>> for A<T>
+//! where
+//!     T: NearlyEqRel + RelTolerance,
+//! {
+//!     fn nearly_eq_rel(&self, other: &B<T>, rel: &RelToleranceType<Self, B<T>>) -> bool {
+//!         self.x.nearly_eq_rel(&other.u, rel) && self.y.nearly_eq_rel(&other.v, rel)
+//!     }
+//! }
+//!
 //! impl<T> NearlyEqTol<B<T>> for A<T>
 //! where
-//!     T: NearlyEqTol + EpsTolerance + UlpsTolerance
+//!     T: NearlyEqTol + EpsTolerance + UlpsTolerance + RelTolerance
 //! {
 //!     fn nearly_eq_tol(&self, other: &B<T>, tol: &Tolerance<Self, B<T>>) -> bool {
-//!         self.x.nearly_eq_tol(&other.u, &(tol.eps, tol.ulps).into()) &&
-//!         self.y.nearly_eq_tol(&other.v, &(tol.eps, tol.ulps).into())
+//!         let tol = (tol.eps, tol.ulps, tol.rel, tol.mode, tol.ulps_across_zero).into();
+//!         self.x.nearly_eq_tol(&other.u, &tol) && self.y.nearly_eq_tol(&other.v, &tol)
 //!     }
 //! }
 //!
 //! // use provided trait implementation
-//! impl<T> NearlyEq<B<T>> for A<T> where T: NearlyEq + EpsTolerance + UlpsTolerance {}
+//! impl<T> NearlyEq<B<T>> for A<T> where T: NearlyEq + EpsTolerance + UlpsTolerance + RelTolerance {}
 //!
 //!
 //! // This implementation allows us to compare A with B
@@ -389,7 +677,12 @@
 /// Asserts that the given comparison is nearly true using the provided tolerance.
 ///
 /// On panic, this macro will print the values of the comparison with their debug
-/// representations as well as the values of the provided tolerance.
+/// representations as well as the values of the provided tolerance. For a relative tolerance
+/// of floating point values, the observed relative error is printed as well.
+///
+/// If an `==` comparison of values implementing [NearlyEqReport] fails, only their mismatching
+/// parts are printed instead, each with its path, difference and ulps distance. At most 10
+/// mismatches are listed, preceded by a summary like `37 of 4096 elements differ`.
 ///
 /// The comparison can be:
 ///   - `a == b` for testing whether a is nearly equal to b
@@ -398,6 +691,7 @@
 /// The tolerance used can be:
 ///   - `eps` for an absolute epsilon tolerance
 ///   - `ulps` for an ulps based tolerance
+///   - `rel` for a relative epsilon tolerance
 ///   - `tol` for an absolute epsilon and ulps based tolerance
 ///   - `default` for an absolute epsilon and ulps based tolerance using default values
 ///
@@ -415,6 +709,12 @@
 /// // use ulps based tolerance
 /// assert_nearly!(a == b, ulps = 5);
 ///
+/// // use relative epsilon tolerance
+/// assert_nearly!(a == b, rel = 0.01);
+///
+/// // use absolute epsilon and relative epsilon tolerance
+/// assert_nearly!(a == b, eps = 0.01, rel = 0.01);
+///
 /// // use absolute epsilon and ulps based tolerance
 /// assert_nearly!(a == b, eps = 0.01, ulps = 5);
 /// assert_nearly!(a == b, tol = Tolerance::new(0.01, 5));
@@ -427,7 +727,12 @@ pub use nearly_macros::assert_nearly;
 /// Asserts that the given comparison is nearly true using the provided tolerance.
 ///
 /// On panic, this macro will print the values of the comparison with their debug
-/// representations as well as the values of the provided tolerance.
+/// representations as well as the values of the provided tolerance. For a relative tolerance
+/// of floating point values, the observed relative error is printed as well.
+///
+/// If an `==` comparison of values implementing [NearlyEqReport] fails, only their mismatching
+/// parts are printed instead, each with its path, difference and ulps distance. At most 10
+/// mismatches are listed, preceded by a summary like `37 of 4096 elements differ`.
 ///
 /// Like [debug_assert!] this macro is only enabled in non optimized builds.
 ///
@@ -438,6 +743,7 @@ pub use nearly_macros::assert_nearly;
 /// The tolerance used can be:
 ///   - `eps` for an absolute epsilon tolerance
 ///   - `ulps` for an ulps based tolerance
+///   - `rel` for a relative epsilon tolerance
 ///   - `tol` for an absolute epsilon and ulps based tolerance
 ///   - `default` for an absolute epsilon and ulps based tolerance using default values
 ///
@@ -455,6 +761,12 @@ pub use nearly_macros::assert_nearly;
 /// // use ulps based tolerance
 /// debug_assert_nearly!(a == b, ulps = 5);
 ///
+/// // use relative epsilon tolerance
+/// debug_assert_nearly!(a == b, rel = 0.01);
+///
+/// // use absolute epsilon and relative epsilon tolerance
+/// debug_assert_nearly!(a == b, eps = 0.01, rel = 0.01);
+///
 /// // use absolute epsilon and ulps based tolerance
 /// debug_assert_nearly!(a == b, eps = 0.01, ulps = 5);
 /// debug_assert_nearly!(a == b, tol = Tolerance::new(0.01, 5));
@@ -473,6 +785,7 @@ pub use nearly_macros::debug_assert_nearly;
 /// The tolerance used can be:
 ///   - `eps` for an absolute epsilon tolerance
 ///   - `ulps` for an ulps based tolerance
+///   - `rel` for a relative epsilon tolerance
 ///   - `tol` for an absolute epsilon and ulps based tolerance
 ///   - `default` for an absolute epsilon and ulps based tolerance using default values
 ///
@@ -490,6 +803,12 @@ pub use nearly_macros::debug_assert_nearly;
 /// // use ulps based tolerance
 /// let eq: bool = nearly!(a == b, ulps = 5);
 ///
+/// // use relative epsilon tolerance
+/// let eq: bool = nearly!(a == b, rel = 0.01);
+///
+/// // use absolute epsilon and relative epsilon tolerance
+/// let eq: bool = nearly!(a == b, eps = 0.01, rel = 0.01);
+///
 /// // use absolute epsilon and ulps based tolerance
 /// let eq: bool = nearly!(a == b, eps = 0.01, ulps = 5);
 /// let eq: bool = nearly!(a == b, tol = Tolerance::new(0.01, 5));
@@ -607,6 +926,60 @@ pub use nearly_macros::NearlyEqEps;
 /// ```
 pub use nearly_macros::NearlyEqUlps;
 
+/// Derives the [NearlyEqRel] trait for a custom type.
+///
+/// This trait can be derived for structs with named or unnamed fields as well as enums.
+/// To derive this trait, all types used for fields have to implemented [NearlyEqRel].
+///
+/// To use the [assert_nearly!] and [debug_assert_nearly!] macros, your type must also implement
+/// the Debug trait.
+///
+/// # Example
+///
+/// ## Same Type
+///
+/// If all fields have the same type, the relative tolerance will have the same type as the
+/// relative tolerance of the fields type. E.g., for [f32] this would be [f32].
+///
+/// ```
+/// use nearly::NearlyEqRel;
+///
+/// #[derive(NearlyEqRel, Debug)]
+/// struct Point {
+///     x: f32,
+///     y: f32,
+///     z: f32,
+/// }
+///
+/// let a = Point{x: -3.4, y: 2.1, z: 1.0e6};
+/// let b = Point{x: -3.4, y: 2.1, z: 1.000001e6};
+///
+/// assert!(a.nearly_eq_rel(&b, &0.00001));
+/// ```
+///
+/// ## Different Types
+///
+/// If the fields have different types, the relative tolerance will have a tuple type. The tuple
+/// will consist of the relative types of the fields type in the same order as they are defined.
+/// E.g., for fields with the type [f32], [f64] and [f32] this would be ([f32], [f64], [f32]).
+///
+/// ```
+/// use nearly::NearlyEqRel;
+///
+/// #[derive(NearlyEqRel, Debug)]
+/// struct Point {
+///     x: f32,
+///     y: f64,
+///     z: f32,
+/// }
+///
+/// let a = Point{x: -3.4, y: 2.1, z: 1.0e6};
+/// let b = Point{x: -3.4, y: 2.1, z: 1.000001e6};
+///
+/// assert!(a.nearly_eq_rel(&b, &(0.00001, 0.000001, 0.00001)));
+/// ```
+pub use nearly_macros::NearlyEqRel;
+
 /// Derives the [NearlyEqTol] trait for a custom type.
 ///
 /// This trait can be derived for structs with named or unnamed fields as well as enums.
@@ -735,8 +1108,292 @@ pub use nearly_macros::NearlyEqTol;
 /// assert_nearly!(a == b, eps = (0.0001, 0.000001, 0.0001), ulps = (8, 12, 8));
 /// assert_nearly!(a == b);
 /// ```
+///
+/// ## Generics
+///
+/// The trait can be derived for generic types. Each type parameter used by a field is bound by
+/// the derived trait and the corresponding tolerance traits. Fields are considered to have the
+/// same type, if their types are written identically.
+///
+/// ```
+/// use nearly::{assert_nearly, NearlyEq};
+///
+/// #[derive(NearlyEq, Debug)]
+/// struct Point<T> {
+///     x: T,
+///     y: T,
+/// }
+///
+/// let a = Point{x: -3.4_f32, y: 2.1};
+/// let b = Point{x: -3.4_f32, y: 2.1000001};
+/// assert_nearly!(a == b, eps = 0.0001);
+///
+/// let a = Point{x: -3.4_f64, y: 2.1};
+/// let b = Point{x: -3.4_f64, y: 2.1000000000000001};
+/// assert_nearly!(a == b, ulps = 8);
+/// ```
+///
+/// ## Enums
+///
+/// Values of enums are only nearly equal if they are the same variant and all fields of that
+/// variant are nearly equal. The tolerance consists of the fields of all variants in the order
+/// they are defined. Unit variants are equal to themselves.
+///
+/// ```
+/// use nearly::{assert_nearly, NearlyEq};
+///
+/// #[derive(NearlyEq, Debug)]
+/// enum Shape {
+///     Circle { x: f32, y: f32, r: f32 },
+///     Line(f32, f32),
+///     Empty,
+/// }
+///
+/// let a = Shape::Circle{x: 1.0, y: 2.0, r: 0.5};
+/// let b = Shape::Circle{x: 1.0, y: 2.0, r: 0.5000001};
+/// assert_nearly!(a == b, eps = 0.0001);
+/// assert_nearly!(a != Shape::Line(1.0, 2.0), eps = 0.0001);
+/// assert_nearly!(Shape::Empty == Shape::Empty);
+/// ```
+///
+/// ## Skipping Fields
+///
+/// Fields marked with `#[nearly(skip)]` are not compared and are not part of the tolerance.
+/// Their types do not need to implement any nearly trait.
+///
+/// ```
+/// use nearly::{assert_nearly, NearlyEq};
+///
+/// #[derive(NearlyEq, Debug)]
+/// struct Sample {
+///     #[nearly(skip)]
+///     label: String,
+///     value: f32,
+/// }
+///
+/// let a = Sample{label: String::from("a"), value: 1.0};
+/// let b = Sample{label: String::from("b"), value: 1.0000001};
+/// assert_nearly!(a == b, eps = 0.0001);
+/// ```
+///
+/// ## Exact Fields
+///
+/// Fields marked with `#[nearly(exact)]` are compared by [PartialEq] and, for the ordering
+/// traits, by [PartialOrd]. They are not part of the tolerance.
+///
+/// ```
+/// use nearly::{assert_nearly, NearlyEq};
+///
+/// #[derive(NearlyEq, Debug)]
+/// struct Sample {
+///     #[nearly(exact)]
+///     count: u32,
+///     value: f32,
+/// }
+///
+/// let a = Sample{count: 3, value: 1.0};
+/// let b = Sample{count: 3, value: 1.0000001};
+/// let c = Sample{count: 4, value: 1.0};
+/// assert_nearly!(a == b, eps = 0.0001);
+/// assert_nearly!(a != c, eps = 0.0001);
+/// ```
+///
+/// ## Custom Comparison Functions
+///
+/// Fields marked with `#[nearly(with = path)]` are compared by the functions of the module
+/// `path` instead of the nearly traits of the field type. The module provides a function for
+/// each comparison of the derived traits, named like the trait functions, e.g.
+/// `nearly_eq_eps(&T, &T, &EpsToleranceType<T>) -> bool`. The tolerance of the field is still
+/// the tolerance of the field type.
+///
+/// ```
+/// use nearly::{assert_nearly, NearlyEq};
+///
+/// mod angle {
+///     use nearly::{EpsToleranceType, RelToleranceType, Tolerance, UlpsToleranceType};
+///     use nearly::{NearlyEqEps, NearlyEqRel, NearlyEqTol, NearlyEqUlps};
+///
+///     fn wrap(a: &f32, b: &f32) -> f32 {
+///         (b - a + std::f32::consts::PI).rem_euclid(std::f32::consts::TAU) - std::f32::consts::PI
+///     }
+///
+///     pub fn nearly_eq_eps(a: &f32, b: &f32, eps: &EpsToleranceType<f32>) -> bool {
+///         wrap(a, b).nearly_eq_eps(&0.0, eps)
+///     }
+///     pub fn nearly_eq_ulps(a: &f32, b: &f32, ulps: &UlpsToleranceType<f32>) -> bool {
+///         wrap(a, b).nearly_eq_ulps(&0.0, ulps)
+///     }
+///     pub fn nearly_eq_rel(a: &f32, b: &f32, rel: &RelToleranceType<f32>) -> bool {
+///         wrap(a, b).nearly_eq_rel(&0.0, rel)
+///     }
+///     pub fn nearly_eq_tol(a: &f32, b: &f32, tol: &Tolerance<f32>) -> bool {
+///         wrap(a, b).nearly_eq_tol(&0.0, tol)
+///     }
+/// }
+///
+/// #[derive(NearlyEq, Debug)]
+/// struct Heading {
+///     #[nearly(with = angle)]
+///     angle: f32,
+///     speed: f32,
+/// }
+///
+/// let a = Heading{angle: 0.0, speed: 1.0};
+/// let b = Heading{angle: std::f32::consts::TAU, speed: 1.0};
+/// assert_nearly!(a == b, eps = 0.0001);
+/// ```
+///
+/// ## Comparing Different Types
+///
+/// The container attribute `#[nearly(rhs = Other)]` additionally derives the traits to compare
+/// the type with `Other`. Each field is compared with the field of `Other` with the same name or
+/// index. The attribute can be given multiple times and is supported for structs only. Lifetimes
+/// of `Other` are declared by the derived impls.
+///
+/// The fields of `Other` are expected to have the same types as the fields of the type. A
+/// different type is given by the field attribute `#[nearly(rhs = Type)]`, or by
+/// `#[nearly(rhs(Other) = Type)]` for the comparison with `Other` only. A field of `Other` that
+/// is a reference is compared by the value it refers to. The tolerance is built from the
+/// tolerances of the pairs of field types like the tolerance of the type itself, except that it is
+/// never a named tolerance.
+///
+/// ```
+/// use nearly::{assert_nearly, NearlyEq};
+///
+/// #[derive(NearlyEq, Debug)]
+/// #[nearly(rhs = PointView<'a>)]
+/// struct Point {
+///     x: f32,
+///     y: f32,
+/// }
+///
+/// #[derive(Debug)]
+/// struct PointView<'a> {
+///     x: f32,
+///     y: f32,
+///     label: &'a str,
+/// }
+///
+/// let a = Point{x: 1.0, y: 2.0};
+/// let b = PointView{x: 1.0, y: 2.0000001, label: "b"};
+/// assert_nearly!(a == b, eps = 0.0001);
+///
+/// #[derive(NearlyEq, Debug)]
+/// #[nearly(rhs = SegmentView<'a>)]
+/// struct Segment {
+///     #[nearly(rhs = PointView<'a>)]
+///     start: Point,
+///     #[nearly(rhs = &'a Point)]
+///     end: Point,
+/// }
+///
+/// #[derive(Debug)]
+/// struct SegmentView<'a> {
+///     start: PointView<'a>,
+///     end: &'a Point,
+/// }
+///
+/// let end = Point{x: 3.0, y: 4.0};
+/// let a = Segment{start: Point{x: 1.0, y: 2.0}, end: Point{x: 3.0, y: 4.0}};
+/// let b = SegmentView{start: PointView{x: 1.0, y: 2.0000001, label: "b"}, end: &end};
+/// assert_nearly!(a == b, eps = (0.0001, 0.0001));
+/// ```
+///
+/// ## Named Tolerances
+///
+/// By default, the tolerance of fields with different types is a tuple. The container attribute
+/// `#[nearly(named_tolerance)]` generates a tolerance struct for each tolerance instead, with a
+/// field for each field that is compared based on a tolerance. For a struct `Point`, these are
+/// `PointEps`, `PointUlps` and `PointRel`. They implement [Clone], [Copy], [Debug] and [Default],
+/// which returns the default tolerance. This attribute is supported for structs only.
+///
+/// ```
+/// use nearly::{assert_nearly, NearlyEq};
+///
+/// #[derive(NearlyEq, Debug)]
+/// #[nearly(named_tolerance)]
+/// struct Point {
+///     x: f32,
+///     y: f64,
+/// }
+///
+/// let a = Point{x: 1.0, y: 2.0};
+/// let b = Point{x: 1.0001, y: 2.001};
+/// assert_nearly!(a == b, eps = PointEps{x: 0.001, y: 0.01});
+/// assert_nearly!(a != b, eps = PointEps{x: 0.001, y: 0.0001});
+/// ```
+///
+/// ## Default Tolerances
+///
+/// The default tolerance of a field can be overridden by `#[nearly(eps = ...)]`,
+/// `#[nearly(ulps = ...)]` or `#[nearly(rel = ...)]`. If any field has a default tolerance, the
+/// tolerance is a tuple of the field tolerances, even if all fields have the same type.
+///
+/// The same attributes on the type itself set the whole default tolerance. Their value has to be
+/// of the tolerance type, i.e. a tuple if the fields have different types.
+///
+/// ```
+/// use nearly::{assert_nearly, EpsTolerance, NearlyEq};
+///
+/// #[derive(NearlyEq, Debug)]
+/// struct Sample {
+///     #[nearly(eps = 0.01)]
+///     coarse: f32,
+///     fine: f32,
+/// }
+///
+/// #[derive(NearlyEq, Debug)]
+/// #[nearly(eps = 0.01)]
+/// struct Point {
+///     x: f32,
+///     y: f32,
+/// }
+///
+/// assert_eq!(<Sample as EpsTolerance>::DEFAULT, (0.01, <f32 as EpsTolerance>::DEFAULT));
+/// assert_eq!(<Point as EpsTolerance>::DEFAULT, 0.01);
+///
+/// let a = Sample{coarse: 1.0, fine: 1.0};
+/// let b = Sample{coarse: 1.005, fine: 1.0};
+/// assert_nearly!(a == b);
+///
+/// let a = Point{x: 1.0, y: 2.0};
+/// let b = Point{x: 1.005, y: 2.005};
+/// assert_nearly!(a == b);
+/// ```
 pub use nearly_macros::NearlyEq;
 
+/// Derives the [NearlyEqReport] trait for a custom type.
+///
+/// This trait can be derived for structs with named or unnamed fields as well as enums.
+/// To derive this trait, the type has to implement [NearlyEqTol] and all types used for fields
+/// have to implement [NearlyEqReport]. Each field is reported at the path of its name or, for
+/// unnamed fields, its index. The fields of an enum variant are preceded by the variant name,
+/// e.g. `Rect.1`. Exactly compared fields and fields compared by a `with` module are reported as
+/// [MismatchKind::NotEqual]. Enums with different variants are reported as
+/// [MismatchKind::Variant].
+///
+/// # Example
+///
+/// ```
+/// use nearly::{MismatchKind, NearlyEq, NearlyEqReport, PathSegment};
+///
+/// #[derive(NearlyEq, NearlyEqReport, Debug)]
+/// struct Point {
+///     x: f32,
+///     y: f32,
+/// }
+///
+/// let a = Point{x: 1.0, y: 2.0};
+/// let b = Point{x: 1.0, y: 2.5};
+///
+/// let report = a.nearly_eq_eps_report(&b, &0.1);
+/// let mismatch = report.first().unwrap();
+/// assert_eq!(mismatch.path.0, vec![PathSegment::Field("y")]);
+/// assert!(matches!(mismatch.kind, MismatchKind::Value { .. }));
+/// ```
+#[cfg(feature = "std")]
+pub use nearly_macros::NearlyEqReport;
+
 /// Derives the [NearlyOrdEps] trait for a custom type.
 ///
 /// This trait can be derived for structs with named or unnamed fields as well as enums.
@@ -849,6 +1506,62 @@ pub use nearly_macros::NearlyOrdEps;
 /// ```
 pub use nearly_macros::NearlyOrdUlps;
 
+/// Derives the [NearlyOrdRel] trait for a custom type.
+///
+/// This trait can be derived for structs with named or unnamed fields as well as enums.
+/// To derive this trait, all types used for fields have to implemented [NearlyOrdRel].
+///
+/// To derive [NearlyOrdRel] on a type, this type also has to implement or derive [NearlyEqRel].
+///
+/// To use the [assert_nearly!] and [debug_assert_nearly!] macros, your type must also implement
+/// the Debug trait.
+///
+/// # Example
+///
+/// ## Same Type
+///
+/// If all fields have the same type, the relative tolerance will have the same type as the
+/// relative tolerance of the fields type. E.g., for [f32] this would be [f32].
+///
+/// ```
+/// use nearly::{NearlyEqRel, NearlyOrdRel};
+///
+/// #[derive(NearlyEqRel, NearlyOrdRel, Debug)]
+/// struct Point {
+///     x: f32,
+///     y: f32,
+///     z: f32,
+/// }
+///
+/// let a = Point{x: -3.4, y: 2.1, z: 1.0e6};
+/// let b = Point{x: -3.4, y: 2.1, z: 1.000001e6};
+///
+/// assert!(a.nearly_le_rel(&b, &0.00001));
+/// ```
+///
+/// ## Different Types
+///
+/// If the fields have different types, the relative tolerance will have a tuple type. The tuple
+/// will consist of the relative types of the fields type in the same order as they are defined.
+/// E.g., for fields with the type [f32], [f64] and [f32] this would be ([f32], [f64], [f32]).
+///
+/// ```
+/// use nearly::{NearlyEqRel, NearlyOrdRel};
+///
+/// #[derive(NearlyEqRel, NearlyOrdRel, Debug)]
+/// struct Point {
+///     x: f32,
+///     y: f64,
+///     z: f32,
+/// }
+///
+/// let a = Point{x: -3.4, y: 2.1, z: 1.0e6};
+/// let b = Point{x: -3.4, y: 2.1, z: 1.000001e6};
+///
+/// assert!(a.nearly_le_rel(&b, &(0.00001, 0.000001, 0.00001)));
+/// ```
+pub use nearly_macros::NearlyOrdRel;
+
 /// Derives the [NearlyOrdTol] trait for a custom type.
 ///
 /// This trait can be derived for structs with named or unnamed fields as well as enums.
@@ -984,24 +1697,75 @@ pub use nearly_macros::NearlyOrdTol;
 /// ```
 pub use nearly_macros::NearlyOrd;
 
+mod iter;
+pub use iter::NearlyIterExt;
+
+mod lexicographic;
+pub use lexicographic::Lexicographic;
+
+#[doc(hidden)]
+pub mod macro_support;
+
 mod nearly_eq;
 pub use nearly_eq::NearlyEq;
 pub use nearly_eq::NearlyEqEps;
+pub use nearly_eq::NearlyEqRel;
 pub use nearly_eq::NearlyEqTol;
 pub use nearly_eq::NearlyEqUlps;
+pub use nearly_eq::NearlyEqUlpsAcrossZero;
 
 mod nearly_ord;
 pub use nearly_ord::NearlyOrd;
 pub use nearly_ord::NearlyOrdEps;
+pub use nearly_ord::NearlyOrdRel;
 pub use nearly_ord::NearlyOrdTol;
 pub use nearly_ord::NearlyOrdUlps;
 
+mod per_element;
+pub use per_element::PerElement;
+
+#[cfg(feature = "std")]
+mod report;
+#[cfg(feature = "std")]
+pub use report::FloatValue;
+#[cfg(feature = "std")]
+pub use report::Mismatch;
+#[cfg(feature = "std")]
+pub use report::MismatchKind;
+#[cfg(feature = "std")]
+pub use report::NearlyEqReport;
+#[cfg(feature = "std")]
+pub use report::Path;
+#[cfg(feature = "std")]
+pub use report::PathSegment;
+#[cfg(feature = "std")]
+pub use report::Report;
+#[cfg(feature = "std")]
+pub use report::ReportTolerance;
+
 mod tolerance;
 pub use tolerance::EpsTolerance;
 pub use tolerance::EpsToleranceType;
+pub use tolerance::RelTolerance;
+pub use tolerance::RelToleranceType;
 pub use tolerance::Tolerance;
+pub use tolerance::ToleranceMode;
 pub use tolerance::UlpsTolerance;
 pub use tolerance::UlpsToleranceType;
 
 mod trait_impl;
 mod ulps;
+pub use ulps::Ulps;
+
+#[cfg(feature = "std")]
+mod unordered;
+#[cfg(feature = "std")]
+pub use unordered::Unordered;
+
+mod wrapper;
+pub use wrapper::DefaultTolerance;
+pub use wrapper::NanFirst;
+pub use wrapper::NanLast;
+pub use wrapper::NanUnordered;
+pub use wrapper::Nearly;
+pub use wrapper::ToleranceProvider;
@@ -0,0 +1,327 @@
+//! Helpers used by the code generated by the nearly macros.
+//!
+//! This module is not part of the public API and may change at any time.
+
+use core::fmt::{Debug, Display, Formatter, Result};
+
+use crate::tolerance::{
+    EpsTolerance, EpsToleranceType, RelTolerance, RelToleranceType, Tolerance, ToleranceMode,
+    UlpsTolerance, UlpsToleranceType,
+};
+
+/// The relative error observed between two values.
+///
+/// The relative error can only be determined for floating point values. For all other types
+/// the relative error is unknown.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum ObservedRelError {
+    F32(f32),
+    F64(f64),
+    Unknown,
+}
+
+impl Display for ObservedRelError {
+    /// Formats the observed relative error as suffix of a relative tolerance.
+    /// An unknown relative error results in an empty output.
+    fn fmt(&self, f: &mut Formatter<'_>) -> Result {
+        match self {
+            ObservedRelError::F32(error) => write!(f, " (observed: {:?})", error),
+            ObservedRelError::F64(error) => write!(f, " (observed: {:?})", error),
+            ObservedRelError::Unknown => Ok(()),
+        }
+    }
+}
+
+/// Wraps both sides of a comparison to determine the observed relative error.
+///
+/// Call `(&RelError(left, right)).observed_rel_error()` with both [ObserveRelError] and
+/// [ObserveRelErrorFallback] in scope. For floating point values, the implementation of
+/// [ObserveRelError] is selected. For all other types, method resolution falls back to
+/// [ObserveRelErrorFallback].
+pub struct RelError<'a, Lhs: ?Sized, Rhs: ?Sized>(pub &'a Lhs, pub &'a Rhs);
+
+/// Determines the observed relative error of floating point values.
+pub trait ObserveRelError {
+    fn observed_rel_error(&self) -> ObservedRelError;
+}
+
+/// Fallback for all types the observed relative error cannot be determined for.
+pub trait ObserveRelErrorFallback {
+    fn observed_rel_error(&self) -> ObservedRelError;
+}
+
+impl<Lhs: ?Sized, Rhs: ?Sized> ObserveRelErrorFallback for &RelError<'_, Lhs, Rhs> {
+    fn observed_rel_error(&self) -> ObservedRelError {
+        ObservedRelError::Unknown
+    }
+}
+
+macro_rules! impl_observe_rel_error {
+    ($float: ty, $variant: ident) => {
+        impl ObserveRelError for RelError<'_, $float, $float> {
+            fn observed_rel_error(&self) -> ObservedRelError {
+                let (a, b) = (*self.0, *self.1);
+                if a == b {
+                    return ObservedRelError::$variant(0.0);
+                }
+
+                let abs = |value: $float| if value < 0.0 { -value } else { value };
+                let largest = if abs(a) > abs(b) { abs(a) } else { abs(b) };
+
+                ObservedRelError::$variant(abs(a - b) / largest)
+            }
+        }
+    };
+}
+
+impl_observe_rel_error!(f32, F32);
+impl_observe_rel_error!(f64, F64);
+
+/// The optional components of a [Tolerance](crate::Tolerance) printed on a failed assertion.
+///
+/// The relative epsilon value is only printed if present and the mode is only printed if it is not
+/// [ToleranceMode::Any].
+pub struct ToleranceDetails<'a, T>(pub &'a Option<T>, pub &'a ToleranceMode);
+
+impl<T: Debug> Display for ToleranceDetails<'_, T> {
+    fn fmt(&self, f: &mut Formatter<'_>) -> Result {
+        if let Some(rel) = self.0 {
+            write!(f, "\n   rel: {:?}", rel)?;
+        }
+        if *self.1 != ToleranceMode::Any {
+            write!(f, "\n  mode: {:?}", self.1)?;
+        }
+        Ok(())
+    }
+}
+
+/// The maximum number of mismatches listed by [Diff].
+pub const MAX_DIFF_ENTRIES: usize = 10;
+
+/// The mismatching parts of two compound values printed on a failed assertion.
+///
+/// Only the first [MAX_DIFF_ENTRIES] mismatches are listed, followed by the number of omitted
+/// mismatches. Without the `std` feature, no diff can be created.
+pub struct Diff {
+    #[cfg(feature = "std")]
+    report: crate::Report,
+}
+
+#[cfg(feature = "std")]
+impl Diff {
+    /// Creates a diff of `report`.
+    ///
+    /// Returns `None` if `report` has no mismatches or the compared values are floating point
+    /// values themselves, since these values are printed as a whole anyway.
+    fn new(report: crate::Report) -> Option<Self> {
+        let is_value = |mismatch: &crate::Mismatch| {
+            mismatch.path.0.is_empty() && matches!(mismatch.kind, crate::MismatchKind::Value { .. })
+        };
+        if report.mismatches().iter().all(is_value) {
+            return None;
+        }
+        Some(Self { report })
+    }
+}
+
+impl Display for Diff {
+    #[cfg(feature = "std")]
+    fn fmt(&self, f: &mut Formatter<'_>) -> Result {
+        use crate::MismatchKind;
+
+        let mismatches = self.report.mismatches();
+        write!(
+            f,
+            "{} of {} elements differ",
+            mismatches.len(),
+            self.report.compared()
+        )?;
+
+        for mismatch in mismatches.iter().take(MAX_DIFF_ENTRIES) {
+            write!(f, "\n        ")?;
+            if !mismatch.path.0.is_empty() {
+                write!(f, "{}: ", mismatch.path)?;
+            }
+            // the tolerance is already printed once for the whole assertion
+            match &mismatch.kind {
+                MismatchKind::Value {
+                    lhs,
+                    rhs,
+                    diff,
+                    ulps,
+                    ..
+                } => {
+                    write!(f, "{} != {} (diff: {}, ulps: ", lhs, rhs, diff)?;
+                    match ulps {
+                        Some(ulps) => write!(f, "{})", ulps)?,
+                        None => write!(f, "-)")?,
+                    }
+                }
+                kind => write!(f, "{}", kind)?,
+            }
+        }
+
+        if mismatches.len() > MAX_DIFF_ENTRIES {
+            write!(
+                f,
+                "\n        ... and {} more",
+                mismatches.len() - MAX_DIFF_ENTRIES
+            )?;
+        }
+        Ok(())
+    }
+
+    #[cfg(not(feature = "std"))]
+    fn fmt(&self, _f: &mut Formatter<'_>) -> Result {
+        Ok(())
+    }
+}
+
+/// Wraps both sides of a comparison to create a [Diff] of their mismatching parts.
+///
+/// Call e.g. `(&Reported(left, right)).report_eps(eps)` with both [ReportDiff] and
+/// [ReportDiffFallback] in scope. For types implementing
+/// [NearlyEqReport](crate::NearlyEqReport), the implementation of [ReportDiff] is selected. For
+/// all other types, method resolution falls back to [ReportDiffFallback] which creates no diff.
+pub struct Reported<'a, Lhs: ?Sized, Rhs: ?Sized>(pub &'a Lhs, pub &'a Rhs);
+
+/// Creates a diff of the mismatching parts of types implementing
+/// [NearlyEqReport](crate::NearlyEqReport).
+pub trait ReportDiff<LhsTol, RhsTol>
+where
+    LhsTol: ?Sized + EpsTolerance<RhsTol> + UlpsTolerance<RhsTol> + RelTolerance<RhsTol>,
+    RhsTol: ?Sized,
+{
+    fn report_eps(&self, eps: &EpsToleranceType<LhsTol, RhsTol>) -> Option<Diff>;
+    fn report_ulps(&self, ulps: &UlpsToleranceType<LhsTol, RhsTol>) -> Option<Diff>;
+    fn report_rel(&self, rel: &RelToleranceType<LhsTol, RhsTol>) -> Option<Diff>;
+    fn report_tol(&self, tol: &Tolerance<LhsTol, RhsTol>) -> Option<Diff>;
+    fn report_eps_ulps(
+        &self,
+        eps: &EpsToleranceType<LhsTol, RhsTol>,
+        ulps: &UlpsToleranceType<LhsTol, RhsTol>,
+    ) -> Option<Diff>;
+    fn report_eps_rel(
+        &self,
+        eps: &EpsToleranceType<LhsTol, RhsTol>,
+        rel: &RelToleranceType<LhsTol, RhsTol>,
+    ) -> Option<Diff>;
+    fn report_ulps_rel(
+        &self,
+        ulps: &UlpsToleranceType<LhsTol, RhsTol>,
+        rel: &RelToleranceType<LhsTol, RhsTol>,
+    ) -> Option<Diff>;
+    fn report_eps_ulps_rel(
+        &self,
+        eps: &EpsToleranceType<LhsTol, RhsTol>,
+        ulps: &UlpsToleranceType<LhsTol, RhsTol>,
+        rel: &RelToleranceType<LhsTol, RhsTol>,
+    ) -> Option<Diff>;
+    fn report_default(&self) -> Option<Diff>;
+}
+
+/// Fallback for all types no diff can be created for.
+pub trait ReportDiffFallback {
+    fn report_eps<T: ?Sized>(&self, _eps: &T) -> Option<Diff> {
+        None
+    }
+    fn report_ulps<T: ?Sized>(&self, _ulps: &T) -> Option<Diff> {
+        None
+    }
+    fn report_rel<T: ?Sized>(&self, _rel: &T) -> Option<Diff> {
+        None
+    }
+    fn report_tol<T: ?Sized>(&self, _tol: &T) -> Option<Diff> {
+        None
+    }
+    fn report_eps_ulps<E: ?Sized, U: ?Sized>(&self, _eps: &E, _ulps: &U) -> Option<Diff> {
+        None
+    }
+    fn report_eps_rel<E: ?Sized, R: ?Sized>(&self, _eps: &E, _rel: &R) -> Option<Diff> {
+        None
+    }
+    fn report_ulps_rel<U: ?Sized, R: ?Sized>(&self, _ulps: &U, _rel: &R) -> Option<Diff> {
+        None
+    }
+    fn report_eps_ulps_rel<E: ?Sized, U: ?Sized, R: ?Sized>(
+        &self,
+        _eps: &E,
+        _ulps: &U,
+        _rel: &R,
+    ) -> Option<Diff> {
+        None
+    }
+    fn report_default(&self) -> Option<Diff> {
+        None
+    }
+}
+
+impl<Lhs: ?Sized, Rhs: ?Sized> ReportDiffFallback for &Reported<'_, Lhs, Rhs> {}
+
+#[cfg(feature = "std")]
+impl<Lhs, Rhs, LhsTol, RhsTol> ReportDiff<LhsTol, RhsTol> for Reported<'_, Lhs, Rhs>
+where
+    Lhs: ?Sized + crate::NearlyEqReport<Rhs, LhsTol, RhsTol>,
+    Rhs: ?Sized,
+    LhsTol: ?Sized + EpsTolerance<RhsTol> + UlpsTolerance<RhsTol> + RelTolerance<RhsTol>,
+    RhsTol: ?Sized,
+{
+    fn report_eps(&self, eps: &EpsToleranceType<LhsTol, RhsTol>) -> Option<Diff> {
+        Diff::new(self.0.nearly_eq_eps_report(self.1, eps))
+    }
+
+    fn report_ulps(&self, ulps: &UlpsToleranceType<LhsTol, RhsTol>) -> Option<Diff> {
+        Diff::new(self.0.nearly_eq_ulps_report(self.1, ulps))
+    }
+
+    fn report_rel(&self, rel: &RelToleranceType<LhsTol, RhsTol>) -> Option<Diff> {
+        Diff::new(self.0.nearly_eq_rel_report(self.1, rel))
+    }
+
+    fn report_tol(&self, tol: &Tolerance<LhsTol, RhsTol>) -> Option<Diff> {
+        Diff::new(self.0.nearly_eq_tol_report(self.1, tol))
+    }
+
+    fn report_eps_ulps(
+        &self,
+        eps: &EpsToleranceType<LhsTol, RhsTol>,
+        ulps: &UlpsToleranceType<LhsTol, RhsTol>,
+    ) -> Option<Diff> {
+        self.report_tol(&Tolerance::new(*eps, *ulps))
+    }
+
+    fn report_eps_rel(
+        &self,
+        eps: &EpsToleranceType<LhsTol, RhsTol>,
+        rel: &RelToleranceType<LhsTol, RhsTol>,
+    ) -> Option<Diff> {
+        let mut report = crate::Report::new();
+        let tol = crate::ReportTolerance::EpsAndRel(*eps, *rel);
+        self.0.report_nearly_eq(self.1, &tol, &mut report);
+        Diff::new(report)
+    }
+
+    fn report_ulps_rel(
+        &self,
+        ulps: &UlpsToleranceType<LhsTol, RhsTol>,
+        rel: &RelToleranceType<LhsTol, RhsTol>,
+    ) -> Option<Diff> {
+        let mut report = crate::Report::new();
+        let tol = crate::ReportTolerance::UlpsAndRel(*ulps, *rel);
+        self.0.report_nearly_eq(self.1, &tol, &mut report);
+        Diff::new(report)
+    }
+
+    fn report_eps_ulps_rel(
+        &self,
+        eps: &EpsToleranceType<LhsTol, RhsTol>,
+        ulps: &UlpsToleranceType<LhsTol, RhsTol>,
+        rel: &RelToleranceType<LhsTol, RhsTol>,
+    ) -> Option<Diff> {
+        self.report_tol(&Tolerance::new(*eps, *ulps).with_rel(*rel))
+    }
+
+    fn report_default(&self) -> Option<Diff> {
+        Diff::new(self.0.nearly_eq_report(self.1))
+    }
+}
@@ -1,5 +1,6 @@
 use crate::tolerance::{
-    EpsTolerance, EpsToleranceType, Tolerance, UlpsTolerance, UlpsToleranceType,
+    EpsTolerance, EpsToleranceType, RelTolerance, RelToleranceType, Tolerance, ToleranceMode,
+    UlpsTolerance, UlpsToleranceType,
 };
 
 /// A trait for nearly equality comparison based on an absolute epsilon value.
@@ -36,32 +37,110 @@ where
     }
 }
 
-/// A trait for nearly equality comparison based on a tolerance including an absolute epsilon value
-/// and an ulps value.
+/// A trait for nearly equality comparison based on an ulps value that is measured across zero.
+///
+/// Contrary to [NearlyEqUlps], values with different signs can be nearly equal.
+/// The ulps distance is measured on a monotonic integer line on which `+0` and `-0` are the same
+/// point. Therefore, the smallest positive and the smallest negative subnormal value have an ulps
+/// distance of 2.
+///
+/// This trait is implemented for floating point values. To compare other types, e.g. collections
+/// or derived types, across zero, use a [Tolerance] with
+/// [with_ulps_across_zero](Tolerance::with_ulps_across_zero()) instead.
+pub trait NearlyEqUlpsAcrossZero<Rhs = Self, LhsTol = Self, RhsTol = Rhs>
+where
+    Rhs: ?Sized,
+    LhsTol: ?Sized + UlpsTolerance<RhsTol>,
+    RhsTol: ?Sized,
+{
+    /// Returns whether `self` is nearly equal to `other` based on an ulps value `ulps` measured
+    /// across zero.
+    fn nearly_eq_ulps_across_zero(
+        &self,
+        other: &Rhs,
+        ulps: &UlpsToleranceType<LhsTol, RhsTol>,
+    ) -> bool;
+
+    /// Returns whether `self` is not nearly equal to `other` based on an ulps value `ulps`
+    /// measured across zero.
+    #[inline]
+    fn nearly_ne_ulps_across_zero(
+        &self,
+        other: &Rhs,
+        ulps: &UlpsToleranceType<LhsTol, RhsTol>,
+    ) -> bool {
+        !self.nearly_eq_ulps_across_zero(other, ulps)
+    }
+}
+
+/// A trait for nearly equality comparison based on a relative epsilon value.
+///
+/// The relative epsilon value is scaled by the larger magnitude of the compared values.
+pub trait NearlyEqRel<Rhs = Self, LhsTol = Self, RhsTol = Rhs>
+where
+    Rhs: ?Sized,
+    LhsTol: ?Sized + RelTolerance<RhsTol>,
+    RhsTol: ?Sized,
+{
+    /// Returns whether `self` is nearly equal to `other` based on a relative epsilon value `rel`.
+    fn nearly_eq_rel(&self, other: &Rhs, rel: &RelToleranceType<LhsTol, RhsTol>) -> bool;
+
+    /// Returns whether `self` is not nearly equal to `other` based on a relative epsilon value
+    /// `rel`.
+    #[inline]
+    fn nearly_ne_rel(&self, other: &Rhs, rel: &RelToleranceType<LhsTol, RhsTol>) -> bool {
+        !self.nearly_eq_rel(other, rel)
+    }
+}
+
+/// A trait for nearly equality comparison based on a tolerance including an absolute epsilon value,
+/// an ulps value and an optional relative epsilon value.
 ///
 /// See [Tolerance].
-/// This trait combines the traits [NearlyEqEps] and [NearlyEqUlps].
+/// This trait combines the traits [NearlyEqEps], [NearlyEqUlps] and [NearlyEqRel].
 pub trait NearlyEqTol<Rhs = Self, LhsTol = Self, RhsTol = Rhs>:
-    NearlyEqEps<Rhs, LhsTol, RhsTol> + NearlyEqUlps<Rhs, LhsTol, RhsTol>
+    NearlyEqEps<Rhs, LhsTol, RhsTol>
+    + NearlyEqUlps<Rhs, LhsTol, RhsTol>
+    + NearlyEqRel<Rhs, LhsTol, RhsTol>
 where
     Rhs: ?Sized,
-    LhsTol: ?Sized + EpsTolerance<RhsTol> + UlpsTolerance<RhsTol>,
+    LhsTol: ?Sized + EpsTolerance<RhsTol> + UlpsTolerance<RhsTol> + RelTolerance<RhsTol>,
     RhsTol: ?Sized,
 {
     /// Returns whether `self` is nearly equal to `other` based on a tolerance `tol`.
     ///
     /// Returns true if either `self` is nearly equal to `other` based on an absolute epsilon value
-    /// `tol.eps` or `self` is nearly equal to `other` based on an ulps value `tol.ulps`.
+    /// `tol.eps` or `self` is nearly equal to `other` based on an ulps value `tol.ulps` or, if
+    /// present, `self` is nearly equal to `other` based on a relative epsilon value `tol.rel`.
+    /// If `tol.mode` is [ToleranceMode::All], `self` must be nearly equal to `other` based on all
+    /// of these values.
+    ///
+    /// The implementations for floating point values measure the ulps distance across zero if
+    /// `tol.ulps_across_zero` is set, see [NearlyEqUlpsAcrossZero].
     #[inline]
     fn nearly_eq_tol(&self, other: &Rhs, tol: &Tolerance<LhsTol, RhsTol>) -> bool {
-        self.nearly_eq_eps(other, &tol.eps) || self.nearly_eq_ulps(other, &tol.ulps)
+        match tol.mode {
+            ToleranceMode::Any => {
+                self.nearly_eq_eps(other, &tol.eps)
+                    || self.nearly_eq_ulps(other, &tol.ulps)
+                    || tol.rel.map_or(false, |rel| self.nearly_eq_rel(other, &rel))
+            }
+            ToleranceMode::All => {
+                self.nearly_eq_eps(other, &tol.eps)
+                    && self.nearly_eq_ulps(other, &tol.ulps)
+                    && tol.rel.map_or(true, |rel| self.nearly_eq_rel(other, &rel))
+            }
+        }
     }
 
     /// Returns whether `self` is not nearly equal to `other` based on a tolerance `tol`.
     ///
     /// Returns true if both `self` is not nearly equal to `other` based on an absolute epsilon value
     /// `tol.eps` ans `self`is not nearly equal to `other` based on an ulps value
-    /// `tol.ulps`.
+    /// `tol.ulps` and, if present, `self` is not nearly equal to `other` based on a relative
+    /// epsilon value `tol.rel`.
+    /// If `tol.mode` is [ToleranceMode::All], this is the negation of
+    /// [nearly_eq_tol](NearlyEqTol::nearly_eq_tol()).
     #[inline]
     fn nearly_ne_tol(&self, other: &Rhs, tol: &Tolerance<LhsTol, RhsTol>) -> bool {
         !self.nearly_eq_tol(other, tol)
@@ -76,7 +155,7 @@ pub trait NearlyEq<Rhs = Self, LhsTol = Self, RhsTol = Rhs>:
     NearlyEqTol<Rhs, LhsTol, RhsTol>
 where
     Rhs: ?Sized,
-    LhsTol: ?Sized + EpsTolerance<RhsTol> + UlpsTolerance<RhsTol>,
+    LhsTol: ?Sized + EpsTolerance<RhsTol> + UlpsTolerance<RhsTol> + RelTolerance<RhsTol>,
     RhsTol: ?Sized,
 {
     /// Returns whether `self` is nearly equal to `other` based on the default tolerance for
@@ -1,8 +1,23 @@
-use crate::nearly_eq::{NearlyEqEps, NearlyEqUlps};
+use core::cmp::Ordering;
+
+use crate::nearly_eq::{NearlyEqEps, NearlyEqRel, NearlyEqUlps};
 use crate::tolerance::{
-    EpsTolerance, EpsToleranceType, Tolerance, UlpsTolerance, UlpsToleranceType,
+    EpsTolerance, EpsToleranceType, RelTolerance, RelToleranceType, Tolerance, ToleranceMode,
+    UlpsTolerance, UlpsToleranceType,
 };
 
+/// Returns the ordering of two values from whether the first one is nearly less than or equal to
+/// and nearly greater than or equal to the second one.
+#[inline]
+fn ordering_from(le: bool, ge: bool) -> Option<Ordering> {
+    match (le, ge) {
+        (true, true) => Some(Ordering::Equal),
+        (true, false) => Some(Ordering::Less),
+        (false, true) => Some(Ordering::Greater),
+        (false, false) => None,
+    }
+}
+
 /// A trait for nearly ordering comparison based on an absolute epsilon value.
 pub trait NearlyOrdEps<Rhs = Self, LhsTol = Self, RhsTol = Rhs>:
     NearlyEqEps<Rhs, LhsTol, RhsTol>
@@ -38,6 +53,24 @@ where
     fn nearly_ge_eps(&self, other: &Rhs, eps: &EpsToleranceType<LhsTol, RhsTol>) -> bool {
         self.nearly_gt_eps(other, eps) || self.nearly_eq_eps(other, eps)
     }
+
+    /// Returns the ordering of `self` and `other` based on an absolute epsilon value `eps`.
+    ///
+    /// Returns [Ordering::Equal] if `self` is both nearly less than or equal to and nearly
+    /// greater than or equal to `other`. Returns [Ordering::Less] if `self` is only nearly less
+    /// than or equal to `other` and [Ordering::Greater] if `self` is only nearly greater than or
+    /// equal to `other`. Returns `None` if the values are not comparable, e.g. for NaN.
+    #[inline]
+    fn nearly_cmp_eps(
+        &self,
+        other: &Rhs,
+        eps: &EpsToleranceType<LhsTol, RhsTol>,
+    ) -> Option<Ordering> {
+        ordering_from(
+            self.nearly_le_eps(other, eps),
+            self.nearly_ge_eps(other, eps),
+        )
+    }
 }
 
 /// A trait for nearly ordering comparison based on an ulps value.
@@ -77,18 +110,89 @@ where
     fn nearly_ge_ulps(&self, other: &Rhs, ulps: &UlpsToleranceType<LhsTol, RhsTol>) -> bool {
         self.nearly_gt_ulps(other, ulps) || self.nearly_eq_ulps(other, ulps)
     }
+
+    /// Returns the ordering of `self` and `other` based on an ulps value `ulps`.
+    ///
+    /// See [nearly_cmp_eps](NearlyOrdEps::nearly_cmp_eps()) for the returned ordering.
+    #[inline]
+    fn nearly_cmp_ulps(
+        &self,
+        other: &Rhs,
+        ulps: &UlpsToleranceType<LhsTol, RhsTol>,
+    ) -> Option<Ordering> {
+        ordering_from(
+            self.nearly_le_ulps(other, ulps),
+            self.nearly_ge_ulps(other, ulps),
+        )
+    }
 }
 
-/// A trait for nearly ordering comparison based on a tolerance including an absolute epsilon value
-/// and an ulps value.
+/// A trait for nearly ordering comparison based on a relative epsilon value.
+pub trait NearlyOrdRel<Rhs = Self, LhsTol = Self, RhsTol = Rhs>:
+    NearlyEqRel<Rhs, LhsTol, RhsTol>
+where
+    Rhs: ?Sized,
+    LhsTol: ?Sized + RelTolerance<RhsTol>,
+    RhsTol: ?Sized,
+{
+    /// Returns whether `self` is strict less than `other` but not nearly equal to `other`
+    /// based on a relative epsilon value `rel`.
+    ///
+    /// See [nearly_ne_rel](NearlyEqRel::nearly_ne_rel()).
+    fn nearly_lt_rel(&self, other: &Rhs, rel: &RelToleranceType<LhsTol, RhsTol>) -> bool;
+
+    /// Returns whether `self` is strict less than `other` or nearly equal to `other`
+    /// based on a relative epsilon value `rel`.
+    ///
+    /// See [nearly_eq_rel](NearlyEqRel::nearly_eq_rel()).
+    #[inline]
+    fn nearly_le_rel(&self, other: &Rhs, rel: &RelToleranceType<LhsTol, RhsTol>) -> bool {
+        self.nearly_lt_rel(other, rel) || self.nearly_eq_rel(other, rel)
+    }
+
+    /// Returns whether `self` is strict greater than `other` but not nearly equal to `other`
+    /// based on a relative epsilon value `rel`.
+    ///
+    /// See [nearly_ne_rel](NearlyEqRel::nearly_ne_rel()).
+    fn nearly_gt_rel(&self, other: &Rhs, rel: &RelToleranceType<LhsTol, RhsTol>) -> bool;
+
+    /// Returns whether `self` is strict greater than `other` or nearly equal to `other`
+    /// based on a relative epsilon value `rel`.
+    ///
+    /// See [nearly_eq_rel](NearlyEqRel::nearly_eq_rel()).
+    #[inline]
+    fn nearly_ge_rel(&self, other: &Rhs, rel: &RelToleranceType<LhsTol, RhsTol>) -> bool {
+        self.nearly_gt_rel(other, rel) || self.nearly_eq_rel(other, rel)
+    }
+
+    /// Returns the ordering of `self` and `other` based on a relative epsilon value `rel`.
+    ///
+    /// See [nearly_cmp_eps](NearlyOrdEps::nearly_cmp_eps()) for the returned ordering.
+    #[inline]
+    fn nearly_cmp_rel(
+        &self,
+        other: &Rhs,
+        rel: &RelToleranceType<LhsTol, RhsTol>,
+    ) -> Option<Ordering> {
+        ordering_from(
+            self.nearly_le_rel(other, rel),
+            self.nearly_ge_rel(other, rel),
+        )
+    }
+}
+
+/// A trait for nearly ordering comparison based on a tolerance including an absolute epsilon value,
+/// an ulps value and an optional relative epsilon value.
 ///
 /// See [Tolerance].
-/// This trait combines the traits [NearlyOrdEps] and [NearlyOrdUlps].
+/// This trait combines the traits [NearlyOrdEps], [NearlyOrdUlps] and [NearlyOrdRel].
 pub trait NearlyOrdTol<Rhs = Self, LhsTol = Self, RhsTol = Rhs>:
-    NearlyOrdEps<Rhs, LhsTol, RhsTol> + NearlyOrdUlps<Rhs, LhsTol, RhsTol>
+    NearlyOrdEps<Rhs, LhsTol, RhsTol>
+    + NearlyOrdUlps<Rhs, LhsTol, RhsTol>
+    + NearlyOrdRel<Rhs, LhsTol, RhsTol>
 where
     Rhs: ?Sized,
-    LhsTol: ?Sized + EpsTolerance<RhsTol> + UlpsTolerance<RhsTol>,
+    LhsTol: ?Sized + EpsTolerance<RhsTol> + UlpsTolerance<RhsTol> + RelTolerance<RhsTol>,
     RhsTol: ?Sized,
 {
     /// Returns whether `self` is strict less than `other` but not nearly equal to `other`
@@ -96,13 +200,29 @@ where
     ///
     /// Returns true if `self` is strict less than `other` but not nearly equal to `other`
     /// based on an absolute epsilon value `tol.eps` and not nearly equal to `other` based
-    /// on an ulps value `tol.ulps`.
+    /// on an ulps value `tol.ulps` and, if present, not nearly equal to `other` based on a relative
+    /// epsilon value `tol.rel`.
+    /// If `tol.mode` is [ToleranceMode::All], `self` must only not be nearly equal to `other`
+    /// based on any of these values, since the values are nearly equal only if they are nearly
+    /// equal based on all of them.
     ///
-    /// See [nearly_ne_eps](NearlyEqEps::nearly_ne_eps()) and
-    /// [nearly_ne_ulps](NearlyEqUlps::nearly_ne_ulps()).
+    /// See [nearly_ne_eps](NearlyEqEps::nearly_ne_eps()),
+    /// [nearly_ne_ulps](NearlyEqUlps::nearly_ne_ulps()) and
+    /// [nearly_ne_rel](NearlyEqRel::nearly_ne_rel()).
     #[inline]
     fn nearly_lt_tol(&self, other: &Rhs, tol: &Tolerance<LhsTol, RhsTol>) -> bool {
-        self.nearly_lt_eps(other, &tol.eps) || self.nearly_lt_ulps(other, &tol.ulps)
+        match tol.mode {
+            ToleranceMode::Any => {
+                self.nearly_lt_eps(other, &tol.eps)
+                    && self.nearly_lt_ulps(other, &tol.ulps)
+                    && tol.rel.map_or(true, |rel| self.nearly_lt_rel(other, &rel))
+            }
+            ToleranceMode::All => {
+                self.nearly_lt_eps(other, &tol.eps)
+                    || self.nearly_lt_ulps(other, &tol.ulps)
+                    || tol.rel.map_or(false, |rel| self.nearly_lt_rel(other, &rel))
+            }
+        }
     }
 
     /// Returns whether `self` is strict less than `other` or nearly equal to `other`
@@ -110,13 +230,27 @@ where
     ///
     /// Returns true if `self` is strict less than `other` or nearly equal to `other`
     /// based on an absolute epsilon value `tol.eps` or nearly equal to `other` based
-    /// on an ulps value `tol.ulps`.
+    /// on an ulps value `tol.ulps` or, if present, nearly equal to `other` based on a relative
+    /// epsilon value `tol.rel`.
+    /// If `tol.mode` is [ToleranceMode::All], the comparison must be true for all of these values.
     ///
-    /// See [nearly_eq_eps](NearlyEqEps::nearly_eq_eps()) and
-    /// [nearly_eq_ulps](NearlyEqUlps::nearly_eq_ulps()).
+    /// See [nearly_eq_eps](NearlyEqEps::nearly_eq_eps()),
+    /// [nearly_eq_ulps](NearlyEqUlps::nearly_eq_ulps()) and
+    /// [nearly_eq_rel](NearlyEqRel::nearly_eq_rel()).
     #[inline]
     fn nearly_le_tol(&self, other: &Rhs, tol: &Tolerance<LhsTol, RhsTol>) -> bool {
-        self.nearly_le_eps(other, &tol.eps) || self.nearly_le_ulps(other, &tol.ulps)
+        match tol.mode {
+            ToleranceMode::Any => {
+                self.nearly_le_eps(other, &tol.eps)
+                    || self.nearly_le_ulps(other, &tol.ulps)
+                    || tol.rel.map_or(false, |rel| self.nearly_le_rel(other, &rel))
+            }
+            ToleranceMode::All => {
+                self.nearly_le_eps(other, &tol.eps)
+                    && self.nearly_le_ulps(other, &tol.ulps)
+                    && tol.rel.map_or(true, |rel| self.nearly_le_rel(other, &rel))
+            }
+        }
     }
 
     /// Returns whether `self` is strict greater than `other` but not nearly equal to `other`
@@ -124,13 +258,29 @@ where
     ///
     /// Returns true if `self` is strict greater than `other` but not nearly equal to `other`
     /// based on an absolute epsilon value `tol.eps` and not nearly equal to `other` based
-    /// on an ulps value `tol.ulps`.
+    /// on an ulps value `tol.ulps` and, if present, not nearly equal to `other` based on a relative
+    /// epsilon value `tol.rel`.
+    /// If `tol.mode` is [ToleranceMode::All], `self` must only not be nearly equal to `other`
+    /// based on any of these values, since the values are nearly equal only if they are nearly
+    /// equal based on all of them.
     ///
-    /// See [nearly_ne_eps](NearlyEqEps::nearly_ne_eps()) and
-    /// [nearly_ne_ulps](NearlyEqUlps::nearly_ne_ulps()).
+    /// See [nearly_ne_eps](NearlyEqEps::nearly_ne_eps()),
+    /// [nearly_ne_ulps](NearlyEqUlps::nearly_ne_ulps()) and
+    /// [nearly_ne_rel](NearlyEqRel::nearly_ne_rel()).
     #[inline]
     fn nearly_gt_tol(&self, other: &Rhs, tol: &Tolerance<LhsTol, RhsTol>) -> bool {
-        self.nearly_gt_eps(other, &tol.eps) || self.nearly_gt_ulps(other, &tol.ulps)
+        match tol.mode {
+            ToleranceMode::Any => {
+                self.nearly_gt_eps(other, &tol.eps)
+                    && self.nearly_gt_ulps(other, &tol.ulps)
+                    && tol.rel.map_or(true, |rel| self.nearly_gt_rel(other, &rel))
+            }
+            ToleranceMode::All => {
+                self.nearly_gt_eps(other, &tol.eps)
+                    || self.nearly_gt_ulps(other, &tol.ulps)
+                    || tol.rel.map_or(false, |rel| self.nearly_gt_rel(other, &rel))
+            }
+        }
     }
 
     /// Returns whether `self` is strict greater than `other` or nearly equal to `other`
@@ -138,13 +288,38 @@ where
     ///
     /// Returns true if `self` is strict greater than `other` or nearly equal to `other`
     /// based on an absolute epsilon value `tol.eps` or nearly equal to `other` based
-    /// on an ulps value `tol.ulps`.
+    /// on an ulps value `tol.ulps` or, if present, nearly equal to `other` based on a relative
+    /// epsilon value `tol.rel`.
+    /// If `tol.mode` is [ToleranceMode::All], the comparison must be true for all of these values.
     ///
-    /// See [nearly_eq_eps](NearlyEqEps::nearly_eq_eps()) and
-    /// [nearly_eq_ulps](NearlyEqUlps::nearly_eq_ulps()).
+    /// See [nearly_eq_eps](NearlyEqEps::nearly_eq_eps()),
+    /// [nearly_eq_ulps](NearlyEqUlps::nearly_eq_ulps()) and
+    /// [nearly_eq_rel](NearlyEqRel::nearly_eq_rel()).
     #[inline]
     fn nearly_ge_tol(&self, other: &Rhs, tol: &Tolerance<LhsTol, RhsTol>) -> bool {
-        self.nearly_ge_eps(other, &tol.eps) || self.nearly_ge_ulps(other, &tol.ulps)
+        match tol.mode {
+            ToleranceMode::Any => {
+                self.nearly_ge_eps(other, &tol.eps)
+                    || self.nearly_ge_ulps(other, &tol.ulps)
+                    || tol.rel.map_or(false, |rel| self.nearly_ge_rel(other, &rel))
+            }
+            ToleranceMode::All => {
+                self.nearly_ge_eps(other, &tol.eps)
+                    && self.nearly_ge_ulps(other, &tol.ulps)
+                    && tol.rel.map_or(true, |rel| self.nearly_ge_rel(other, &rel))
+            }
+        }
+    }
+
+    /// Returns the ordering of `self` and `other` based on a tolerance `tol`.
+    ///
+    /// See [nearly_cmp_eps](NearlyOrdEps::nearly_cmp_eps()) for the returned ordering.
+    #[inline]
+    fn nearly_cmp_tol(&self, other: &Rhs, tol: &Tolerance<LhsTol, RhsTol>) -> Option<Ordering> {
+        ordering_from(
+            self.nearly_le_tol(other, tol),
+            self.nearly_ge_tol(other, tol),
+        )
     }
 }
 
@@ -156,7 +331,7 @@ pub trait NearlyOrd<Rhs = Self, LhsTol = Self, RhsTol = Rhs>:
     NearlyOrdTol<Rhs, LhsTol, RhsTol>
 where
     Rhs: ?Sized,
-    LhsTol: ?Sized + EpsTolerance<RhsTol> + UlpsTolerance<RhsTol>,
+    LhsTol: ?Sized + EpsTolerance<RhsTol> + UlpsTolerance<RhsTol> + RelTolerance<RhsTol>,
     RhsTol: ?Sized,
 {
     /// Returns whether `self` is strict less than `other` but not nearly equal to `other`
@@ -186,4 +361,12 @@ where
     fn nearly_ge(&self, other: &Rhs) -> bool {
         self.nearly_ge_tol(other, &Tolerance::<LhsTol, RhsTol>::default())
     }
+
+    /// Returns the ordering of `self` and `other` based on the default tolerance for comparisons of `self` with `other`.
+    ///
+    /// See [nearly_cmp_eps](NearlyOrdEps::nearly_cmp_eps()) for the returned ordering.
+    #[inline]
+    fn nearly_cmp(&self, other: &Rhs) -> Option<Ordering> {
+        ordering_from(self.nearly_le(other), self.nearly_ge(other))
+    }
 }
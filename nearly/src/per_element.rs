@@ -0,0 +1,29 @@
+/// A wrapper to compare tuples by nearly comparisons with a tolerance for each element.
+///
+/// The nearly traits implemented for tuples compare all elements with the same tolerance.
+/// Therefore, all elements have to be of the same type, like `(f32, f32)`. Wrapping tuples into
+/// [PerElement] compares each element with its own tolerance instead. The tolerance is a tuple of
+/// the tolerances of the elements, so the elements may have different types like `(f32, f64)` or
+/// `(f32, Vec<f64>)`. Unlike tuples, [PerElement] implements the tolerance traits, so it can be
+/// used as the element of a collection or as the field of a derived type.
+///
+/// ```
+/// use nearly::{nearly, EpsTolerance, PerElement};
+///
+/// let a = PerElement((1.0f32, 2.0f64));
+/// let b = PerElement((1.05f32, 2.0f64));
+///
+/// assert!(nearly!(a == b, eps = (0.1, 0.1)));
+/// assert!(!nearly!(a == b, eps = (0.01, 0.1)));
+/// assert_eq!(
+///     <PerElement<(f32, f64)> as EpsTolerance>::DEFAULT,
+///     (<f32 as EpsTolerance>::DEFAULT, <f64 as EpsTolerance>::DEFAULT)
+/// );
+///
+/// let a = (1.0, 2.0);
+/// let b = (1.05, 2.0);
+/// assert!(nearly!(a == b, eps = 0.1));
+/// assert!(nearly!(PerElement(a) == PerElement(b), eps = (0.1, 0.01)));
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Default)]
+pub struct PerElement<T>(pub T);
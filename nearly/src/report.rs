@@ -0,0 +1,446 @@
+#![cfg(feature = "std")]
+
+use crate::nearly_eq::NearlyEqTol;
+use crate::tolerance::{
+    EpsTolerance, EpsToleranceType, RelTolerance, RelToleranceType, Tolerance, ToleranceMode,
+    UlpsTolerance, UlpsToleranceType,
+};
+
+use std::fmt::{Debug, Display, Formatter, Result};
+use std::string::String;
+use std::vec::Vec;
+
+/// A trait to explain why two values are not nearly equal.
+///
+/// Contrary to the other nearly traits, the functions of this trait do not return a `bool` but a
+/// [Report] containing a [Mismatch] for each part of `self` that is not nearly equal to the
+/// corresponding part of `other`. Each mismatch holds the [Path] to that part, e.g. the field
+/// names, indices and map keys. If the report is empty, `self` is nearly equal to `other`.
+///
+/// The trait can be derived by [NearlyEqReport](nearly_macros::NearlyEqReport).
+///
+/// ```
+/// use nearly::{NearlyEqReport, PathSegment};
+///
+/// let a = vec![1.0, 2.0, 3.0, 4.0];
+/// let b = vec![1.0, 2.5, 3.0, 4.5];
+///
+/// let report = a.nearly_eq_eps_report(&b, &0.1);
+/// assert!(!report.is_nearly_eq());
+/// assert_eq!(report.mismatches().len(), 2);
+/// assert_eq!(report.first().unwrap().path.0, vec![PathSegment::Index(1)]);
+/// assert_eq!(report.first().unwrap().to_string(), "[1]: 2.0 != 2.5 (diff: 0.5, ulps: 1125899906842624, eps: 0.1)");
+/// ```
+pub trait NearlyEqReport<Rhs = Self, LhsTol = Self, RhsTol = Rhs>:
+    NearlyEqTol<Rhs, LhsTol, RhsTol>
+where
+    Rhs: ?Sized,
+    LhsTol: ?Sized + EpsTolerance<RhsTol> + UlpsTolerance<RhsTol> + RelTolerance<RhsTol>,
+    RhsTol: ?Sized,
+{
+    /// Adds a mismatch to `report` for each part of `self` that is not nearly equal to the
+    /// corresponding part of `other` based on the tolerance `tol`.
+    ///
+    /// The mismatches are added at the current path of `report`.
+    fn report_nearly_eq(
+        &self,
+        other: &Rhs,
+        tol: &ReportTolerance<LhsTol, RhsTol>,
+        report: &mut Report,
+    );
+
+    /// Returns a report of all parts of `self` that are not nearly equal to the corresponding
+    /// parts of `other` based on an absolute epsilon value `eps`.
+    fn nearly_eq_eps_report(&self, other: &Rhs, eps: &EpsToleranceType<LhsTol, RhsTol>) -> Report {
+        let mut report = Report::new();
+        self.report_nearly_eq(other, &ReportTolerance::Eps(*eps), &mut report);
+        report
+    }
+
+    /// Returns a report of all parts of `self` that are not nearly equal to the corresponding
+    /// parts of `other` based on an ulps value `ulps`.
+    fn nearly_eq_ulps_report(
+        &self,
+        other: &Rhs,
+        ulps: &UlpsToleranceType<LhsTol, RhsTol>,
+    ) -> Report {
+        let mut report = Report::new();
+        self.report_nearly_eq(other, &ReportTolerance::Ulps(*ulps), &mut report);
+        report
+    }
+
+    /// Returns a report of all parts of `self` that are not nearly equal to the corresponding
+    /// parts of `other` based on a relative epsilon value `rel`.
+    fn nearly_eq_rel_report(&self, other: &Rhs, rel: &RelToleranceType<LhsTol, RhsTol>) -> Report {
+        let mut report = Report::new();
+        self.report_nearly_eq(other, &ReportTolerance::Rel(*rel), &mut report);
+        report
+    }
+
+    /// Returns a report of all parts of `self` that are not nearly equal to the corresponding
+    /// parts of `other` based on a tolerance `tol`.
+    fn nearly_eq_tol_report(&self, other: &Rhs, tol: &Tolerance<LhsTol, RhsTol>) -> Report {
+        let mut report = Report::new();
+        self.report_nearly_eq(other, &ReportTolerance::Tol(*tol), &mut report);
+        report
+    }
+
+    /// Returns a report of all parts of `self` that are not nearly equal to the corresponding
+    /// parts of `other` based on the default tolerance for comparisons of `Self` with `other`.
+    fn nearly_eq_report(&self, other: &Rhs) -> Report {
+        self.nearly_eq_tol_report(other, &Tolerance::<LhsTol, RhsTol>::default())
+    }
+}
+
+/// The tolerance a [Report] is created with.
+///
+/// This is the tolerance of one of the functions of [NearlyEqReport]. Values of compound types
+/// pass the matching part of the tolerance to each of their parts.
+pub enum ReportTolerance<Lhs, Rhs = Lhs>
+where
+    Lhs: ?Sized + EpsTolerance<Rhs> + UlpsTolerance<Rhs> + RelTolerance<Rhs>,
+    Rhs: ?Sized,
+{
+    /// An absolute epsilon value like for [NearlyEqEps](crate::NearlyEqEps).
+    Eps(EpsToleranceType<Lhs, Rhs>),
+    /// An ulps value like for [NearlyEqUlps](crate::NearlyEqUlps).
+    Ulps(UlpsToleranceType<Lhs, Rhs>),
+    /// A relative epsilon value like for [NearlyEqRel](crate::NearlyEqRel).
+    Rel(RelToleranceType<Lhs, Rhs>),
+    /// A tolerance like for [NearlyEqTol].
+    Tol(Tolerance<Lhs, Rhs>),
+    /// An absolute epsilon value combined with a relative epsilon value like for the `eps` and
+    /// `rel` arguments of the [nearly](crate::nearly!) macros. The values are nearly equal if
+    /// they are nearly equal based on any of both.
+    EpsAndRel(EpsToleranceType<Lhs, Rhs>, RelToleranceType<Lhs, Rhs>),
+    /// An ulps value combined with a relative epsilon value like for the `ulps` and `rel`
+    /// arguments of the [nearly](crate::nearly!) macros. The values are nearly equal if they are
+    /// nearly equal based on any of both.
+    UlpsAndRel(UlpsToleranceType<Lhs, Rhs>, RelToleranceType<Lhs, Rhs>),
+}
+
+impl<Lhs, Rhs> ReportTolerance<Lhs, Rhs>
+where
+    Lhs: ?Sized + EpsTolerance<Rhs> + UlpsTolerance<Rhs> + RelTolerance<Rhs>,
+    Rhs: ?Sized,
+{
+    /// Returns whether `lhs` is nearly equal to `rhs` based on this tolerance.
+    pub fn nearly_eq<A, B>(&self, lhs: &A, rhs: &B) -> bool
+    where
+        A: ?Sized + NearlyEqTol<B, Lhs, Rhs>,
+        B: ?Sized,
+    {
+        match self {
+            ReportTolerance::Eps(eps) => lhs.nearly_eq_eps(rhs, eps),
+            ReportTolerance::Ulps(ulps) => lhs.nearly_eq_ulps(rhs, ulps),
+            ReportTolerance::Rel(rel) => lhs.nearly_eq_rel(rhs, rel),
+            ReportTolerance::Tol(tol) => lhs.nearly_eq_tol(rhs, tol),
+            ReportTolerance::EpsAndRel(eps, rel) => {
+                lhs.nearly_eq_eps(rhs, eps) || lhs.nearly_eq_rel(rhs, rel)
+            }
+            ReportTolerance::UlpsAndRel(ulps, rel) => {
+                lhs.nearly_eq_ulps(rhs, ulps) || lhs.nearly_eq_rel(rhs, rel)
+            }
+        }
+    }
+}
+
+// We have to implement Copy, Clone and Debug explicitly for the same reasons as for Tolerance.
+
+impl<Lhs, Rhs> Clone for ReportTolerance<Lhs, Rhs>
+where
+    Lhs: ?Sized + EpsTolerance<Rhs> + UlpsTolerance<Rhs> + RelTolerance<Rhs>,
+    Rhs: ?Sized,
+{
+    fn clone(&self) -> Self {
+        *self
+    }
+}
+
+impl<Lhs, Rhs> Copy for ReportTolerance<Lhs, Rhs>
+where
+    Lhs: ?Sized + EpsTolerance<Rhs> + UlpsTolerance<Rhs> + RelTolerance<Rhs>,
+    Rhs: ?Sized,
+{
+}
+
+impl<Lhs, Rhs> Debug for ReportTolerance<Lhs, Rhs>
+where
+    Lhs: ?Sized + EpsTolerance<Rhs> + UlpsTolerance<Rhs> + RelTolerance<Rhs>,
+    Rhs: ?Sized,
+{
+    fn fmt(&self, f: &mut Formatter<'_>) -> Result {
+        match self {
+            ReportTolerance::Eps(eps) => f.debug_tuple("Eps").field(eps).finish(),
+            ReportTolerance::Ulps(ulps) => f.debug_tuple("Ulps").field(ulps).finish(),
+            ReportTolerance::Rel(rel) => f.debug_tuple("Rel").field(rel).finish(),
+            ReportTolerance::Tol(tol) => f.debug_tuple("Tol").field(tol).finish(),
+            ReportTolerance::EpsAndRel(eps, rel) => {
+                f.debug_tuple("EpsAndRel").field(eps).field(rel).finish()
+            }
+            ReportTolerance::UlpsAndRel(ulps, rel) => {
+                f.debug_tuple("UlpsAndRel").field(ulps).field(rel).finish()
+            }
+        }
+    }
+}
+
+impl<Lhs, Rhs> Display for ReportTolerance<Lhs, Rhs>
+where
+    Lhs: ?Sized + EpsTolerance<Rhs> + UlpsTolerance<Rhs> + RelTolerance<Rhs>,
+    Rhs: ?Sized,
+{
+    /// Formats the components of the tolerance, e.g. `eps: 0.1, ulps: 4`.
+    fn fmt(&self, f: &mut Formatter<'_>) -> Result {
+        match self {
+            ReportTolerance::Eps(eps) => write!(f, "eps: {:?}", eps),
+            ReportTolerance::Ulps(ulps) => write!(f, "ulps: {:?}", ulps),
+            ReportTolerance::Rel(rel) => write!(f, "rel: {:?}", rel),
+            ReportTolerance::Tol(tol) => {
+                write!(f, "eps: {:?}, ulps: {:?}", tol.eps, tol.ulps)?;
+                if let Some(rel) = &tol.rel {
+                    write!(f, ", rel: {:?}", rel)?;
+                }
+                if tol.mode != ToleranceMode::Any {
+                    write!(f, ", mode: {}", tol.mode)?;
+                }
+                Ok(())
+            }
+            ReportTolerance::EpsAndRel(eps, rel) => write!(f, "eps: {:?}, rel: {:?}", eps, rel),
+            ReportTolerance::UlpsAndRel(ulps, rel) => {
+                write!(f, "ulps: {:?}, rel: {:?}", ulps, rel)
+            }
+        }
+    }
+}
+
+/// A segment of the [Path] to a [Mismatch].
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub enum PathSegment {
+    /// The name of a struct field, the index of a tuple field or the name of an enum variant.
+    Field(&'static str),
+    /// The index of an element of a sequence.
+    Index(usize),
+    /// The debug representation of a map key.
+    Key(String),
+}
+
+impl Display for PathSegment {
+    fn fmt(&self, f: &mut Formatter<'_>) -> Result {
+        match self {
+            PathSegment::Field(name) => write!(f, ".{}", name),
+            PathSegment::Index(index) => write!(f, "[{}]", index),
+            PathSegment::Key(key) => write!(f, "[{}]", key),
+        }
+    }
+}
+
+/// The path from the compared values to the part that is not nearly equal.
+///
+/// The path is empty if the compared values themselves are not nearly equal.
+/// It is formatted like an access expression, e.g. `points[2].x`.
+#[derive(Debug, Clone, Default, PartialEq, Eq, Hash)]
+pub struct Path(pub Vec<PathSegment>);
+
+impl Display for Path {
+    fn fmt(&self, f: &mut Formatter<'_>) -> Result {
+        for (i, segment) in self.0.iter().enumerate() {
+            match segment {
+                PathSegment::Field(name) if i == 0 => write!(f, "{}", name)?,
+                segment => write!(f, "{}", segment)?,
+            }
+        }
+        Ok(())
+    }
+}
+
+/// A floating point value of a [Mismatch].
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum FloatValue {
+    F32(f32),
+    F64(f64),
+}
+
+impl Display for FloatValue {
+    fn fmt(&self, f: &mut Formatter<'_>) -> Result {
+        match self {
+            FloatValue::F32(value) => write!(f, "{:?}", value),
+            FloatValue::F64(value) => write!(f, "{:?}", value),
+        }
+    }
+}
+
+/// The reason two parts of the compared values are not nearly equal.
+#[derive(Debug, Clone, PartialEq)]
+pub enum MismatchKind {
+    /// Two floating point values are not nearly equal.
+    Value {
+        /// The value of the left side.
+        lhs: FloatValue,
+        /// The value of the right side.
+        rhs: FloatValue,
+        /// The absolute difference of both values.
+        diff: FloatValue,
+        /// The ulps distance of both values or `None` if one of them is NaN.
+        ulps: Option<u64>,
+        /// The formatted tolerance the values were compared with.
+        tolerance: String,
+    },
+    /// Two sequences have a different number of elements.
+    Length { lhs: usize, rhs: usize },
+    /// A map key is only present on the left side.
+    OnlyInLhs,
+    /// A map key is only present on the right side.
+    OnlyInRhs,
+    /// Two values have different enum variants, e.g. `Some` and `None`.
+    Variant {
+        lhs: &'static str,
+        rhs: &'static str,
+    },
+    /// Two values that are compared exactly or by a custom function are not equal.
+    NotEqual,
+}
+
+/// A part of the compared values that is not nearly equal.
+#[derive(Debug, Clone, PartialEq)]
+pub struct Mismatch {
+    /// The path to the part that is not nearly equal.
+    pub path: Path,
+    /// The reason the part is not nearly equal.
+    pub kind: MismatchKind,
+}
+
+impl Display for MismatchKind {
+    fn fmt(&self, f: &mut Formatter<'_>) -> Result {
+        match self {
+            MismatchKind::Value {
+                lhs,
+                rhs,
+                diff,
+                ulps,
+                tolerance,
+            } => {
+                write!(f, "{} != {} (diff: {}, ulps: ", lhs, rhs, diff)?;
+                match ulps {
+                    Some(ulps) => write!(f, "{}", ulps)?,
+                    None => write!(f, "-")?,
+                }
+                write!(f, ", {})", tolerance)
+            }
+            MismatchKind::Length { lhs, rhs } => write!(f, "length {} != {}", lhs, rhs),
+            MismatchKind::OnlyInLhs => write!(f, "only in left"),
+            MismatchKind::OnlyInRhs => write!(f, "only in right"),
+            MismatchKind::Variant { lhs, rhs } => write!(f, "variant {} != {}", lhs, rhs),
+            MismatchKind::NotEqual => write!(f, "not equal"),
+        }
+    }
+}
+
+impl Display for Mismatch {
+    fn fmt(&self, f: &mut Formatter<'_>) -> Result {
+        if !self.path.0.is_empty() {
+            write!(f, "{}: ", self.path)?;
+        }
+        write!(f, "{}", self.kind)
+    }
+}
+
+/// The result of a comparison by [NearlyEqReport].
+///
+/// The report contains a [Mismatch] for each part of the compared values that is not nearly
+/// equal, in the order they were compared. Maps with an unspecified iteration order like `HashMap`
+/// report their mismatches in that order.
+///
+/// Implementations of [NearlyEqReport] descend into the parts of a value with
+/// [field](Report::field), [index](Report::index) and [key](Report::key) and add mismatches with
+/// [compare](Report::compare) or [push](Report::push).
+#[derive(Debug, Clone, Default, PartialEq)]
+pub struct Report {
+    path: Vec<PathSegment>,
+    mismatches: Vec<Mismatch>,
+    compared: usize,
+}
+
+impl Report {
+    /// Creates an empty report.
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Returns whether the compared values are nearly equal, i.e. the report has no mismatches.
+    pub fn is_nearly_eq(&self) -> bool {
+        self.mismatches.is_empty()
+    }
+
+    /// Returns the first mismatch if any.
+    pub fn first(&self) -> Option<&Mismatch> {
+        self.mismatches.first()
+    }
+
+    /// Returns all mismatches.
+    pub fn mismatches(&self) -> &[Mismatch] {
+        &self.mismatches
+    }
+
+    /// Returns the number of compared parts.
+    ///
+    /// These are all parts added by [compare](Report::compare) and [push](Report::push), i.e.
+    /// the compared leaf values like floats and the structural mismatches like differing lengths.
+    pub fn compared(&self) -> usize {
+        self.compared
+    }
+
+    /// Adds a mismatch of kind `kind` at the current path unless `nearly_eq` is true.
+    ///
+    /// In both cases, the part at the current path counts as compared.
+    pub fn compare(&mut self, nearly_eq: bool, kind: impl FnOnce() -> MismatchKind) {
+        if nearly_eq {
+            self.compared += 1;
+        } else {
+            self.push(kind());
+        }
+    }
+
+    /// Adds a mismatch of kind `kind` at the current path.
+    pub fn push(&mut self, kind: MismatchKind) {
+        self.compared += 1;
+        self.mismatches.push(Mismatch {
+            path: Path(self.path.clone()),
+            kind,
+        });
+    }
+
+    /// Calls `f` with the field `name` appended to the current path.
+    pub fn field(&mut self, name: &'static str, f: impl FnOnce(&mut Self)) {
+        self.descend(PathSegment::Field(name), f);
+    }
+
+    /// Calls `f` with the index `index` appended to the current path.
+    pub fn index(&mut self, index: usize, f: impl FnOnce(&mut Self)) {
+        self.descend(PathSegment::Index(index), f);
+    }
+
+    /// Calls `f` with the map key `key` appended to the current path.
+    pub fn key<K: ?Sized + Debug>(&mut self, key: &K, f: impl FnOnce(&mut Self)) {
+        self.descend(PathSegment::Key(std::format!("{:?}", key)), f);
+    }
+
+    fn descend(&mut self, segment: PathSegment, f: impl FnOnce(&mut Self)) {
+        self.path.push(segment);
+        f(self);
+        self.path.pop();
+    }
+}
+
+impl Display for Report {
+    /// Formats each mismatch on a separate line.
+    fn fmt(&self, f: &mut Formatter<'_>) -> Result {
+        for (i, mismatch) in self.mismatches.iter().enumerate() {
+            if i > 0 {
+                writeln!(f)?;
+            }
+            write!(f, "{}", mismatch)?;
+        }
+        Ok(())
+    }
+}
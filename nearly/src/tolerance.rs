@@ -53,6 +53,30 @@ where
 /// It specifies the type of the ulps values for the type `Lhs` comparing to the type `Rhs`.
 pub type UlpsToleranceType<Lhs, Rhs = Lhs> = <Lhs as UlpsTolerance<Rhs>>::T;
 
+/// A trait specifying the tolerance used for relative epsilon based comparisons.
+///
+/// This tolerance if for the type implementing this trait comparing to `Rhs`.
+pub trait RelTolerance<Rhs = Self>
+where
+    Rhs: ?Sized,
+{
+    /// The type of the relative tolerance values.
+    type T: Debug + Copy;
+
+    /// The default relative tolerance value.
+    const DEFAULT: Self::T;
+
+    /// Returns the default relative tolerance value that is used when comparing self with other.
+    fn default_rel(&self, _other: &Rhs) -> Self::T {
+        Self::DEFAULT
+    }
+}
+
+/// A type definition for convenience.
+///
+/// It specifies the type of the relative values for the type `Lhs` comparing to the type `Rhs`.
+pub type RelToleranceType<Lhs, Rhs = Lhs> = <Lhs as RelTolerance<Rhs>>::T;
+
 impl EpsTolerance for f32 {
     type T = f32;
     const DEFAULT: f32 = 1e-6;
@@ -63,6 +87,11 @@ impl UlpsTolerance for f32 {
     const DEFAULT: i32 = 4;
 }
 
+impl RelTolerance for f32 {
+    type T = f32;
+    const DEFAULT: f32 = 1e-6;
+}
+
 impl EpsTolerance for f64 {
     type T = f64;
     const DEFAULT: f64 = 1e-14;
@@ -73,6 +102,11 @@ impl UlpsTolerance for f64 {
     const DEFAULT: i64 = 4;
 }
 
+impl RelTolerance for f64 {
+    type T = f64;
+    const DEFAULT: f64 = 1e-14;
+}
+
 impl EpsTolerance for () {
     type T = ();
     const DEFAULT: () = ();
@@ -83,39 +117,137 @@ impl UlpsTolerance for () {
     const DEFAULT: () = ();
 }
 
+impl RelTolerance for () {
+    type T = ();
+    const DEFAULT: () = ();
+}
+
+/// Specifies how the components of a [Tolerance] are combined during a nearly comparison.
+///
+/// The mode applies to the nearly equality of the components. In both modes, the strict ordering
+/// comparisons `lt` and `gt` are true if the values are not nearly equal, so for values that are
+/// not NaN exactly one of `eq`, `lt` and `gt` is true.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Default)]
+pub enum ToleranceMode {
+    /// The comparison is true if it is true for any of the tolerance components.
+    #[default]
+    Any,
+    /// The comparison is true if it is true for all of the tolerance components.
+    All,
+}
+
+impl Display for ToleranceMode {
+    fn fmt(&self, f: &mut Formatter<'_>) -> Result {
+        match self {
+            ToleranceMode::Any => write!(f, "any"),
+            ToleranceMode::All => write!(f, "all"),
+        }
+    }
+}
+
 /// A tolerance data type that is used for nearly comparisons based on a tolerance value.
 ///
 /// This data type combines an absolute epsilon value that will be used for comparisons based on
 /// absolute epsilon values and an ulps value that will be used for comparisons based on
-/// ulps values.
+/// ulps values. Optionally, a relative epsilon value can be added that will be used for
+/// comparisons based on relative epsilon values.
+///
+/// The `mode` specifies whether a comparison must be true for any or for all of these values.
+/// By default, a comparison is true if it is true for any of them.
+///
+/// If `ulps_across_zero` is set, the ulps distance of floating point values is measured across
+/// zero like for [NearlyEqUlpsAcrossZero](crate::NearlyEqUlpsAcrossZero), so values with
+/// different signs can be nearly equal based on the ulps value. Compound types pass it on to
+/// their parts, so it applies to all floating point values compared with this tolerance.
+///
+/// ```
+/// use nearly::{Tolerance, ToleranceMode};
+///
+/// let tol = Tolerance::<f64>::new(1e-12, 4).with_rel(1e-9);
+/// assert_eq!(tol.rel, Some(1e-9));
+/// assert_eq!(tol.mode, ToleranceMode::Any);
+///
+/// let tol = tol.with_mode(ToleranceMode::All);
+/// assert_eq!(tol.mode, ToleranceMode::All);
+///
+/// let tol = tol.with_ulps_across_zero(true);
+/// assert!(tol.ulps_across_zero);
+/// ```
 pub struct Tolerance<Lhs, Rhs = Lhs>
 where
-    Lhs: ?Sized + EpsTolerance<Rhs> + UlpsTolerance<Rhs>,
+    Lhs: ?Sized + EpsTolerance<Rhs> + UlpsTolerance<Rhs> + RelTolerance<Rhs>,
     Rhs: ?Sized,
 {
     pub eps: EpsToleranceType<Lhs, Rhs>,
     pub ulps: UlpsToleranceType<Lhs, Rhs>,
+    pub rel: Option<RelToleranceType<Lhs, Rhs>>,
+    pub mode: ToleranceMode,
+    pub ulps_across_zero: bool,
 }
 
 impl<Lhs, Rhs> Tolerance<Lhs, Rhs>
 where
-    Lhs: ?Sized + EpsTolerance<Rhs> + UlpsTolerance<Rhs>,
+    Lhs: ?Sized + EpsTolerance<Rhs> + UlpsTolerance<Rhs> + RelTolerance<Rhs>,
     Rhs: ?Sized,
 {
+    /// Creates a tolerance with an absolute epsilon value `eps` and an ulps value `ulps`.
+    /// The created tolerance has no relative epsilon value, uses [ToleranceMode::Any] and does
+    /// not measure the ulps distance across zero.
     pub fn new(eps: EpsToleranceType<Lhs, Rhs>, ulps: UlpsToleranceType<Lhs, Rhs>) -> Self {
-        Tolerance::<Lhs, Rhs> { eps, ulps }
+        Tolerance::<Lhs, Rhs> {
+            eps,
+            ulps,
+            rel: None,
+            mode: ToleranceMode::Any,
+            ulps_across_zero: false,
+        }
+    }
+
+    /// Returns the tolerance with the absolute epsilon value set to `eps`.
+    pub fn with_eps(self, eps: EpsToleranceType<Lhs, Rhs>) -> Self {
+        Tolerance::<Lhs, Rhs> { eps, ..self }
+    }
+
+    /// Returns the tolerance with the ulps value set to `ulps`.
+    pub fn with_ulps(self, ulps: UlpsToleranceType<Lhs, Rhs>) -> Self {
+        Tolerance::<Lhs, Rhs> { ulps, ..self }
+    }
+
+    /// Returns the tolerance with the relative epsilon value set to `rel`.
+    pub fn with_rel(self, rel: RelToleranceType<Lhs, Rhs>) -> Self {
+        Tolerance::<Lhs, Rhs> {
+            rel: Some(rel),
+            ..self
+        }
+    }
+
+    /// Returns the tolerance with the combination mode set to `mode`.
+    pub fn with_mode(self, mode: ToleranceMode) -> Self {
+        Tolerance::<Lhs, Rhs> { mode, ..self }
+    }
+
+    /// Returns the tolerance measuring the ulps distance across zero if `ulps_across_zero` is
+    /// true.
+    pub fn with_ulps_across_zero(self, ulps_across_zero: bool) -> Self {
+        Tolerance::<Lhs, Rhs> {
+            ulps_across_zero,
+            ..self
+        }
     }
 }
 
 impl<Lhs, Rhs> Default for Tolerance<Lhs, Rhs>
 where
-    Lhs: ?Sized + EpsTolerance<Rhs> + UlpsTolerance<Rhs>,
+    Lhs: ?Sized + EpsTolerance<Rhs> + UlpsTolerance<Rhs> + RelTolerance<Rhs>,
     Rhs: ?Sized,
 {
     fn default() -> Self {
         Tolerance::<Lhs, Rhs> {
             eps: <Lhs as EpsTolerance<Rhs>>::DEFAULT,
             ulps: <Lhs as UlpsTolerance<Rhs>>::DEFAULT,
+            rel: None,
+            mode: ToleranceMode::Any,
+            ulps_across_zero: false,
         }
     }
 }
@@ -123,13 +255,103 @@ where
 impl<Lhs, Rhs> From<(EpsToleranceType<Lhs, Rhs>, UlpsToleranceType<Lhs, Rhs>)>
     for Tolerance<Lhs, Rhs>
 where
-    Lhs: ?Sized + EpsTolerance<Rhs> + UlpsTolerance<Rhs>,
+    Lhs: ?Sized + EpsTolerance<Rhs> + UlpsTolerance<Rhs> + RelTolerance<Rhs>,
     Rhs: ?Sized,
 {
     fn from(tuple: (EpsToleranceType<Lhs, Rhs>, UlpsToleranceType<Lhs, Rhs>)) -> Self {
         Tolerance::<Lhs, Rhs> {
             eps: tuple.0,
             ulps: tuple.1,
+            rel: None,
+            mode: ToleranceMode::Any,
+            ulps_across_zero: false,
+        }
+    }
+}
+
+impl<Lhs, Rhs>
+    From<(
+        EpsToleranceType<Lhs, Rhs>,
+        UlpsToleranceType<Lhs, Rhs>,
+        Option<RelToleranceType<Lhs, Rhs>>,
+    )> for Tolerance<Lhs, Rhs>
+where
+    Lhs: ?Sized + EpsTolerance<Rhs> + UlpsTolerance<Rhs> + RelTolerance<Rhs>,
+    Rhs: ?Sized,
+{
+    fn from(
+        tuple: (
+            EpsToleranceType<Lhs, Rhs>,
+            UlpsToleranceType<Lhs, Rhs>,
+            Option<RelToleranceType<Lhs, Rhs>>,
+        ),
+    ) -> Self {
+        Tolerance::<Lhs, Rhs> {
+            eps: tuple.0,
+            ulps: tuple.1,
+            rel: tuple.2,
+            mode: ToleranceMode::Any,
+            ulps_across_zero: false,
+        }
+    }
+}
+
+impl<Lhs, Rhs>
+    From<(
+        EpsToleranceType<Lhs, Rhs>,
+        UlpsToleranceType<Lhs, Rhs>,
+        Option<RelToleranceType<Lhs, Rhs>>,
+        ToleranceMode,
+    )> for Tolerance<Lhs, Rhs>
+where
+    Lhs: ?Sized + EpsTolerance<Rhs> + UlpsTolerance<Rhs> + RelTolerance<Rhs>,
+    Rhs: ?Sized,
+{
+    fn from(
+        tuple: (
+            EpsToleranceType<Lhs, Rhs>,
+            UlpsToleranceType<Lhs, Rhs>,
+            Option<RelToleranceType<Lhs, Rhs>>,
+            ToleranceMode,
+        ),
+    ) -> Self {
+        Tolerance::<Lhs, Rhs> {
+            eps: tuple.0,
+            ulps: tuple.1,
+            rel: tuple.2,
+            mode: tuple.3,
+            ulps_across_zero: false,
+        }
+    }
+}
+
+impl<Lhs, Rhs>
+    From<(
+        EpsToleranceType<Lhs, Rhs>,
+        UlpsToleranceType<Lhs, Rhs>,
+        Option<RelToleranceType<Lhs, Rhs>>,
+        ToleranceMode,
+        bool,
+    )> for Tolerance<Lhs, Rhs>
+where
+    Lhs: ?Sized + EpsTolerance<Rhs> + UlpsTolerance<Rhs> + RelTolerance<Rhs>,
+    Rhs: ?Sized,
+{
+    fn from(
+        tuple: (
+            EpsToleranceType<Lhs, Rhs>,
+            UlpsToleranceType<Lhs, Rhs>,
+            Option<RelToleranceType<Lhs, Rhs>>,
+            ToleranceMode,
+            bool,
+        ),
+    ) -> Self {
+        Tolerance::<Lhs, Rhs> {
+            eps: tuple.0,
+            ulps: tuple.1,
+            rel: tuple.2,
+            mode: tuple.3,
+            ulps_across_zero: tuple.4,
         }
     }
 }
@@ -137,7 +359,7 @@ where
 impl<Lhs, Rhs> From<Tolerance<Lhs, Rhs>>
     for (EpsToleranceType<Lhs, Rhs>, UlpsToleranceType<Lhs, Rhs>)
 where
-    Lhs: ?Sized + EpsTolerance<Rhs> + UlpsTolerance<Rhs>,
+    Lhs: ?Sized + EpsTolerance<Rhs> + UlpsTolerance<Rhs> + RelTolerance<Rhs>,
     Rhs: ?Sized,
 {
     fn from(val: Tolerance<Lhs, Rhs>) -> Self {
@@ -145,36 +367,101 @@ where
     }
 }
 
+impl<Lhs, Rhs> From<Tolerance<Lhs, Rhs>>
+    for (
+        EpsToleranceType<Lhs, Rhs>,
+        UlpsToleranceType<Lhs, Rhs>,
+        Option<RelToleranceType<Lhs, Rhs>>,
+    )
+where
+    Lhs: ?Sized + EpsTolerance<Rhs> + UlpsTolerance<Rhs> + RelTolerance<Rhs>,
+    Rhs: ?Sized,
+{
+    fn from(val: Tolerance<Lhs, Rhs>) -> Self {
+        (val.eps, val.ulps, val.rel)
+    }
+}
+
+impl<Lhs, Rhs> From<Tolerance<Lhs, Rhs>>
+    for (
+        EpsToleranceType<Lhs, Rhs>,
+        UlpsToleranceType<Lhs, Rhs>,
+        Option<RelToleranceType<Lhs, Rhs>>,
+        ToleranceMode,
+    )
+where
+    Lhs: ?Sized + EpsTolerance<Rhs> + UlpsTolerance<Rhs> + RelTolerance<Rhs>,
+    Rhs: ?Sized,
+{
+    fn from(val: Tolerance<Lhs, Rhs>) -> Self {
+        (val.eps, val.ulps, val.rel, val.mode)
+    }
+}
+
+impl<Lhs, Rhs> From<Tolerance<Lhs, Rhs>>
+    for (
+        EpsToleranceType<Lhs, Rhs>,
+        UlpsToleranceType<Lhs, Rhs>,
+        Option<RelToleranceType<Lhs, Rhs>>,
+        ToleranceMode,
+        bool,
+    )
+where
+    Lhs: ?Sized + EpsTolerance<Rhs> + UlpsTolerance<Rhs> + RelTolerance<Rhs>,
+    Rhs: ?Sized,
+{
+    fn from(val: Tolerance<Lhs, Rhs>) -> Self {
+        (val.eps, val.ulps, val.rel, val.mode, val.ulps_across_zero)
+    }
+}
+
 impl<Lhs, Rhs> PartialEq for Tolerance<Lhs, Rhs>
 where
-    Lhs: ?Sized + EpsTolerance<Rhs> + UlpsTolerance<Rhs>,
+    Lhs: ?Sized + EpsTolerance<Rhs> + UlpsTolerance<Rhs> + RelTolerance<Rhs>,
     Rhs: ?Sized,
     EpsToleranceType<Lhs, Rhs>: PartialEq,
     UlpsToleranceType<Lhs, Rhs>: PartialEq,
+    RelToleranceType<Lhs, Rhs>: PartialEq,
 {
     fn eq(&self, other: &Self) -> bool {
-        self.eps == other.eps && self.ulps == other.ulps
+        self.eps == other.eps
+            && self.ulps == other.ulps
+            && self.rel == other.rel
+            && self.mode == other.mode
+            && self.ulps_across_zero == other.ulps_across_zero
     }
 }
 
 impl<Lhs, Rhs> Eq for Tolerance<Lhs, Rhs>
 where
-    Lhs: ?Sized + EpsTolerance<Rhs> + UlpsTolerance<Rhs>,
+    Lhs: ?Sized + EpsTolerance<Rhs> + UlpsTolerance<Rhs> + RelTolerance<Rhs>,
     Rhs: ?Sized,
     EpsToleranceType<Lhs, Rhs>: Eq,
     UlpsToleranceType<Lhs, Rhs>: Eq,
+    RelToleranceType<Lhs, Rhs>: Eq,
 {
 }
 
 impl<Lhs, Rhs> Display for Tolerance<Lhs, Rhs>
 where
-    Lhs: ?Sized + EpsTolerance<Rhs> + UlpsTolerance<Rhs>,
+    Lhs: ?Sized + EpsTolerance<Rhs> + UlpsTolerance<Rhs> + RelTolerance<Rhs>,
     Rhs: ?Sized,
     EpsToleranceType<Lhs, Rhs>: Display,
     UlpsToleranceType<Lhs, Rhs>: Display,
+    RelToleranceType<Lhs, Rhs>: Display,
 {
     fn fmt(&self, f: &mut Formatter<'_>) -> Result {
-        write!(f, "(eps: {}, ulps: {})", self.eps, self.ulps)
+        write!(f, "(eps: {}, ulps: {}", self.eps, self.ulps)?;
+        if let Some(rel) = &self.rel {
+            write!(f, ", rel: {}", rel)?;
+        }
+        if self.mode != ToleranceMode::Any {
+            write!(f, ", mode: {}", self.mode)?;
+        }
+        if self.ulps_across_zero {
+            write!(f, ", ulps across zero")?;
+        }
+        write!(f, ")")
     }
 }
 
@@ -184,7 +471,7 @@ where
 
 impl<Lhs, Rhs> Clone for Tolerance<Lhs, Rhs>
 where
-    Lhs: ?Sized + EpsTolerance<Rhs> + UlpsTolerance<Rhs>,
+    Lhs: ?Sized + EpsTolerance<Rhs> + UlpsTolerance<Rhs> + RelTolerance<Rhs>,
     Rhs: ?Sized,
 {
     fn clone(&self) -> Self {
@@ -194,36 +481,41 @@ where
 
 impl<Lhs, Rhs> Copy for Tolerance<Lhs, Rhs>
 where
-    Lhs: ?Sized + EpsTolerance<Rhs> + UlpsTolerance<Rhs>,
+    Lhs: ?Sized + EpsTolerance<Rhs> + UlpsTolerance<Rhs> + RelTolerance<Rhs>,
     Rhs: ?Sized,
 {
 }
 
 // We implement the Debug trait explicitly. Deriving the Debug trait would add
 // a trait bound to Lhs and Rhs to also implement Debug. This is not required.
-// We only need the Debug trait bound for the corresponding eps and ulps values.
+// We only need the Debug trait bound for the corresponding eps, ulps and rel values.
 impl<Lhs, Rhs> Debug for Tolerance<Lhs, Rhs>
 where
-    Lhs: ?Sized + EpsTolerance<Rhs> + UlpsTolerance<Rhs>,
+    Lhs: ?Sized + EpsTolerance<Rhs> + UlpsTolerance<Rhs> + RelTolerance<Rhs>,
     Rhs: ?Sized,
 {
     fn fmt(&self, f: &mut Formatter) -> Result {
         f.debug_struct("Tolerance")
             .field("eps", &self.eps)
             .field("ulps", &self.ulps)
+            .field("rel", &self.rel)
+            .field("mode", &self.mode)
+            .field("ulps_across_zero", &self.ulps_across_zero)
             .finish()
     }
 }
 
 #[cfg(test)]
 mod tests {
-    use super::{EpsToleranceType, Tolerance, UlpsToleranceType};
+    use super::{EpsToleranceType, RelToleranceType, Tolerance, ToleranceMode, UlpsToleranceType};
 
     #[test]
     fn new_f32() {
         let tolerance = Tolerance::<f32>::new(0.01, 5);
         assert_eq!(tolerance.eps, 0.01);
         assert_eq!(tolerance.ulps, 5);
+        assert_eq!(tolerance.rel, None);
+        assert_eq!(tolerance.mode, ToleranceMode::Any);
     }
 
     #[test]
@@ -231,6 +523,8 @@ mod tests {
         let tolerance = Tolerance::<f64>::new(0.01, 5);
         assert_eq!(tolerance.eps, 0.01);
         assert_eq!(tolerance.ulps, 5);
+        assert_eq!(tolerance.rel, None);
+        assert_eq!(tolerance.mode, ToleranceMode::Any);
     }
 
     #[test]
@@ -265,7 +559,13 @@ mod tests {
 
     #[test]
     fn into_tuple_f32() {
-        let tolerance = Tolerance::<f32> { eps: 0.01, ulps: 5 };
+        let tolerance = Tolerance::<f32> {
+            eps: 0.01,
+            ulps: 5,
+            rel: None,
+            mode: ToleranceMode::Any,
+            ulps_across_zero: false,
+        };
         let tuple: (EpsToleranceType<f32>, UlpsToleranceType<f32>) = tolerance.into();
         assert_eq!(tuple.0, 0.01);
         assert_eq!(tuple.1, 5);
@@ -273,7 +573,13 @@ mod tests {
 
     #[test]
     fn into_tuple_f64() {
-        let tolerance = Tolerance::<f64> { eps: 0.01, ulps: 5 };
+        let tolerance = Tolerance::<f64> {
+            eps: 0.01,
+            ulps: 5,
+            rel: None,
+            mode: ToleranceMode::Any,
+            ulps_across_zero: false,
+        };
         let tuple: (EpsToleranceType<f64>, UlpsToleranceType<f64>) = tolerance.into();
         assert_eq!(tuple.0, 0.01);
         assert_eq!(tuple.1, 5);
@@ -310,4 +616,255 @@ mod tests {
         assert_ne!(tolerance, Tolerance::<f64>::new(0.02, 5));
         assert_ne!(tolerance, Tolerance::<f64>::new(0.01, 10));
     }
+
+    #[test]
+    fn with_rel_f32() {
+        let tolerance = Tolerance::<f32>::new(0.01, 5).with_rel(0.001);
+        assert_eq!(tolerance.eps, 0.01);
+        assert_eq!(tolerance.ulps, 5);
+        assert_eq!(tolerance.rel, Some(0.001));
+
+        let tolerance = tolerance.with_eps(0.02).with_ulps(7);
+        assert_eq!(tolerance.eps, 0.02);
+        assert_eq!(tolerance.ulps, 7);
+        assert_eq!(tolerance.rel, Some(0.001));
+    }
+
+    #[test]
+    fn from_tuple_with_rel_f32() {
+        let tuple: (
+            EpsToleranceType<f32>,
+            UlpsToleranceType<f32>,
+            Option<RelToleranceType<f32>>,
+        ) = (0.01, 5, Some(0.001));
+        let tolerance = Tolerance::<f32>::from(tuple);
+        assert_eq!(tolerance.eps, 0.01);
+        assert_eq!(tolerance.ulps, 5);
+        assert_eq!(tolerance.rel, Some(0.001));
+    }
+
+    #[test]
+    fn into_tuple_with_rel_f32() {
+        let tolerance = Tolerance::<f32>::new(0.01, 5).with_rel(0.001);
+        let tuple: (
+            EpsToleranceType<f32>,
+            UlpsToleranceType<f32>,
+            Option<RelToleranceType<f32>>,
+        ) = tolerance.into();
+        assert_eq!(tuple.0, 0.01);
+        assert_eq!(tuple.1, 5);
+        assert_eq!(tuple.2, Some(0.001));
+    }
+
+    #[test]
+    fn equal_with_rel_f32() {
+        let tolerance = Tolerance::<f32>::new(0.01, 5).with_rel(0.001);
+        assert_eq!(tolerance, Tolerance::<f32>::new(0.01, 5).with_rel(0.001));
+        assert_ne!(tolerance, Tolerance::<f32>::new(0.01, 5));
+        assert_ne!(tolerance, Tolerance::<f32>::new(0.01, 5).with_rel(0.002));
+    }
+
+    #[test]
+    fn with_rel_f64() {
+        let tolerance = Tolerance::<f64>::new(0.01, 5).with_rel(0.001);
+        assert_eq!(tolerance.eps, 0.01);
+        assert_eq!(tolerance.ulps, 5);
+        assert_eq!(tolerance.rel, Some(0.001));
+
+        let tolerance = tolerance.with_eps(0.02).with_ulps(7);
+        assert_eq!(tolerance.eps, 0.02);
+        assert_eq!(tolerance.ulps, 7);
+        assert_eq!(tolerance.rel, Some(0.001));
+    }
+
+    #[test]
+    fn from_tuple_with_rel_f64() {
+        let tuple: (
+            EpsToleranceType<f64>,
+            UlpsToleranceType<f64>,
+            Option<RelToleranceType<f64>>,
+        ) = (0.01, 5, Some(0.001));
+        let tolerance = Tolerance::<f64>::from(tuple);
+        assert_eq!(tolerance.eps, 0.01);
+        assert_eq!(tolerance.ulps, 5);
+        assert_eq!(tolerance.rel, Some(0.001));
+    }
+
+    #[test]
+    fn into_tuple_with_rel_f64() {
+        let tolerance = Tolerance::<f64>::new(0.01, 5).with_rel(0.001);
+        let tuple: (
+            EpsToleranceType<f64>,
+            UlpsToleranceType<f64>,
+            Option<RelToleranceType<f64>>,
+        ) = tolerance.into();
+        assert_eq!(tuple.0, 0.01);
+        assert_eq!(tuple.1, 5);
+        assert_eq!(tuple.2, Some(0.001));
+    }
+
+    #[test]
+    fn equal_with_rel_f64() {
+        let tolerance = Tolerance::<f64>::new(0.01, 5).with_rel(0.001);
+        assert_eq!(tolerance, Tolerance::<f64>::new(0.01, 5).with_rel(0.001));
+        assert_ne!(tolerance, Tolerance::<f64>::new(0.01, 5));
+        assert_ne!(tolerance, Tolerance::<f64>::new(0.01, 5).with_rel(0.002));
+    }
+
+    #[test]
+    fn with_mode_f32() {
+        let tolerance = Tolerance::<f32>::new(0.01, 5).with_mode(ToleranceMode::All);
+        assert_eq!(tolerance.eps, 0.01);
+        assert_eq!(tolerance.ulps, 5);
+        assert_eq!(tolerance.rel, None);
+        assert_eq!(tolerance.mode, ToleranceMode::All);
+
+        let tolerance = tolerance.with_rel(0.001);
+        assert_eq!(tolerance.rel, Some(0.001));
+        assert_eq!(tolerance.mode, ToleranceMode::All);
+    }
+
+    #[test]
+    fn equal_with_mode_f32() {
+        let tolerance = Tolerance::<f32>::new(0.01, 5).with_mode(ToleranceMode::All);
+        assert_eq!(
+            tolerance,
+            Tolerance::<f32>::new(0.01, 5).with_mode(ToleranceMode::All)
+        );
+        assert_ne!(tolerance, Tolerance::<f32>::new(0.01, 5));
+    }
+
+    #[test]
+    fn with_mode_f64() {
+        let tolerance = Tolerance::<f64>::new(0.01, 5).with_mode(ToleranceMode::All);
+        assert_eq!(tolerance.eps, 0.01);
+        assert_eq!(tolerance.ulps, 5);
+        assert_eq!(tolerance.rel, None);
+        assert_eq!(tolerance.mode, ToleranceMode::All);
+
+        let tolerance = tolerance.with_rel(0.001);
+        assert_eq!(tolerance.rel, Some(0.001));
+        assert_eq!(tolerance.mode, ToleranceMode::All);
+    }
+
+    #[test]
+    fn equal_with_mode_f64() {
+        let tolerance = Tolerance::<f64>::new(0.01, 5).with_mode(ToleranceMode::All);
+        assert_eq!(
+            tolerance,
+            Tolerance::<f64>::new(0.01, 5).with_mode(ToleranceMode::All)
+        );
+        assert_ne!(tolerance, Tolerance::<f64>::new(0.01, 5));
+    }
+
+    #[test]
+    fn from_tuple_with_mode_f32() {
+        let tuple: (
+            EpsToleranceType<f32>,
+            UlpsToleranceType<f32>,
+            Option<RelToleranceType<f32>>,
+            ToleranceMode,
+        ) = (0.01, 5, Some(0.001), ToleranceMode::All);
+        let tolerance = Tolerance::<f32>::from(tuple);
+        assert_eq!(tolerance.eps, 0.01);
+        assert_eq!(tolerance.ulps, 5);
+        assert_eq!(tolerance.rel, Some(0.001));
+        assert_eq!(tolerance.mode, ToleranceMode::All);
+    }
+
+    #[test]
+    fn into_tuple_with_mode_f32() {
+        let tolerance = Tolerance::<f32>::new(0.01, 5).with_mode(ToleranceMode::All);
+        let tuple: (
+            EpsToleranceType<f32>,
+            UlpsToleranceType<f32>,
+            Option<RelToleranceType<f32>>,
+            ToleranceMode,
+        ) = tolerance.into();
+        assert_eq!(tuple.0, 0.01);
+        assert_eq!(tuple.1, 5);
+        assert_eq!(tuple.2, None);
+        assert_eq!(tuple.3, ToleranceMode::All);
+    }
+
+    #[test]
+    fn from_tuple_with_mode_f64() {
+        let tuple: (
+            EpsToleranceType<f64>,
+            UlpsToleranceType<f64>,
+            Option<RelToleranceType<f64>>,
+            ToleranceMode,
+        ) = (0.01, 5, Some(0.001), ToleranceMode::All);
+        let tolerance = Tolerance::<f64>::from(tuple);
+        assert_eq!(tolerance.eps, 0.01);
+        assert_eq!(tolerance.ulps, 5);
+        assert_eq!(tolerance.rel, Some(0.001));
+        assert_eq!(tolerance.mode, ToleranceMode::All);
+    }
+
+    #[test]
+    fn into_tuple_with_mode_f64() {
+        let tolerance = Tolerance::<f64>::new(0.01, 5).with_mode(ToleranceMode::All);
+        let tuple: (
+            EpsToleranceType<f64>,
+            UlpsToleranceType<f64>,
+            Option<RelToleranceType<f64>>,
+            ToleranceMode,
+        ) = tolerance.into();
+        assert_eq!(tuple.0, 0.01);
+        assert_eq!(tuple.1, 5);
+        assert_eq!(tuple.2, None);
+        assert_eq!(tuple.3, ToleranceMode::All);
+    }
+
+    #[test]
+    fn with_ulps_across_zero_f32() {
+        let tolerance = Tolerance::<f32>::new(0.01, 5);
+        assert!(!tolerance.ulps_across_zero);
+
+        let tolerance = tolerance.with_ulps_across_zero(true);
+        assert!(tolerance.ulps_across_zero);
+        assert_ne!(tolerance, Tolerance::<f32>::new(0.01, 5));
+        assert_eq!(
+            tolerance.with_ulps_across_zero(false),
+            Tolerance::<f32>::new(0.01, 5)
+        );
+    }
+
+    #[test]
+    fn with_ulps_across_zero_f64() {
+        let tolerance = Tolerance::<f64>::new(0.01, 5);
+        assert!(!tolerance.ulps_across_zero);
+
+        let tolerance = tolerance.with_ulps_across_zero(true);
+        assert!(tolerance.ulps_across_zero);
+        assert_ne!(tolerance, Tolerance::<f64>::new(0.01, 5));
+        assert_eq!(
+            tolerance.with_ulps_across_zero(false),
+            Tolerance::<f64>::new(0.01, 5)
+        );
+    }
+
+    #[test]
+    fn tuple_with_ulps_across_zero_f64() {
+        let tuple: (
+            EpsToleranceType<f64>,
+            UlpsToleranceType<f64>,
+            Option<RelToleranceType<f64>>,
+            ToleranceMode,
+            bool,
+        ) = (0.01, 5, None, ToleranceMode::All, true);
+        let tolerance = Tolerance::<f64>::from(tuple);
+        assert_eq!(tolerance.mode, ToleranceMode::All);
+        assert!(tolerance.ulps_across_zero);
+
+        let tuple: (
+            EpsToleranceType<f64>,
+            UlpsToleranceType<f64>,
+            Option<RelToleranceType<f64>>,
+            ToleranceMode,
+            bool,
+        ) = tolerance.into();
+        assert_eq!(tuple, (0.01, 5, None, ToleranceMode::All, true));
+    }
 }
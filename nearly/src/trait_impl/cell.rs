@@ -0,0 +1,291 @@
+use crate::nearly_eq::{NearlyEq, NearlyEqEps, NearlyEqRel, NearlyEqTol, NearlyEqUlps};
+use crate::nearly_ord::{NearlyOrd, NearlyOrdEps, NearlyOrdRel, NearlyOrdTol, NearlyOrdUlps};
+use crate::tolerance::{
+    EpsTolerance, EpsToleranceType, RelTolerance, RelToleranceType, Tolerance, UlpsTolerance,
+    UlpsToleranceType,
+};
+
+use core::cell::{Cell, RefCell};
+
+/// Implements the nearly traits for a container whose content is accessed by `$get`.
+///
+/// The tolerance types are the ones of the content, so that containers of collections are
+/// compared with the tolerance of the collection elements.
+macro_rules! impl_cell {
+    ([$($vars:tt)*], $lhs: ty, $rhs: ty, $get: path) => {
+        ///////////////
+        // nearly_eq //
+        ///////////////
+
+        impl<$($vars)*, LhsTol, RhsTol> NearlyEqEps<$rhs, LhsTol, RhsTol> for $lhs
+        where
+            Lhs: NearlyEqEps<Rhs, LhsTol, RhsTol>,
+            LhsTol: ?Sized + EpsTolerance<RhsTol>,
+            RhsTol: ?Sized,
+        {
+            #[inline]
+            fn nearly_eq_eps(&self, other: &$rhs, eps: &EpsToleranceType<LhsTol, RhsTol>) -> bool {
+                let (lhs, rhs) = ($get(self), $get(other));
+                Lhs::nearly_eq_eps(&lhs, &rhs, eps)
+            }
+        }
+
+        impl<$($vars)*, LhsTol, RhsTol> NearlyEqUlps<$rhs, LhsTol, RhsTol> for $lhs
+        where
+            Lhs: NearlyEqUlps<Rhs, LhsTol, RhsTol>,
+            LhsTol: ?Sized + UlpsTolerance<RhsTol>,
+            RhsTol: ?Sized,
+        {
+            #[inline]
+            fn nearly_eq_ulps(
+                &self,
+                other: &$rhs,
+                ulps: &UlpsToleranceType<LhsTol, RhsTol>,
+            ) -> bool {
+                let (lhs, rhs) = ($get(self), $get(other));
+                Lhs::nearly_eq_ulps(&lhs, &rhs, ulps)
+            }
+        }
+
+        impl<$($vars)*, LhsTol, RhsTol> NearlyEqRel<$rhs, LhsTol, RhsTol> for $lhs
+        where
+            Lhs: NearlyEqRel<Rhs, LhsTol, RhsTol>,
+            LhsTol: ?Sized + RelTolerance<RhsTol>,
+            RhsTol: ?Sized,
+        {
+            #[inline]
+            fn nearly_eq_rel(&self, other: &$rhs, rel: &RelToleranceType<LhsTol, RhsTol>) -> bool {
+                let (lhs, rhs) = ($get(self), $get(other));
+                Lhs::nearly_eq_rel(&lhs, &rhs, rel)
+            }
+        }
+
+        impl<$($vars)*, LhsTol, RhsTol> NearlyEqTol<$rhs, LhsTol, RhsTol> for $lhs
+        where
+            Lhs: NearlyEqTol<Rhs, LhsTol, RhsTol>,
+            LhsTol: ?Sized + EpsTolerance<RhsTol> + UlpsTolerance<RhsTol> + RelTolerance<RhsTol>,
+            RhsTol: ?Sized,
+        {
+            #[inline]
+            fn nearly_eq_tol(&self, other: &$rhs, tol: &Tolerance<LhsTol, RhsTol>) -> bool {
+                let (lhs, rhs) = ($get(self), $get(other));
+                Lhs::nearly_eq_tol(&lhs, &rhs, tol)
+            }
+        }
+
+        impl<$($vars)*, LhsTol, RhsTol> NearlyEq<$rhs, LhsTol, RhsTol> for $lhs
+        where
+            Lhs: NearlyEq<Rhs, LhsTol, RhsTol>,
+            LhsTol: ?Sized + EpsTolerance<RhsTol> + UlpsTolerance<RhsTol> + RelTolerance<RhsTol>,
+            RhsTol: ?Sized,
+        {
+        }
+
+        ////////////////
+        // nearly_ord //
+        ////////////////
+
+        impl<$($vars)*, LhsTol, RhsTol> NearlyOrdEps<$rhs, LhsTol, RhsTol> for $lhs
+        where
+            Lhs: NearlyOrdEps<Rhs, LhsTol, RhsTol>,
+            LhsTol: ?Sized + EpsTolerance<RhsTol>,
+            RhsTol: ?Sized,
+        {
+            #[inline]
+            fn nearly_lt_eps(&self, other: &$rhs, eps: &EpsToleranceType<LhsTol, RhsTol>) -> bool {
+                let (lhs, rhs) = ($get(self), $get(other));
+                Lhs::nearly_lt_eps(&lhs, &rhs, eps)
+            }
+
+            #[inline]
+            fn nearly_le_eps(&self, other: &$rhs, eps: &EpsToleranceType<LhsTol, RhsTol>) -> bool {
+                let (lhs, rhs) = ($get(self), $get(other));
+                Lhs::nearly_le_eps(&lhs, &rhs, eps)
+            }
+
+            #[inline]
+            fn nearly_gt_eps(&self, other: &$rhs, eps: &EpsToleranceType<LhsTol, RhsTol>) -> bool {
+                let (lhs, rhs) = ($get(self), $get(other));
+                Lhs::nearly_gt_eps(&lhs, &rhs, eps)
+            }
+
+            #[inline]
+            fn nearly_ge_eps(&self, other: &$rhs, eps: &EpsToleranceType<LhsTol, RhsTol>) -> bool {
+                let (lhs, rhs) = ($get(self), $get(other));
+                Lhs::nearly_ge_eps(&lhs, &rhs, eps)
+            }
+        }
+
+        impl<$($vars)*, LhsTol, RhsTol> NearlyOrdUlps<$rhs, LhsTol, RhsTol> for $lhs
+        where
+            Lhs: NearlyOrdUlps<Rhs, LhsTol, RhsTol>,
+            LhsTol: ?Sized + UlpsTolerance<RhsTol>,
+            RhsTol: ?Sized,
+        {
+            #[inline]
+            fn nearly_lt_ulps(
+                &self,
+                other: &$rhs,
+                ulps: &UlpsToleranceType<LhsTol, RhsTol>,
+            ) -> bool {
+                let (lhs, rhs) = ($get(self), $get(other));
+                Lhs::nearly_lt_ulps(&lhs, &rhs, ulps)
+            }
+
+            #[inline]
+            fn nearly_le_ulps(
+                &self,
+                other: &$rhs,
+                ulps: &UlpsToleranceType<LhsTol, RhsTol>,
+            ) -> bool {
+                let (lhs, rhs) = ($get(self), $get(other));
+                Lhs::nearly_le_ulps(&lhs, &rhs, ulps)
+            }
+
+            #[inline]
+            fn nearly_gt_ulps(
+                &self,
+                other: &$rhs,
+                ulps: &UlpsToleranceType<LhsTol, RhsTol>,
+            ) -> bool {
+                let (lhs, rhs) = ($get(self), $get(other));
+                Lhs::nearly_gt_ulps(&lhs, &rhs, ulps)
+            }
+
+            #[inline]
+            fn nearly_ge_ulps(
+                &self,
+                other: &$rhs,
+                ulps: &UlpsToleranceType<LhsTol, RhsTol>,
+            ) -> bool {
+                let (lhs, rhs) = ($get(self), $get(other));
+                Lhs::nearly_ge_ulps(&lhs, &rhs, ulps)
+            }
+        }
+
+        impl<$($vars)*, LhsTol, RhsTol> NearlyOrdRel<$rhs, LhsTol, RhsTol> for $lhs
+        where
+            Lhs: NearlyOrdRel<Rhs, LhsTol, RhsTol>,
+            LhsTol: ?Sized + RelTolerance<RhsTol>,
+            RhsTol: ?Sized,
+        {
+            #[inline]
+            fn nearly_lt_rel(&self, other: &$rhs, rel: &RelToleranceType<LhsTol, RhsTol>) -> bool {
+                let (lhs, rhs) = ($get(self), $get(other));
+                Lhs::nearly_lt_rel(&lhs, &rhs, rel)
+            }
+
+            #[inline]
+            fn nearly_le_rel(&self, other: &$rhs, rel: &RelToleranceType<LhsTol, RhsTol>) -> bool {
+                let (lhs, rhs) = ($get(self), $get(other));
+                Lhs::nearly_le_rel(&lhs, &rhs, rel)
+            }
+
+            #[inline]
+            fn nearly_gt_rel(&self, other: &$rhs, rel: &RelToleranceType<LhsTol, RhsTol>) -> bool {
+                let (lhs, rhs) = ($get(self), $get(other));
+                Lhs::nearly_gt_rel(&lhs, &rhs, rel)
+            }
+
+            #[inline]
+            fn nearly_ge_rel(&self, other: &$rhs, rel: &RelToleranceType<LhsTol, RhsTol>) -> bool {
+                let (lhs, rhs) = ($get(self), $get(other));
+                Lhs::nearly_ge_rel(&lhs, &rhs, rel)
+            }
+        }
+
+        impl<$($vars)*, LhsTol, RhsTol> NearlyOrdTol<$rhs, LhsTol, RhsTol> for $lhs
+        where
+            Lhs: NearlyOrdTol<Rhs, LhsTol, RhsTol>,
+            LhsTol: ?Sized + EpsTolerance<RhsTol> + UlpsTolerance<RhsTol> + RelTolerance<RhsTol>,
+            RhsTol: ?Sized,
+        {
+            #[inline]
+            fn nearly_lt_tol(&self, other: &$rhs, tol: &Tolerance<LhsTol, RhsTol>) -> bool {
+                let (lhs, rhs) = ($get(self), $get(other));
+                Lhs::nearly_lt_tol(&lhs, &rhs, tol)
+            }
+
+            #[inline]
+            fn nearly_le_tol(&self, other: &$rhs, tol: &Tolerance<LhsTol, RhsTol>) -> bool {
+                let (lhs, rhs) = ($get(self), $get(other));
+                Lhs::nearly_le_tol(&lhs, &rhs, tol)
+            }
+
+            #[inline]
+            fn nearly_gt_tol(&self, other: &$rhs, tol: &Tolerance<LhsTol, RhsTol>) -> bool {
+                let (lhs, rhs) = ($get(self), $get(other));
+                Lhs::nearly_gt_tol(&lhs, &rhs, tol)
+            }
+
+            #[inline]
+            fn nearly_ge_tol(&self, other: &$rhs, tol: &Tolerance<LhsTol, RhsTol>) -> bool {
+                let (lhs, rhs) = ($get(self), $get(other));
+                Lhs::nearly_ge_tol(&lhs, &rhs, tol)
+            }
+        }
+
+        impl<$($vars)*, LhsTol, RhsTol> NearlyOrd<$rhs, LhsTol, RhsTol> for $lhs
+        where
+            Lhs: NearlyOrd<Rhs, LhsTol, RhsTol>,
+            LhsTol: ?Sized + EpsTolerance<RhsTol> + UlpsTolerance<RhsTol> + RelTolerance<RhsTol>,
+            RhsTol: ?Sized,
+        {
+        }
+
+        impl<$($vars)*> EpsTolerance<$rhs> for $lhs
+        where
+            Lhs: EpsTolerance<Rhs>,
+        {
+            type T = EpsToleranceType<Lhs, Rhs>;
+            const DEFAULT: Self::T = <Lhs as EpsTolerance<Rhs>>::DEFAULT;
+        }
+
+        impl<$($vars)*> UlpsTolerance<$rhs> for $lhs
+        where
+            Lhs: UlpsTolerance<Rhs>,
+        {
+            type T = UlpsToleranceType<Lhs, Rhs>;
+            const DEFAULT: Self::T = <Lhs as UlpsTolerance<Rhs>>::DEFAULT;
+        }
+
+        impl<$($vars)*> RelTolerance<$rhs> for $lhs
+        where
+            Lhs: RelTolerance<Rhs>,
+        {
+            type T = RelToleranceType<Lhs, Rhs>;
+            const DEFAULT: Self::T = <Lhs as RelTolerance<Rhs>>::DEFAULT;
+        }
+    };
+}
+
+impl_cell!([Lhs: Copy, Rhs: Copy], Cell<Lhs>, Cell<Rhs>, Cell::get);
+impl_cell!([Lhs: ?Sized, Rhs: ?Sized], RefCell<Lhs>, RefCell<Rhs>, RefCell::borrow);
+
+#[cfg(feature = "std")]
+mod std_cell {
+    use super::*;
+    use std::borrow::{Cow, ToOwned};
+    use std::ops::Deref;
+    use std::sync::{Mutex, MutexGuard, PoisonError, RwLock, RwLockReadGuard};
+
+    /// Locks `mutex` and ignores poisoning, since the comparison only reads the content.
+    fn lock<T: ?Sized>(mutex: &Mutex<T>) -> MutexGuard<'_, T> {
+        mutex.lock().unwrap_or_else(PoisonError::into_inner)
+    }
+
+    /// Locks `lock` for reading and ignores poisoning, since the comparison only reads the
+    /// content.
+    fn read<T: ?Sized>(lock: &RwLock<T>) -> RwLockReadGuard<'_, T> {
+        lock.read().unwrap_or_else(PoisonError::into_inner)
+    }
+
+    impl_cell!(
+        ['a, 'b, Lhs: ?Sized + ToOwned, Rhs: ?Sized + ToOwned],
+        Cow<'a, Lhs>,
+        Cow<'b, Rhs>,
+        Deref::deref
+    );
+    impl_cell!([Lhs: ?Sized, Rhs: ?Sized], Mutex<Lhs>, Mutex<Rhs>, lock);
+    impl_cell!([Lhs: ?Sized, Rhs: ?Sized], RwLock<Lhs>, RwLock<Rhs>, read);
+}
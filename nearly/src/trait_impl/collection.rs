@@ -1,9 +1,13 @@
-use crate::nearly_eq::{NearlyEq, NearlyEqEps, NearlyEqTol, NearlyEqUlps};
-use crate::nearly_ord::{NearlyOrd, NearlyOrdEps, NearlyOrdTol, NearlyOrdUlps};
+use crate::nearly_eq::{NearlyEq, NearlyEqEps, NearlyEqRel, NearlyEqTol, NearlyEqUlps};
+use crate::nearly_ord::{NearlyOrd, NearlyOrdEps, NearlyOrdRel, NearlyOrdTol, NearlyOrdUlps};
 use crate::tolerance::{
-    EpsTolerance, EpsToleranceType, Tolerance, UlpsTolerance, UlpsToleranceType,
+    EpsTolerance, EpsToleranceType, RelTolerance, RelToleranceType, Tolerance, UlpsTolerance,
+    UlpsToleranceType,
 };
 
+#[cfg(feature = "std")]
+use crate::report::{MismatchKind, NearlyEqReport, Report, ReportTolerance};
+
 macro_rules! impl_collection {
     ([$($vars:tt)*], $lhs: ty, $rhs: ty) => {
         ///////////////
@@ -44,9 +48,26 @@ macro_rules! impl_collection {
             }
         }
 
+        impl<Lhs, Rhs, $($vars)*> NearlyEqRel<$rhs, Lhs, Rhs> for $lhs
+        where
+            Lhs: NearlyEqRel<Rhs> + RelTolerance<Rhs>,
+        {
+            fn nearly_eq_rel(
+                &self,
+                other: &$rhs,
+                rel: &RelToleranceType<Lhs, Rhs>,
+            ) -> bool {
+                self.len() == other.len()
+                    && self
+                        .iter()
+                        .zip(other.iter())
+                        .all(|(a, b)| NearlyEqRel::nearly_eq_rel(a, b, rel))
+            }
+        }
+
         impl<Lhs, Rhs, $($vars)*> NearlyEqTol<$rhs, Lhs, Rhs> for $lhs
         where
-            Lhs: NearlyEqTol<Rhs> + EpsTolerance<Rhs> + UlpsTolerance<Rhs>,
+            Lhs: NearlyEqTol<Rhs> + EpsTolerance<Rhs> + UlpsTolerance<Rhs> + RelTolerance<Rhs>,
         {
             fn nearly_eq_tol(
                 &self,
@@ -63,10 +84,37 @@ macro_rules! impl_collection {
 
         impl<Lhs, Rhs, $($vars)*> NearlyEq<$rhs, Lhs, Rhs> for $lhs
         where
-            Lhs: NearlyEq<Rhs> + EpsTolerance<Rhs> + UlpsTolerance<Rhs>,
+            Lhs: NearlyEq<Rhs> + EpsTolerance<Rhs> + UlpsTolerance<Rhs> + RelTolerance<Rhs>,
         {
         }
 
+        #[cfg(feature = "std")]
+        impl<Lhs, Rhs, $($vars)*> NearlyEqReport<$rhs, Lhs, Rhs> for $lhs
+        where
+            Lhs: NearlyEqReport<Rhs> + EpsTolerance<Rhs> + UlpsTolerance<Rhs> + RelTolerance<Rhs>,
+        {
+            fn report_nearly_eq(
+                &self,
+                other: &$rhs,
+                tol: &ReportTolerance<Lhs, Rhs>,
+                report: &mut Report,
+            ) {
+                if self.len() != other.len() {
+                    report.push(MismatchKind::Length {
+                        lhs: self.len(),
+                        rhs: other.len(),
+                    });
+                    return;
+                }
+
+                for (i, (a, b)) in self.iter().zip(other.iter()).enumerate() {
+                    report.index(i, |report| {
+                        NearlyEqReport::report_nearly_eq(a, b, tol, report)
+                    });
+                }
+            }
+        }
+
         ////////////////
         // nearly_ord //
         ////////////////
@@ -177,9 +225,62 @@ macro_rules! impl_collection {
             }
         }
 
+        impl<Lhs, Rhs, $($vars)*> NearlyOrdRel<$rhs, Lhs, Rhs> for $lhs
+        where
+            Lhs: NearlyOrdRel<Rhs> + RelTolerance<Rhs>,
+        {
+            fn nearly_lt_rel(
+                &self,
+                other: &$rhs,
+                rel: &RelToleranceType<Lhs, Rhs>,
+            ) -> bool {
+                self.len() == other.len()
+                    && self
+                        .iter()
+                        .zip(other.iter())
+                        .all(|(a, b)| NearlyOrdRel::nearly_lt_rel(a, b, rel))
+            }
+
+            fn nearly_le_rel(
+                &self,
+                other: &$rhs,
+                rel: &RelToleranceType<Lhs, Rhs>,
+            ) -> bool {
+                self.len() == other.len()
+                    && self
+                        .iter()
+                        .zip(other.iter())
+                        .all(|(a, b)| NearlyOrdRel::nearly_le_rel(a, b, rel))
+            }
+
+            fn nearly_gt_rel(
+                &self,
+                other: &$rhs,
+                rel: &RelToleranceType<Lhs, Rhs>,
+            ) -> bool {
+                self.len() == other.len()
+                    && self
+                        .iter()
+                        .zip(other.iter())
+                        .all(|(a, b)| NearlyOrdRel::nearly_gt_rel(a, b, rel))
+            }
+
+            fn nearly_ge_rel(
+                &self,
+                other: &$rhs,
+                rel: &RelToleranceType<Lhs, Rhs>,
+            ) -> bool {
+                self.len() == other.len()
+                    && self
+                        .iter()
+                        .zip(other.iter())
+                        .all(|(a, b)| NearlyOrdRel::nearly_ge_rel(a, b, rel))
+            }
+        }
+
         impl<Lhs, Rhs, $($vars)*> NearlyOrdTol<$rhs, Lhs, Rhs> for $lhs
         where
-            Lhs: NearlyOrdTol<Rhs> + EpsTolerance<Rhs> + UlpsTolerance<Rhs>,
+            Lhs: NearlyOrdTol<Rhs> + EpsTolerance<Rhs> + UlpsTolerance<Rhs> + RelTolerance<Rhs>,
         {
             fn nearly_lt_tol(
                 &self,
@@ -232,7 +333,7 @@ macro_rules! impl_collection {
 
         impl<Lhs, Rhs, $($vars)*> NearlyOrd<$rhs, Lhs, Rhs> for $lhs
         where
-            Lhs: NearlyOrd<Rhs> + EpsTolerance<Rhs> + UlpsTolerance<Rhs>,
+            Lhs: NearlyOrd<Rhs> + EpsTolerance<Rhs> + UlpsTolerance<Rhs> + RelTolerance<Rhs>,
         {
         }
     };
@@ -253,10 +354,17 @@ impl_collection!([const N: usize], &[Lhs], [Rhs; N]);
 #[cfg(feature = "std")]
 mod std_collection {
     use super::*;
+    use std::boxed::Box;
     use std::collections::{LinkedList, VecDeque};
+    use std::rc::Rc;
+    use std::sync::Arc;
 
     impl_collection!([], Vec<Lhs>, Vec<Rhs>);
     impl_collection!([], Vec<Lhs>, VecDeque<Rhs>);
+    impl_collection!([], Vec<Lhs>, LinkedList<Rhs>);
+    impl_collection!([], Vec<Lhs>, Box<[Rhs]>);
+    impl_collection!([], Vec<Lhs>, Rc<[Rhs]>);
+    impl_collection!([], Vec<Lhs>, Arc<[Rhs]>);
     impl_collection!([const N: usize], Vec<Lhs>, [Rhs; N]);
     impl_collection!([], Vec<Lhs>, [Rhs]);
     impl_collection!([], Vec<Lhs>, &[Rhs]);
@@ -265,8 +373,12 @@ mod std_collection {
     impl_collection!([], [Lhs], Vec<Rhs>);
     impl_collection!([], &[Lhs], Vec<Rhs>);
 
-    impl_collection!([], VecDeque<Lhs>, VecDeque<Rhs>);
     impl_collection!([], VecDeque<Lhs>, Vec<Rhs>);
+    impl_collection!([], VecDeque<Lhs>, VecDeque<Rhs>);
+    impl_collection!([], VecDeque<Lhs>, LinkedList<Rhs>);
+    impl_collection!([], VecDeque<Lhs>, Box<[Rhs]>);
+    impl_collection!([], VecDeque<Lhs>, Rc<[Rhs]>);
+    impl_collection!([], VecDeque<Lhs>, Arc<[Rhs]>);
     impl_collection!([const N: usize], VecDeque<Lhs>, [Rhs; N]);
     impl_collection!([], VecDeque<Lhs>, [Rhs]);
     impl_collection!([], VecDeque<Lhs>, &[Rhs]);
@@ -275,5 +387,59 @@ mod std_collection {
     impl_collection!([], [Lhs], VecDeque<Rhs>);
     impl_collection!([], &[Lhs], VecDeque<Rhs>);
 
+    impl_collection!([], LinkedList<Lhs>, Vec<Rhs>);
+    impl_collection!([], LinkedList<Lhs>, VecDeque<Rhs>);
     impl_collection!([], LinkedList<Lhs>, LinkedList<Rhs>);
+    impl_collection!([], LinkedList<Lhs>, Box<[Rhs]>);
+    impl_collection!([], LinkedList<Lhs>, Rc<[Rhs]>);
+    impl_collection!([], LinkedList<Lhs>, Arc<[Rhs]>);
+    impl_collection!([const N: usize], LinkedList<Lhs>, [Rhs; N]);
+    impl_collection!([], LinkedList<Lhs>, [Rhs]);
+    impl_collection!([], LinkedList<Lhs>, &[Rhs]);
+
+    impl_collection!([const N: usize], [Lhs; N], LinkedList<Rhs>);
+    impl_collection!([], [Lhs], LinkedList<Rhs>);
+    impl_collection!([], &[Lhs], LinkedList<Rhs>);
+
+    impl_collection!([], Box<[Lhs]>, Vec<Rhs>);
+    impl_collection!([], Box<[Lhs]>, VecDeque<Rhs>);
+    impl_collection!([], Box<[Lhs]>, LinkedList<Rhs>);
+    impl_collection!([], Box<[Lhs]>, Box<[Rhs]>);
+    impl_collection!([], Box<[Lhs]>, Rc<[Rhs]>);
+    impl_collection!([], Box<[Lhs]>, Arc<[Rhs]>);
+    impl_collection!([const N: usize], Box<[Lhs]>, [Rhs; N]);
+    impl_collection!([], Box<[Lhs]>, [Rhs]);
+    impl_collection!([], Box<[Lhs]>, &[Rhs]);
+
+    impl_collection!([const N: usize], [Lhs; N], Box<[Rhs]>);
+    impl_collection!([], [Lhs], Box<[Rhs]>);
+    impl_collection!([], &[Lhs], Box<[Rhs]>);
+
+    impl_collection!([], Rc<[Lhs]>, Vec<Rhs>);
+    impl_collection!([], Rc<[Lhs]>, VecDeque<Rhs>);
+    impl_collection!([], Rc<[Lhs]>, LinkedList<Rhs>);
+    impl_collection!([], Rc<[Lhs]>, Box<[Rhs]>);
+    impl_collection!([], Rc<[Lhs]>, Rc<[Rhs]>);
+    impl_collection!([], Rc<[Lhs]>, Arc<[Rhs]>);
+    impl_collection!([const N: usize], Rc<[Lhs]>, [Rhs; N]);
+    impl_collection!([], Rc<[Lhs]>, [Rhs]);
+    impl_collection!([], Rc<[Lhs]>, &[Rhs]);
+
+    impl_collection!([const N: usize], [Lhs; N], Rc<[Rhs]>);
+    impl_collection!([], [Lhs], Rc<[Rhs]>);
+    impl_collection!([], &[Lhs], Rc<[Rhs]>);
+
+    impl_collection!([], Arc<[Lhs]>, Vec<Rhs>);
+    impl_collection!([], Arc<[Lhs]>, VecDeque<Rhs>);
+    impl_collection!([], Arc<[Lhs]>, LinkedList<Rhs>);
+    impl_collection!([], Arc<[Lhs]>, Box<[Rhs]>);
+    impl_collection!([], Arc<[Lhs]>, Rc<[Rhs]>);
+    impl_collection!([], Arc<[Lhs]>, Arc<[Rhs]>);
+    impl_collection!([const N: usize], Arc<[Lhs]>, [Rhs; N]);
+    impl_collection!([], Arc<[Lhs]>, [Rhs]);
+    impl_collection!([], Arc<[Lhs]>, &[Rhs]);
+
+    impl_collection!([const N: usize], [Lhs; N], Arc<[Rhs]>);
+    impl_collection!([], [Lhs], Arc<[Rhs]>);
+    impl_collection!([], &[Lhs], Arc<[Rhs]>);
 }
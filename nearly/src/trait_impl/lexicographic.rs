@@ -0,0 +1,294 @@
+use core::cmp::Ordering;
+
+use crate::lexicographic::Lexicographic;
+use crate::nearly_eq::{NearlyEq, NearlyEqEps, NearlyEqRel, NearlyEqTol, NearlyEqUlps};
+use crate::nearly_ord::{NearlyOrd, NearlyOrdEps, NearlyOrdRel, NearlyOrdTol, NearlyOrdUlps};
+use crate::tolerance::{
+    EpsTolerance, EpsToleranceType, RelTolerance, RelToleranceType, Tolerance, UlpsTolerance,
+    UlpsToleranceType,
+};
+
+/// Compares the elements of `lhs` and `rhs` lexicographically.
+///
+/// Elements that are nearly equal based on `eq` are skipped. The first pair of elements that
+/// is not nearly equal decides the ordering based on `lt` and `gt`. Returns `None` if that pair
+/// is neither less nor greater. If one iterator ends before the other, the shorter one is less.
+fn lexicographic_cmp<'a, Lhs, Rhs>(
+    mut lhs: impl Iterator<Item = &'a Lhs>,
+    mut rhs: impl Iterator<Item = &'a Rhs>,
+    eq: impl Fn(&Lhs, &Rhs) -> bool,
+    lt: impl Fn(&Lhs, &Rhs) -> bool,
+    gt: impl Fn(&Lhs, &Rhs) -> bool,
+) -> Option<Ordering>
+where
+    Lhs: 'a,
+    Rhs: 'a,
+{
+    loop {
+        match (lhs.next(), rhs.next()) {
+            (Some(a), Some(b)) => {
+                if eq(a, b) {
+                    continue;
+                }
+                if lt(a, b) {
+                    return Some(Ordering::Less);
+                }
+                if gt(a, b) {
+                    return Some(Ordering::Greater);
+                }
+                return None;
+            }
+            (Some(_), None) => return Some(Ordering::Greater),
+            (None, Some(_)) => return Some(Ordering::Less),
+            (None, None) => return Some(Ordering::Equal),
+        }
+    }
+}
+
+macro_rules! impl_lexicographic_ord {
+    (
+        $tol_type: ty,
+        $tol: ident,
+        $eq: path,
+        $lt: path,
+        $gt: path,
+        $cmp_fn: ident,
+        $lt_fn: ident,
+        $le_fn: ident,
+        $gt_fn: ident,
+        $ge_fn: ident,
+        $rhs: ty
+    ) => {
+        fn $cmp_fn(&self, other: &Lexicographic<$rhs>, $tol: &$tol_type) -> Option<Ordering> {
+            lexicographic_cmp(
+                self.0.iter(),
+                other.0.iter(),
+                |a, b| $eq(a, b, $tol),
+                |a, b| $lt(a, b, $tol),
+                |a, b| $gt(a, b, $tol),
+            )
+        }
+
+        fn $lt_fn(&self, other: &Lexicographic<$rhs>, $tol: &$tol_type) -> bool {
+            matches!(self.$cmp_fn(other, $tol), Some(Ordering::Less))
+        }
+
+        fn $le_fn(&self, other: &Lexicographic<$rhs>, $tol: &$tol_type) -> bool {
+            matches!(
+                self.$cmp_fn(other, $tol),
+                Some(Ordering::Less | Ordering::Equal)
+            )
+        }
+
+        fn $gt_fn(&self, other: &Lexicographic<$rhs>, $tol: &$tol_type) -> bool {
+            matches!(self.$cmp_fn(other, $tol), Some(Ordering::Greater))
+        }
+
+        fn $ge_fn(&self, other: &Lexicographic<$rhs>, $tol: &$tol_type) -> bool {
+            matches!(
+                self.$cmp_fn(other, $tol),
+                Some(Ordering::Greater | Ordering::Equal)
+            )
+        }
+    };
+}
+
+macro_rules! impl_lexicographic {
+    ([$($vars:tt)*], $lhs: ty, $rhs: ty) => {
+        ///////////////
+        // nearly_eq //
+        ///////////////
+
+        impl<Lhs, Rhs, $($vars)*> NearlyEqEps<Lexicographic<$rhs>, Lhs, Rhs> for Lexicographic<$lhs>
+        where
+            Lhs: NearlyEqEps<Rhs> + EpsTolerance<Rhs>,
+        {
+            fn nearly_eq_eps(
+                &self,
+                other: &Lexicographic<$rhs>,
+                eps: &EpsToleranceType<Lhs, Rhs>,
+            ) -> bool {
+                NearlyEqEps::nearly_eq_eps(&self.0, &other.0, eps)
+            }
+        }
+
+        impl<Lhs, Rhs, $($vars)*> NearlyEqUlps<Lexicographic<$rhs>, Lhs, Rhs> for Lexicographic<$lhs>
+        where
+            Lhs: NearlyEqUlps<Rhs> + UlpsTolerance<Rhs>,
+        {
+            fn nearly_eq_ulps(
+                &self,
+                other: &Lexicographic<$rhs>,
+                ulps: &UlpsToleranceType<Lhs, Rhs>,
+            ) -> bool {
+                NearlyEqUlps::nearly_eq_ulps(&self.0, &other.0, ulps)
+            }
+        }
+
+        impl<Lhs, Rhs, $($vars)*> NearlyEqRel<Lexicographic<$rhs>, Lhs, Rhs> for Lexicographic<$lhs>
+        where
+            Lhs: NearlyEqRel<Rhs> + RelTolerance<Rhs>,
+        {
+            fn nearly_eq_rel(
+                &self,
+                other: &Lexicographic<$rhs>,
+                rel: &RelToleranceType<Lhs, Rhs>,
+            ) -> bool {
+                NearlyEqRel::nearly_eq_rel(&self.0, &other.0, rel)
+            }
+        }
+
+        impl<Lhs, Rhs, $($vars)*> NearlyEqTol<Lexicographic<$rhs>, Lhs, Rhs> for Lexicographic<$lhs>
+        where
+            Lhs: NearlyEqTol<Rhs> + EpsTolerance<Rhs> + UlpsTolerance<Rhs> + RelTolerance<Rhs>,
+        {
+            fn nearly_eq_tol(
+                &self,
+                other: &Lexicographic<$rhs>,
+                tol: &Tolerance<Lhs, Rhs>,
+            ) -> bool {
+                NearlyEqTol::nearly_eq_tol(&self.0, &other.0, tol)
+            }
+        }
+
+        impl<Lhs, Rhs, $($vars)*> NearlyEq<Lexicographic<$rhs>, Lhs, Rhs> for Lexicographic<$lhs>
+        where
+            Lhs: NearlyEq<Rhs> + EpsTolerance<Rhs> + UlpsTolerance<Rhs> + RelTolerance<Rhs>,
+        {
+        }
+
+        ////////////////
+        // nearly_ord //
+        ////////////////
+
+        impl<Lhs, Rhs, $($vars)*> NearlyOrdEps<Lexicographic<$rhs>, Lhs, Rhs> for Lexicographic<$lhs>
+        where
+            Lhs: NearlyOrdEps<Rhs> + EpsTolerance<Rhs>,
+        {
+            impl_lexicographic_ord!(
+                EpsToleranceType<Lhs, Rhs>,
+                eps,
+                NearlyEqEps::nearly_eq_eps,
+                NearlyOrdEps::nearly_lt_eps,
+                NearlyOrdEps::nearly_gt_eps,
+                nearly_cmp_eps,
+                nearly_lt_eps,
+                nearly_le_eps,
+                nearly_gt_eps,
+                nearly_ge_eps,
+                $rhs
+            );
+        }
+
+        impl<Lhs, Rhs, $($vars)*> NearlyOrdUlps<Lexicographic<$rhs>, Lhs, Rhs> for Lexicographic<$lhs>
+        where
+            Lhs: NearlyOrdUlps<Rhs> + UlpsTolerance<Rhs>,
+        {
+            impl_lexicographic_ord!(
+                UlpsToleranceType<Lhs, Rhs>,
+                ulps,
+                NearlyEqUlps::nearly_eq_ulps,
+                NearlyOrdUlps::nearly_lt_ulps,
+                NearlyOrdUlps::nearly_gt_ulps,
+                nearly_cmp_ulps,
+                nearly_lt_ulps,
+                nearly_le_ulps,
+                nearly_gt_ulps,
+                nearly_ge_ulps,
+                $rhs
+            );
+        }
+
+        impl<Lhs, Rhs, $($vars)*> NearlyOrdRel<Lexicographic<$rhs>, Lhs, Rhs> for Lexicographic<$lhs>
+        where
+            Lhs: NearlyOrdRel<Rhs> + RelTolerance<Rhs>,
+        {
+            impl_lexicographic_ord!(
+                RelToleranceType<Lhs, Rhs>,
+                rel,
+                NearlyEqRel::nearly_eq_rel,
+                NearlyOrdRel::nearly_lt_rel,
+                NearlyOrdRel::nearly_gt_rel,
+                nearly_cmp_rel,
+                nearly_lt_rel,
+                nearly_le_rel,
+                nearly_gt_rel,
+                nearly_ge_rel,
+                $rhs
+            );
+        }
+
+        impl<Lhs, Rhs, $($vars)*> NearlyOrdTol<Lexicographic<$rhs>, Lhs, Rhs> for Lexicographic<$lhs>
+        where
+            Lhs: NearlyOrdTol<Rhs>
+                + NearlyEqTol<Rhs>
+                + EpsTolerance<Rhs>
+                + UlpsTolerance<Rhs>
+                + RelTolerance<Rhs>,
+        {
+            impl_lexicographic_ord!(
+                Tolerance<Lhs, Rhs>,
+                tol,
+                NearlyEqTol::nearly_eq_tol,
+                NearlyOrdTol::nearly_lt_tol,
+                NearlyOrdTol::nearly_gt_tol,
+                nearly_cmp_tol,
+                nearly_lt_tol,
+                nearly_le_tol,
+                nearly_gt_tol,
+                nearly_ge_tol,
+                $rhs
+            );
+        }
+
+        impl<Lhs, Rhs, $($vars)*> NearlyOrd<Lexicographic<$rhs>, Lhs, Rhs> for Lexicographic<$lhs>
+        where
+            Lhs: NearlyOrd<Rhs>
+                + NearlyEq<Rhs>
+                + EpsTolerance<Rhs>
+                + UlpsTolerance<Rhs>
+                + RelTolerance<Rhs>,
+        {
+        }
+    };
+}
+
+impl_lexicographic!([const N: usize], [Lhs; N], [Rhs; N]);
+impl_lexicographic!([const N: usize], [Lhs; N], [Rhs]);
+impl_lexicographic!([const N: usize], [Lhs; N], &[Rhs]);
+
+impl_lexicographic!([], [Lhs], [Rhs]);
+impl_lexicographic!([], [Lhs], &[Rhs]);
+impl_lexicographic!([const N: usize], [Lhs], [Rhs; N]);
+
+impl_lexicographic!([], &[Lhs], &[Rhs]);
+impl_lexicographic!([], &[Lhs], [Rhs]);
+impl_lexicographic!([const N: usize], &[Lhs], [Rhs; N]);
+
+#[cfg(feature = "std")]
+mod std_lexicographic {
+    use super::*;
+    use std::collections::{LinkedList, VecDeque};
+
+    impl_lexicographic!([], Vec<Lhs>, Vec<Rhs>);
+    impl_lexicographic!([], Vec<Lhs>, VecDeque<Rhs>);
+    impl_lexicographic!([const N: usize], Vec<Lhs>, [Rhs; N]);
+    impl_lexicographic!([], Vec<Lhs>, [Rhs]);
+    impl_lexicographic!([], Vec<Lhs>, &[Rhs]);
+
+    impl_lexicographic!([const N: usize], [Lhs; N], Vec<Rhs>);
+    impl_lexicographic!([], [Lhs], Vec<Rhs>);
+    impl_lexicographic!([], &[Lhs], Vec<Rhs>);
+
+    impl_lexicographic!([], VecDeque<Lhs>, VecDeque<Rhs>);
+    impl_lexicographic!([], VecDeque<Lhs>, Vec<Rhs>);
+    impl_lexicographic!([const N: usize], VecDeque<Lhs>, [Rhs; N]);
+    impl_lexicographic!([], VecDeque<Lhs>, [Rhs]);
+    impl_lexicographic!([], VecDeque<Lhs>, &[Rhs]);
+
+    impl_lexicographic!([const N: usize], [Lhs; N], VecDeque<Rhs>);
+    impl_lexicographic!([], [Lhs], VecDeque<Rhs>);
+    impl_lexicographic!([], &[Lhs], VecDeque<Rhs>);
+
+    impl_lexicographic!([], LinkedList<Lhs>, LinkedList<Rhs>);
+}
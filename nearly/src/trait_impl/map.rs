@@ -1,78 +1,26 @@
 #![cfg(feature = "std")]
 
-use crate::nearly_eq::{NearlyEq, NearlyEqEps, NearlyEqTol, NearlyEqUlps};
-use crate::nearly_ord::{NearlyOrd, NearlyOrdEps, NearlyOrdTol, NearlyOrdUlps};
+use crate::nearly_eq::{NearlyEq, NearlyEqEps, NearlyEqRel, NearlyEqTol, NearlyEqUlps};
+use crate::nearly_ord::{NearlyOrd, NearlyOrdEps, NearlyOrdRel, NearlyOrdTol, NearlyOrdUlps};
 use crate::tolerance::{
-    EpsTolerance, EpsToleranceType, Tolerance, UlpsTolerance, UlpsToleranceType,
+    EpsTolerance, EpsToleranceType, RelTolerance, RelToleranceType, Tolerance, UlpsTolerance,
+    UlpsToleranceType,
 };
 
+use crate::report::{MismatchKind, NearlyEqReport, Report, ReportTolerance};
+
 use std::collections::{BTreeMap, HashMap};
+use std::fmt::Debug;
 use std::hash::{BuildHasher, Hash};
 
+// Two BTreeMaps iterate in the order of their keys, so they are compared by walking both maps in
+// lockstep instead of looking up every key. Any other combination of maps is implemented by
+// `impl_map_by_key!`.
+
 ///////////////
 // nearly_eq //
 ///////////////
 
-impl<K, Lhs, Rhs, S> NearlyEqEps<HashMap<K, Rhs, S>, Lhs, Rhs> for HashMap<K, Lhs, S>
-where
-    K: Eq + Hash,
-    Lhs: NearlyEqEps<Rhs> + EpsTolerance<Rhs>,
-    S: BuildHasher,
-{
-    fn nearly_eq_eps(&self, other: &HashMap<K, Rhs, S>, eps: &EpsToleranceType<Lhs, Rhs>) -> bool {
-        self.len() == other.len()
-            && self.iter().all(|(key, v_lhs)| {
-                other
-                    .get(key)
-                    .map_or(false, |v_rhs| NearlyEqEps::nearly_eq_eps(v_lhs, v_rhs, eps))
-            })
-    }
-}
-
-impl<K, Lhs, Rhs, S> NearlyEqUlps<HashMap<K, Rhs, S>, Lhs, Rhs> for HashMap<K, Lhs, S>
-where
-    K: Eq + Hash,
-    Lhs: NearlyEqUlps<Rhs> + UlpsTolerance<Rhs>,
-    S: BuildHasher,
-{
-    fn nearly_eq_ulps(
-        &self,
-        other: &HashMap<K, Rhs, S>,
-        ulps: &UlpsToleranceType<Lhs, Rhs>,
-    ) -> bool {
-        self.len() == other.len()
-            && self.iter().all(|(key, v_lhs)| {
-                other.get(key).map_or(false, |v_rhs| {
-                    NearlyEqUlps::nearly_eq_ulps(v_lhs, v_rhs, ulps)
-                })
-            })
-    }
-}
-
-impl<K, Lhs, Rhs, S> NearlyEqTol<HashMap<K, Rhs, S>, Lhs, Rhs> for HashMap<K, Lhs, S>
-where
-    K: Eq + Hash,
-    Lhs: NearlyEqTol<Rhs> + EpsTolerance<Rhs> + UlpsTolerance<Rhs>,
-    S: BuildHasher,
-{
-    fn nearly_eq_tol(&self, other: &HashMap<K, Rhs, S>, tol: &Tolerance<Lhs, Rhs>) -> bool {
-        self.len() == other.len()
-            && self.iter().all(|(key, v_lhs)| {
-                other
-                    .get(key)
-                    .map_or(false, |v_rhs| NearlyEqTol::nearly_eq_tol(v_lhs, v_rhs, tol))
-            })
-    }
-}
-
-impl<K, Lhs, Rhs, S> NearlyEq<HashMap<K, Rhs, S>, Lhs, Rhs> for HashMap<K, Lhs, S>
-where
-    K: Eq + Hash,
-    Lhs: NearlyEq<Rhs> + EpsTolerance<Rhs> + UlpsTolerance<Rhs>,
-    S: BuildHasher,
-{
-}
-
 impl<K, Lhs, Rhs> NearlyEqEps<BTreeMap<K, Rhs>, Lhs, Rhs> for BTreeMap<K, Lhs>
 where
     K: PartialEq,
@@ -101,10 +49,24 @@ where
     }
 }
 
+impl<K, Lhs, Rhs> NearlyEqRel<BTreeMap<K, Rhs>, Lhs, Rhs> for BTreeMap<K, Lhs>
+where
+    K: PartialEq,
+    Lhs: NearlyEqRel<Rhs> + RelTolerance<Rhs>,
+{
+    fn nearly_eq_rel(&self, other: &BTreeMap<K, Rhs>, rel: &RelToleranceType<Lhs, Rhs>) -> bool {
+        self.len() == other.len()
+            && self
+                .iter()
+                .zip(other)
+                .all(|(a, b)| a.0 == b.0 && NearlyEqRel::nearly_eq_rel(a.1, b.1, rel))
+    }
+}
+
 impl<K, Lhs, Rhs> NearlyEqTol<BTreeMap<K, Rhs>, Lhs, Rhs> for BTreeMap<K, Lhs>
 where
     K: PartialEq,
-    Lhs: NearlyEqTol<Rhs> + EpsTolerance<Rhs> + UlpsTolerance<Rhs>,
+    Lhs: NearlyEqTol<Rhs> + EpsTolerance<Rhs> + UlpsTolerance<Rhs> + RelTolerance<Rhs>,
 {
     fn nearly_eq_tol(&self, other: &BTreeMap<K, Rhs>, tol: &Tolerance<Lhs, Rhs>) -> bool {
         self.len() == other.len()
@@ -118,7 +80,7 @@ where
 impl<K, Lhs, Rhs> NearlyEq<BTreeMap<K, Rhs>, Lhs, Rhs> for BTreeMap<K, Lhs>
 where
     K: PartialEq,
-    Lhs: NearlyEq<Rhs> + EpsTolerance<Rhs> + UlpsTolerance<Rhs>,
+    Lhs: NearlyEq<Rhs> + EpsTolerance<Rhs> + UlpsTolerance<Rhs> + RelTolerance<Rhs>,
 {
 }
 
@@ -126,159 +88,6 @@ where
 // nearly_ord //
 ////////////////
 
-impl<K, Lhs, Rhs, S> NearlyOrdEps<HashMap<K, Rhs, S>, Lhs, Rhs> for HashMap<K, Lhs, S>
-where
-    K: Eq + Hash,
-    Lhs: NearlyOrdEps<Rhs> + EpsTolerance<Rhs>,
-    S: BuildHasher,
-{
-    fn nearly_lt_eps(&self, other: &HashMap<K, Rhs, S>, eps: &EpsToleranceType<Lhs, Rhs>) -> bool {
-        self.len() == other.len()
-            && self.iter().all(|(key, v_lhs)| {
-                other.get(key).map_or(false, |v_rhs| {
-                    NearlyOrdEps::nearly_lt_eps(v_lhs, v_rhs, eps)
-                })
-            })
-    }
-
-    fn nearly_le_eps(&self, other: &HashMap<K, Rhs, S>, eps: &EpsToleranceType<Lhs, Rhs>) -> bool {
-        self.len() == other.len()
-            && self.iter().all(|(key, v_lhs)| {
-                other.get(key).map_or(false, |v_rhs| {
-                    NearlyOrdEps::nearly_le_eps(v_lhs, v_rhs, eps)
-                })
-            })
-    }
-
-    fn nearly_gt_eps(&self, other: &HashMap<K, Rhs, S>, eps: &EpsToleranceType<Lhs, Rhs>) -> bool {
-        self.len() == other.len()
-            && self.iter().all(|(key, v_lhs)| {
-                other.get(key).map_or(false, |v_rhs| {
-                    NearlyOrdEps::nearly_gt_eps(v_lhs, v_rhs, eps)
-                })
-            })
-    }
-
-    fn nearly_ge_eps(&self, other: &HashMap<K, Rhs, S>, eps: &EpsToleranceType<Lhs, Rhs>) -> bool {
-        self.len() == other.len()
-            && self.iter().all(|(key, v_lhs)| {
-                other.get(key).map_or(false, |v_rhs| {
-                    NearlyOrdEps::nearly_ge_eps(v_lhs, v_rhs, eps)
-                })
-            })
-    }
-}
-
-impl<K, Lhs, Rhs, S> NearlyOrdUlps<HashMap<K, Rhs, S>, Lhs, Rhs> for HashMap<K, Lhs, S>
-where
-    K: Eq + Hash,
-    Lhs: NearlyOrdUlps<Rhs> + UlpsTolerance<Rhs>,
-    S: BuildHasher,
-{
-    fn nearly_lt_ulps(
-        &self,
-        other: &HashMap<K, Rhs, S>,
-        ulps: &UlpsToleranceType<Lhs, Rhs>,
-    ) -> bool {
-        self.len() == other.len()
-            && self.iter().all(|(key, v_lhs)| {
-                other.get(key).map_or(false, |v_rhs| {
-                    NearlyOrdUlps::nearly_lt_ulps(v_lhs, v_rhs, ulps)
-                })
-            })
-    }
-
-    fn nearly_le_ulps(
-        &self,
-        other: &HashMap<K, Rhs, S>,
-        ulps: &UlpsToleranceType<Lhs, Rhs>,
-    ) -> bool {
-        self.len() == other.len()
-            && self.iter().all(|(key, v_lhs)| {
-                other.get(key).map_or(false, |v_rhs| {
-                    NearlyOrdUlps::nearly_le_ulps(v_lhs, v_rhs, ulps)
-                })
-            })
-    }
-
-    fn nearly_gt_ulps(
-        &self,
-        other: &HashMap<K, Rhs, S>,
-        ulps: &UlpsToleranceType<Lhs, Rhs>,
-    ) -> bool {
-        self.len() == other.len()
-            && self.iter().all(|(key, v_lhs)| {
-                other.get(key).map_or(false, |v_rhs| {
-                    NearlyOrdUlps::nearly_gt_ulps(v_lhs, v_rhs, ulps)
-                })
-            })
-    }
-
-    fn nearly_ge_ulps(
-        &self,
-        other: &HashMap<K, Rhs, S>,
-        ulps: &UlpsToleranceType<Lhs, Rhs>,
-    ) -> bool {
-        self.len() == other.len()
-            && self.iter().all(|(key, v_lhs)| {
-                other.get(key).map_or(false, |v_rhs| {
-                    NearlyOrdUlps::nearly_ge_ulps(v_lhs, v_rhs, ulps)
-                })
-            })
-    }
-}
-
-impl<K, Lhs, Rhs, S> NearlyOrdTol<HashMap<K, Rhs, S>, Lhs, Rhs> for HashMap<K, Lhs, S>
-where
-    K: Eq + Hash,
-    Lhs: NearlyOrdTol<Rhs> + EpsTolerance<Rhs> + UlpsTolerance<Rhs>,
-    S: BuildHasher,
-{
-    fn nearly_lt_tol(&self, other: &HashMap<K, Rhs, S>, tol: &Tolerance<Lhs, Rhs>) -> bool {
-        self.len() == other.len()
-            && self.iter().all(|(key, v_lhs)| {
-                other.get(key).map_or(false, |v_rhs| {
-                    NearlyOrdTol::nearly_lt_tol(v_lhs, v_rhs, tol)
-                })
-            })
-    }
-
-    fn nearly_le_tol(&self, other: &HashMap<K, Rhs, S>, tol: &Tolerance<Lhs, Rhs>) -> bool {
-        self.len() == other.len()
-            && self.iter().all(|(key, v_lhs)| {
-                other.get(key).map_or(false, |v_rhs| {
-                    NearlyOrdTol::nearly_le_tol(v_lhs, v_rhs, tol)
-                })
-            })
-    }
-
-    fn nearly_gt_tol(&self, other: &HashMap<K, Rhs, S>, tol: &Tolerance<Lhs, Rhs>) -> bool {
-        self.len() == other.len()
-            && self.iter().all(|(key, v_lhs)| {
-                other.get(key).map_or(false, |v_rhs| {
-                    NearlyOrdTol::nearly_gt_tol(v_lhs, v_rhs, tol)
-                })
-            })
-    }
-
-    fn nearly_ge_tol(&self, other: &HashMap<K, Rhs, S>, tol: &Tolerance<Lhs, Rhs>) -> bool {
-        self.len() == other.len()
-            && self.iter().all(|(key, v_lhs)| {
-                other.get(key).map_or(false, |v_rhs| {
-                    NearlyOrdTol::nearly_ge_tol(v_lhs, v_rhs, tol)
-                })
-            })
-    }
-}
-
-impl<K, Lhs, Rhs, S> NearlyOrd<HashMap<K, Rhs, S>, Lhs, Rhs> for HashMap<K, Lhs, S>
-where
-    K: Eq + Hash,
-    Lhs: NearlyOrd<Rhs> + EpsTolerance<Rhs> + UlpsTolerance<Rhs>,
-    S: BuildHasher,
-{
-}
-
 impl<K, Lhs, Rhs> NearlyOrdEps<BTreeMap<K, Rhs>, Lhs, Rhs> for BTreeMap<K, Lhs>
 where
     K: PartialEq,
@@ -355,10 +164,48 @@ where
     }
 }
 
+impl<K, Lhs, Rhs> NearlyOrdRel<BTreeMap<K, Rhs>, Lhs, Rhs> for BTreeMap<K, Lhs>
+where
+    K: PartialEq,
+    Lhs: NearlyOrdRel<Rhs> + RelTolerance<Rhs>,
+{
+    fn nearly_lt_rel(&self, other: &BTreeMap<K, Rhs>, rel: &RelToleranceType<Lhs, Rhs>) -> bool {
+        self.len() == other.len()
+            && self
+                .iter()
+                .zip(other)
+                .all(|(a, b)| a.0 == b.0 && NearlyOrdRel::nearly_lt_rel(a.1, b.1, rel))
+    }
+
+    fn nearly_le_rel(&self, other: &BTreeMap<K, Rhs>, rel: &RelToleranceType<Lhs, Rhs>) -> bool {
+        self.len() == other.len()
+            && self
+                .iter()
+                .zip(other)
+                .all(|(a, b)| a.0 == b.0 && NearlyOrdRel::nearly_le_rel(a.1, b.1, rel))
+    }
+
+    fn nearly_gt_rel(&self, other: &BTreeMap<K, Rhs>, rel: &RelToleranceType<Lhs, Rhs>) -> bool {
+        self.len() == other.len()
+            && self
+                .iter()
+                .zip(other)
+                .all(|(a, b)| a.0 == b.0 && NearlyOrdRel::nearly_gt_rel(a.1, b.1, rel))
+    }
+
+    fn nearly_ge_rel(&self, other: &BTreeMap<K, Rhs>, rel: &RelToleranceType<Lhs, Rhs>) -> bool {
+        self.len() == other.len()
+            && self
+                .iter()
+                .zip(other)
+                .all(|(a, b)| a.0 == b.0 && NearlyOrdRel::nearly_ge_rel(a.1, b.1, rel))
+    }
+}
+
 impl<K, Lhs, Rhs> NearlyOrdTol<BTreeMap<K, Rhs>, Lhs, Rhs> for BTreeMap<K, Lhs>
 where
     K: PartialEq,
-    Lhs: NearlyOrdTol<Rhs> + EpsTolerance<Rhs> + UlpsTolerance<Rhs>,
+    Lhs: NearlyOrdTol<Rhs> + EpsTolerance<Rhs> + UlpsTolerance<Rhs> + RelTolerance<Rhs>,
 {
     fn nearly_lt_tol(&self, other: &BTreeMap<K, Rhs>, tol: &Tolerance<Lhs, Rhs>) -> bool {
         self.len() == other.len()
@@ -396,6 +243,442 @@ where
 impl<K, Lhs, Rhs> NearlyOrd<BTreeMap<K, Rhs>, Lhs, Rhs> for BTreeMap<K, Lhs>
 where
     K: PartialEq,
-    Lhs: NearlyOrd<Rhs> + EpsTolerance<Rhs> + UlpsTolerance<Rhs>,
+    Lhs: NearlyOrd<Rhs> + EpsTolerance<Rhs> + UlpsTolerance<Rhs> + RelTolerance<Rhs>,
 {
 }
+
+/// Implements the nearly traits for maps by looking up the keys of `$lhs` in `$rhs`.
+macro_rules! impl_map_by_key {
+    ([$($vars:tt)*], $lhs: ty, $rhs: ty, [$($bounds:tt)*]) => {
+        ///////////////
+        // nearly_eq //
+        ///////////////
+
+        impl<K, Lhs, Rhs, $($vars)*> NearlyEqEps<$rhs, Lhs, Rhs> for $lhs
+        where
+            Lhs: NearlyEqEps<Rhs> + EpsTolerance<Rhs>,
+            $($bounds)*
+        {
+            fn nearly_eq_eps(
+                &self,
+                other: &$rhs,
+                eps: &EpsToleranceType<Lhs, Rhs>,
+            ) -> bool {
+                self.len() == other.len()
+                    && self.iter().all(|(key, v_lhs)| {
+                        other
+                            .get(key)
+                            .map_or(false, |v_rhs| {
+                                NearlyEqEps::nearly_eq_eps(v_lhs, v_rhs, eps)
+                            })
+                    })
+            }
+        }
+
+        impl<K, Lhs, Rhs, $($vars)*> NearlyEqUlps<$rhs, Lhs, Rhs> for $lhs
+        where
+            Lhs: NearlyEqUlps<Rhs> + UlpsTolerance<Rhs>,
+            $($bounds)*
+        {
+            fn nearly_eq_ulps(
+                &self,
+                other: &$rhs,
+                ulps: &UlpsToleranceType<Lhs, Rhs>,
+            ) -> bool {
+                self.len() == other.len()
+                    && self.iter().all(|(key, v_lhs)| {
+                        other
+                            .get(key)
+                            .map_or(false, |v_rhs| {
+                                NearlyEqUlps::nearly_eq_ulps(v_lhs, v_rhs, ulps)
+                            })
+                    })
+            }
+        }
+
+        impl<K, Lhs, Rhs, $($vars)*> NearlyEqRel<$rhs, Lhs, Rhs> for $lhs
+        where
+            Lhs: NearlyEqRel<Rhs> + RelTolerance<Rhs>,
+            $($bounds)*
+        {
+            fn nearly_eq_rel(
+                &self,
+                other: &$rhs,
+                rel: &RelToleranceType<Lhs, Rhs>,
+            ) -> bool {
+                self.len() == other.len()
+                    && self.iter().all(|(key, v_lhs)| {
+                        other
+                            .get(key)
+                            .map_or(false, |v_rhs| {
+                                NearlyEqRel::nearly_eq_rel(v_lhs, v_rhs, rel)
+                            })
+                    })
+            }
+        }
+
+        impl<K, Lhs, Rhs, $($vars)*> NearlyEqTol<$rhs, Lhs, Rhs> for $lhs
+        where
+            Lhs: NearlyEqTol<Rhs> + EpsTolerance<Rhs> + UlpsTolerance<Rhs> + RelTolerance<Rhs>,
+            $($bounds)*
+        {
+            fn nearly_eq_tol(
+                &self,
+                other: &$rhs,
+                tol: &Tolerance<Lhs, Rhs>,
+            ) -> bool {
+                self.len() == other.len()
+                    && self.iter().all(|(key, v_lhs)| {
+                        other
+                            .get(key)
+                            .map_or(false, |v_rhs| {
+                                NearlyEqTol::nearly_eq_tol(v_lhs, v_rhs, tol)
+                            })
+                    })
+            }
+        }
+
+        impl<K, Lhs, Rhs, $($vars)*> NearlyEq<$rhs, Lhs, Rhs> for $lhs
+        where
+            Lhs: NearlyEq<Rhs> + EpsTolerance<Rhs> + UlpsTolerance<Rhs> + RelTolerance<Rhs>,
+            $($bounds)*
+        {
+        }
+
+        ////////////////
+        // nearly_ord //
+        ////////////////
+
+        impl<K, Lhs, Rhs, $($vars)*> NearlyOrdEps<$rhs, Lhs, Rhs> for $lhs
+        where
+            Lhs: NearlyOrdEps<Rhs> + EpsTolerance<Rhs>,
+            $($bounds)*
+        {
+            fn nearly_lt_eps(
+                &self,
+                other: &$rhs,
+                eps: &EpsToleranceType<Lhs, Rhs>,
+            ) -> bool {
+                self.len() == other.len()
+                    && self.iter().all(|(key, v_lhs)| {
+                        other
+                            .get(key)
+                            .map_or(false, |v_rhs| {
+                                NearlyOrdEps::nearly_lt_eps(v_lhs, v_rhs, eps)
+                            })
+                    })
+            }
+
+            fn nearly_le_eps(
+                &self,
+                other: &$rhs,
+                eps: &EpsToleranceType<Lhs, Rhs>,
+            ) -> bool {
+                self.len() == other.len()
+                    && self.iter().all(|(key, v_lhs)| {
+                        other
+                            .get(key)
+                            .map_or(false, |v_rhs| {
+                                NearlyOrdEps::nearly_le_eps(v_lhs, v_rhs, eps)
+                            })
+                    })
+            }
+
+            fn nearly_gt_eps(
+                &self,
+                other: &$rhs,
+                eps: &EpsToleranceType<Lhs, Rhs>,
+            ) -> bool {
+                self.len() == other.len()
+                    && self.iter().all(|(key, v_lhs)| {
+                        other
+                            .get(key)
+                            .map_or(false, |v_rhs| {
+                                NearlyOrdEps::nearly_gt_eps(v_lhs, v_rhs, eps)
+                            })
+                    })
+            }
+
+            fn nearly_ge_eps(
+                &self,
+                other: &$rhs,
+                eps: &EpsToleranceType<Lhs, Rhs>,
+            ) -> bool {
+                self.len() == other.len()
+                    && self.iter().all(|(key, v_lhs)| {
+                        other
+                            .get(key)
+                            .map_or(false, |v_rhs| {
+                                NearlyOrdEps::nearly_ge_eps(v_lhs, v_rhs, eps)
+                            })
+                    })
+            }
+        }
+
+        impl<K, Lhs, Rhs, $($vars)*> NearlyOrdUlps<$rhs, Lhs, Rhs> for $lhs
+        where
+            Lhs: NearlyOrdUlps<Rhs> + UlpsTolerance<Rhs>,
+            $($bounds)*
+        {
+            fn nearly_lt_ulps(
+                &self,
+                other: &$rhs,
+                ulps: &UlpsToleranceType<Lhs, Rhs>,
+            ) -> bool {
+                self.len() == other.len()
+                    && self.iter().all(|(key, v_lhs)| {
+                        other
+                            .get(key)
+                            .map_or(false, |v_rhs| {
+                                NearlyOrdUlps::nearly_lt_ulps(v_lhs, v_rhs, ulps)
+                            })
+                    })
+            }
+
+            fn nearly_le_ulps(
+                &self,
+                other: &$rhs,
+                ulps: &UlpsToleranceType<Lhs, Rhs>,
+            ) -> bool {
+                self.len() == other.len()
+                    && self.iter().all(|(key, v_lhs)| {
+                        other
+                            .get(key)
+                            .map_or(false, |v_rhs| {
+                                NearlyOrdUlps::nearly_le_ulps(v_lhs, v_rhs, ulps)
+                            })
+                    })
+            }
+
+            fn nearly_gt_ulps(
+                &self,
+                other: &$rhs,
+                ulps: &UlpsToleranceType<Lhs, Rhs>,
+            ) -> bool {
+                self.len() == other.len()
+                    && self.iter().all(|(key, v_lhs)| {
+                        other
+                            .get(key)
+                            .map_or(false, |v_rhs| {
+                                NearlyOrdUlps::nearly_gt_ulps(v_lhs, v_rhs, ulps)
+                            })
+                    })
+            }
+
+            fn nearly_ge_ulps(
+                &self,
+                other: &$rhs,
+                ulps: &UlpsToleranceType<Lhs, Rhs>,
+            ) -> bool {
+                self.len() == other.len()
+                    && self.iter().all(|(key, v_lhs)| {
+                        other
+                            .get(key)
+                            .map_or(false, |v_rhs| {
+                                NearlyOrdUlps::nearly_ge_ulps(v_lhs, v_rhs, ulps)
+                            })
+                    })
+            }
+        }
+
+        impl<K, Lhs, Rhs, $($vars)*> NearlyOrdRel<$rhs, Lhs, Rhs> for $lhs
+        where
+            Lhs: NearlyOrdRel<Rhs> + RelTolerance<Rhs>,
+            $($bounds)*
+        {
+            fn nearly_lt_rel(
+                &self,
+                other: &$rhs,
+                rel: &RelToleranceType<Lhs, Rhs>,
+            ) -> bool {
+                self.len() == other.len()
+                    && self.iter().all(|(key, v_lhs)| {
+                        other
+                            .get(key)
+                            .map_or(false, |v_rhs| {
+                                NearlyOrdRel::nearly_lt_rel(v_lhs, v_rhs, rel)
+                            })
+                    })
+            }
+
+            fn nearly_le_rel(
+                &self,
+                other: &$rhs,
+                rel: &RelToleranceType<Lhs, Rhs>,
+            ) -> bool {
+                self.len() == other.len()
+                    && self.iter().all(|(key, v_lhs)| {
+                        other
+                            .get(key)
+                            .map_or(false, |v_rhs| {
+                                NearlyOrdRel::nearly_le_rel(v_lhs, v_rhs, rel)
+                            })
+                    })
+            }
+
+            fn nearly_gt_rel(
+                &self,
+                other: &$rhs,
+                rel: &RelToleranceType<Lhs, Rhs>,
+            ) -> bool {
+                self.len() == other.len()
+                    && self.iter().all(|(key, v_lhs)| {
+                        other
+                            .get(key)
+                            .map_or(false, |v_rhs| {
+                                NearlyOrdRel::nearly_gt_rel(v_lhs, v_rhs, rel)
+                            })
+                    })
+            }
+
+            fn nearly_ge_rel(
+                &self,
+                other: &$rhs,
+                rel: &RelToleranceType<Lhs, Rhs>,
+            ) -> bool {
+                self.len() == other.len()
+                    && self.iter().all(|(key, v_lhs)| {
+                        other
+                            .get(key)
+                            .map_or(false, |v_rhs| {
+                                NearlyOrdRel::nearly_ge_rel(v_lhs, v_rhs, rel)
+                            })
+                    })
+            }
+        }
+
+        impl<K, Lhs, Rhs, $($vars)*> NearlyOrdTol<$rhs, Lhs, Rhs> for $lhs
+        where
+            Lhs: NearlyOrdTol<Rhs> + EpsTolerance<Rhs> + UlpsTolerance<Rhs> + RelTolerance<Rhs>,
+            $($bounds)*
+        {
+            fn nearly_lt_tol(
+                &self,
+                other: &$rhs,
+                tol: &Tolerance<Lhs, Rhs>,
+            ) -> bool {
+                self.len() == other.len()
+                    && self.iter().all(|(key, v_lhs)| {
+                        other
+                            .get(key)
+                            .map_or(false, |v_rhs| {
+                                NearlyOrdTol::nearly_lt_tol(v_lhs, v_rhs, tol)
+                            })
+                    })
+            }
+
+            fn nearly_le_tol(
+                &self,
+                other: &$rhs,
+                tol: &Tolerance<Lhs, Rhs>,
+            ) -> bool {
+                self.len() == other.len()
+                    && self.iter().all(|(key, v_lhs)| {
+                        other
+                            .get(key)
+                            .map_or(false, |v_rhs| {
+                                NearlyOrdTol::nearly_le_tol(v_lhs, v_rhs, tol)
+                            })
+                    })
+            }
+
+            fn nearly_gt_tol(
+                &self,
+                other: &$rhs,
+                tol: &Tolerance<Lhs, Rhs>,
+            ) -> bool {
+                self.len() == other.len()
+                    && self.iter().all(|(key, v_lhs)| {
+                        other
+                            .get(key)
+                            .map_or(false, |v_rhs| {
+                                NearlyOrdTol::nearly_gt_tol(v_lhs, v_rhs, tol)
+                            })
+                    })
+            }
+
+            fn nearly_ge_tol(
+                &self,
+                other: &$rhs,
+                tol: &Tolerance<Lhs, Rhs>,
+            ) -> bool {
+                self.len() == other.len()
+                    && self.iter().all(|(key, v_lhs)| {
+                        other
+                            .get(key)
+                            .map_or(false, |v_rhs| {
+                                NearlyOrdTol::nearly_ge_tol(v_lhs, v_rhs, tol)
+                            })
+                    })
+            }
+        }
+
+        impl<K, Lhs, Rhs, $($vars)*> NearlyOrd<$rhs, Lhs, Rhs> for $lhs
+        where
+            Lhs: NearlyOrd<Rhs> + EpsTolerance<Rhs> + UlpsTolerance<Rhs> + RelTolerance<Rhs>,
+            $($bounds)*
+        {
+        }
+    };
+}
+
+impl_map_by_key!(
+    [S1, S2],
+    HashMap<K, Lhs, S1>,
+    HashMap<K, Rhs, S2>,
+    [K: Eq + Hash, S2: BuildHasher]
+);
+impl_map_by_key!([S], HashMap<K, Lhs, S>, BTreeMap<K, Rhs>, [K: Ord]);
+impl_map_by_key!([S], BTreeMap<K, Lhs>, HashMap<K, Rhs, S>, [K: Eq + Hash, S: BuildHasher]);
+
+/// Implements [NearlyEqReport] for maps by looking up the keys of each map in the other map.
+///
+/// Values of keys present in both maps are reported at the path of the key. Keys present in
+/// only one of the maps are reported as [MismatchKind::OnlyInLhs] or [MismatchKind::OnlyInRhs].
+macro_rules! impl_map_report {
+    ([$($vars:tt)*], $lhs: ty, $rhs: ty, [$($bounds:tt)*]) => {
+        impl<K, Lhs, Rhs, $($vars)*> NearlyEqReport<$rhs, Lhs, Rhs> for $lhs
+        where
+            Lhs: NearlyEqReport<Rhs> + EpsTolerance<Rhs> + UlpsTolerance<Rhs> + RelTolerance<Rhs>,
+            $($bounds)*
+        {
+            fn report_nearly_eq(
+                &self,
+                other: &$rhs,
+                tol: &ReportTolerance<Lhs, Rhs>,
+                report: &mut Report,
+            ) {
+                for (key, v_lhs) in self.iter() {
+                    report.key(key, |report| match other.get(key) {
+                        Some(v_rhs) => NearlyEqReport::report_nearly_eq(v_lhs, v_rhs, tol, report),
+                        None => report.push(MismatchKind::OnlyInLhs),
+                    });
+                }
+
+                for key in other.keys().filter(|key| !self.contains_key(key)) {
+                    report.key(key, |report| report.push(MismatchKind::OnlyInRhs));
+                }
+            }
+        }
+    };
+}
+
+impl_map_report!(
+    [S1, S2],
+    HashMap<K, Lhs, S1>,
+    HashMap<K, Rhs, S2>,
+    [K: Eq + Hash + Debug, S1: BuildHasher, S2: BuildHasher]
+);
+impl_map_report!([], BTreeMap<K, Lhs>, BTreeMap<K, Rhs>, [K: Ord + Debug]);
+impl_map_report!(
+    [S],
+    HashMap<K, Lhs, S>,
+    BTreeMap<K, Rhs>,
+    [K: Eq + Hash + Ord + Debug, S: BuildHasher]
+);
+impl_map_report!(
+    [S],
+    BTreeMap<K, Lhs>,
+    HashMap<K, Rhs, S>,
+    [K: Eq + Hash + Ord + Debug, S: BuildHasher]
+);
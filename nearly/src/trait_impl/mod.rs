@@ -1,8 +1,15 @@
+mod cell;
 mod collection;
+mod lexicographic;
 mod map;
+mod option;
+mod per_element;
 mod pin;
 mod pointer;
 mod primitive;
 mod reference;
+mod result;
+mod set;
 mod tuple;
 mod unit;
+mod unordered;
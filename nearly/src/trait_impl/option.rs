@@ -0,0 +1,345 @@
+use crate::nearly_eq::{NearlyEq, NearlyEqEps, NearlyEqRel, NearlyEqTol, NearlyEqUlps};
+use crate::nearly_ord::{NearlyOrd, NearlyOrdEps, NearlyOrdRel, NearlyOrdTol, NearlyOrdUlps};
+use crate::tolerance::{
+    EpsTolerance, EpsToleranceType, RelTolerance, RelToleranceType, Tolerance, UlpsTolerance,
+    UlpsToleranceType,
+};
+
+#[cfg(feature = "std")]
+use crate::report::{MismatchKind, NearlyEqReport, Report, ReportTolerance};
+
+/// Returns the name of the variant of an option.
+#[cfg(feature = "std")]
+fn variant<T>(option: &Option<T>) -> &'static str {
+    match option {
+        Some(_) => "Some",
+        None => "None",
+    }
+}
+
+/// Compares two options with `$some` if both are `Some`.
+/// Returns true if the options match `$other` and false otherwise.
+macro_rules! cmp_option {
+    ($self: expr, $rhs: expr, |$a: ident, $b: ident| $some: expr, $other: pat) => {
+        match ($self, $rhs) {
+            (Some($a), Some($b)) => $some,
+            $other => true,
+            _ => false,
+        }
+    };
+}
+
+macro_rules! impl_option {
+    ($lhs: ty, $rhs: ty) => {
+        ///////////////
+        // nearly_eq //
+        ///////////////
+
+        impl<Lhs, Rhs> NearlyEqEps<$rhs, Lhs, Rhs> for $lhs
+        where
+            Lhs: NearlyEqEps<Rhs> + EpsTolerance<Rhs>,
+        {
+            #[inline]
+            fn nearly_eq_eps(&self, other: &$rhs, eps: &EpsToleranceType<Lhs, Rhs>) -> bool {
+                cmp_option!(
+                    self,
+                    other,
+                    |a, b| <Lhs as NearlyEqEps<Rhs>>::nearly_eq_eps(a, b, eps),
+                    (None, None)
+                )
+            }
+        }
+
+        impl<Lhs, Rhs> NearlyEqUlps<$rhs, Lhs, Rhs> for $lhs
+        where
+            Lhs: NearlyEqUlps<Rhs> + UlpsTolerance<Rhs>,
+        {
+            #[inline]
+            fn nearly_eq_ulps(&self, other: &$rhs, ulps: &UlpsToleranceType<Lhs, Rhs>) -> bool {
+                cmp_option!(
+                    self,
+                    other,
+                    |a, b| <Lhs as NearlyEqUlps<Rhs>>::nearly_eq_ulps(a, b, ulps),
+                    (None, None)
+                )
+            }
+        }
+
+        impl<Lhs, Rhs> NearlyEqRel<$rhs, Lhs, Rhs> for $lhs
+        where
+            Lhs: NearlyEqRel<Rhs> + RelTolerance<Rhs>,
+        {
+            #[inline]
+            fn nearly_eq_rel(&self, other: &$rhs, rel: &RelToleranceType<Lhs, Rhs>) -> bool {
+                cmp_option!(
+                    self,
+                    other,
+                    |a, b| <Lhs as NearlyEqRel<Rhs>>::nearly_eq_rel(a, b, rel),
+                    (None, None)
+                )
+            }
+        }
+
+        impl<Lhs, Rhs> NearlyEqTol<$rhs, Lhs, Rhs> for $lhs
+        where
+            Lhs: NearlyEqTol<Rhs> + EpsTolerance<Rhs> + UlpsTolerance<Rhs> + RelTolerance<Rhs>,
+        {
+            #[inline]
+            fn nearly_eq_tol(&self, other: &$rhs, tol: &Tolerance<Lhs, Rhs>) -> bool {
+                cmp_option!(
+                    self,
+                    other,
+                    |a, b| <Lhs as NearlyEqTol<Rhs>>::nearly_eq_tol(a, b, tol),
+                    (None, None)
+                )
+            }
+        }
+
+        impl<Lhs, Rhs> NearlyEq<$rhs, Lhs, Rhs> for $lhs where
+            Lhs: NearlyEq<Rhs> + EpsTolerance<Rhs> + UlpsTolerance<Rhs> + RelTolerance<Rhs>
+        {
+        }
+
+        #[cfg(feature = "std")]
+        impl<Lhs, Rhs> NearlyEqReport<$rhs, Lhs, Rhs> for $lhs
+        where
+            Lhs: NearlyEqReport<Rhs> + EpsTolerance<Rhs> + UlpsTolerance<Rhs> + RelTolerance<Rhs>,
+        {
+            fn report_nearly_eq(
+                &self,
+                other: &$rhs,
+                tol: &ReportTolerance<Lhs, Rhs>,
+                report: &mut Report,
+            ) {
+                match (self, other) {
+                    (Some(a), Some(b)) => {
+                        <Lhs as NearlyEqReport<Rhs>>::report_nearly_eq(a, b, tol, report)
+                    }
+                    (None, None) => {}
+                    (a, b) => report.push(MismatchKind::Variant {
+                        lhs: variant(a),
+                        rhs: variant(b),
+                    }),
+                }
+            }
+        }
+
+        ////////////////
+        // nearly_ord //
+        ////////////////
+
+        impl<Lhs, Rhs> NearlyOrdEps<$rhs, Lhs, Rhs> for $lhs
+        where
+            Lhs: NearlyOrdEps<Rhs> + EpsTolerance<Rhs>,
+        {
+            #[inline]
+            fn nearly_lt_eps(&self, other: &$rhs, eps: &EpsToleranceType<Lhs, Rhs>) -> bool {
+                cmp_option!(
+                    self,
+                    other,
+                    |a, b| <Lhs as NearlyOrdEps<Rhs>>::nearly_lt_eps(a, b, eps),
+                    (None, Some(_))
+                )
+            }
+
+            #[inline]
+            fn nearly_le_eps(&self, other: &$rhs, eps: &EpsToleranceType<Lhs, Rhs>) -> bool {
+                cmp_option!(
+                    self,
+                    other,
+                    |a, b| <Lhs as NearlyOrdEps<Rhs>>::nearly_le_eps(a, b, eps),
+                    (None, _)
+                )
+            }
+
+            #[inline]
+            fn nearly_gt_eps(&self, other: &$rhs, eps: &EpsToleranceType<Lhs, Rhs>) -> bool {
+                cmp_option!(
+                    self,
+                    other,
+                    |a, b| <Lhs as NearlyOrdEps<Rhs>>::nearly_gt_eps(a, b, eps),
+                    (Some(_), None)
+                )
+            }
+
+            #[inline]
+            fn nearly_ge_eps(&self, other: &$rhs, eps: &EpsToleranceType<Lhs, Rhs>) -> bool {
+                cmp_option!(
+                    self,
+                    other,
+                    |a, b| <Lhs as NearlyOrdEps<Rhs>>::nearly_ge_eps(a, b, eps),
+                    (_, None)
+                )
+            }
+        }
+
+        impl<Lhs, Rhs> NearlyOrdUlps<$rhs, Lhs, Rhs> for $lhs
+        where
+            Lhs: NearlyOrdUlps<Rhs> + UlpsTolerance<Rhs>,
+        {
+            #[inline]
+            fn nearly_lt_ulps(&self, other: &$rhs, ulps: &UlpsToleranceType<Lhs, Rhs>) -> bool {
+                cmp_option!(
+                    self,
+                    other,
+                    |a, b| <Lhs as NearlyOrdUlps<Rhs>>::nearly_lt_ulps(a, b, ulps),
+                    (None, Some(_))
+                )
+            }
+
+            #[inline]
+            fn nearly_le_ulps(&self, other: &$rhs, ulps: &UlpsToleranceType<Lhs, Rhs>) -> bool {
+                cmp_option!(
+                    self,
+                    other,
+                    |a, b| <Lhs as NearlyOrdUlps<Rhs>>::nearly_le_ulps(a, b, ulps),
+                    (None, _)
+                )
+            }
+
+            #[inline]
+            fn nearly_gt_ulps(&self, other: &$rhs, ulps: &UlpsToleranceType<Lhs, Rhs>) -> bool {
+                cmp_option!(
+                    self,
+                    other,
+                    |a, b| <Lhs as NearlyOrdUlps<Rhs>>::nearly_gt_ulps(a, b, ulps),
+                    (Some(_), None)
+                )
+            }
+
+            #[inline]
+            fn nearly_ge_ulps(&self, other: &$rhs, ulps: &UlpsToleranceType<Lhs, Rhs>) -> bool {
+                cmp_option!(
+                    self,
+                    other,
+                    |a, b| <Lhs as NearlyOrdUlps<Rhs>>::nearly_ge_ulps(a, b, ulps),
+                    (_, None)
+                )
+            }
+        }
+
+        impl<Lhs, Rhs> NearlyOrdRel<$rhs, Lhs, Rhs> for $lhs
+        where
+            Lhs: NearlyOrdRel<Rhs> + RelTolerance<Rhs>,
+        {
+            #[inline]
+            fn nearly_lt_rel(&self, other: &$rhs, rel: &RelToleranceType<Lhs, Rhs>) -> bool {
+                cmp_option!(
+                    self,
+                    other,
+                    |a, b| <Lhs as NearlyOrdRel<Rhs>>::nearly_lt_rel(a, b, rel),
+                    (None, Some(_))
+                )
+            }
+
+            #[inline]
+            fn nearly_le_rel(&self, other: &$rhs, rel: &RelToleranceType<Lhs, Rhs>) -> bool {
+                cmp_option!(
+                    self,
+                    other,
+                    |a, b| <Lhs as NearlyOrdRel<Rhs>>::nearly_le_rel(a, b, rel),
+                    (None, _)
+                )
+            }
+
+            #[inline]
+            fn nearly_gt_rel(&self, other: &$rhs, rel: &RelToleranceType<Lhs, Rhs>) -> bool {
+                cmp_option!(
+                    self,
+                    other,
+                    |a, b| <Lhs as NearlyOrdRel<Rhs>>::nearly_gt_rel(a, b, rel),
+                    (Some(_), None)
+                )
+            }
+
+            #[inline]
+            fn nearly_ge_rel(&self, other: &$rhs, rel: &RelToleranceType<Lhs, Rhs>) -> bool {
+                cmp_option!(
+                    self,
+                    other,
+                    |a, b| <Lhs as NearlyOrdRel<Rhs>>::nearly_ge_rel(a, b, rel),
+                    (_, None)
+                )
+            }
+        }
+
+        impl<Lhs, Rhs> NearlyOrdTol<$rhs, Lhs, Rhs> for $lhs
+        where
+            Lhs: NearlyOrdTol<Rhs> + EpsTolerance<Rhs> + UlpsTolerance<Rhs> + RelTolerance<Rhs>,
+        {
+            #[inline]
+            fn nearly_lt_tol(&self, other: &$rhs, tol: &Tolerance<Lhs, Rhs>) -> bool {
+                cmp_option!(
+                    self,
+                    other,
+                    |a, b| <Lhs as NearlyOrdTol<Rhs>>::nearly_lt_tol(a, b, tol),
+                    (None, Some(_))
+                )
+            }
+
+            #[inline]
+            fn nearly_le_tol(&self, other: &$rhs, tol: &Tolerance<Lhs, Rhs>) -> bool {
+                cmp_option!(
+                    self,
+                    other,
+                    |a, b| <Lhs as NearlyOrdTol<Rhs>>::nearly_le_tol(a, b, tol),
+                    (None, _)
+                )
+            }
+
+            #[inline]
+            fn nearly_gt_tol(&self, other: &$rhs, tol: &Tolerance<Lhs, Rhs>) -> bool {
+                cmp_option!(
+                    self,
+                    other,
+                    |a, b| <Lhs as NearlyOrdTol<Rhs>>::nearly_gt_tol(a, b, tol),
+                    (Some(_), None)
+                )
+            }
+
+            #[inline]
+            fn nearly_ge_tol(&self, other: &$rhs, tol: &Tolerance<Lhs, Rhs>) -> bool {
+                cmp_option!(
+                    self,
+                    other,
+                    |a, b| <Lhs as NearlyOrdTol<Rhs>>::nearly_ge_tol(a, b, tol),
+                    (_, None)
+                )
+            }
+        }
+
+        impl<Lhs, Rhs> NearlyOrd<$rhs, Lhs, Rhs> for $lhs where
+            Lhs: NearlyOrd<Rhs> + EpsTolerance<Rhs> + UlpsTolerance<Rhs> + RelTolerance<Rhs>
+        {
+        }
+    };
+}
+
+impl_option!(Option<Lhs>, Option<Rhs>);
+impl_option!(Option<&Lhs>, Option<Rhs>);
+impl_option!(Option<Lhs>, Option<&Rhs>);
+impl_option!(Option<&Lhs>, Option<&Rhs>);
+
+impl<Lhs, Rhs> EpsTolerance<Option<Rhs>> for Option<Lhs>
+where
+    Lhs: EpsTolerance<Rhs>,
+{
+    type T = EpsToleranceType<Lhs, Rhs>;
+    const DEFAULT: Self::T = <Lhs as EpsTolerance<Rhs>>::DEFAULT;
+}
+
+impl<Lhs, Rhs> UlpsTolerance<Option<Rhs>> for Option<Lhs>
+where
+    Lhs: UlpsTolerance<Rhs>,
+{
+    type T = UlpsToleranceType<Lhs, Rhs>;
+    const DEFAULT: Self::T = <Lhs as UlpsTolerance<Rhs>>::DEFAULT;
+}
+
+impl<Lhs, Rhs> RelTolerance<Option<Rhs>> for Option<Lhs>
+where
+    Lhs: RelTolerance<Rhs>,
+{
+    type T = RelToleranceType<Lhs, Rhs>;
+    const DEFAULT: Self::T = <Lhs as RelTolerance<Rhs>>::DEFAULT;
+}
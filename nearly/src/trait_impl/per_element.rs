@@ -0,0 +1,447 @@
+use crate::nearly_eq::{NearlyEq, NearlyEqEps, NearlyEqRel, NearlyEqTol, NearlyEqUlps};
+use crate::nearly_ord::{NearlyOrd, NearlyOrdEps, NearlyOrdRel, NearlyOrdTol, NearlyOrdUlps};
+use crate::per_element::PerElement;
+use crate::tolerance::{
+    EpsTolerance, EpsToleranceType, RelTolerance, RelToleranceType, Tolerance, UlpsTolerance,
+    UlpsToleranceType,
+};
+
+#[cfg(feature = "std")]
+use crate::report::{NearlyEqReport, Report, ReportTolerance};
+
+macro_rules! impl_per_element {
+    ($lhs:ident, $rhs:ident, $lhs_tol:ident, $rhs_tol:ident, $idx: tt) => {
+        impl_per_element!(@impl $lhs, $rhs, $lhs_tol, $rhs_tol, $idx);
+    };
+    (
+        $lhs:ident $( $lhs_tail:ident )+,
+        $rhs:ident $( $rhs_tail:ident )+,
+        $lhs_tol:ident $( $lhs_tol_tail:ident )+,
+        $rhs_tol:ident $( $rhs_tol_tail:ident )+,
+        $idx:tt $( $idx_tail:tt )+
+    ) => {
+        impl_per_element!(
+            $( $lhs_tail )+,
+            $( $rhs_tail )+,
+            $( $lhs_tol_tail )+,
+            $( $rhs_tol_tail )+,
+            $( $idx_tail )+
+        );
+        impl_per_element!(
+            @impl
+            $lhs $( $lhs_tail )+,
+            $rhs $( $rhs_tail )+,
+            $lhs_tol $( $lhs_tol_tail )+,
+            $rhs_tol $( $rhs_tol_tail )+,
+            $idx $( $idx_tail )+
+        );
+    };
+    (
+        @impl
+        $( $lhs: ident )+,
+        $( $rhs: ident )+,
+        $( $lhs_tol: ident )+,
+        $( $rhs_tol: ident )+,
+        $( $idx: tt )+
+    ) => {
+        ///////////////
+        // tolerance //
+        ///////////////
+
+        impl<$($lhs, $rhs),+> EpsTolerance<PerElement<($($rhs,)+)>> for PerElement<($($lhs,)+)>
+        where
+            $($lhs: EpsTolerance<$rhs>),+
+        {
+            type T = ($(EpsToleranceType<$lhs, $rhs>,)+);
+            const DEFAULT: Self::T = ($(<$lhs as EpsTolerance<$rhs>>::DEFAULT,)+);
+        }
+
+        impl<$($lhs, $rhs),+> UlpsTolerance<PerElement<($($rhs,)+)>> for PerElement<($($lhs,)+)>
+        where
+            $($lhs: UlpsTolerance<$rhs>),+
+        {
+            type T = ($(UlpsToleranceType<$lhs, $rhs>,)+);
+            const DEFAULT: Self::T = ($(<$lhs as UlpsTolerance<$rhs>>::DEFAULT,)+);
+        }
+
+        impl<$($lhs, $rhs),+> RelTolerance<PerElement<($($rhs,)+)>> for PerElement<($($lhs,)+)>
+        where
+            $($lhs: RelTolerance<$rhs>),+
+        {
+            type T = ($(RelToleranceType<$lhs, $rhs>,)+);
+            const DEFAULT: Self::T = ($(<$lhs as RelTolerance<$rhs>>::DEFAULT,)+);
+        }
+
+        ///////////////
+        // nearly_eq //
+        ///////////////
+
+        impl<$($lhs, $rhs, $lhs_tol, $rhs_tol),+>
+            NearlyEqEps<PerElement<($($rhs,)+)>, PerElement<($($lhs_tol,)+)>, PerElement<($($rhs_tol,)+)>> for PerElement<($($lhs,)+)>
+        where
+            $(
+                $lhs: NearlyEqEps<$rhs, $lhs_tol, $rhs_tol>,
+                $lhs_tol: EpsTolerance<$rhs_tol>,
+            )+
+        {
+            fn nearly_eq_eps(
+                &self,
+                other: &PerElement<($($rhs,)+)>,
+                eps: &EpsToleranceType<PerElement<($($lhs_tol,)+)>, PerElement<($($rhs_tol,)+)>>,
+            ) -> bool {
+                $( self.0.$idx.nearly_eq_eps(&other.0.$idx, &eps.$idx) )&&+
+            }
+        }
+
+        impl<$($lhs, $rhs, $lhs_tol, $rhs_tol),+>
+            NearlyEqUlps<PerElement<($($rhs,)+)>, PerElement<($($lhs_tol,)+)>, PerElement<($($rhs_tol,)+)>> for PerElement<($($lhs,)+)>
+        where
+            $(
+                $lhs: NearlyEqUlps<$rhs, $lhs_tol, $rhs_tol>,
+                $lhs_tol: UlpsTolerance<$rhs_tol>,
+            )+
+        {
+            fn nearly_eq_ulps(
+                &self,
+                other: &PerElement<($($rhs,)+)>,
+                ulps: &UlpsToleranceType<PerElement<($($lhs_tol,)+)>, PerElement<($($rhs_tol,)+)>>,
+            ) -> bool {
+                $( self.0.$idx.nearly_eq_ulps(&other.0.$idx, &ulps.$idx) )&&+
+            }
+        }
+
+        impl<$($lhs, $rhs, $lhs_tol, $rhs_tol),+>
+            NearlyEqRel<PerElement<($($rhs,)+)>, PerElement<($($lhs_tol,)+)>, PerElement<($($rhs_tol,)+)>> for PerElement<($($lhs,)+)>
+        where
+            $(
+                $lhs: NearlyEqRel<$rhs, $lhs_tol, $rhs_tol>,
+                $lhs_tol: RelTolerance<$rhs_tol>,
+            )+
+        {
+            fn nearly_eq_rel(
+                &self,
+                other: &PerElement<($($rhs,)+)>,
+                rel: &RelToleranceType<PerElement<($($lhs_tol,)+)>, PerElement<($($rhs_tol,)+)>>,
+            ) -> bool {
+                $( self.0.$idx.nearly_eq_rel(&other.0.$idx, &rel.$idx) )&&+
+            }
+        }
+
+        impl<$($lhs, $rhs, $lhs_tol, $rhs_tol),+>
+            NearlyEqTol<PerElement<($($rhs,)+)>, PerElement<($($lhs_tol,)+)>, PerElement<($($rhs_tol,)+)>> for PerElement<($($lhs,)+)>
+        where
+            $(
+                $lhs: NearlyEqTol<$rhs, $lhs_tol, $rhs_tol>,
+                $lhs_tol: EpsTolerance<$rhs_tol> + UlpsTolerance<$rhs_tol> + RelTolerance<$rhs_tol>,
+            )+
+        {
+            fn nearly_eq_tol(
+                &self,
+                other: &PerElement<($($rhs,)+)>,
+                tol: &Tolerance<PerElement<($($lhs_tol,)+)>, PerElement<($($rhs_tol,)+)>>,
+            ) -> bool {
+                $(
+                    self.0.$idx.nearly_eq_tol(
+                        &other.0.$idx,
+                        &(
+                            tol.eps.$idx,
+                            tol.ulps.$idx,
+                            tol.rel.map(|rel| rel.$idx),
+                            tol.mode,
+                            tol.ulps_across_zero,
+                        ).into()
+                    )
+                )&&+
+            }
+        }
+
+        impl<$($lhs, $rhs, $lhs_tol, $rhs_tol),+>
+            NearlyEq<PerElement<($($rhs,)+)>, PerElement<($($lhs_tol,)+)>, PerElement<($($rhs_tol,)+)>> for PerElement<($($lhs,)+)>
+        where
+            $(
+                $lhs: NearlyEq<$rhs, $lhs_tol, $rhs_tol>,
+                $lhs_tol: EpsTolerance<$rhs_tol> + UlpsTolerance<$rhs_tol> + RelTolerance<$rhs_tol>,
+            )+
+        {
+        }
+
+        #[cfg(feature = "std")]
+        impl<$($lhs, $rhs, $lhs_tol, $rhs_tol),+>
+            NearlyEqReport<PerElement<($($rhs,)+)>, PerElement<($($lhs_tol,)+)>, PerElement<($($rhs_tol,)+)>> for PerElement<($($lhs,)+)>
+        where
+            $(
+                $lhs: NearlyEqReport<$rhs, $lhs_tol, $rhs_tol>,
+                $lhs_tol: EpsTolerance<$rhs_tol> + UlpsTolerance<$rhs_tol> + RelTolerance<$rhs_tol>,
+            )+
+        {
+            fn report_nearly_eq(
+                &self,
+                other: &PerElement<($($rhs,)+)>,
+                tol: &ReportTolerance<PerElement<($($lhs_tol,)+)>, PerElement<($($rhs_tol,)+)>>,
+                report: &mut Report,
+            ) {
+                // the elements are listed in reverse order, report them in ascending order
+                let elem_reports: &[&dyn Fn(&mut Report)] = &[$(
+                    &|report: &mut Report| {
+                        let elem_tol = match tol {
+                            ReportTolerance::Eps(eps) => ReportTolerance::Eps(eps.$idx),
+                            ReportTolerance::Ulps(ulps) => ReportTolerance::Ulps(ulps.$idx),
+                            ReportTolerance::Rel(rel) => ReportTolerance::Rel(rel.$idx),
+                            ReportTolerance::Tol(tol) => ReportTolerance::Tol(
+                                (
+                                    tol.eps.$idx,
+                                    tol.ulps.$idx,
+                                    tol.rel.map(|rel| rel.$idx),
+                                    tol.mode,
+                                    tol.ulps_across_zero,
+                                ).into()
+                            ),
+                            ReportTolerance::EpsAndRel(eps, rel) => {
+                                ReportTolerance::EpsAndRel(eps.$idx, rel.$idx)
+                            }
+                            ReportTolerance::UlpsAndRel(ulps, rel) => {
+                                ReportTolerance::UlpsAndRel(ulps.$idx, rel.$idx)
+                            }
+                        };
+                        report.field(stringify!($idx), |report| {
+                            self.0.$idx.report_nearly_eq(&other.0.$idx, &elem_tol, report)
+                        });
+                    },
+                )+];
+
+                for elem_report in elem_reports.iter().rev() {
+                    elem_report(report);
+                }
+            }
+        }
+
+        ////////////////
+        // nearly_ord //
+        ////////////////
+
+        impl<$($lhs, $rhs, $lhs_tol, $rhs_tol),+>
+            NearlyOrdEps<PerElement<($($rhs,)+)>, PerElement<($($lhs_tol,)+)>, PerElement<($($rhs_tol,)+)>> for PerElement<($($lhs,)+)>
+        where
+            $(
+                $lhs: NearlyOrdEps<$rhs, $lhs_tol, $rhs_tol>,
+                $lhs_tol: EpsTolerance<$rhs_tol>,
+            )+
+        {
+            fn nearly_lt_eps(
+                &self,
+                other: &PerElement<($($rhs,)+)>,
+                eps: &EpsToleranceType<PerElement<($($lhs_tol,)+)>, PerElement<($($rhs_tol,)+)>>,
+            ) -> bool {
+                $( self.0.$idx.nearly_lt_eps(&other.0.$idx, &eps.$idx) )&&+
+            }
+
+            fn nearly_le_eps(
+                &self,
+                other: &PerElement<($($rhs,)+)>,
+                eps: &EpsToleranceType<PerElement<($($lhs_tol,)+)>, PerElement<($($rhs_tol,)+)>>,
+            ) -> bool {
+                $( self.0.$idx.nearly_le_eps(&other.0.$idx, &eps.$idx) )&&+
+            }
+
+            fn nearly_gt_eps(
+                &self,
+                other: &PerElement<($($rhs,)+)>,
+                eps: &EpsToleranceType<PerElement<($($lhs_tol,)+)>, PerElement<($($rhs_tol,)+)>>,
+            ) -> bool {
+                $( self.0.$idx.nearly_gt_eps(&other.0.$idx, &eps.$idx) )&&+
+            }
+
+            fn nearly_ge_eps(
+                &self,
+                other: &PerElement<($($rhs,)+)>,
+                eps: &EpsToleranceType<PerElement<($($lhs_tol,)+)>, PerElement<($($rhs_tol,)+)>>,
+            ) -> bool {
+                $( self.0.$idx.nearly_ge_eps(&other.0.$idx, &eps.$idx) )&&+
+            }
+        }
+
+        impl<$($lhs, $rhs, $lhs_tol, $rhs_tol),+>
+            NearlyOrdUlps<PerElement<($($rhs,)+)>, PerElement<($($lhs_tol,)+)>, PerElement<($($rhs_tol,)+)>> for PerElement<($($lhs,)+)>
+        where
+            $(
+                $lhs: NearlyOrdUlps<$rhs, $lhs_tol, $rhs_tol>,
+                $lhs_tol: UlpsTolerance<$rhs_tol>,
+            )+
+        {
+            fn nearly_lt_ulps(
+                &self,
+                other: &PerElement<($($rhs,)+)>,
+                ulps: &UlpsToleranceType<PerElement<($($lhs_tol,)+)>, PerElement<($($rhs_tol,)+)>>,
+            ) -> bool {
+                $( self.0.$idx.nearly_lt_ulps(&other.0.$idx, &ulps.$idx) )&&+
+            }
+
+            fn nearly_le_ulps(
+                &self,
+                other: &PerElement<($($rhs,)+)>,
+                ulps: &UlpsToleranceType<PerElement<($($lhs_tol,)+)>, PerElement<($($rhs_tol,)+)>>,
+            ) -> bool {
+                $( self.0.$idx.nearly_le_ulps(&other.0.$idx, &ulps.$idx) )&&+
+            }
+
+            fn nearly_gt_ulps(
+                &self,
+                other: &PerElement<($($rhs,)+)>,
+                ulps: &UlpsToleranceType<PerElement<($($lhs_tol,)+)>, PerElement<($($rhs_tol,)+)>>,
+            ) -> bool {
+                $( self.0.$idx.nearly_gt_ulps(&other.0.$idx, &ulps.$idx) )&&+
+            }
+
+            fn nearly_ge_ulps(
+                &self,
+                other: &PerElement<($($rhs,)+)>,
+                ulps: &UlpsToleranceType<PerElement<($($lhs_tol,)+)>, PerElement<($($rhs_tol,)+)>>,
+            ) -> bool {
+                $( self.0.$idx.nearly_ge_ulps(&other.0.$idx, &ulps.$idx) )&&+
+            }
+        }
+
+        impl<$($lhs, $rhs, $lhs_tol, $rhs_tol),+>
+            NearlyOrdRel<PerElement<($($rhs,)+)>, PerElement<($($lhs_tol,)+)>, PerElement<($($rhs_tol,)+)>> for PerElement<($($lhs,)+)>
+        where
+            $(
+                $lhs: NearlyOrdRel<$rhs, $lhs_tol, $rhs_tol>,
+                $lhs_tol: RelTolerance<$rhs_tol>,
+            )+
+        {
+            fn nearly_lt_rel(
+                &self,
+                other: &PerElement<($($rhs,)+)>,
+                rel: &RelToleranceType<PerElement<($($lhs_tol,)+)>, PerElement<($($rhs_tol,)+)>>,
+            ) -> bool {
+                $( self.0.$idx.nearly_lt_rel(&other.0.$idx, &rel.$idx) )&&+
+            }
+
+            fn nearly_le_rel(
+                &self,
+                other: &PerElement<($($rhs,)+)>,
+                rel: &RelToleranceType<PerElement<($($lhs_tol,)+)>, PerElement<($($rhs_tol,)+)>>,
+            ) -> bool {
+                $( self.0.$idx.nearly_le_rel(&other.0.$idx, &rel.$idx) )&&+
+            }
+
+            fn nearly_gt_rel(
+                &self,
+                other: &PerElement<($($rhs,)+)>,
+                rel: &RelToleranceType<PerElement<($($lhs_tol,)+)>, PerElement<($($rhs_tol,)+)>>,
+            ) -> bool {
+                $( self.0.$idx.nearly_gt_rel(&other.0.$idx, &rel.$idx) )&&+
+            }
+
+            fn nearly_ge_rel(
+                &self,
+                other: &PerElement<($($rhs,)+)>,
+                rel: &RelToleranceType<PerElement<($($lhs_tol,)+)>, PerElement<($($rhs_tol,)+)>>,
+            ) -> bool {
+                $( self.0.$idx.nearly_ge_rel(&other.0.$idx, &rel.$idx) )&&+
+            }
+        }
+
+        impl<$($lhs, $rhs, $lhs_tol, $rhs_tol),+>
+            NearlyOrdTol<PerElement<($($rhs,)+)>, PerElement<($($lhs_tol,)+)>, PerElement<($($rhs_tol,)+)>> for PerElement<($($lhs,)+)>
+        where
+            $(
+                $lhs: NearlyOrdTol<$rhs, $lhs_tol, $rhs_tol>,
+                $lhs_tol: EpsTolerance<$rhs_tol> + UlpsTolerance<$rhs_tol> + RelTolerance<$rhs_tol>,
+            )+
+        {
+            fn nearly_lt_tol(
+                &self,
+                other: &PerElement<($($rhs,)+)>,
+                tol: &Tolerance<PerElement<($($lhs_tol,)+)>, PerElement<($($rhs_tol,)+)>>,
+            ) -> bool {
+                $(
+                    self.0.$idx.nearly_lt_tol(
+                        &other.0.$idx,
+                        &(
+                            tol.eps.$idx,
+                            tol.ulps.$idx,
+                            tol.rel.map(|rel| rel.$idx),
+                            tol.mode,
+                            tol.ulps_across_zero,
+                        ).into()
+                    )
+                )&&+
+            }
+
+            fn nearly_le_tol(
+                &self,
+                other: &PerElement<($($rhs,)+)>,
+                tol: &Tolerance<PerElement<($($lhs_tol,)+)>, PerElement<($($rhs_tol,)+)>>,
+            ) -> bool {
+                $(
+                    self.0.$idx.nearly_le_tol(
+                        &other.0.$idx,
+                        &(
+                            tol.eps.$idx,
+                            tol.ulps.$idx,
+                            tol.rel.map(|rel| rel.$idx),
+                            tol.mode,
+                            tol.ulps_across_zero,
+                        ).into()
+                    )
+                )&&+
+            }
+
+            fn nearly_gt_tol(
+                &self,
+                other: &PerElement<($($rhs,)+)>,
+                tol: &Tolerance<PerElement<($($lhs_tol,)+)>, PerElement<($($rhs_tol,)+)>>,
+            ) -> bool {
+                $(
+                    self.0.$idx.nearly_gt_tol(
+                        &other.0.$idx,
+                        &(
+                            tol.eps.$idx,
+                            tol.ulps.$idx,
+                            tol.rel.map(|rel| rel.$idx),
+                            tol.mode,
+                            tol.ulps_across_zero,
+                        ).into()
+                    )
+                )&&+
+            }
+
+            fn nearly_ge_tol(
+                &self,
+                other: &PerElement<($($rhs,)+)>,
+                tol: &Tolerance<PerElement<($($lhs_tol,)+)>, PerElement<($($rhs_tol,)+)>>,
+            ) -> bool {
+                $(
+                    self.0.$idx.nearly_ge_tol(
+                        &other.0.$idx,
+                        &(
+                            tol.eps.$idx,
+                            tol.ulps.$idx,
+                            tol.rel.map(|rel| rel.$idx),
+                            tol.mode,
+                            tol.ulps_across_zero,
+                        ).into()
+                    )
+                )&&+
+            }
+        }
+
+        impl<$($lhs, $rhs, $lhs_tol, $rhs_tol),+>
+            NearlyOrd<PerElement<($($rhs,)+)>, PerElement<($($lhs_tol,)+)>, PerElement<($($rhs_tol,)+)>> for PerElement<($($lhs,)+)>
+        where
+            $(
+                $lhs: NearlyOrd<$rhs, $lhs_tol, $rhs_tol>,
+                $lhs_tol: EpsTolerance<$rhs_tol> + UlpsTolerance<$rhs_tol> + RelTolerance<$rhs_tol>,
+            )+
+        {
+        }
+    }
+}
+
+impl_per_element!(
+    L11 L10 L9 L8 L7 L6 L5 L4 L3 L2 L1 L0,
+    R11 R10 R9 R8 R7 R6 R5 R4 R3 R2 R1 R0,
+    LT11 LT10 LT9 LT8 LT7 LT6 LT5 LT4 LT3 LT2 LT1 LT0,
+    RT11 RT10 RT9 RT8 RT7 RT6 RT5 RT4 RT3 RT2 RT1 RT0,
+    11 10 9 8 7 6 5 4 3 2 1 0);
@@ -1,9 +1,10 @@
 #![cfg(feature = "std")]
 
-use crate::nearly_eq::{NearlyEq, NearlyEqEps, NearlyEqTol, NearlyEqUlps};
-use crate::nearly_ord::{NearlyOrd, NearlyOrdEps, NearlyOrdTol, NearlyOrdUlps};
+use crate::nearly_eq::{NearlyEq, NearlyEqEps, NearlyEqRel, NearlyEqTol, NearlyEqUlps};
+use crate::nearly_ord::{NearlyOrd, NearlyOrdEps, NearlyOrdRel, NearlyOrdTol, NearlyOrdUlps};
 use crate::tolerance::{
-    EpsTolerance, EpsToleranceType, Tolerance, UlpsTolerance, UlpsToleranceType,
+    EpsTolerance, EpsToleranceType, RelTolerance, RelToleranceType, Tolerance, UlpsTolerance,
+    UlpsToleranceType,
 };
 
 use std::ops::Deref;
@@ -41,9 +42,26 @@ where
     }
 }
 
+impl<Lhs: Deref, Rhs: Deref> NearlyEqRel<Pin<Rhs>, Lhs::Target, Rhs::Target> for Pin<Lhs>
+where
+    Lhs::Target: NearlyEqRel<Rhs::Target> + RelTolerance<Rhs::Target>,
+{
+    #[inline]
+    fn nearly_eq_rel(
+        &self,
+        other: &Pin<Rhs>,
+        rel: &RelToleranceType<Lhs::Target, Rhs::Target>,
+    ) -> bool {
+        Lhs::Target::nearly_eq_rel(self, other, rel)
+    }
+}
+
 impl<Lhs: Deref, Rhs: Deref> NearlyEqTol<Pin<Rhs>, Lhs::Target, Rhs::Target> for Pin<Lhs>
 where
-    Lhs::Target: NearlyEqTol<Rhs::Target> + EpsTolerance<Rhs::Target> + UlpsTolerance<Rhs::Target>,
+    Lhs::Target: NearlyEqTol<Rhs::Target>
+        + EpsTolerance<Rhs::Target>
+        + UlpsTolerance<Rhs::Target>
+        + RelTolerance<Rhs::Target>,
 {
     #[inline]
     fn nearly_eq_tol(&self, other: &Pin<Rhs>, tol: &Tolerance<Lhs::Target, Rhs::Target>) -> bool {
@@ -52,7 +70,10 @@ where
 }
 
 impl<Lhs: Deref, Rhs: Deref> NearlyEq<Pin<Rhs>, Lhs::Target, Rhs::Target> for Pin<Lhs> where
-    Lhs::Target: NearlyEq<Rhs::Target> + EpsTolerance<Rhs::Target> + UlpsTolerance<Rhs::Target>
+    Lhs::Target: NearlyEq<Rhs::Target>
+        + EpsTolerance<Rhs::Target>
+        + UlpsTolerance<Rhs::Target>
+        + RelTolerance<Rhs::Target>
 {
 }
 
@@ -142,9 +163,53 @@ where
     }
 }
 
+impl<Lhs: Deref, Rhs: Deref> NearlyOrdRel<Pin<Rhs>, Lhs::Target, Rhs::Target> for Pin<Lhs>
+where
+    Lhs::Target: NearlyOrdRel<Rhs::Target> + RelTolerance<Rhs::Target>,
+{
+    #[inline]
+    fn nearly_lt_rel(
+        &self,
+        other: &Pin<Rhs>,
+        rel: &RelToleranceType<Lhs::Target, Rhs::Target>,
+    ) -> bool {
+        Lhs::Target::nearly_lt_rel(self, other, rel)
+    }
+
+    #[inline]
+    fn nearly_le_rel(
+        &self,
+        other: &Pin<Rhs>,
+        rel: &RelToleranceType<Lhs::Target, Rhs::Target>,
+    ) -> bool {
+        Lhs::Target::nearly_le_rel(self, other, rel)
+    }
+
+    #[inline]
+    fn nearly_gt_rel(
+        &self,
+        other: &Pin<Rhs>,
+        rel: &RelToleranceType<Lhs::Target, Rhs::Target>,
+    ) -> bool {
+        Lhs::Target::nearly_gt_rel(self, other, rel)
+    }
+
+    #[inline]
+    fn nearly_ge_rel(
+        &self,
+        other: &Pin<Rhs>,
+        rel: &RelToleranceType<Lhs::Target, Rhs::Target>,
+    ) -> bool {
+        Lhs::Target::nearly_ge_rel(self, other, rel)
+    }
+}
+
 impl<Lhs: Deref, Rhs: Deref> NearlyOrdTol<Pin<Rhs>, Lhs::Target, Rhs::Target> for Pin<Lhs>
 where
-    Lhs::Target: NearlyOrdTol<Rhs::Target> + EpsTolerance<Rhs::Target> + UlpsTolerance<Rhs::Target>,
+    Lhs::Target: NearlyOrdTol<Rhs::Target>
+        + EpsTolerance<Rhs::Target>
+        + UlpsTolerance<Rhs::Target>
+        + RelTolerance<Rhs::Target>,
 {
     #[inline]
     fn nearly_lt_tol(&self, other: &Pin<Rhs>, tol: &Tolerance<Lhs::Target, Rhs::Target>) -> bool {
@@ -168,6 +233,9 @@ where
 }
 
 impl<Lhs: Deref, Rhs: Deref> NearlyOrd<Pin<Rhs>, Lhs::Target, Rhs::Target> for Pin<Lhs> where
-    Lhs::Target: NearlyOrd<Rhs::Target> + EpsTolerance<Rhs::Target> + UlpsTolerance<Rhs::Target>
+    Lhs::Target: NearlyOrd<Rhs::Target>
+        + EpsTolerance<Rhs::Target>
+        + UlpsTolerance<Rhs::Target>
+        + RelTolerance<Rhs::Target>
 {
 }
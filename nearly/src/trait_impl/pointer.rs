@@ -1,14 +1,15 @@
 #![cfg(feature = "std")]
 
-use crate::nearly_eq::{NearlyEq, NearlyEqEps, NearlyEqTol, NearlyEqUlps};
-use crate::nearly_ord::{NearlyOrd, NearlyOrdEps, NearlyOrdTol, NearlyOrdUlps};
+use crate::nearly_eq::{NearlyEq, NearlyEqEps, NearlyEqRel, NearlyEqTol, NearlyEqUlps};
+use crate::nearly_ord::{NearlyOrd, NearlyOrdEps, NearlyOrdRel, NearlyOrdTol, NearlyOrdUlps};
 use crate::tolerance::{
-    EpsTolerance, EpsToleranceType, Tolerance, UlpsTolerance, UlpsToleranceType,
+    EpsTolerance, EpsToleranceType, RelTolerance, RelToleranceType, Tolerance, UlpsTolerance,
+    UlpsToleranceType,
 };
 
 use std::boxed::Box;
-use std::rc::Rc;
-use std::sync::Arc;
+use std::rc::{self, Rc};
+use std::sync::{self, Arc};
 
 macro_rules! impl_pointer {
     ($lhs: ty, $rhs: ty) => {
@@ -36,9 +37,19 @@ macro_rules! impl_pointer {
             }
         }
 
+        impl<Lhs, Rhs> NearlyEqRel<$rhs, Lhs, Rhs> for $lhs
+        where
+            Lhs: NearlyEqRel<Rhs> + RelTolerance<Rhs>,
+        {
+            #[inline]
+            fn nearly_eq_rel(&self, other: &$rhs, rel: &RelToleranceType<Lhs, Rhs>) -> bool {
+                NearlyEqRel::nearly_eq_rel(&**self, &**other, rel)
+            }
+        }
+
         impl<Lhs, Rhs> NearlyEqTol<$rhs, Lhs, Rhs> for $lhs
         where
-            Lhs: NearlyEqTol<Rhs> + EpsTolerance<Rhs> + UlpsTolerance<Rhs>,
+            Lhs: NearlyEqTol<Rhs> + EpsTolerance<Rhs> + UlpsTolerance<Rhs> + RelTolerance<Rhs>,
         {
             #[inline]
             fn nearly_eq_tol(&self, other: &$rhs, tol: &Tolerance<Lhs, Rhs>) -> bool {
@@ -47,7 +58,7 @@ macro_rules! impl_pointer {
         }
 
         impl<Lhs, Rhs> NearlyEq<$rhs, Lhs, Rhs> for $lhs where
-            Lhs: NearlyEq<Rhs> + EpsTolerance<Rhs> + UlpsTolerance<Rhs>
+            Lhs: NearlyEq<Rhs> + EpsTolerance<Rhs> + UlpsTolerance<Rhs> + RelTolerance<Rhs>
         {
         }
 
@@ -105,9 +116,34 @@ macro_rules! impl_pointer {
             }
         }
 
+        impl<Lhs, Rhs> NearlyOrdRel<$rhs, Lhs, Rhs> for $lhs
+        where
+            Lhs: NearlyOrdRel<Rhs> + RelTolerance<Rhs>,
+        {
+            #[inline]
+            fn nearly_lt_rel(&self, other: &$rhs, rel: &RelToleranceType<Lhs, Rhs>) -> bool {
+                NearlyOrdRel::nearly_lt_rel(&**self, &**other, rel)
+            }
+
+            #[inline]
+            fn nearly_le_rel(&self, other: &$rhs, rel: &RelToleranceType<Lhs, Rhs>) -> bool {
+                NearlyOrdRel::nearly_le_rel(&**self, &**other, rel)
+            }
+
+            #[inline]
+            fn nearly_gt_rel(&self, other: &$rhs, rel: &RelToleranceType<Lhs, Rhs>) -> bool {
+                NearlyOrdRel::nearly_gt_rel(&**self, &**other, rel)
+            }
+
+            #[inline]
+            fn nearly_ge_rel(&self, other: &$rhs, rel: &RelToleranceType<Lhs, Rhs>) -> bool {
+                NearlyOrdRel::nearly_ge_rel(&**self, &**other, rel)
+            }
+        }
+
         impl<Lhs, Rhs> NearlyOrdTol<$rhs, Lhs, Rhs> for $lhs
         where
-            Lhs: NearlyOrdTol<Rhs> + EpsTolerance<Rhs> + UlpsTolerance<Rhs>,
+            Lhs: NearlyOrdTol<Rhs> + EpsTolerance<Rhs> + UlpsTolerance<Rhs> + RelTolerance<Rhs>,
         {
             #[inline]
             fn nearly_lt_tol(&self, other: &$rhs, tol: &Tolerance<Lhs, Rhs>) -> bool {
@@ -131,7 +167,7 @@ macro_rules! impl_pointer {
         }
 
         impl<Lhs, Rhs> NearlyOrd<$rhs, Lhs, Rhs> for $lhs where
-            Lhs: NearlyOrd<Rhs> + EpsTolerance<Rhs> + UlpsTolerance<Rhs>
+            Lhs: NearlyOrd<Rhs> + EpsTolerance<Rhs> + UlpsTolerance<Rhs> + RelTolerance<Rhs>
         {
         }
     };
@@ -140,3 +176,272 @@ macro_rules! impl_pointer {
 impl_pointer!(Arc<Lhs>, Arc<Rhs>);
 impl_pointer!(Box<Lhs>, Box<Rhs>);
 impl_pointer!(Rc<Lhs>, Rc<Rhs>);
+
+/// Implements the nearly traits for weak pointers by upgrading them.
+///
+/// The upgraded pointers are compared like options, i.e. two dangling pointers are equal and a
+/// dangling pointer is less than a pointer to a value.
+macro_rules! impl_weak {
+    ($lhs: ty, $rhs: ty) => {
+        ///////////////
+        // nearly_eq //
+        ///////////////
+
+        impl<Lhs, Rhs> NearlyEqEps<$rhs, Lhs, Rhs> for $lhs
+        where
+            Lhs: NearlyEqEps<Rhs> + EpsTolerance<Rhs>,
+        {
+            #[inline]
+            fn nearly_eq_eps(&self, other: &$rhs, eps: &EpsToleranceType<Lhs, Rhs>) -> bool {
+                let (lhs, rhs) = (self.upgrade(), other.upgrade());
+                <Option<&Lhs> as NearlyEqEps<Option<&Rhs>, Lhs, Rhs>>::nearly_eq_eps(
+                    &lhs.as_deref(),
+                    &rhs.as_deref(),
+                    eps,
+                )
+            }
+        }
+
+        impl<Lhs, Rhs> NearlyEqUlps<$rhs, Lhs, Rhs> for $lhs
+        where
+            Lhs: NearlyEqUlps<Rhs> + UlpsTolerance<Rhs>,
+        {
+            #[inline]
+            fn nearly_eq_ulps(&self, other: &$rhs, ulps: &UlpsToleranceType<Lhs, Rhs>) -> bool {
+                let (lhs, rhs) = (self.upgrade(), other.upgrade());
+                <Option<&Lhs> as NearlyEqUlps<Option<&Rhs>, Lhs, Rhs>>::nearly_eq_ulps(
+                    &lhs.as_deref(),
+                    &rhs.as_deref(),
+                    ulps,
+                )
+            }
+        }
+
+        impl<Lhs, Rhs> NearlyEqRel<$rhs, Lhs, Rhs> for $lhs
+        where
+            Lhs: NearlyEqRel<Rhs> + RelTolerance<Rhs>,
+        {
+            #[inline]
+            fn nearly_eq_rel(&self, other: &$rhs, rel: &RelToleranceType<Lhs, Rhs>) -> bool {
+                let (lhs, rhs) = (self.upgrade(), other.upgrade());
+                <Option<&Lhs> as NearlyEqRel<Option<&Rhs>, Lhs, Rhs>>::nearly_eq_rel(
+                    &lhs.as_deref(),
+                    &rhs.as_deref(),
+                    rel,
+                )
+            }
+        }
+
+        impl<Lhs, Rhs> NearlyEqTol<$rhs, Lhs, Rhs> for $lhs
+        where
+            Lhs: NearlyEqTol<Rhs> + EpsTolerance<Rhs> + UlpsTolerance<Rhs> + RelTolerance<Rhs>,
+        {
+            #[inline]
+            fn nearly_eq_tol(&self, other: &$rhs, tol: &Tolerance<Lhs, Rhs>) -> bool {
+                let (lhs, rhs) = (self.upgrade(), other.upgrade());
+                <Option<&Lhs> as NearlyEqTol<Option<&Rhs>, Lhs, Rhs>>::nearly_eq_tol(
+                    &lhs.as_deref(),
+                    &rhs.as_deref(),
+                    tol,
+                )
+            }
+        }
+
+        impl<Lhs, Rhs> NearlyEq<$rhs, Lhs, Rhs> for $lhs where
+            Lhs: NearlyEq<Rhs> + EpsTolerance<Rhs> + UlpsTolerance<Rhs> + RelTolerance<Rhs>
+        {
+        }
+
+        ////////////////
+        // nearly_ord //
+        ////////////////
+
+        impl<Lhs, Rhs> NearlyOrdEps<$rhs, Lhs, Rhs> for $lhs
+        where
+            Lhs: NearlyOrdEps<Rhs> + EpsTolerance<Rhs>,
+        {
+            #[inline]
+            fn nearly_lt_eps(&self, other: &$rhs, eps: &EpsToleranceType<Lhs, Rhs>) -> bool {
+                let (lhs, rhs) = (self.upgrade(), other.upgrade());
+                <Option<&Lhs> as NearlyOrdEps<Option<&Rhs>, Lhs, Rhs>>::nearly_lt_eps(
+                    &lhs.as_deref(),
+                    &rhs.as_deref(),
+                    eps,
+                )
+            }
+
+            #[inline]
+            fn nearly_le_eps(&self, other: &$rhs, eps: &EpsToleranceType<Lhs, Rhs>) -> bool {
+                let (lhs, rhs) = (self.upgrade(), other.upgrade());
+                <Option<&Lhs> as NearlyOrdEps<Option<&Rhs>, Lhs, Rhs>>::nearly_le_eps(
+                    &lhs.as_deref(),
+                    &rhs.as_deref(),
+                    eps,
+                )
+            }
+
+            #[inline]
+            fn nearly_gt_eps(&self, other: &$rhs, eps: &EpsToleranceType<Lhs, Rhs>) -> bool {
+                let (lhs, rhs) = (self.upgrade(), other.upgrade());
+                <Option<&Lhs> as NearlyOrdEps<Option<&Rhs>, Lhs, Rhs>>::nearly_gt_eps(
+                    &lhs.as_deref(),
+                    &rhs.as_deref(),
+                    eps,
+                )
+            }
+
+            #[inline]
+            fn nearly_ge_eps(&self, other: &$rhs, eps: &EpsToleranceType<Lhs, Rhs>) -> bool {
+                let (lhs, rhs) = (self.upgrade(), other.upgrade());
+                <Option<&Lhs> as NearlyOrdEps<Option<&Rhs>, Lhs, Rhs>>::nearly_ge_eps(
+                    &lhs.as_deref(),
+                    &rhs.as_deref(),
+                    eps,
+                )
+            }
+        }
+
+        impl<Lhs, Rhs> NearlyOrdUlps<$rhs, Lhs, Rhs> for $lhs
+        where
+            Lhs: NearlyOrdUlps<Rhs> + UlpsTolerance<Rhs>,
+        {
+            #[inline]
+            fn nearly_lt_ulps(&self, other: &$rhs, ulps: &UlpsToleranceType<Lhs, Rhs>) -> bool {
+                let (lhs, rhs) = (self.upgrade(), other.upgrade());
+                <Option<&Lhs> as NearlyOrdUlps<Option<&Rhs>, Lhs, Rhs>>::nearly_lt_ulps(
+                    &lhs.as_deref(),
+                    &rhs.as_deref(),
+                    ulps,
+                )
+            }
+
+            #[inline]
+            fn nearly_le_ulps(&self, other: &$rhs, ulps: &UlpsToleranceType<Lhs, Rhs>) -> bool {
+                let (lhs, rhs) = (self.upgrade(), other.upgrade());
+                <Option<&Lhs> as NearlyOrdUlps<Option<&Rhs>, Lhs, Rhs>>::nearly_le_ulps(
+                    &lhs.as_deref(),
+                    &rhs.as_deref(),
+                    ulps,
+                )
+            }
+
+            #[inline]
+            fn nearly_gt_ulps(&self, other: &$rhs, ulps: &UlpsToleranceType<Lhs, Rhs>) -> bool {
+                let (lhs, rhs) = (self.upgrade(), other.upgrade());
+                <Option<&Lhs> as NearlyOrdUlps<Option<&Rhs>, Lhs, Rhs>>::nearly_gt_ulps(
+                    &lhs.as_deref(),
+                    &rhs.as_deref(),
+                    ulps,
+                )
+            }
+
+            #[inline]
+            fn nearly_ge_ulps(&self, other: &$rhs, ulps: &UlpsToleranceType<Lhs, Rhs>) -> bool {
+                let (lhs, rhs) = (self.upgrade(), other.upgrade());
+                <Option<&Lhs> as NearlyOrdUlps<Option<&Rhs>, Lhs, Rhs>>::nearly_ge_ulps(
+                    &lhs.as_deref(),
+                    &rhs.as_deref(),
+                    ulps,
+                )
+            }
+        }
+
+        impl<Lhs, Rhs> NearlyOrdRel<$rhs, Lhs, Rhs> for $lhs
+        where
+            Lhs: NearlyOrdRel<Rhs> + RelTolerance<Rhs>,
+        {
+            #[inline]
+            fn nearly_lt_rel(&self, other: &$rhs, rel: &RelToleranceType<Lhs, Rhs>) -> bool {
+                let (lhs, rhs) = (self.upgrade(), other.upgrade());
+                <Option<&Lhs> as NearlyOrdRel<Option<&Rhs>, Lhs, Rhs>>::nearly_lt_rel(
+                    &lhs.as_deref(),
+                    &rhs.as_deref(),
+                    rel,
+                )
+            }
+
+            #[inline]
+            fn nearly_le_rel(&self, other: &$rhs, rel: &RelToleranceType<Lhs, Rhs>) -> bool {
+                let (lhs, rhs) = (self.upgrade(), other.upgrade());
+                <Option<&Lhs> as NearlyOrdRel<Option<&Rhs>, Lhs, Rhs>>::nearly_le_rel(
+                    &lhs.as_deref(),
+                    &rhs.as_deref(),
+                    rel,
+                )
+            }
+
+            #[inline]
+            fn nearly_gt_rel(&self, other: &$rhs, rel: &RelToleranceType<Lhs, Rhs>) -> bool {
+                let (lhs, rhs) = (self.upgrade(), other.upgrade());
+                <Option<&Lhs> as NearlyOrdRel<Option<&Rhs>, Lhs, Rhs>>::nearly_gt_rel(
+                    &lhs.as_deref(),
+                    &rhs.as_deref(),
+                    rel,
+                )
+            }
+
+            #[inline]
+            fn nearly_ge_rel(&self, other: &$rhs, rel: &RelToleranceType<Lhs, Rhs>) -> bool {
+                let (lhs, rhs) = (self.upgrade(), other.upgrade());
+                <Option<&Lhs> as NearlyOrdRel<Option<&Rhs>, Lhs, Rhs>>::nearly_ge_rel(
+                    &lhs.as_deref(),
+                    &rhs.as_deref(),
+                    rel,
+                )
+            }
+        }
+
+        impl<Lhs, Rhs> NearlyOrdTol<$rhs, Lhs, Rhs> for $lhs
+        where
+            Lhs: NearlyOrdTol<Rhs> + EpsTolerance<Rhs> + UlpsTolerance<Rhs> + RelTolerance<Rhs>,
+        {
+            #[inline]
+            fn nearly_lt_tol(&self, other: &$rhs, tol: &Tolerance<Lhs, Rhs>) -> bool {
+                let (lhs, rhs) = (self.upgrade(), other.upgrade());
+                <Option<&Lhs> as NearlyOrdTol<Option<&Rhs>, Lhs, Rhs>>::nearly_lt_tol(
+                    &lhs.as_deref(),
+                    &rhs.as_deref(),
+                    tol,
+                )
+            }
+
+            #[inline]
+            fn nearly_le_tol(&self, other: &$rhs, tol: &Tolerance<Lhs, Rhs>) -> bool {
+                let (lhs, rhs) = (self.upgrade(), other.upgrade());
+                <Option<&Lhs> as NearlyOrdTol<Option<&Rhs>, Lhs, Rhs>>::nearly_le_tol(
+                    &lhs.as_deref(),
+                    &rhs.as_deref(),
+                    tol,
+                )
+            }
+
+            #[inline]
+            fn nearly_gt_tol(&self, other: &$rhs, tol: &Tolerance<Lhs, Rhs>) -> bool {
+                let (lhs, rhs) = (self.upgrade(), other.upgrade());
+                <Option<&Lhs> as NearlyOrdTol<Option<&Rhs>, Lhs, Rhs>>::nearly_gt_tol(
+                    &lhs.as_deref(),
+                    &rhs.as_deref(),
+                    tol,
+                )
+            }
+
+            #[inline]
+            fn nearly_ge_tol(&self, other: &$rhs, tol: &Tolerance<Lhs, Rhs>) -> bool {
+                let (lhs, rhs) = (self.upgrade(), other.upgrade());
+                <Option<&Lhs> as NearlyOrdTol<Option<&Rhs>, Lhs, Rhs>>::nearly_ge_tol(
+                    &lhs.as_deref(),
+                    &rhs.as_deref(),
+                    tol,
+                )
+            }
+        }
+
+        impl<Lhs, Rhs> NearlyOrd<$rhs, Lhs, Rhs> for $lhs where
+            Lhs: NearlyOrd<Rhs> + EpsTolerance<Rhs> + UlpsTolerance<Rhs> + RelTolerance<Rhs>
+        {
+        }
+    };
+}
+
+impl_weak!(rc::Weak<Lhs>, rc::Weak<Rhs>);
+impl_weak!(sync::Weak<Lhs>, sync::Weak<Rhs>);
@@ -1,8 +1,26 @@
-use crate::nearly_eq::{NearlyEq, NearlyEqEps, NearlyEqTol, NearlyEqUlps};
-use crate::nearly_ord::{NearlyOrd, NearlyOrdEps, NearlyOrdTol, NearlyOrdUlps};
-use crate::tolerance::{EpsToleranceType, UlpsToleranceType};
+use crate::nearly_eq::{
+    NearlyEq, NearlyEqEps, NearlyEqRel, NearlyEqTol, NearlyEqUlps, NearlyEqUlpsAcrossZero,
+};
+use crate::nearly_ord::{NearlyOrd, NearlyOrdEps, NearlyOrdRel, NearlyOrdTol, NearlyOrdUlps};
+use crate::tolerance::{
+    EpsToleranceType, RelToleranceType, Tolerance, ToleranceMode, UlpsToleranceType,
+};
 use crate::ulps::Ulps;
 
+macro_rules! abs {
+    ($float: ty, $value: expr) => {{
+        #[cfg(not(feature = "std"))]
+        // use custom abs in no_std by setting sign bit to 0
+        let abs = <$float>::from_bits(
+            ($value).to_bits() & !(1 << ((core::mem::size_of::<$float>() * 8) - 1)),
+        );
+        #[cfg(feature = "std")]
+        let abs = ($value).abs();
+
+        abs
+    }};
+}
+
 macro_rules! impl_float {
     ($float: ty) => {
         ///////////////
@@ -16,16 +34,7 @@ macro_rules! impl_float {
                     return true;
                 }
 
-                let diff = self - other;
-                #[cfg(not(feature = "std"))]
-                // use custom abs in no_std by setting sign bit to 0
-                let abs = <$float>::from_bits(
-                    diff.to_bits() & !(1 << ((core::mem::size_of::<$float>() * 8) - 1)),
-                );
-                #[cfg(feature = "std")]
-                let abs = diff.abs();
-
-                abs <= *eps
+                abs!($float, self - other) <= *eps
             }
         }
 
@@ -36,6 +45,8 @@ macro_rules! impl_float {
             /// This function will only work for inputs with the same sign.
             /// It will always return false if `self` and `other` have different signs.
             /// Therefore, do not use this function for comparison around zero.
+            /// Use [NearlyEqUlpsAcrossZero] or a [Tolerance] measuring the ulps distance across
+            /// zero instead.
             fn nearly_eq_ulps(&self, other: &Self, ulps: &UlpsToleranceType<$float>) -> bool {
                 // handles +0 == -0
                 if self == other {
@@ -54,7 +65,91 @@ macro_rules! impl_float {
             }
         }
 
-        impl NearlyEqTol for $float {}
+        impl NearlyEqUlpsAcrossZero for $float {
+            /// Returns true if the signed ulps distance between `self` and `other` measured across
+            /// zero is in range `[-ulps, ulps]`.
+            ///
+            /// Contrary to [NearlyEqUlps], this function also works for inputs with different
+            /// signs.
+            fn nearly_eq_ulps_across_zero(
+                &self,
+                other: &Self,
+                ulps: &UlpsToleranceType<$float>,
+            ) -> bool {
+                // handles +0 == -0
+                if self == other {
+                    return true;
+                }
+
+                if self.is_nan() || other.is_nan() {
+                    return false;
+                }
+
+                // a distance exceeding the integer range is larger than any ulps value
+                self.signed_ulps_distance_across_zero(*other)
+                    .map_or(false, |ulps_distance| {
+                        ulps_distance >= -*ulps && ulps_distance <= *ulps
+                    })
+            }
+        }
+
+        impl NearlyEqRel for $float {
+            /// Returns true if `|self - other|` is less than or equal to `rel` times the larger
+            /// magnitude of `self` and `other`.
+            ///
+            /// Infinite values are only nearly equal to infinite values with the same sign.
+            fn nearly_eq_rel(&self, other: &Self, rel: &RelToleranceType<Self>) -> bool {
+                // handles +0 == -0 and infinite values with the same sign
+                if self == other {
+                    return true;
+                }
+
+                if !self.is_finite() || !other.is_finite() {
+                    return false;
+                }
+
+                let abs_self = abs!($float, *self);
+                let abs_other = abs!($float, *other);
+                let largest = if abs_self > abs_other {
+                    abs_self
+                } else {
+                    abs_other
+                };
+
+                abs!($float, self - other) <= largest * *rel
+            }
+        }
+
+        impl NearlyEqTol for $float {
+            /// Returns true if `self` is nearly equal to `other` based on any of the components of
+            /// `tol`, or all of them if `tol.mode` is [ToleranceMode::All].
+            ///
+            /// If `tol.ulps_across_zero` is set, the ulps distance is measured across zero like
+            /// for [NearlyEqUlpsAcrossZero].
+            fn nearly_eq_tol(&self, other: &Self, tol: &Tolerance<Self>) -> bool {
+                let eq_ulps = || {
+                    if tol.ulps_across_zero {
+                        self.nearly_eq_ulps_across_zero(other, &tol.ulps)
+                    } else {
+                        self.nearly_eq_ulps(other, &tol.ulps)
+                    }
+                };
+
+                match tol.mode {
+                    ToleranceMode::Any => {
+                        self.nearly_eq_eps(other, &tol.eps)
+                            || eq_ulps()
+                            || tol.rel.map_or(false, |rel| self.nearly_eq_rel(other, &rel))
+                    }
+                    ToleranceMode::All => {
+                        self.nearly_eq_eps(other, &tol.eps)
+                            && eq_ulps()
+                            && tol.rel.map_or(true, |rel| self.nearly_eq_rel(other, &rel))
+                    }
+                }
+            }
+        }
+
         impl NearlyEq for $float {}
 
         ////////////////
@@ -93,10 +188,88 @@ macro_rules! impl_float {
             }
         }
 
-        impl NearlyOrdTol for $float {}
+        impl NearlyOrdRel for $float {
+            /// Returns true if `self < other` and `self` is not nearly equal to `other` based on
+            /// the relative epsilon value `rel`.
+            #[inline]
+            fn nearly_lt_rel(&self, other: &Self, rel: &RelToleranceType<Self>) -> bool {
+                self < other && self.nearly_ne_rel(other, rel)
+            }
+
+            /// Returns true if `self > other` and `self` is not nearly equal to `other` based on
+            /// the relative epsilon value `rel`.
+            #[inline]
+            fn nearly_gt_rel(&self, other: &Self, rel: &RelToleranceType<Self>) -> bool {
+                self > other && self.nearly_ne_rel(other, rel)
+            }
+        }
+
+        impl NearlyOrdTol for $float {
+            /// Returns true if `self < other` and `self` is not nearly equal to `other` based on
+            /// the tolerance `tol`.
+            #[inline]
+            fn nearly_lt_tol(&self, other: &Self, tol: &Tolerance<Self>) -> bool {
+                self < other && self.nearly_ne_tol(other, tol)
+            }
+
+            /// Returns true if `self < other` or `self` is nearly equal to `other` based on the
+            /// tolerance `tol`.
+            #[inline]
+            fn nearly_le_tol(&self, other: &Self, tol: &Tolerance<Self>) -> bool {
+                self < other || self.nearly_eq_tol(other, tol)
+            }
+
+            /// Returns true if `self > other` and `self` is not nearly equal to `other` based on
+            /// the tolerance `tol`.
+            #[inline]
+            fn nearly_gt_tol(&self, other: &Self, tol: &Tolerance<Self>) -> bool {
+                self > other && self.nearly_ne_tol(other, tol)
+            }
+
+            /// Returns true if `self > other` or `self` is nearly equal to `other` based on the
+            /// tolerance `tol`.
+            #[inline]
+            fn nearly_ge_tol(&self, other: &Self, tol: &Tolerance<Self>) -> bool {
+                self > other || self.nearly_eq_tol(other, tol)
+            }
+        }
+
         impl NearlyOrd for $float {}
     };
 }
 
 impl_float!(f32);
 impl_float!(f64);
+
+#[cfg(feature = "std")]
+mod std_report {
+    use crate::report::{FloatValue, MismatchKind, NearlyEqReport, Report, ReportTolerance};
+    use crate::ulps::Ulps;
+    use std::string::ToString;
+
+    macro_rules! impl_float_report {
+        ($float: ty, $variant: ident) => {
+            impl NearlyEqReport for $float {
+                /// Adds a mismatch with both values, their absolute difference and their ulps
+                /// distance if `self` is not nearly equal to `other`.
+                fn report_nearly_eq(
+                    &self,
+                    other: &Self,
+                    tol: &ReportTolerance<Self>,
+                    report: &mut Report,
+                ) {
+                    report.compare(tol.nearly_eq(self, other), || MismatchKind::Value {
+                        lhs: FloatValue::$variant(*self),
+                        rhs: FloatValue::$variant(*other),
+                        diff: FloatValue::$variant((self - other).abs()),
+                        ulps: self.ulps_distance(*other).map(u64::from),
+                        tolerance: tol.to_string(),
+                    });
+                }
+            }
+        };
+    }
+
+    impl_float_report!(f32, F32);
+    impl_float_report!(f64, F64);
+}
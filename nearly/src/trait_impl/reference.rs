@@ -1,7 +1,8 @@
-use crate::nearly_eq::{NearlyEq, NearlyEqEps, NearlyEqTol, NearlyEqUlps};
-use crate::nearly_ord::{NearlyOrd, NearlyOrdEps, NearlyOrdTol, NearlyOrdUlps};
+use crate::nearly_eq::{NearlyEq, NearlyEqEps, NearlyEqRel, NearlyEqTol, NearlyEqUlps};
+use crate::nearly_ord::{NearlyOrd, NearlyOrdEps, NearlyOrdRel, NearlyOrdTol, NearlyOrdUlps};
 use crate::tolerance::{
-    EpsTolerance, EpsToleranceType, Tolerance, UlpsTolerance, UlpsToleranceType,
+    EpsTolerance, EpsToleranceType, RelTolerance, RelToleranceType, Tolerance, UlpsTolerance,
+    UlpsToleranceType,
 };
 
 macro_rules! impl_ref {
@@ -30,9 +31,19 @@ macro_rules! impl_ref {
             }
         }
 
+        impl<Lhs: ?Sized, Rhs: ?Sized> NearlyEqRel<$rhs, Lhs, Rhs> for $lhs
+        where
+            Lhs: NearlyEqRel<Rhs> + RelTolerance<Rhs>,
+        {
+            #[inline]
+            fn nearly_eq_rel(&self, other: &$rhs, rel: &RelToleranceType<Lhs, Rhs>) -> bool {
+                NearlyEqRel::nearly_eq_rel(*self, *other, rel)
+            }
+        }
+
         impl<Lhs: ?Sized, Rhs: ?Sized> NearlyEqTol<$rhs, Lhs, Rhs> for $lhs
         where
-            Lhs: NearlyEqTol<Rhs> + EpsTolerance<Rhs> + UlpsTolerance<Rhs>,
+            Lhs: NearlyEqTol<Rhs> + EpsTolerance<Rhs> + UlpsTolerance<Rhs> + RelTolerance<Rhs>,
         {
             #[inline]
             fn nearly_eq_tol(&self, other: &$rhs, tol: &Tolerance<Lhs, Rhs>) -> bool {
@@ -41,7 +52,7 @@ macro_rules! impl_ref {
         }
 
         impl<Lhs: ?Sized, Rhs: ?Sized> NearlyEq<$rhs, Lhs, Rhs> for $lhs where
-            Lhs: NearlyEq<Rhs> + EpsTolerance<Rhs> + UlpsTolerance<Rhs>
+            Lhs: NearlyEq<Rhs> + EpsTolerance<Rhs> + UlpsTolerance<Rhs> + RelTolerance<Rhs>
         {
         }
 
@@ -99,9 +110,34 @@ macro_rules! impl_ref {
             }
         }
 
+        impl<Lhs: ?Sized, Rhs: ?Sized> NearlyOrdRel<$rhs, Lhs, Rhs> for $lhs
+        where
+            Lhs: NearlyOrdRel<Rhs> + RelTolerance<Rhs>,
+        {
+            #[inline]
+            fn nearly_lt_rel(&self, other: &$rhs, rel: &RelToleranceType<Lhs, Rhs>) -> bool {
+                NearlyOrdRel::nearly_lt_rel(*self, *other, rel)
+            }
+
+            #[inline]
+            fn nearly_le_rel(&self, other: &$rhs, rel: &RelToleranceType<Lhs, Rhs>) -> bool {
+                NearlyOrdRel::nearly_le_rel(*self, *other, rel)
+            }
+
+            #[inline]
+            fn nearly_gt_rel(&self, other: &$rhs, rel: &RelToleranceType<Lhs, Rhs>) -> bool {
+                NearlyOrdRel::nearly_gt_rel(*self, *other, rel)
+            }
+
+            #[inline]
+            fn nearly_ge_rel(&self, other: &$rhs, rel: &RelToleranceType<Lhs, Rhs>) -> bool {
+                NearlyOrdRel::nearly_ge_rel(*self, *other, rel)
+            }
+        }
+
         impl<Lhs: ?Sized, Rhs: ?Sized> NearlyOrdTol<$rhs, Lhs, Rhs> for $lhs
         where
-            Lhs: NearlyOrdTol<Rhs> + EpsTolerance<Rhs> + UlpsTolerance<Rhs>,
+            Lhs: NearlyOrdTol<Rhs> + EpsTolerance<Rhs> + UlpsTolerance<Rhs> + RelTolerance<Rhs>,
         {
             #[inline]
             fn nearly_lt_tol(&self, other: &$rhs, tol: &Tolerance<Lhs, Rhs>) -> bool {
@@ -125,7 +161,7 @@ macro_rules! impl_ref {
         }
 
         impl<Lhs: ?Sized, Rhs: ?Sized> NearlyOrd<$rhs, Lhs, Rhs> for $lhs where
-            Lhs: NearlyOrd<Rhs> + EpsTolerance<Rhs> + UlpsTolerance<Rhs>
+            Lhs: NearlyOrd<Rhs> + EpsTolerance<Rhs> + UlpsTolerance<Rhs> + RelTolerance<Rhs>
         {
         }
     };
@@ -135,3 +171,73 @@ impl_ref!(&Lhs, &Rhs);
 impl_ref!(&Lhs, &mut Rhs);
 impl_ref!(&mut Lhs, &Rhs);
 impl_ref!(&mut Lhs, &mut Rhs);
+
+macro_rules! impl_ref_tolerance {
+    ($lhs: ty, $rhs: ty) => {
+        impl<Lhs: ?Sized, Rhs: ?Sized> EpsTolerance<$rhs> for $lhs
+        where
+            Lhs: EpsTolerance<Rhs>,
+        {
+            type T = EpsToleranceType<Lhs, Rhs>;
+            const DEFAULT: Self::T = <Lhs as EpsTolerance<Rhs>>::DEFAULT;
+        }
+
+        impl<Lhs: ?Sized, Rhs: ?Sized> UlpsTolerance<$rhs> for $lhs
+        where
+            Lhs: UlpsTolerance<Rhs>,
+        {
+            type T = UlpsToleranceType<Lhs, Rhs>;
+            const DEFAULT: Self::T = <Lhs as UlpsTolerance<Rhs>>::DEFAULT;
+        }
+
+        impl<Lhs: ?Sized, Rhs: ?Sized> RelTolerance<$rhs> for $lhs
+        where
+            Lhs: RelTolerance<Rhs>,
+        {
+            type T = RelToleranceType<Lhs, Rhs>;
+            const DEFAULT: Self::T = <Lhs as RelTolerance<Rhs>>::DEFAULT;
+        }
+    };
+}
+
+impl_ref_tolerance!(&Lhs, &Rhs);
+impl_ref_tolerance!(&Lhs, &mut Rhs);
+impl_ref_tolerance!(&mut Lhs, &Rhs);
+impl_ref_tolerance!(&mut Lhs, &mut Rhs);
+
+macro_rules! impl_ref_value_tolerance {
+    ($ty: ty) => {
+        impl EpsTolerance<$ty> for &$ty {
+            type T = EpsToleranceType<$ty>;
+            const DEFAULT: Self::T = <$ty as EpsTolerance>::DEFAULT;
+        }
+
+        impl EpsTolerance<&$ty> for $ty {
+            type T = EpsToleranceType<$ty>;
+            const DEFAULT: Self::T = <$ty as EpsTolerance>::DEFAULT;
+        }
+
+        impl UlpsTolerance<$ty> for &$ty {
+            type T = UlpsToleranceType<$ty>;
+            const DEFAULT: Self::T = <$ty as UlpsTolerance>::DEFAULT;
+        }
+
+        impl UlpsTolerance<&$ty> for $ty {
+            type T = UlpsToleranceType<$ty>;
+            const DEFAULT: Self::T = <$ty as UlpsTolerance>::DEFAULT;
+        }
+
+        impl RelTolerance<$ty> for &$ty {
+            type T = RelToleranceType<$ty>;
+            const DEFAULT: Self::T = <$ty as RelTolerance>::DEFAULT;
+        }
+
+        impl RelTolerance<&$ty> for $ty {
+            type T = RelToleranceType<$ty>;
+            const DEFAULT: Self::T = <$ty as RelTolerance>::DEFAULT;
+        }
+    };
+}
+
+impl_ref_value_tolerance!(f32);
+impl_ref_value_tolerance!(f64);
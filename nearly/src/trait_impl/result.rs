@@ -0,0 +1,380 @@
+use crate::nearly_eq::{NearlyEq, NearlyEqEps, NearlyEqRel, NearlyEqTol, NearlyEqUlps};
+use crate::nearly_ord::{NearlyOrd, NearlyOrdEps, NearlyOrdRel, NearlyOrdTol, NearlyOrdUlps};
+use crate::tolerance::{
+    EpsTolerance, EpsToleranceType, RelTolerance, RelToleranceType, Tolerance, UlpsTolerance,
+    UlpsToleranceType,
+};
+
+/// Compares two results with `$ok` if both are `Ok` and with `$err` if both are `Err`.
+/// Returns `$ok_err` if only the left result is `Ok` and `$err_ok` if only the right one is.
+macro_rules! cmp_result {
+    (
+        $self: expr,
+        $rhs: expr,
+        |$a: ident, $b: ident| $ok: expr,
+        |$x: ident, $y: ident| $err: expr,
+        $ok_err: literal,
+        $err_ok: literal
+    ) => {
+        match ($self, $rhs) {
+            (Ok($a), Ok($b)) => $ok,
+            (Err($x), Err($y)) => $err,
+            (Ok(_), Err(_)) => $ok_err,
+            (Err(_), Ok(_)) => $err_ok,
+        }
+    };
+}
+
+///////////////
+// nearly_eq //
+///////////////
+
+impl<Lhs, Rhs, LhsErr, RhsErr> NearlyEqEps<Result<Rhs, RhsErr>, Lhs, Rhs> for Result<Lhs, LhsErr>
+where
+    Lhs: NearlyEqEps<Rhs> + EpsTolerance<Rhs>,
+    LhsErr: PartialEq<RhsErr>,
+{
+    #[inline]
+    fn nearly_eq_eps(&self, other: &Result<Rhs, RhsErr>, eps: &EpsToleranceType<Lhs, Rhs>) -> bool {
+        cmp_result!(
+            self,
+            other,
+            |a, b| <Lhs as NearlyEqEps<Rhs>>::nearly_eq_eps(a, b, eps),
+            |x, y| x == y,
+            false,
+            false
+        )
+    }
+}
+
+impl<Lhs, Rhs, LhsErr, RhsErr> NearlyEqUlps<Result<Rhs, RhsErr>, Lhs, Rhs> for Result<Lhs, LhsErr>
+where
+    Lhs: NearlyEqUlps<Rhs> + UlpsTolerance<Rhs>,
+    LhsErr: PartialEq<RhsErr>,
+{
+    #[inline]
+    fn nearly_eq_ulps(
+        &self,
+        other: &Result<Rhs, RhsErr>,
+        ulps: &UlpsToleranceType<Lhs, Rhs>,
+    ) -> bool {
+        cmp_result!(
+            self,
+            other,
+            |a, b| <Lhs as NearlyEqUlps<Rhs>>::nearly_eq_ulps(a, b, ulps),
+            |x, y| x == y,
+            false,
+            false
+        )
+    }
+}
+
+impl<Lhs, Rhs, LhsErr, RhsErr> NearlyEqRel<Result<Rhs, RhsErr>, Lhs, Rhs> for Result<Lhs, LhsErr>
+where
+    Lhs: NearlyEqRel<Rhs> + RelTolerance<Rhs>,
+    LhsErr: PartialEq<RhsErr>,
+{
+    #[inline]
+    fn nearly_eq_rel(&self, other: &Result<Rhs, RhsErr>, rel: &RelToleranceType<Lhs, Rhs>) -> bool {
+        cmp_result!(
+            self,
+            other,
+            |a, b| <Lhs as NearlyEqRel<Rhs>>::nearly_eq_rel(a, b, rel),
+            |x, y| x == y,
+            false,
+            false
+        )
+    }
+}
+
+impl<Lhs, Rhs, LhsErr, RhsErr> NearlyEqTol<Result<Rhs, RhsErr>, Lhs, Rhs> for Result<Lhs, LhsErr>
+where
+    Lhs: NearlyEqTol<Rhs> + EpsTolerance<Rhs> + UlpsTolerance<Rhs> + RelTolerance<Rhs>,
+    LhsErr: PartialEq<RhsErr>,
+{
+    #[inline]
+    fn nearly_eq_tol(&self, other: &Result<Rhs, RhsErr>, tol: &Tolerance<Lhs, Rhs>) -> bool {
+        cmp_result!(
+            self,
+            other,
+            |a, b| <Lhs as NearlyEqTol<Rhs>>::nearly_eq_tol(a, b, tol),
+            |x, y| x == y,
+            false,
+            false
+        )
+    }
+}
+
+impl<Lhs, Rhs, LhsErr, RhsErr> NearlyEq<Result<Rhs, RhsErr>, Lhs, Rhs> for Result<Lhs, LhsErr>
+where
+    Lhs: NearlyEq<Rhs> + EpsTolerance<Rhs> + UlpsTolerance<Rhs> + RelTolerance<Rhs>,
+    LhsErr: PartialEq<RhsErr>,
+{
+}
+
+////////////////
+// nearly_ord //
+////////////////
+
+impl<Lhs, Rhs, LhsErr, RhsErr> NearlyOrdEps<Result<Rhs, RhsErr>, Lhs, Rhs> for Result<Lhs, LhsErr>
+where
+    Lhs: NearlyOrdEps<Rhs> + EpsTolerance<Rhs>,
+    LhsErr: PartialOrd<RhsErr>,
+{
+    #[inline]
+    fn nearly_lt_eps(&self, other: &Result<Rhs, RhsErr>, eps: &EpsToleranceType<Lhs, Rhs>) -> bool {
+        cmp_result!(
+            self,
+            other,
+            |a, b| <Lhs as NearlyOrdEps<Rhs>>::nearly_lt_eps(a, b, eps),
+            |x, y| x < y,
+            true,
+            false
+        )
+    }
+
+    #[inline]
+    fn nearly_le_eps(&self, other: &Result<Rhs, RhsErr>, eps: &EpsToleranceType<Lhs, Rhs>) -> bool {
+        cmp_result!(
+            self,
+            other,
+            |a, b| <Lhs as NearlyOrdEps<Rhs>>::nearly_le_eps(a, b, eps),
+            |x, y| x <= y,
+            true,
+            false
+        )
+    }
+
+    #[inline]
+    fn nearly_gt_eps(&self, other: &Result<Rhs, RhsErr>, eps: &EpsToleranceType<Lhs, Rhs>) -> bool {
+        cmp_result!(
+            self,
+            other,
+            |a, b| <Lhs as NearlyOrdEps<Rhs>>::nearly_gt_eps(a, b, eps),
+            |x, y| x > y,
+            false,
+            true
+        )
+    }
+
+    #[inline]
+    fn nearly_ge_eps(&self, other: &Result<Rhs, RhsErr>, eps: &EpsToleranceType<Lhs, Rhs>) -> bool {
+        cmp_result!(
+            self,
+            other,
+            |a, b| <Lhs as NearlyOrdEps<Rhs>>::nearly_ge_eps(a, b, eps),
+            |x, y| x >= y,
+            false,
+            true
+        )
+    }
+}
+
+impl<Lhs, Rhs, LhsErr, RhsErr> NearlyOrdUlps<Result<Rhs, RhsErr>, Lhs, Rhs> for Result<Lhs, LhsErr>
+where
+    Lhs: NearlyOrdUlps<Rhs> + UlpsTolerance<Rhs>,
+    LhsErr: PartialOrd<RhsErr>,
+{
+    #[inline]
+    fn nearly_lt_ulps(
+        &self,
+        other: &Result<Rhs, RhsErr>,
+        ulps: &UlpsToleranceType<Lhs, Rhs>,
+    ) -> bool {
+        cmp_result!(
+            self,
+            other,
+            |a, b| <Lhs as NearlyOrdUlps<Rhs>>::nearly_lt_ulps(a, b, ulps),
+            |x, y| x < y,
+            true,
+            false
+        )
+    }
+
+    #[inline]
+    fn nearly_le_ulps(
+        &self,
+        other: &Result<Rhs, RhsErr>,
+        ulps: &UlpsToleranceType<Lhs, Rhs>,
+    ) -> bool {
+        cmp_result!(
+            self,
+            other,
+            |a, b| <Lhs as NearlyOrdUlps<Rhs>>::nearly_le_ulps(a, b, ulps),
+            |x, y| x <= y,
+            true,
+            false
+        )
+    }
+
+    #[inline]
+    fn nearly_gt_ulps(
+        &self,
+        other: &Result<Rhs, RhsErr>,
+        ulps: &UlpsToleranceType<Lhs, Rhs>,
+    ) -> bool {
+        cmp_result!(
+            self,
+            other,
+            |a, b| <Lhs as NearlyOrdUlps<Rhs>>::nearly_gt_ulps(a, b, ulps),
+            |x, y| x > y,
+            false,
+            true
+        )
+    }
+
+    #[inline]
+    fn nearly_ge_ulps(
+        &self,
+        other: &Result<Rhs, RhsErr>,
+        ulps: &UlpsToleranceType<Lhs, Rhs>,
+    ) -> bool {
+        cmp_result!(
+            self,
+            other,
+            |a, b| <Lhs as NearlyOrdUlps<Rhs>>::nearly_ge_ulps(a, b, ulps),
+            |x, y| x >= y,
+            false,
+            true
+        )
+    }
+}
+
+impl<Lhs, Rhs, LhsErr, RhsErr> NearlyOrdRel<Result<Rhs, RhsErr>, Lhs, Rhs> for Result<Lhs, LhsErr>
+where
+    Lhs: NearlyOrdRel<Rhs> + RelTolerance<Rhs>,
+    LhsErr: PartialOrd<RhsErr>,
+{
+    #[inline]
+    fn nearly_lt_rel(&self, other: &Result<Rhs, RhsErr>, rel: &RelToleranceType<Lhs, Rhs>) -> bool {
+        cmp_result!(
+            self,
+            other,
+            |a, b| <Lhs as NearlyOrdRel<Rhs>>::nearly_lt_rel(a, b, rel),
+            |x, y| x < y,
+            true,
+            false
+        )
+    }
+
+    #[inline]
+    fn nearly_le_rel(&self, other: &Result<Rhs, RhsErr>, rel: &RelToleranceType<Lhs, Rhs>) -> bool {
+        cmp_result!(
+            self,
+            other,
+            |a, b| <Lhs as NearlyOrdRel<Rhs>>::nearly_le_rel(a, b, rel),
+            |x, y| x <= y,
+            true,
+            false
+        )
+    }
+
+    #[inline]
+    fn nearly_gt_rel(&self, other: &Result<Rhs, RhsErr>, rel: &RelToleranceType<Lhs, Rhs>) -> bool {
+        cmp_result!(
+            self,
+            other,
+            |a, b| <Lhs as NearlyOrdRel<Rhs>>::nearly_gt_rel(a, b, rel),
+            |x, y| x > y,
+            false,
+            true
+        )
+    }
+
+    #[inline]
+    fn nearly_ge_rel(&self, other: &Result<Rhs, RhsErr>, rel: &RelToleranceType<Lhs, Rhs>) -> bool {
+        cmp_result!(
+            self,
+            other,
+            |a, b| <Lhs as NearlyOrdRel<Rhs>>::nearly_ge_rel(a, b, rel),
+            |x, y| x >= y,
+            false,
+            true
+        )
+    }
+}
+
+impl<Lhs, Rhs, LhsErr, RhsErr> NearlyOrdTol<Result<Rhs, RhsErr>, Lhs, Rhs> for Result<Lhs, LhsErr>
+where
+    Lhs: NearlyOrdTol<Rhs> + EpsTolerance<Rhs> + UlpsTolerance<Rhs> + RelTolerance<Rhs>,
+    LhsErr: PartialOrd<RhsErr>,
+{
+    #[inline]
+    fn nearly_lt_tol(&self, other: &Result<Rhs, RhsErr>, tol: &Tolerance<Lhs, Rhs>) -> bool {
+        cmp_result!(
+            self,
+            other,
+            |a, b| <Lhs as NearlyOrdTol<Rhs>>::nearly_lt_tol(a, b, tol),
+            |x, y| x < y,
+            true,
+            false
+        )
+    }
+
+    #[inline]
+    fn nearly_le_tol(&self, other: &Result<Rhs, RhsErr>, tol: &Tolerance<Lhs, Rhs>) -> bool {
+        cmp_result!(
+            self,
+            other,
+            |a, b| <Lhs as NearlyOrdTol<Rhs>>::nearly_le_tol(a, b, tol),
+            |x, y| x <= y,
+            true,
+            false
+        )
+    }
+
+    #[inline]
+    fn nearly_gt_tol(&self, other: &Result<Rhs, RhsErr>, tol: &Tolerance<Lhs, Rhs>) -> bool {
+        cmp_result!(
+            self,
+            other,
+            |a, b| <Lhs as NearlyOrdTol<Rhs>>::nearly_gt_tol(a, b, tol),
+            |x, y| x > y,
+            false,
+            true
+        )
+    }
+
+    #[inline]
+    fn nearly_ge_tol(&self, other: &Result<Rhs, RhsErr>, tol: &Tolerance<Lhs, Rhs>) -> bool {
+        cmp_result!(
+            self,
+            other,
+            |a, b| <Lhs as NearlyOrdTol<Rhs>>::nearly_ge_tol(a, b, tol),
+            |x, y| x >= y,
+            false,
+            true
+        )
+    }
+}
+
+impl<Lhs, Rhs, LhsErr, RhsErr> NearlyOrd<Result<Rhs, RhsErr>, Lhs, Rhs> for Result<Lhs, LhsErr>
+where
+    Lhs: NearlyOrd<Rhs> + EpsTolerance<Rhs> + UlpsTolerance<Rhs> + RelTolerance<Rhs>,
+    LhsErr: PartialOrd<RhsErr>,
+{
+}
+
+impl<Lhs, Rhs, LhsErr, RhsErr> EpsTolerance<Result<Rhs, RhsErr>> for Result<Lhs, LhsErr>
+where
+    Lhs: EpsTolerance<Rhs>,
+{
+    type T = EpsToleranceType<Lhs, Rhs>;
+    const DEFAULT: Self::T = <Lhs as EpsTolerance<Rhs>>::DEFAULT;
+}
+
+impl<Lhs, Rhs, LhsErr, RhsErr> UlpsTolerance<Result<Rhs, RhsErr>> for Result<Lhs, LhsErr>
+where
+    Lhs: UlpsTolerance<Rhs>,
+{
+    type T = UlpsToleranceType<Lhs, Rhs>;
+    const DEFAULT: Self::T = <Lhs as UlpsTolerance<Rhs>>::DEFAULT;
+}
+
+impl<Lhs, Rhs, LhsErr, RhsErr> RelTolerance<Result<Rhs, RhsErr>> for Result<Lhs, LhsErr>
+where
+    Lhs: RelTolerance<Rhs>,
+{
+    type T = RelToleranceType<Lhs, Rhs>;
+    const DEFAULT: Self::T = <Lhs as RelTolerance<Rhs>>::DEFAULT;
+}
@@ -0,0 +1,84 @@
+#![cfg(feature = "std")]
+
+use super::unordered::unordered_eq;
+use crate::nearly_eq::{NearlyEq, NearlyEqEps, NearlyEqRel, NearlyEqTol, NearlyEqUlps};
+use crate::tolerance::{
+    EpsTolerance, EpsToleranceType, RelTolerance, RelToleranceType, Tolerance, UlpsTolerance,
+    UlpsToleranceType,
+};
+
+use std::collections::{BTreeSet, HashSet};
+
+// Sets are compared like collections wrapped into `Unordered`, since the nearly equal elements
+// of two sets are not required to be equal with respect to `Eq`, `Hash` or `Ord`.
+
+macro_rules! impl_set {
+    ([$($vars:tt)*], $lhs: ty, $rhs: ty) => {
+        impl<Lhs, Rhs, $($vars)*> NearlyEqEps<$rhs, Lhs, Rhs> for $lhs
+        where
+            Lhs: NearlyEqEps<Rhs> + EpsTolerance<Rhs>,
+        {
+            fn nearly_eq_eps(
+                &self,
+                other: &$rhs,
+                eps: &EpsToleranceType<Lhs, Rhs>,
+            ) -> bool {
+                unordered_eq(self.iter(), other.iter(), |a, b| {
+                    NearlyEqEps::nearly_eq_eps(a, b, eps)
+                })
+            }
+        }
+
+        impl<Lhs, Rhs, $($vars)*> NearlyEqUlps<$rhs, Lhs, Rhs> for $lhs
+        where
+            Lhs: NearlyEqUlps<Rhs> + UlpsTolerance<Rhs>,
+        {
+            fn nearly_eq_ulps(
+                &self,
+                other: &$rhs,
+                ulps: &UlpsToleranceType<Lhs, Rhs>,
+            ) -> bool {
+                unordered_eq(self.iter(), other.iter(), |a, b| {
+                    NearlyEqUlps::nearly_eq_ulps(a, b, ulps)
+                })
+            }
+        }
+
+        impl<Lhs, Rhs, $($vars)*> NearlyEqRel<$rhs, Lhs, Rhs> for $lhs
+        where
+            Lhs: NearlyEqRel<Rhs> + RelTolerance<Rhs>,
+        {
+            fn nearly_eq_rel(
+                &self,
+                other: &$rhs,
+                rel: &RelToleranceType<Lhs, Rhs>,
+            ) -> bool {
+                unordered_eq(self.iter(), other.iter(), |a, b| {
+                    NearlyEqRel::nearly_eq_rel(a, b, rel)
+                })
+            }
+        }
+
+        impl<Lhs, Rhs, $($vars)*> NearlyEqTol<$rhs, Lhs, Rhs> for $lhs
+        where
+            Lhs: NearlyEqTol<Rhs> + EpsTolerance<Rhs> + UlpsTolerance<Rhs> + RelTolerance<Rhs>,
+        {
+            fn nearly_eq_tol(&self, other: &$rhs, tol: &Tolerance<Lhs, Rhs>) -> bool {
+                unordered_eq(self.iter(), other.iter(), |a, b| {
+                    NearlyEqTol::nearly_eq_tol(a, b, tol)
+                })
+            }
+        }
+
+        impl<Lhs, Rhs, $($vars)*> NearlyEq<$rhs, Lhs, Rhs> for $lhs
+        where
+            Lhs: NearlyEq<Rhs> + EpsTolerance<Rhs> + UlpsTolerance<Rhs> + RelTolerance<Rhs>,
+        {
+        }
+    };
+}
+
+impl_set!([S], HashSet<Lhs, S>, HashSet<Rhs, S>);
+impl_set!([S], HashSet<Lhs, S>, BTreeSet<Rhs>);
+impl_set!([], BTreeSet<Lhs>, BTreeSet<Rhs>);
+impl_set!([S], BTreeSet<Lhs>, HashSet<Rhs, S>);
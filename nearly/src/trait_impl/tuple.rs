@@ -1,9 +1,13 @@
-use crate::nearly_eq::{NearlyEq, NearlyEqEps, NearlyEqTol, NearlyEqUlps};
-use crate::nearly_ord::{NearlyOrd, NearlyOrdEps, NearlyOrdTol, NearlyOrdUlps};
+use crate::nearly_eq::{NearlyEq, NearlyEqEps, NearlyEqRel, NearlyEqTol, NearlyEqUlps};
+use crate::nearly_ord::{NearlyOrd, NearlyOrdEps, NearlyOrdRel, NearlyOrdTol, NearlyOrdUlps};
 use crate::tolerance::{
-    EpsTolerance, EpsToleranceType, Tolerance, UlpsTolerance, UlpsToleranceType,
+    EpsTolerance, EpsToleranceType, RelTolerance, RelToleranceType, Tolerance, UlpsTolerance,
+    UlpsToleranceType,
 };
 
+#[cfg(feature = "std")]
+use crate::report::{NearlyEqReport, Report, ReportTolerance};
+
 macro_rules! impl_tuple {
     ($lhs:ident, $rhs:ident, $idx: tt) => {
         impl_tuple!(@impl $lhs, $rhs, $idx);
@@ -35,9 +39,18 @@ macro_rules! impl_tuple {
             }
         }
 
+        impl<Lhs, Rhs> NearlyEqRel<($($rhs,)+), Lhs, Rhs> for ($($lhs,)+)
+        where
+            Lhs: NearlyEqRel<Rhs> + RelTolerance<Rhs>,
+        {
+            fn nearly_eq_rel(&self, other: &($($rhs,)+), rel: &RelToleranceType<Lhs, Rhs>) -> bool {
+                $( self.$idx.nearly_eq_rel(&other.$idx, rel) )&&+
+            }
+        }
+
         impl<Lhs, Rhs> NearlyEqTol<($($rhs,)+), Lhs, Rhs> for ($($lhs,)+)
         where
-            Lhs: NearlyEqTol<Rhs> + EpsTolerance<Rhs> + UlpsTolerance<Rhs>,
+            Lhs: NearlyEqTol<Rhs> + EpsTolerance<Rhs> + UlpsTolerance<Rhs> + RelTolerance<Rhs>,
         {
             fn nearly_eq_tol(&self, other: &($($rhs,)+), tol: &Tolerance<Lhs, Rhs>) -> bool {
                 $( self.$idx.nearly_eq_tol(&other.$idx, tol) )&&+
@@ -46,10 +59,36 @@ macro_rules! impl_tuple {
 
         impl<Lhs, Rhs> NearlyEq<($($rhs,)+), Lhs, Rhs> for ($($lhs,)+)
         where
-            Lhs: NearlyEq<Rhs> + EpsTolerance<Rhs> + UlpsTolerance<Rhs>,
+            Lhs: NearlyEq<Rhs> + EpsTolerance<Rhs> + UlpsTolerance<Rhs> + RelTolerance<Rhs>,
         {
         }
 
+        #[cfg(feature = "std")]
+        impl<Lhs, Rhs> NearlyEqReport<($($rhs,)+), Lhs, Rhs> for ($($lhs,)+)
+        where
+            Lhs: NearlyEqReport<Rhs> + EpsTolerance<Rhs> + UlpsTolerance<Rhs> + RelTolerance<Rhs>,
+        {
+            fn report_nearly_eq(
+                &self,
+                other: &($($rhs,)+),
+                tol: &ReportTolerance<Lhs, Rhs>,
+                report: &mut Report,
+            ) {
+                // the elements are listed in reverse order, report them in ascending order
+                let elem_reports: &[&dyn Fn(&mut Report)] = &[$(
+                    &|report: &mut Report| {
+                        report.field(stringify!($idx), |report| {
+                            self.$idx.report_nearly_eq(&other.$idx, tol, report)
+                        });
+                    },
+                )+];
+
+                for elem_report in elem_reports.iter().rev() {
+                    elem_report(report);
+                }
+            }
+        }
+
         ////////////////
         // nearly_ord //
         ////////////////
@@ -96,9 +135,30 @@ macro_rules! impl_tuple {
             }
         }
 
+        impl<Lhs, Rhs> NearlyOrdRel<($($rhs,)+), Lhs, Rhs> for ($($lhs,)+)
+        where
+            Lhs: NearlyOrdRel<Rhs> + RelTolerance<Rhs>,
+        {
+            fn nearly_lt_rel(&self, other: &($($rhs,)+), rel: &RelToleranceType<Lhs, Rhs>) -> bool {
+                $( self.$idx.nearly_lt_rel(&other.$idx, rel) )&&+
+            }
+
+            fn nearly_le_rel(&self, other: &($($rhs,)+), rel: &RelToleranceType<Lhs, Rhs>) -> bool {
+                $( self.$idx.nearly_le_rel(&other.$idx, rel) )&&+
+            }
+
+            fn nearly_gt_rel(&self, other: &($($rhs,)+), rel: &RelToleranceType<Lhs, Rhs>) -> bool {
+                $( self.$idx.nearly_gt_rel(&other.$idx, rel) )&&+
+            }
+
+            fn nearly_ge_rel(&self, other: &($($rhs,)+), rel: &RelToleranceType<Lhs, Rhs>) -> bool {
+                $( self.$idx.nearly_ge_rel(&other.$idx, rel) )&&+
+            }
+        }
+
         impl<Lhs, Rhs> NearlyOrdTol<($($rhs,)+), Lhs, Rhs> for ($($lhs,)+)
         where
-            Lhs: NearlyOrdTol<Rhs> + EpsTolerance<Rhs> + UlpsTolerance<Rhs>,
+            Lhs: NearlyOrdTol<Rhs> + EpsTolerance<Rhs> + UlpsTolerance<Rhs> + RelTolerance<Rhs>,
         {
             fn nearly_lt_tol(&self, other: &($($rhs,)+), tol: &Tolerance<Lhs, Rhs>) -> bool {
                 $( self.$idx.nearly_lt_tol(&other.$idx, tol) )&&+
@@ -119,7 +179,7 @@ macro_rules! impl_tuple {
 
         impl<Lhs, Rhs> NearlyOrd<($($rhs,)+), Lhs, Rhs> for ($($lhs,)+)
         where
-            Lhs: NearlyOrd<Rhs> + EpsTolerance<Rhs> + UlpsTolerance<Rhs>,
+            Lhs: NearlyOrd<Rhs> + EpsTolerance<Rhs> + UlpsTolerance<Rhs> + RelTolerance<Rhs>,
         {
         }
     }
@@ -1,6 +1,6 @@
-use crate::nearly_eq::{NearlyEq, NearlyEqEps, NearlyEqTol, NearlyEqUlps};
-use crate::nearly_ord::{NearlyOrd, NearlyOrdEps, NearlyOrdTol, NearlyOrdUlps};
-use crate::tolerance::{EpsToleranceType, UlpsToleranceType};
+use crate::nearly_eq::{NearlyEq, NearlyEqEps, NearlyEqRel, NearlyEqTol, NearlyEqUlps};
+use crate::nearly_ord::{NearlyOrd, NearlyOrdEps, NearlyOrdRel, NearlyOrdTol, NearlyOrdUlps};
+use crate::tolerance::{EpsToleranceType, RelToleranceType, UlpsToleranceType};
 
 ///////////////
 // nearly_eq //
@@ -20,6 +20,13 @@ impl NearlyEqUlps for () {
     }
 }
 
+impl NearlyEqRel for () {
+    #[inline]
+    fn nearly_eq_rel(&self, _other: &Self, _rel: &RelToleranceType<Self>) -> bool {
+        true
+    }
+}
+
 impl NearlyEqTol for () {}
 impl NearlyEq for () {}
 
@@ -51,5 +58,17 @@ impl NearlyOrdUlps for () {
     }
 }
 
+impl NearlyOrdRel for () {
+    #[inline]
+    fn nearly_lt_rel(&self, _other: &Self, _rel: &RelToleranceType<Self>) -> bool {
+        false
+    }
+
+    #[inline]
+    fn nearly_gt_rel(&self, _other: &Self, _rel: &RelToleranceType<Self>) -> bool {
+        false
+    }
+}
+
 impl NearlyOrdTol for () {}
 impl NearlyOrd for () {}
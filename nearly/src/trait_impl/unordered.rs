@@ -0,0 +1,210 @@
+#![cfg(feature = "std")]
+
+use crate::nearly_eq::{NearlyEq, NearlyEqEps, NearlyEqRel, NearlyEqTol, NearlyEqUlps};
+use crate::tolerance::{
+    EpsTolerance, EpsToleranceType, RelTolerance, RelToleranceType, Tolerance, UlpsTolerance,
+    UlpsToleranceType,
+};
+use crate::unordered::Unordered;
+
+use std::collections::{LinkedList, VecDeque};
+use std::vec;
+use std::vec::Vec;
+
+/// Returns whether each element of `lhs` can be matched to a distinct element of `rhs` it is
+/// nearly equal to based on `eq`.
+///
+/// Both iterators must yield the same number of elements. The matching is a maximum bipartite
+/// matching. Therefore, the result does not depend on the order of the elements.
+///
+/// The elements are matched greedily first, which already matches all elements of most inputs.
+/// Each element left unmatched is matched by an augmenting path found by a breadth first search.
+/// The comparisons of the elements are not stored but computed when needed.
+pub(super) fn unordered_eq<'a, Lhs, Rhs>(
+    lhs: impl Iterator<Item = &'a Lhs>,
+    rhs: impl Iterator<Item = &'a Rhs>,
+    eq: impl Fn(&Lhs, &Rhs) -> bool,
+) -> bool
+where
+    Lhs: 'a,
+    Rhs: 'a,
+{
+    let lhs: Vec<&Lhs> = lhs.collect();
+    let rhs: Vec<&Rhs> = rhs.collect();
+    if lhs.len() != rhs.len() {
+        return false;
+    }
+
+    let mut matching = Matching {
+        lhs: vec![None; lhs.len()],
+        rhs: vec![None; rhs.len()],
+    };
+
+    for (i, a) in lhs.iter().enumerate() {
+        let free = (0..rhs.len()).find(|&j| matching.rhs[j].is_none() && eq(a, rhs[j]));
+        if let Some(j) = free {
+            matching.lhs[i] = Some(j);
+            matching.rhs[j] = Some(i);
+        }
+    }
+
+    (0..lhs.len()).all(|i| matching.lhs[i].is_some() || matching.augment(i, &lhs, &rhs, &eq))
+}
+
+/// A matching of lhs elements to rhs elements by their indices.
+struct Matching {
+    /// The index of the rhs element each lhs element is matched to.
+    lhs: Vec<Option<usize>>,
+    /// The index of the lhs element each rhs element is matched to.
+    rhs: Vec<Option<usize>>,
+}
+
+impl Matching {
+    /// Tries to match the unmatched lhs element `start` by searching an augmenting path starting
+    /// at `start`. If such a path is found, the matching is flipped along the path.
+    fn augment<Lhs, Rhs>(
+        &mut self,
+        start: usize,
+        lhs: &[&Lhs],
+        rhs: &[&Rhs],
+        eq: impl Fn(&Lhs, &Rhs) -> bool,
+    ) -> bool {
+        // the lhs element each visited rhs element was reached from
+        let mut reached_from: Vec<Option<usize>> = vec![None; rhs.len()];
+        let mut queue = VecDeque::from([start]);
+
+        while let Some(i) = queue.pop_front() {
+            for j in 0..rhs.len() {
+                if reached_from[j].is_some() || !eq(lhs[i], rhs[j]) {
+                    continue;
+                }
+                reached_from[j] = Some(i);
+
+                match self.rhs[j] {
+                    Some(k) => queue.push_back(k),
+                    None => {
+                        self.flip(j, &reached_from);
+                        return true;
+                    }
+                }
+            }
+        }
+
+        false
+    }
+
+    /// Flips the matching along the augmenting path ending at the free rhs element `end`.
+    fn flip(&mut self, end: usize, reached_from: &[Option<usize>]) {
+        let mut j = end;
+        while let Some(i) = reached_from[j] {
+            let previous = self.lhs[i];
+            self.lhs[i] = Some(j);
+            self.rhs[j] = Some(i);
+
+            match previous {
+                Some(previous) => j = previous,
+                None => break,
+            }
+        }
+    }
+}
+
+macro_rules! impl_unordered {
+    ([$($vars:tt)*], $lhs: ty, $rhs: ty) => {
+        impl<Lhs, Rhs, $($vars)*> NearlyEqEps<Unordered<$rhs>, Lhs, Rhs> for Unordered<$lhs>
+        where
+            Lhs: NearlyEqEps<Rhs> + EpsTolerance<Rhs>,
+        {
+            fn nearly_eq_eps(
+                &self,
+                other: &Unordered<$rhs>,
+                eps: &EpsToleranceType<Lhs, Rhs>,
+            ) -> bool {
+                unordered_eq(self.0.iter(), other.0.iter(), |a, b| {
+                    NearlyEqEps::nearly_eq_eps(a, b, eps)
+                })
+            }
+        }
+
+        impl<Lhs, Rhs, $($vars)*> NearlyEqUlps<Unordered<$rhs>, Lhs, Rhs> for Unordered<$lhs>
+        where
+            Lhs: NearlyEqUlps<Rhs> + UlpsTolerance<Rhs>,
+        {
+            fn nearly_eq_ulps(
+                &self,
+                other: &Unordered<$rhs>,
+                ulps: &UlpsToleranceType<Lhs, Rhs>,
+            ) -> bool {
+                unordered_eq(self.0.iter(), other.0.iter(), |a, b| {
+                    NearlyEqUlps::nearly_eq_ulps(a, b, ulps)
+                })
+            }
+        }
+
+        impl<Lhs, Rhs, $($vars)*> NearlyEqRel<Unordered<$rhs>, Lhs, Rhs> for Unordered<$lhs>
+        where
+            Lhs: NearlyEqRel<Rhs> + RelTolerance<Rhs>,
+        {
+            fn nearly_eq_rel(
+                &self,
+                other: &Unordered<$rhs>,
+                rel: &RelToleranceType<Lhs, Rhs>,
+            ) -> bool {
+                unordered_eq(self.0.iter(), other.0.iter(), |a, b| {
+                    NearlyEqRel::nearly_eq_rel(a, b, rel)
+                })
+            }
+        }
+
+        impl<Lhs, Rhs, $($vars)*> NearlyEqTol<Unordered<$rhs>, Lhs, Rhs> for Unordered<$lhs>
+        where
+            Lhs: NearlyEqTol<Rhs> + EpsTolerance<Rhs> + UlpsTolerance<Rhs> + RelTolerance<Rhs>,
+        {
+            fn nearly_eq_tol(&self, other: &Unordered<$rhs>, tol: &Tolerance<Lhs, Rhs>) -> bool {
+                unordered_eq(self.0.iter(), other.0.iter(), |a, b| {
+                    NearlyEqTol::nearly_eq_tol(a, b, tol)
+                })
+            }
+        }
+
+        impl<Lhs, Rhs, $($vars)*> NearlyEq<Unordered<$rhs>, Lhs, Rhs> for Unordered<$lhs>
+        where
+            Lhs: NearlyEq<Rhs> + EpsTolerance<Rhs> + UlpsTolerance<Rhs> + RelTolerance<Rhs>,
+        {
+        }
+    };
+}
+
+impl_unordered!([const N: usize], [Lhs; N], [Rhs; N]);
+impl_unordered!([const N: usize], [Lhs; N], [Rhs]);
+impl_unordered!([const N: usize], [Lhs; N], &[Rhs]);
+
+impl_unordered!([], [Lhs], [Rhs]);
+impl_unordered!([], [Lhs], &[Rhs]);
+impl_unordered!([const N: usize], [Lhs], [Rhs; N]);
+
+impl_unordered!([], &[Lhs], &[Rhs]);
+impl_unordered!([], &[Lhs], [Rhs]);
+impl_unordered!([const N: usize], &[Lhs], [Rhs; N]);
+
+impl_unordered!([], Vec<Lhs>, Vec<Rhs>);
+impl_unordered!([], Vec<Lhs>, VecDeque<Rhs>);
+impl_unordered!([const N: usize], Vec<Lhs>, [Rhs; N]);
+impl_unordered!([], Vec<Lhs>, [Rhs]);
+impl_unordered!([], Vec<Lhs>, &[Rhs]);
+
+impl_unordered!([const N: usize], [Lhs; N], Vec<Rhs>);
+impl_unordered!([], [Lhs], Vec<Rhs>);
+impl_unordered!([], &[Lhs], Vec<Rhs>);
+
+impl_unordered!([], VecDeque<Lhs>, VecDeque<Rhs>);
+impl_unordered!([], VecDeque<Lhs>, Vec<Rhs>);
+impl_unordered!([const N: usize], VecDeque<Lhs>, [Rhs; N]);
+impl_unordered!([], VecDeque<Lhs>, [Rhs]);
+impl_unordered!([], VecDeque<Lhs>, &[Rhs]);
+
+impl_unordered!([const N: usize], [Lhs; N], VecDeque<Rhs>);
+impl_unordered!([], [Lhs], VecDeque<Rhs>);
+impl_unordered!([], &[Lhs], VecDeque<Rhs>);
+
+impl_unordered!([], LinkedList<Lhs>, LinkedList<Rhs>);
@@ -1,34 +1,183 @@
 /// A trait for ulps based calculations and operations on floating point types.
-pub(crate) trait Ulps
+///
+/// An ulp (unit in the last place) is the distance between a floating point value and the next
+/// representable value. This trait is implemented for [f32] and [f64].
+///
+/// ```
+/// use nearly::Ulps;
+///
+/// let a: f32 = 1.0;
+/// let b = a.step_ulps(3);
+/// assert_eq!(a.ulps_distance(b), Some(3));
+/// assert_eq!(b.step_ulps(-3), a);
+///
+/// // the distance is measured across zero
+/// assert_eq!(1e-45_f32.ulps_distance(-1e-45), Some(2));
+/// assert_eq!(0.0_f32.step_down(), -1e-45);
+/// ```
+pub trait Ulps
 where
     Self: Copy,
 {
-    /// The integer representation of the floating point type implementing this trait.
+    /// The signed integer representation of the floating point type implementing this trait.
     type IntRep;
 
-    /// Returns the signed ulps distance between `self` and `other`.
+    /// The unsigned integer representation of the floating point type implementing this trait.
+    type UIntRep;
+
+    /// Returns the signed ulps distance between the bit patterns of `self` and `other`.
+    ///
+    /// This corresponds to the ulps representation of `self - other` for values with the same
+    /// sign. For values with different signs, the result is meaningless. Use
+    /// [signed_ulps_distance_across_zero](Ulps::signed_ulps_distance_across_zero()) for values
+    /// with different signs.
     fn signed_ulps_distance(self, other: Self) -> Self::IntRep;
+
+    /// Returns the signed ulps distance between `self` and `other` measured across zero.
+    ///
+    /// The bit patterns are mapped onto a monotonic integer line, where `+0` and `-0` are both
+    /// mapped to zero and negative values are mapped to negative integers. Therefore, the distance
+    /// is continuous across zero. Returns `None` if the distance exceeds the range of
+    /// [IntRep](Ulps::IntRep).
+    fn signed_ulps_distance_across_zero(self, other: Self) -> Option<Self::IntRep>;
+
+    /// Returns the number of ulps between `self` and `other` measured across zero.
+    ///
+    /// `+0` and `-0` have a distance of zero and infinite values are one ulp away from the
+    /// largest finite value with the same sign. Returns `None` if `self` or `other` is NaN.
+    fn ulps_distance(self, other: Self) -> Option<Self::UIntRep>;
+
+    /// Returns the value that is `n` ulps away from `self`.
+    ///
+    /// A positive `n` steps towards positive infinity, a negative `n` steps towards negative
+    /// infinity. Stepping is continuous across zero and saturates at the infinite values.
+    /// NaN is returned unchanged.
+    fn step_ulps(self, n: Self::IntRep) -> Self;
+
+    /// Returns the smallest value that is greater than `self`.
+    ///
+    /// This is the same as [step_ulps(1)](Ulps::step_ulps()). Unlike the inherent `next_up` of
+    /// the floating point types, this is available on every supported Rust version.
+    fn step_up(self) -> Self;
+
+    /// Returns the greatest value that is less than `self`.
+    ///
+    /// This is the same as [step_ulps(-1)](Ulps::step_ulps()). Unlike the inherent `next_down` of
+    /// the floating point types, this is available on every supported Rust version.
+    fn step_down(self) -> Self;
+
+    /// Returns the size of an ulp at `self`.
+    ///
+    /// This is the distance between the magnitude of `self` and the next representable value
+    /// with a larger magnitude. For the largest finite value, the distance to the next smaller
+    /// value is returned. Returns NaN for infinite and NaN values.
+    fn ulp(self) -> Self;
+}
+
+/// Maps the bit pattern of a floating point value onto a monotonic integer line.
+macro_rules! to_monotonic {
+    ($float: ty, $int: ty, $value: expr) => {{
+        let i_value = ($value).to_bits() as $int;
+        if i_value < 0 {
+            <$int>::MIN.wrapping_sub(i_value)
+        } else {
+            i_value
+        }
+    }};
+}
+
+/// Maps a value of the monotonic integer line back to a floating point value.
+macro_rules! from_monotonic {
+    ($float: ty, $int: ty, $value: expr) => {{
+        let i_value: $int = $value;
+        if i_value < 0 {
+            <$float>::from_bits(<$int>::MIN.wrapping_sub(i_value) as _)
+        } else {
+            <$float>::from_bits(i_value as _)
+        }
+    }};
 }
 
 macro_rules! impl_ulps {
-    ($float: ty, $int: ty) => {
+    ($float: ident, $int: ty, $uint: ty) => {
         impl Ulps for $float {
             type IntRep = $int;
+            type UIntRep = $uint;
 
-            /// Returns the signed ulps distance between `self` and `other`.
-            /// This corresponds to the ulps representation of `self - other`.
             fn signed_ulps_distance(self, other: Self) -> Self::IntRep {
                 let i_self = self.to_bits() as $int;
                 let i_other = other.to_bits() as $int;
 
                 i_self.wrapping_sub(i_other)
             }
+
+            fn signed_ulps_distance_across_zero(self, other: Self) -> Option<Self::IntRep> {
+                to_monotonic!($float, $int, self).checked_sub(to_monotonic!($float, $int, other))
+            }
+
+            fn ulps_distance(self, other: Self) -> Option<Self::UIntRep> {
+                if self.is_nan() || other.is_nan() {
+                    return None;
+                }
+
+                let m_self = to_monotonic!($float, $int, self);
+                let m_other = to_monotonic!($float, $int, other);
+
+                // the distance of all non NaN values fits into the unsigned representation
+                if m_self > m_other {
+                    Some((m_self as $uint).wrapping_sub(m_other as $uint))
+                } else {
+                    Some((m_other as $uint).wrapping_sub(m_self as $uint))
+                }
+            }
+
+            fn step_ulps(self, n: Self::IntRep) -> Self {
+                if self.is_nan() || n == 0 {
+                    return self;
+                }
+
+                let max = to_monotonic!($float, $int, $float::INFINITY);
+                let stepped = to_monotonic!($float, $int, self).saturating_add(n);
+                let clamped = if stepped > max {
+                    max
+                } else if stepped < -max {
+                    -max
+                } else {
+                    stepped
+                };
+
+                from_monotonic!($float, $int, clamped)
+            }
+
+            #[inline]
+            fn step_up(self) -> Self {
+                self.step_ulps(1)
+            }
+
+            #[inline]
+            fn step_down(self) -> Self {
+                self.step_ulps(-1)
+            }
+
+            fn ulp(self) -> Self {
+                if !self.is_finite() {
+                    return $float::NAN;
+                }
+
+                // clear the sign bit
+                let abs = $float::from_bits(self.to_bits() & (<$uint>::MAX >> 1));
+                if abs == $float::MAX {
+                    abs - Ulps::step_down(abs)
+                } else {
+                    Ulps::step_up(abs) - abs
+                }
+            }
         }
     };
 }
 
-impl_ulps!(f32, i32);
-impl_ulps!(f64, i64);
+impl_ulps!(f32, i32, u32);
+impl_ulps!(f64, i64, u64);
 
 #[cfg(test)]
 mod tests {
@@ -119,4 +268,176 @@ mod tests {
         assert!(a.signed_ulps_distance(b) < -2);
         assert!(b.signed_ulps_distance(a) < -2);
     }
+
+    #[test]
+    fn signed_ulps_distance_across_zero_f32() {
+        {
+            let a: f32 = 0.0;
+            let b: f32 = -0.0;
+            assert_eq!(a.signed_ulps_distance_across_zero(b), Some(0));
+            assert_eq!(b.signed_ulps_distance_across_zero(a), Some(0));
+        }
+        {
+            let a: f32 = 1e-45;
+            let b: f32 = -1e-45;
+            assert_eq!(a.signed_ulps_distance_across_zero(b), Some(2));
+            assert_eq!(b.signed_ulps_distance_across_zero(a), Some(-2));
+        }
+        {
+            let a: f32 = 1.0;
+            let b: f32 = 1.0000008;
+            assert_eq!(a.signed_ulps_distance_across_zero(b), Some(-7));
+            assert_eq!(b.signed_ulps_distance_across_zero(a), Some(7));
+        }
+        {
+            let a: f32 = -1.0;
+            let b: f32 = -1.0000008;
+            assert_eq!(a.signed_ulps_distance_across_zero(b), Some(7));
+            assert_eq!(b.signed_ulps_distance_across_zero(a), Some(-7));
+        }
+        {
+            let a: f32 = f32::MAX;
+            let b: f32 = f32::MIN;
+            assert_eq!(a.signed_ulps_distance_across_zero(b), None);
+            assert_eq!(b.signed_ulps_distance_across_zero(a), None);
+        }
+    }
+
+    #[test]
+    fn signed_ulps_distance_across_zero_f64() {
+        {
+            let a: f64 = 0.0;
+            let b: f64 = -0.0;
+            assert_eq!(a.signed_ulps_distance_across_zero(b), Some(0));
+            assert_eq!(b.signed_ulps_distance_across_zero(a), Some(0));
+        }
+        {
+            let a: f64 = 5e-324;
+            let b: f64 = -5e-324;
+            assert_eq!(a.signed_ulps_distance_across_zero(b), Some(2));
+            assert_eq!(b.signed_ulps_distance_across_zero(a), Some(-2));
+        }
+        {
+            let a: f64 = 1.0;
+            let b: f64 = 1.0000000000000016;
+            assert_eq!(a.signed_ulps_distance_across_zero(b), Some(-7));
+            assert_eq!(b.signed_ulps_distance_across_zero(a), Some(7));
+        }
+        {
+            let a: f64 = -1.0;
+            let b: f64 = -1.0000000000000016;
+            assert_eq!(a.signed_ulps_distance_across_zero(b), Some(7));
+            assert_eq!(b.signed_ulps_distance_across_zero(a), Some(-7));
+        }
+        {
+            let a: f64 = f64::MAX;
+            let b: f64 = f64::MIN;
+            assert_eq!(a.signed_ulps_distance_across_zero(b), None);
+            assert_eq!(b.signed_ulps_distance_across_zero(a), None);
+        }
+    }
+
+    #[test]
+    fn ulps_distance_f32() {
+        assert_eq!(0.0_f32.ulps_distance(-0.0), Some(0));
+        assert_eq!(1.0_f32.ulps_distance(1.0000008), Some(7));
+        assert_eq!(1.0000008_f32.ulps_distance(1.0), Some(7));
+        assert_eq!(1e-45_f32.ulps_distance(-1e-45), Some(2));
+        assert_eq!(f32::MAX.ulps_distance(f32::INFINITY), Some(1));
+        assert_eq!(
+            f32::INFINITY.ulps_distance(f32::NEG_INFINITY),
+            Some(2 * f32::INFINITY.to_bits())
+        );
+        assert_eq!(f32::NAN.ulps_distance(1.0), None);
+        assert_eq!(1.0_f32.ulps_distance(f32::NAN), None);
+    }
+
+    #[test]
+    fn step_ulps_f32() {
+        assert_eq!(1.0_f32.step_ulps(0), 1.0);
+        assert_eq!(1.0_f32.step_ulps(7), 1.0000008);
+        assert_eq!(1.0000008_f32.step_ulps(-7), 1.0);
+        assert_eq!(1e-45_f32.step_ulps(-2), -1e-45);
+        assert_eq!((-1e-45_f32).step_ulps(2), 1e-45);
+        assert_eq!(f32::MAX.step_ulps(i32::MAX), f32::INFINITY);
+        assert_eq!(f32::MIN.step_ulps(i32::MIN), f32::NEG_INFINITY);
+        assert_eq!(f32::INFINITY.step_ulps(1), f32::INFINITY);
+        assert!(f32::NAN.step_ulps(1).is_nan());
+    }
+
+    #[test]
+    fn step_up_down_f32() {
+        assert_eq!(Ulps::step_up(0.0_f32), 1e-45);
+        assert_eq!(Ulps::step_up(-0.0_f32), 1e-45);
+        assert_eq!(Ulps::step_down(0.0_f32), -1e-45);
+        assert_eq!(Ulps::step_up(1.0_f32), 1.0 + f32::EPSILON);
+        assert_eq!(Ulps::step_down(1.0 + f32::EPSILON), 1.0);
+        assert_eq!(Ulps::step_up(f32::MAX), f32::INFINITY);
+        assert_eq!(Ulps::step_down(f32::INFINITY), f32::MAX);
+        assert_eq!(Ulps::step_up(f32::NEG_INFINITY), f32::MIN);
+        assert!(Ulps::step_up(f32::NAN).is_nan());
+    }
+
+    #[test]
+    fn ulp_f32() {
+        assert_eq!(0.0_f32.ulp(), 1e-45);
+        assert_eq!(1.0_f32.ulp(), f32::EPSILON);
+        assert_eq!((-1.0_f32).ulp(), f32::EPSILON);
+        assert_eq!(2.0_f32.ulp(), 2.0 * f32::EPSILON);
+        assert_eq!(f32::MAX.ulp(), f32::MAX - Ulps::step_down(f32::MAX));
+        assert!(f32::INFINITY.ulp().is_nan());
+        assert!(f32::NAN.ulp().is_nan());
+    }
+
+    #[test]
+    fn ulps_distance_f64() {
+        assert_eq!(0.0_f64.ulps_distance(-0.0), Some(0));
+        assert_eq!(1.0_f64.ulps_distance(1.0000000000000016), Some(7));
+        assert_eq!(1.0000000000000016_f64.ulps_distance(1.0), Some(7));
+        assert_eq!(5e-324_f64.ulps_distance(-5e-324), Some(2));
+        assert_eq!(f64::MAX.ulps_distance(f64::INFINITY), Some(1));
+        assert_eq!(
+            f64::INFINITY.ulps_distance(f64::NEG_INFINITY),
+            Some(2 * f64::INFINITY.to_bits())
+        );
+        assert_eq!(f64::NAN.ulps_distance(1.0), None);
+        assert_eq!(1.0_f64.ulps_distance(f64::NAN), None);
+    }
+
+    #[test]
+    fn step_ulps_f64() {
+        assert_eq!(1.0_f64.step_ulps(0), 1.0);
+        assert_eq!(1.0_f64.step_ulps(7), 1.0000000000000016);
+        assert_eq!(1.0000000000000016_f64.step_ulps(-7), 1.0);
+        assert_eq!(5e-324_f64.step_ulps(-2), -5e-324);
+        assert_eq!((-5e-324_f64).step_ulps(2), 5e-324);
+        assert_eq!(f64::MAX.step_ulps(i64::MAX), f64::INFINITY);
+        assert_eq!(f64::MIN.step_ulps(i64::MIN), f64::NEG_INFINITY);
+        assert_eq!(f64::INFINITY.step_ulps(1), f64::INFINITY);
+        assert!(f64::NAN.step_ulps(1).is_nan());
+    }
+
+    #[test]
+    fn step_up_down_f64() {
+        assert_eq!(Ulps::step_up(0.0_f64), 5e-324);
+        assert_eq!(Ulps::step_up(-0.0_f64), 5e-324);
+        assert_eq!(Ulps::step_down(0.0_f64), -5e-324);
+        assert_eq!(Ulps::step_up(1.0_f64), 1.0 + f64::EPSILON);
+        assert_eq!(Ulps::step_down(1.0 + f64::EPSILON), 1.0);
+        assert_eq!(Ulps::step_up(f64::MAX), f64::INFINITY);
+        assert_eq!(Ulps::step_down(f64::INFINITY), f64::MAX);
+        assert_eq!(Ulps::step_up(f64::NEG_INFINITY), f64::MIN);
+        assert!(Ulps::step_up(f64::NAN).is_nan());
+    }
+
+    #[test]
+    fn ulp_f64() {
+        assert_eq!(0.0_f64.ulp(), 5e-324);
+        assert_eq!(1.0_f64.ulp(), f64::EPSILON);
+        assert_eq!((-1.0_f64).ulp(), f64::EPSILON);
+        assert_eq!(2.0_f64.ulp(), 2.0 * f64::EPSILON);
+        assert_eq!(f64::MAX.ulp(), f64::MAX - Ulps::step_down(f64::MAX));
+        assert!(f64::INFINITY.ulp().is_nan());
+        assert!(f64::NAN.ulp().is_nan());
+    }
 }
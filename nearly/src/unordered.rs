@@ -0,0 +1,34 @@
+/// A wrapper to compare collections by nearly equality regardless of the order of their elements.
+///
+/// The nearly equality traits implemented for collections compare the elements pairwise.
+/// Wrapping collections into [Unordered] compares them as multisets instead. Two collections are
+/// nearly equal, if they have the same length and each element on the left can be matched to a
+/// distinct element on the right that it is nearly equal to.
+///
+/// Since nearly equality is not transitive, matching each element to the first nearly equal
+/// element is not sufficient. The matching is computed as a maximum bipartite matching by
+/// augmenting paths. Hence, the comparison returns true if and only if such a one-to-one
+/// matching exists, independent of the order of the elements.
+///
+/// For collections of length `n`, the elements are matched greedily first, which takes up to
+/// `n²` element comparisons and suffices if the collections contain the nearly equal elements in
+/// a similar order or many identical elements. Each element left unmatched requires up to `n²`
+/// further comparisons to search an augmenting path, so the worst case takes `O(n³)` time. The
+/// element comparisons are not stored, so the memory usage is `O(n)`.
+///
+/// ```
+/// use nearly::{nearly, Unordered};
+///
+/// let a = Unordered([1.0, 2.0, 3.0]);
+/// let b = Unordered([3.0, 1.05, 2.0]);
+///
+/// assert!(nearly!(a == b, eps = 0.1));
+/// assert!(!nearly!(a.0 == b.0, eps = 0.1));
+///
+/// // 1.0 is only nearly equal to 1.05, so 1.1 has to be matched with 1.15
+/// let a = Unordered(vec![1.1, 1.0]);
+/// let b = Unordered(vec![1.05, 1.15]);
+/// assert!(nearly!(a == b, eps = 0.1));
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Default)]
+pub struct Unordered<T: ?Sized>(pub T);
@@ -0,0 +1,221 @@
+use core::cmp::Ordering;
+use core::fmt::{Debug, Formatter, Result};
+use core::marker::PhantomData;
+use core::ops::Deref;
+
+use crate::nearly_eq::NearlyEqTol;
+use crate::nearly_ord::NearlyOrdTol;
+use crate::tolerance::{EpsTolerance, RelTolerance, Tolerance, UlpsTolerance};
+
+/// A type that provides the tolerance used by the [Nearly] wrapper.
+///
+/// Implement this trait for a zero sized marker type to specify a tolerance at the type level.
+///
+/// ```
+/// use nearly::{Nearly, Tolerance, ToleranceProvider};
+///
+/// struct Coarse;
+///
+/// impl ToleranceProvider<f64> for Coarse {
+///     fn tolerance() -> Tolerance<f64> {
+///         Tolerance::new(0.01, 0)
+///     }
+/// }
+///
+/// assert!(Nearly::<f64, Coarse>::new(1.0) == Nearly::new(1.005));
+/// ```
+pub trait ToleranceProvider<T>
+where
+    T: ?Sized + EpsTolerance + UlpsTolerance + RelTolerance,
+{
+    /// Returns the tolerance used to compare values of type `T`.
+    fn tolerance() -> Tolerance<T>;
+}
+
+/// A [ToleranceProvider] that provides the default tolerance of a type.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Default)]
+pub struct DefaultTolerance;
+
+impl<T> ToleranceProvider<T> for DefaultTolerance
+where
+    T: ?Sized + EpsTolerance + UlpsTolerance + RelTolerance,
+{
+    fn tolerance() -> Tolerance<T> {
+        Tolerance::default()
+    }
+}
+
+/// A marker type for [Nearly] to leave NaN values unordered.
+///
+/// The wrapper implements [PartialEq] and [PartialOrd] only, like the primitive floating point
+/// types do.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Default)]
+pub struct NanUnordered;
+
+/// A marker type for [Nearly] to order NaN values before all other values.
+///
+/// The wrapper implements [Eq] and [Ord] for [f32] and [f64]. All NaN values are equal.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Default)]
+pub struct NanFirst;
+
+/// A marker type for [Nearly] to order NaN values after all other values.
+///
+/// The wrapper implements [Eq] and [Ord] for [f32] and [f64]. All NaN values are equal.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Default)]
+pub struct NanLast;
+
+/// A wrapper that implements the std comparison traits by nearly comparisons.
+///
+/// [PartialEq] is implemented by [nearly_eq_tol](NearlyEqTol::nearly_eq_tol) and [PartialOrd]
+/// is implemented by [nearly_cmp_tol](NearlyOrdTol::nearly_cmp_tol), both using the tolerance
+/// provided by `Tol`. This allows to use nearly comparisons with std APIs like
+/// [sort_by](slice::sort_by), `dedup_by` or [binary_search_by](slice::binary_search_by).
+///
+/// If `Nan` is [NanFirst] or [NanLast], the wrapper additionally implements [Eq] and [Ord] for
+/// [f32] and [f64] by placing NaN values first or last. Therefore, it can be used as a key of a
+/// `BTreeMap` or a `BTreeSet`.
+///
+/// Note that nearly equality is not transitive. Values `a`, `b` and `c` might be nearly equal
+/// `a == b` and `b == c`, while `a == c` is not. Hence, the resulting ordering depends on the
+/// order in which values are compared, if values are close to each other.
+///
+/// ```
+/// use nearly::{DefaultTolerance, Nearly, NanLast};
+/// use std::collections::BTreeSet;
+///
+/// type Key = Nearly<f64, DefaultTolerance, NanLast>;
+///
+/// let mut values = vec![3.0, f64::NAN, 1.0, 2.0, 1.0 + 1e-15];
+/// values.sort_by_key(|v| Key::new(*v));
+/// values.dedup_by_key(|v| Key::new(*v));
+/// assert_eq!(values[..3], [1.0, 2.0, 3.0]);
+/// assert!(values[3].is_nan());
+///
+/// let set: BTreeSet<Key> = [1.0, 1.0 + 1e-15, 2.0].into_iter().map(Key::new).collect();
+/// assert_eq!(set.len(), 2);
+/// ```
+pub struct Nearly<T, Tol = DefaultTolerance, Nan = NanUnordered> {
+    value: T,
+    marker: PhantomData<fn() -> (Tol, Nan)>,
+}
+
+impl<T, Tol, Nan> Nearly<T, Tol, Nan> {
+    /// Wraps `value`.
+    pub const fn new(value: T) -> Self {
+        Nearly {
+            value,
+            marker: PhantomData,
+        }
+    }
+
+    /// Returns a reference to the wrapped value.
+    pub const fn get(&self) -> &T {
+        &self.value
+    }
+
+    /// Unwraps the wrapped value.
+    pub fn into_inner(self) -> T {
+        self.value
+    }
+}
+
+impl<T, Tol, Nan> Deref for Nearly<T, Tol, Nan> {
+    type Target = T;
+
+    fn deref(&self) -> &T {
+        &self.value
+    }
+}
+
+impl<T, Tol, Nan> From<T> for Nearly<T, Tol, Nan> {
+    fn from(value: T) -> Self {
+        Nearly::new(value)
+    }
+}
+
+impl<T: Clone, Tol, Nan> Clone for Nearly<T, Tol, Nan> {
+    fn clone(&self) -> Self {
+        Nearly::new(self.value.clone())
+    }
+}
+
+impl<T: Copy, Tol, Nan> Copy for Nearly<T, Tol, Nan> {}
+
+impl<T: Default, Tol, Nan> Default for Nearly<T, Tol, Nan> {
+    fn default() -> Self {
+        Nearly::new(T::default())
+    }
+}
+
+impl<T: Debug, Tol, Nan> Debug for Nearly<T, Tol, Nan> {
+    fn fmt(&self, f: &mut Formatter<'_>) -> Result {
+        f.debug_tuple("Nearly").field(&self.value).finish()
+    }
+}
+
+impl<T, Tol> PartialEq for Nearly<T, Tol, NanUnordered>
+where
+    T: NearlyEqTol + EpsTolerance + UlpsTolerance + RelTolerance,
+    Tol: ToleranceProvider<T>,
+{
+    fn eq(&self, other: &Self) -> bool {
+        self.value.nearly_eq_tol(&other.value, &Tol::tolerance())
+    }
+}
+
+impl<T, Tol> PartialOrd for Nearly<T, Tol, NanUnordered>
+where
+    T: NearlyOrdTol + NearlyEqTol + EpsTolerance + UlpsTolerance + RelTolerance,
+    Tol: ToleranceProvider<T>,
+{
+    fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+        self.value.nearly_cmp_tol(&other.value, &Tol::tolerance())
+    }
+}
+
+macro_rules! impl_nan_ordered {
+    ($float: ty, $nan: ty, $nan_ordering: expr) => {
+        impl<Tol> PartialEq for Nearly<$float, Tol, $nan>
+        where
+            Tol: ToleranceProvider<$float>,
+        {
+            fn eq(&self, other: &Self) -> bool {
+                self.cmp(other) == Ordering::Equal
+            }
+        }
+
+        impl<Tol> Eq for Nearly<$float, Tol, $nan> where Tol: ToleranceProvider<$float> {}
+
+        impl<Tol> PartialOrd for Nearly<$float, Tol, $nan>
+        where
+            Tol: ToleranceProvider<$float>,
+        {
+            fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+                Some(self.cmp(other))
+            }
+        }
+
+        impl<Tol> Ord for Nearly<$float, Tol, $nan>
+        where
+            Tol: ToleranceProvider<$float>,
+        {
+            fn cmp(&self, other: &Self) -> Ordering {
+                match (self.value.is_nan(), other.value.is_nan()) {
+                    (true, true) => Ordering::Equal,
+                    (true, false) => $nan_ordering,
+                    (false, true) => $nan_ordering.reverse(),
+                    // values that are not NaN are always comparable
+                    (false, false) => self
+                        .value
+                        .nearly_cmp_tol(&other.value, &Tol::tolerance())
+                        .unwrap_or(Ordering::Equal),
+                }
+            }
+        }
+    };
+}
+
+impl_nan_ordered!(f32, NanFirst, Ordering::Less);
+impl_nan_ordered!(f64, NanFirst, Ordering::Less);
+impl_nan_ordered!(f32, NanLast, Ordering::Greater);
+impl_nan_ordered!(f64, NanLast, Ordering::Greater);
@@ -0,0 +1,166 @@
+#![cfg(feature = "std")]
+
+use nearly::{assert_nearly, NearlyEq, NearlyEqReport, Tolerance};
+
+#[derive(NearlyEq, NearlyEqReport, Debug)]
+struct Point {
+    x: f32,
+    y: f32,
+}
+
+#[derive(NearlyEq, Debug)]
+struct Unreported {
+    x: f32,
+}
+
+#[test]
+#[should_panic(expected = r#"assertion `nearly (left == right)` failed
+   eps: 0.1
+  diff: 2 of 4 elements differ
+        [1]: 2.0 != 2.5 (diff: 0.5, ulps: 2097152)
+        [3]: 4.0 != 4.5 (diff: 0.5, ulps: 1048576)"#)]
+fn assert_report_eps() {
+    let a = vec![1.0f32, 2.0, 3.0, 4.0];
+    let b = vec![1.0f32, 2.5, 3.0, 4.5];
+    assert_nearly!(a == b, eps = 0.1);
+}
+
+#[test]
+#[should_panic(expected = r#"assertion `nearly (left == right)` failed
+  ulps: 4
+  diff: 1 of 2 elements differ
+        y: 2.0 != 2.5 (diff: 0.5, ulps: 2097152)"#)]
+fn assert_report_ulps() {
+    let a = Point { x: 1.0, y: 2.0 };
+    let b = Point { x: 1.0, y: 2.5 };
+    assert_nearly!(a == b, ulps = 4);
+}
+
+#[test]
+#[should_panic(expected = r#"assertion `nearly (left == right)` failed
+   rel: 0.01
+  diff: 1 of 2 elements differ
+        [0].y: 2.0 != 2.5 (diff: 0.5, ulps: 2097152)"#)]
+fn assert_report_rel() {
+    let a = [Point { x: 1.0, y: 2.0 }];
+    let b = [Point { x: 1.0, y: 2.5 }];
+    assert_nearly!(a == b, rel = 0.01);
+}
+
+#[test]
+#[should_panic(expected = r#"assertion `nearly (left == right)` failed
+   eps: 0.1
+  ulps: 4
+  diff: 1 of 2 elements differ
+        [1]: 2.0 != 2.5 (diff: 0.5, ulps: 1125899906842624)"#)]
+fn assert_report_eps_and_ulps() {
+    let a = [1.0f64, 2.0];
+    let b = [1.0f64, 2.5];
+    assert_nearly!(a == b, eps = 0.1, ulps = 4);
+}
+
+#[test]
+#[should_panic(expected = r#"assertion `nearly (left == right)` failed
+   eps: 0.1
+  ulps: 4
+  diff: 1 of 1 elements differ
+        length 2 != 3"#)]
+fn assert_report_tol() {
+    let a = vec![1.0f64, 2.0];
+    let b = vec![1.0f64, 2.0, 3.0];
+    assert_nearly!(a == b, tol = Tolerance::new(0.1, 4));
+}
+
+#[test]
+#[should_panic(expected = r#"assertion `nearly (left == right)` failed
+   eps: 0.1
+  diff: 37 of 4096 elements differ
+        [0]: 0.0 != 1.0 (diff: 1.0, ulps: 1065353216)"#)]
+fn assert_report_capped() {
+    let a = vec![0.0f32; 4096];
+    let mut b = a.clone();
+    for i in 0..37 {
+        b[i * 100] = 1.0;
+    }
+
+    let result = std::panic::catch_unwind(|| assert_nearly!(a == b, eps = 0.1));
+    let message = *result.unwrap_err().downcast::<String>().unwrap();
+    let lines: Vec<&str> = message.lines().collect();
+    assert_eq!(lines.len(), 3 + 10 + 1);
+    assert_eq!(
+        lines[12],
+        "        [900]: 0.0 != 1.0 (diff: 1.0, ulps: 1065353216)"
+    );
+    assert_eq!(lines[13], "        ... and 27 more");
+
+    std::panic::resume_unwind(Box::new(message));
+}
+
+#[test]
+#[should_panic(expected = r#"assertion `nearly (left == right)` failed
+  left: 1.0
+ right: 1.5
+   eps: 0.1"#)]
+fn assert_report_scalar() {
+    assert_nearly!(1.0f32 == 1.5f32, eps = 0.1);
+}
+
+#[test]
+#[should_panic(expected = r#"assertion `nearly (left == right)` failed
+  left: Unreported { x: 1.0 }
+ right: Unreported { x: 1.5 }
+   eps: 0.1"#)]
+fn assert_report_unreported() {
+    let a = Unreported { x: 1.0 };
+    let b = Unreported { x: 1.5 };
+    assert_nearly!(a == b, eps = 0.1);
+}
+
+#[test]
+#[should_panic(expected = r#"assertion `nearly (left == right)` failed
+   eps: 0.1
+   rel: 0.01
+  diff: 1 of 2 elements differ
+        [0]: 1.0 != 1.5 (diff: 0.5, ulps: 4194304)"#)]
+fn assert_report_eps_and_rel() {
+    let a = [1.0f32, 2.0];
+    let b = [1.5f32, 2.0];
+    assert_nearly!(a == b, eps = 0.1, rel = 0.01);
+}
+
+#[test]
+#[should_panic(expected = r#"assertion `nearly (left == right)` failed
+  ulps: 4
+   rel: 0.1
+  diff: 1 of 2 elements differ
+        x: 1.0 != 1.5 (diff: 0.5, ulps: 4194304)"#)]
+fn assert_report_ulps_and_rel() {
+    // y is only nearly equal based on rel, x on neither tolerance
+    let a = Point { x: 1.0, y: 2.0 };
+    let b = Point { x: 1.5, y: 2.1 };
+    assert_nearly!(a == b, ulps = 4, rel = 0.1);
+}
+
+#[test]
+#[should_panic(expected = r#"assertion `nearly (left == right)` failed
+   eps: 0.1
+  ulps: 4
+   rel: 0.01
+  diff: 1 of 3 elements differ
+        [2]: 3.0 != 3.5 (diff: 0.5, ulps: 2097152)"#)]
+fn assert_report_eps_and_ulps_and_rel() {
+    // the second elements are only nearly equal based on rel
+    let a = vec![1.0f32, 200.0, 3.0];
+    let b = vec![1.05f32, 201.0, 3.5];
+    assert_nearly!(a == b, eps = 0.1, ulps = 4, rel = 0.01);
+}
+
+#[test]
+#[should_panic(expected = r#"assertion `nearly (left != right)` failed
+  left: [1.0, 2.0]
+ right: [1.0, 2.0]
+   eps: 0.1"#)]
+fn assert_report_ne() {
+    let a = [1.0f32, 2.0];
+    assert_nearly!(a != a, eps = 0.1);
+}
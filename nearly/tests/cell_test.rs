@@ -0,0 +1,187 @@
+use core::cell::{Cell, RefCell};
+use nearly::{
+    assert_nearly, nearly, EpsTolerance, NearlyEq, NearlyEqEps, NearlyEqRel, NearlyEqTol,
+    NearlyEqUlps, NearlyOrd, NearlyOrdEps, NearlyOrdRel, NearlyOrdTol, NearlyOrdUlps, Tolerance,
+};
+
+#[test]
+fn cell_eq() {
+    let a = Cell::new(1.0f64);
+    let b = Cell::new(1.05f64);
+
+    assert!(a.nearly_eq_eps(&b, &0.1));
+    assert!(!a.nearly_eq_eps(&b, &0.01));
+    assert!(a.nearly_eq_ulps(&a, &0));
+    assert!(a.nearly_eq_rel(&b, &0.1));
+    assert!(a.nearly_eq_tol(&b, &Tolerance::new(0.1, 0)));
+    assert!(!a.nearly_eq(&b));
+
+    b.set(1.0);
+    assert!(a.nearly_eq(&b));
+    assert_nearly!(a == b);
+}
+
+#[test]
+fn cell_ord() {
+    let a = Cell::new(1.0f32);
+    let b = Cell::new(2.0f32);
+
+    assert!(a.nearly_lt_eps(&b, &0.1));
+    assert!(a.nearly_le_ulps(&b, &4));
+    assert!(b.nearly_gt_rel(&a, &0.1));
+    assert!(b.nearly_ge_tol(&a, &Tolerance::new(0.1, 4)));
+    assert!(a.nearly_lt(&b));
+    assert_nearly!(a < b, eps = 0.1);
+}
+
+#[test]
+fn ref_cell_eq() {
+    let a = RefCell::new([1.0f64, 2.0]);
+    let b = RefCell::new([1.05f64, 2.0]);
+
+    assert!(a.nearly_eq_eps(&b, &0.1));
+    assert!(!a.nearly_eq_eps(&b, &0.01));
+    assert!(a.nearly_eq_eps(&a, &0.0));
+
+    b.borrow_mut()[0] = 1.0;
+    assert!(a.nearly_eq_ulps(&b, &0));
+    assert!(nearly!(a == b, eps = 0.01));
+
+    let _guard = a.borrow();
+    assert!(a.nearly_le_eps(&b, &0.01));
+}
+
+#[test]
+#[should_panic]
+fn ref_cell_mutably_borrowed() {
+    let a = RefCell::new(1.0f64);
+    let b = RefCell::new(1.0f64);
+
+    let _guard = a.borrow_mut();
+    a.nearly_eq_eps(&b, &0.1);
+}
+
+#[derive(Debug, NearlyEq, NearlyOrd)]
+struct State {
+    position: Cell<f64>,
+    velocity: Cell<f64>,
+}
+
+#[test]
+fn cell_derive() {
+    assert_eq!(
+        <Cell<f64> as EpsTolerance>::DEFAULT,
+        <f64 as EpsTolerance>::DEFAULT
+    );
+
+    let a = State {
+        position: Cell::new(1.0),
+        velocity: Cell::new(2.0),
+    };
+    let b = State {
+        position: Cell::new(1.05),
+        velocity: Cell::new(2.0),
+    };
+
+    assert_nearly!(a == b, eps = 0.1);
+    assert_nearly!(a != b, eps = 0.01);
+    assert_nearly!(a <= b, eps = 0.01);
+}
+
+#[cfg(feature = "std")]
+mod std_cell {
+    use super::*;
+    use std::borrow::Cow;
+    use std::rc::{self, Rc};
+    use std::sync::{self, Arc, Mutex, RwLock};
+    use std::thread;
+
+    #[test]
+    fn cow_eq() {
+        let values = [1.0f64, 2.0];
+        let a: Cow<[f64]> = Cow::Borrowed(&values);
+        let b: Cow<[f64]> = Cow::Owned(vec![1.05, 2.0]);
+
+        assert!(a.nearly_eq_eps(&b, &0.1));
+        assert!(!a.nearly_eq_eps(&b, &0.01));
+        assert!(a.nearly_le_tol(&b, &Tolerance::new(0.01, 0)));
+        assert!(!a.nearly_gt_rel(&b, &0.01));
+    }
+
+    #[test]
+    fn mutex_eq() {
+        let a = Mutex::new(vec![1.0f32, 2.0]);
+        let b = Mutex::new(vec![1.05f32, 2.0]);
+
+        assert!(a.nearly_eq_eps(&b, &0.1));
+        assert!(!a.nearly_eq_eps(&b, &0.01));
+        assert!(a.nearly_lt_ulps(&Mutex::new(vec![2.0, 3.0]), &4));
+        assert!(nearly!(a == b, eps = 0.1));
+    }
+
+    #[test]
+    fn mutex_poisoned() {
+        let a = Arc::new(Mutex::new(1.0f64));
+        let b = Mutex::new(1.0f64);
+
+        let poison = Arc::clone(&a);
+        let _ = thread::spawn(move || {
+            let _guard = poison.lock().unwrap();
+            panic!("poison the mutex");
+        })
+        .join();
+
+        assert!(a.is_poisoned());
+        assert!(a.nearly_eq_eps(&b, &0.1));
+        assert!(b.nearly_eq_eps(&a, &0.1));
+    }
+
+    #[test]
+    fn rw_lock_eq() {
+        let a = RwLock::new(1.0f64);
+        let b = RwLock::new(1.05f64);
+
+        assert!(a.nearly_eq_eps(&b, &0.1));
+        assert!(!a.nearly_eq_rel(&b, &0.01));
+        assert!(a.nearly_eq_eps(&a, &0.0));
+        assert!(b.nearly_gt_eps(&a, &0.01));
+        assert!(nearly!(a < b, eps = 0.01));
+
+        let _guard = a.read().unwrap();
+        assert!(a.nearly_eq_eps(&b, &0.1));
+    }
+
+    #[test]
+    fn rc_weak_eq() {
+        let a = Rc::new(1.0f64);
+        let b = Rc::new(1.05f64);
+        let weak_a = Rc::downgrade(&a);
+        let weak_b = Rc::downgrade(&b);
+        let dangling: rc::Weak<f64> = rc::Weak::new();
+
+        assert!(weak_a.nearly_eq_eps(&weak_b, &0.1));
+        assert!(!weak_a.nearly_eq_eps(&weak_b, &0.01));
+        assert!(weak_a.nearly_lt_eps(&weak_b, &0.01));
+        assert!(!weak_a.nearly_eq_eps(&dangling, &0.1));
+        assert!(dangling.nearly_eq_eps(&rc::Weak::new(), &0.1));
+        assert!(dangling.nearly_lt_ulps(&weak_a, &4));
+
+        drop(b);
+        assert!(!weak_a.nearly_eq_eps(&weak_b, &0.1));
+        assert!(weak_a.nearly_gt_eps(&weak_b, &0.1));
+    }
+
+    #[test]
+    fn sync_weak_eq() {
+        let a = Arc::new(1.0f32);
+        let b = Arc::new(1.05f32);
+        let weak_a = Arc::downgrade(&a);
+        let weak_b = Arc::downgrade(&b);
+        let dangling: sync::Weak<f32> = sync::Weak::new();
+
+        assert!(weak_a.nearly_eq_tol(&weak_b, &Tolerance::new(0.1, 0)));
+        assert!(!weak_a.nearly_eq_rel(&weak_b, &0.01));
+        assert!(weak_a.nearly_ge_rel(&dangling, &0.01));
+        assert!(dangling.nearly_le_tol(&weak_a, &Tolerance::new(0.1, 0)));
+    }
+}
@@ -1,7 +1,8 @@
 use mockall::predicate::eq;
 use mockall::Sequence;
 use nearly::{
-    NearlyEqEps, NearlyEqTol, NearlyEqUlps, NearlyOrdEps, NearlyOrdTol, NearlyOrdUlps, Tolerance,
+    NearlyEqEps, NearlyEqRel, NearlyEqTol, NearlyEqUlps, NearlyOrdEps, NearlyOrdRel, NearlyOrdTol,
+    NearlyOrdUlps, Tolerance,
 };
 use paste::paste;
 
@@ -9,7 +10,16 @@ mod common;
 use common::{MockLhs, Rhs};
 
 macro_rules! checkpoint {
-    ($container: expr) => {
+    ($container: expr, rc) => {
+        checkpoint!(Rc::get_mut(&mut $container).expect("Shared pointer"), slice)
+    };
+    ($container: expr, arc) => {
+        checkpoint!(
+            Arc::get_mut(&mut $container).expect("Shared pointer"),
+            slice
+        )
+    };
+    ($container: expr, $_coll: tt) => {
         for i in $container.iter_mut() {
             i.checkpoint();
         }
@@ -32,6 +42,15 @@ macro_rules! lhs_value {
     (linked_list) => {
         LinkedList::from([MockLhs::new(), MockLhs::new(), MockLhs::new()])
     };
+    (boxed) => {
+        [MockLhs::new(), MockLhs::new(), MockLhs::new()].into()
+    };
+    (rc) => {
+        Vec::from([MockLhs::new(), MockLhs::new(), MockLhs::new()]).into()
+    };
+    (arc) => {
+        Vec::from([MockLhs::new(), MockLhs::new(), MockLhs::new()]).into()
+    };
 }
 
 macro_rules! lhs_value_short {
@@ -50,6 +69,15 @@ macro_rules! lhs_value_short {
     (linked_list) => {
         LinkedList::from([MockLhs::new(), MockLhs::new()])
     };
+    (boxed) => {
+        [MockLhs::new(), MockLhs::new()].into()
+    };
+    (rc) => {
+        Vec::from([MockLhs::new(), MockLhs::new()]).into()
+    };
+    (arc) => {
+        Vec::from([MockLhs::new(), MockLhs::new()]).into()
+    };
 }
 
 macro_rules! rhs_value {
@@ -68,6 +96,15 @@ macro_rules! rhs_value {
     (linked_list) => {
         LinkedList::from([Rhs(3), Rhs(7), Rhs(11)])
     };
+    (boxed) => {
+        [Rhs(3), Rhs(7), Rhs(11)].into()
+    };
+    (rc) => {
+        Vec::from([Rhs(3), Rhs(7), Rhs(11)]).into()
+    };
+    (arc) => {
+        Vec::from([Rhs(3), Rhs(7), Rhs(11)]).into()
+    };
 }
 
 macro_rules! rhs_value_short {
@@ -86,6 +123,15 @@ macro_rules! rhs_value_short {
     (linked_list) => {
         LinkedList::from([Rhs(3), Rhs(7)])
     };
+    (boxed) => {
+        [Rhs(3), Rhs(7)].into()
+    };
+    (rc) => {
+        Vec::from([Rhs(3), Rhs(7)]).into()
+    };
+    (arc) => {
+        Vec::from([Rhs(3), Rhs(7)]).into()
+    };
 }
 
 macro_rules! get_type {
@@ -100,6 +146,15 @@ macro_rules! get_type {
     };
     ($inner: ty, linked_list) => {
         LinkedList<$inner>
+    };
+    ($inner: ty, boxed) => {
+        Box<[$inner]>
+    };
+    ($inner: ty, rc) => {
+        Rc<[$inner]>
+    };
+    ($inner: ty, arc) => {
+        Arc<[$inner]>
     }
 }
 
@@ -140,6 +195,12 @@ macro_rules! rhs_type_short {
 }
 
 macro_rules! get_element {
+    ($container: expr, $idx: expr, rc) => {
+        Rc::get_mut(&mut $container).expect("Shared pointer")[$idx]
+    };
+    ($container: expr, $idx: expr, arc) => {
+        Arc::get_mut(&mut $container).expect("Shared pointer")[$idx]
+    };
     ($container: expr, $idx: expr, linked_list) => {{
         let mut iter = $container.iter_mut();
         #[allow(clippy::reversed_empty_ranges)]
@@ -202,7 +263,7 @@ macro_rules! impl_test_same_length {
 
                 assert!(a.[<nearly_ $fn _eps>](&b, &0.1));
 
-                checkpoint!(a);
+                checkpoint!(a, $lhs);
                 get_element!(a, 0, $lhs).[<expect_nearly_ $fn _eps>]()
                     .with(eq(Rhs(3)), eq(0.1))
                     .times(1)
@@ -213,7 +274,7 @@ macro_rules! impl_test_same_length {
 
                 assert!(!a.[<nearly_ $fn _eps>](&b, &0.1));
 
-                checkpoint!(a);
+                checkpoint!(a, $lhs);
                 get_element!(a, 0, $lhs).[<expect_nearly_ $fn _eps>]()
                     .with(eq(Rhs(3)), eq(0.1))
                     .times(1)
@@ -228,7 +289,7 @@ macro_rules! impl_test_same_length {
 
                 assert!(!a.[<nearly_ $fn _eps>](&b, &0.1));
 
-                checkpoint!(a);
+                checkpoint!(a, $lhs);
                 get_element!(a, 0, $lhs).[<expect_nearly_ $fn _eps>]()
                     .with(eq(Rhs(3)), eq(0.1))
                     .times(1)
@@ -248,6 +309,78 @@ macro_rules! impl_test_same_length {
                 assert!(!a.[<nearly_ $fn _eps>](&b, &0.1));
             }
 
+            #[test]
+            fn [<nearly_ $fn _rel_ $lhs _ $rhs>]() {
+                #[allow(unused_mut)]
+                let mut a: lhs_type!($lhs) = lhs_value!($lhs);
+                let b: rhs_type!($rhs)  = rhs_value!($rhs);
+
+                let mut seq = Sequence::new();
+
+                get_element!(a, 0, $lhs).[<expect_nearly_ $fn _rel>]()
+                    .with(eq(Rhs(3)), eq(0.1))
+                    .times(1)
+                    .in_sequence(&mut seq)
+                    .return_const(true);
+                get_element!(a, 1, $lhs).[<expect_nearly_ $fn _rel>]()
+                    .with(eq(Rhs(7)), eq(0.1))
+                    .times(1)
+                    .in_sequence(&mut seq)
+                    .return_const(true);
+                get_element!(a, 2, $lhs).[<expect_nearly_ $fn _rel>]()
+                    .with(eq(Rhs(11)), eq(0.1))
+                    .times(1)
+                    .in_sequence(&mut seq)
+                    .return_const(true);
+
+                assert!(a.[<nearly_ $fn _rel>](&b, &0.1));
+
+                checkpoint!(a, $lhs);
+                get_element!(a, 0, $lhs).[<expect_nearly_ $fn _rel>]()
+                    .with(eq(Rhs(3)), eq(0.1))
+                    .times(1)
+                    .in_sequence(&mut seq)
+                    .return_const(false);
+                get_element!(a, 1, $lhs).[<expect_nearly_ $fn _rel>]().times(0);
+                get_element!(a, 2, $lhs).[<expect_nearly_ $fn _rel>]().times(0);
+
+                assert!(!a.[<nearly_ $fn _rel>](&b, &0.1));
+
+                checkpoint!(a, $lhs);
+                get_element!(a, 0, $lhs).[<expect_nearly_ $fn _rel>]()
+                    .with(eq(Rhs(3)), eq(0.1))
+                    .times(1)
+                    .in_sequence(&mut seq)
+                    .return_const(true);
+                get_element!(a, 1, $lhs).[<expect_nearly_ $fn _rel>]()
+                    .with(eq(Rhs(7)), eq(0.1))
+                    .times(1)
+                    .in_sequence(&mut seq)
+                    .return_const(false);
+                get_element!(a, 2, $lhs).[<expect_nearly_ $fn _rel>]().times(0);
+
+                assert!(!a.[<nearly_ $fn _rel>](&b, &0.1));
+
+                checkpoint!(a, $lhs);
+                get_element!(a, 0, $lhs).[<expect_nearly_ $fn _rel>]()
+                    .with(eq(Rhs(3)), eq(0.1))
+                    .times(1)
+                    .in_sequence(&mut seq)
+                    .return_const(true);
+                get_element!(a, 1, $lhs).[<expect_nearly_ $fn _rel>]()
+                    .with(eq(Rhs(7)), eq(0.1))
+                    .times(1)
+                    .in_sequence(&mut seq)
+                    .return_const(true);
+                get_element!(a, 2, $lhs).[<expect_nearly_ $fn _rel>]()
+                    .with(eq(Rhs(11)), eq(0.1))
+                    .times(1)
+                    .in_sequence(&mut seq)
+                    .return_const(false);
+
+                assert!(!a.[<nearly_ $fn _rel>](&b, &0.1));
+            }
+
             #[test]
             fn [<nearly_ $fn _ulps_ $lhs _ $rhs>]() {
                 #[allow(unused_mut)]
@@ -274,7 +407,7 @@ macro_rules! impl_test_same_length {
 
                 assert!(a.[<nearly_ $fn _ulps>](&b, &5));
 
-                checkpoint!(a);
+                checkpoint!(a, $lhs);
                 get_element!(a, 0, $lhs).[<expect_nearly_ $fn _ulps>]()
                     .with(eq(Rhs(3)), eq(5))
                     .times(1)
@@ -285,7 +418,7 @@ macro_rules! impl_test_same_length {
 
                 assert!(!a.[<nearly_ $fn _ulps>](&b, &5));
 
-                checkpoint!(a);
+                checkpoint!(a, $lhs);
                 get_element!(a, 0, $lhs).[<expect_nearly_ $fn _ulps>]()
                     .with(eq(Rhs(3)), eq(5))
                     .times(1)
@@ -300,7 +433,7 @@ macro_rules! impl_test_same_length {
 
                 assert!(!a.[<nearly_ $fn _ulps>](&b, &5));
 
-                checkpoint!(a);
+                checkpoint!(a, $lhs);
                 get_element!(a, 0, $lhs).[<expect_nearly_ $fn _ulps>]()
                     .with(eq(Rhs(3)), eq(5))
                     .times(1)
@@ -346,7 +479,7 @@ macro_rules! impl_test_same_length {
 
                 assert!(a.[<nearly_ $fn _tol>](&b, &Tolerance::<MockLhs, Rhs>::new(0.1, 5)));
 
-                checkpoint!(a);
+                checkpoint!(a, $lhs);
                 get_element!(a, 0, $lhs).[<expect_nearly_ $fn _tol>]()
                     .with(eq(Rhs(3)), eq(Tolerance::<MockLhs, Rhs>::new(0.1, 5)))
                     .times(1)
@@ -357,7 +490,7 @@ macro_rules! impl_test_same_length {
 
                 assert!(!a.[<nearly_ $fn _tol>](&b, &Tolerance::<MockLhs, Rhs>::new(0.1, 5)));
 
-                checkpoint!(a);
+                checkpoint!(a, $lhs);
                 get_element!(a, 0, $lhs).[<expect_nearly_ $fn _tol>]()
                     .with(eq(Rhs(3)), eq(Tolerance::<MockLhs, Rhs>::new(0.1, 5)))
                     .times(1)
@@ -372,7 +505,7 @@ macro_rules! impl_test_same_length {
 
                 assert!(!a.[<nearly_ $fn _tol>](&b, &Tolerance::<MockLhs, Rhs>::new(0.1, 5)));
 
-                checkpoint!(a);
+                checkpoint!(a, $lhs);
                 get_element!(a, 0, $lhs).[<expect_nearly_ $fn _tol>]()
                     .with(eq(Rhs(3)), eq(Tolerance::<MockLhs, Rhs>::new(0.1, 5)))
                     .times(1)
@@ -427,6 +560,35 @@ macro_rules! impl_test_different_length {
                 }
             }
 
+            #[test]
+            fn [<nearly_ $fn _rel_different_length_ $lhs _ $rhs>]() {
+                {
+                    #[allow(unused_mut)]
+                    let mut a: lhs_type!($lhs) = lhs_value!($lhs);
+                    let b: rhs_type_short!($rhs)  = rhs_value_short!($rhs);
+
+                    assert!(a.len() > b.len());
+
+                    get_element!(a, 0, $lhs).[<expect_nearly_ $fn _rel>]().times(0);
+                    get_element!(a, 1, $lhs).[<expect_nearly_ $fn _rel>]().times(0);
+                    get_element!(a, 2, $lhs).[<expect_nearly_ $fn _rel>]().times(0);
+
+                    assert!(!a.[<nearly_ $fn _rel>](&b, &0.1));
+                }
+                {
+                    #[allow(unused_mut)]
+                    let mut a: lhs_type_short!($lhs) = lhs_value_short!($lhs);
+                    let b: rhs_type!($rhs)  = rhs_value!($rhs);
+
+                    assert!(a.len() < b.len());
+
+                    get_element!(a, 0, $lhs).[<expect_nearly_ $fn _rel>]().times(0);
+                    get_element!(a, 1, $lhs).[<expect_nearly_ $fn _rel>]().times(0);
+
+                    assert!(!a.[<nearly_ $fn _rel>](&b, &0.1));
+                }
+            }
+
             #[test]
             fn [<nearly_ $fn _ulps_different_length_ $lhs _ $rhs>]() {
                 {
@@ -497,20 +659,78 @@ impl_test!(slice, array);
 mod std_types {
     use super::*;
     use std::collections::{LinkedList, VecDeque};
+    use std::rc::Rc;
+    use std::sync::Arc;
 
     impl_test!(vec, vec);
     impl_test!(vec, vec_deque);
+    impl_test!(vec, linked_list);
+    impl_test!(vec, boxed);
+    impl_test!(vec, rc);
+    impl_test!(vec, arc);
     impl_test!(vec, array);
     impl_test!(vec, slice);
+
     impl_test!(array, vec);
     impl_test!(slice, vec);
 
-    impl_test!(vec_deque, vec_deque);
     impl_test!(vec_deque, vec);
+    impl_test!(vec_deque, vec_deque);
+    impl_test!(vec_deque, linked_list);
+    impl_test!(vec_deque, boxed);
+    impl_test!(vec_deque, rc);
+    impl_test!(vec_deque, arc);
     impl_test!(vec_deque, array);
     impl_test!(vec_deque, slice);
+
     impl_test!(array, vec_deque);
     impl_test!(slice, vec_deque);
 
+    impl_test!(linked_list, vec);
+    impl_test!(linked_list, vec_deque);
     impl_test!(linked_list, linked_list);
+    impl_test!(linked_list, boxed);
+    impl_test!(linked_list, rc);
+    impl_test!(linked_list, arc);
+    impl_test!(linked_list, array);
+    impl_test!(linked_list, slice);
+
+    impl_test!(array, linked_list);
+    impl_test!(slice, linked_list);
+
+    impl_test!(boxed, vec);
+    impl_test!(boxed, vec_deque);
+    impl_test!(boxed, linked_list);
+    impl_test!(boxed, boxed);
+    impl_test!(boxed, rc);
+    impl_test!(boxed, arc);
+    impl_test!(boxed, array);
+    impl_test!(boxed, slice);
+
+    impl_test!(array, boxed);
+    impl_test!(slice, boxed);
+
+    impl_test!(rc, vec);
+    impl_test!(rc, vec_deque);
+    impl_test!(rc, linked_list);
+    impl_test!(rc, boxed);
+    impl_test!(rc, rc);
+    impl_test!(rc, arc);
+    impl_test!(rc, array);
+    impl_test!(rc, slice);
+
+    impl_test!(array, rc);
+    impl_test!(slice, rc);
+
+    impl_test!(arc, vec);
+    impl_test!(arc, vec_deque);
+    impl_test!(arc, linked_list);
+    impl_test!(arc, boxed);
+    impl_test!(arc, rc);
+    impl_test!(arc, arc);
+    impl_test!(arc, array);
+    impl_test!(arc, slice);
+
+    impl_test!(array, arc);
+    impl_test!(slice, arc);
 }
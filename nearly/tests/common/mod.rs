@@ -1,9 +1,11 @@
 use mockall::mock;
 use nearly::{
-    EpsTolerance, EpsToleranceType, NearlyEq, NearlyEqEps, NearlyEqTol, NearlyEqUlps, NearlyOrd,
-    NearlyOrdEps, NearlyOrdTol, NearlyOrdUlps, Tolerance, UlpsTolerance, UlpsToleranceType,
+    EpsTolerance, EpsToleranceType, NearlyEq, NearlyEqEps, NearlyEqRel, NearlyEqTol, NearlyEqUlps,
+    NearlyOrd, NearlyOrdEps, NearlyOrdRel, NearlyOrdTol, NearlyOrdUlps, RelTolerance,
+    RelToleranceType, Tolerance, UlpsTolerance, UlpsToleranceType,
 };
 
+#[allow(dead_code)]
 #[derive(Debug, PartialEq)]
 pub struct Rhs(pub i32);
 
@@ -21,6 +23,11 @@ mock!(
         const DEFAULT: i32 = 3;
     }
 
+    impl RelTolerance<Rhs> for Lhs {
+        type T = f32;
+        const DEFAULT: f32 = 0.001;
+    }
+
     impl NearlyEqEps<Rhs> for Lhs {
         fn nearly_eq_eps(&self, other: &Rhs, eps: &EpsToleranceType<Self, Rhs>) -> bool;
         fn nearly_ne_eps(&self, other: &Rhs, eps: &EpsToleranceType<Self, Rhs>) -> bool;
@@ -31,6 +38,11 @@ mock!(
         fn nearly_ne_ulps(&self, other: &Rhs, ulps: &UlpsToleranceType<Self, Rhs>) -> bool;
     }
 
+    impl NearlyEqRel<Rhs> for Lhs {
+        fn nearly_eq_rel(&self, other: &Rhs, rel: &RelToleranceType<Self, Rhs>) -> bool;
+        fn nearly_ne_rel(&self, other: &Rhs, rel: &RelToleranceType<Self, Rhs>) -> bool;
+    }
+
     impl NearlyEqTol<Rhs> for Lhs {
         fn nearly_eq_tol(&self, other: &Rhs, tol: &Tolerance<Self, Rhs>) -> bool;
         fn nearly_ne_tol(&self, other: &Rhs, tol: &Tolerance<Self, Rhs>) -> bool;
@@ -55,6 +67,13 @@ mock!(
         fn nearly_ge_ulps(&self, other: &Rhs, ulps: &UlpsToleranceType<Self, Rhs>) -> bool;
     }
 
+    impl NearlyOrdRel<Rhs> for Lhs {
+        fn nearly_lt_rel(&self, other: &Rhs, rel: &RelToleranceType<Self, Rhs>) -> bool;
+        fn nearly_le_rel(&self, other: &Rhs, rel: &RelToleranceType<Self, Rhs>) -> bool;
+        fn nearly_gt_rel(&self, other: &Rhs, rel: &RelToleranceType<Self, Rhs>) -> bool;
+        fn nearly_ge_rel(&self, other: &Rhs, rel: &RelToleranceType<Self, Rhs>) -> bool;
+    }
+
     impl NearlyOrdTol<Rhs> for Lhs {
         fn nearly_lt_tol(&self, other: &Rhs, tol: &Tolerance<Self, Rhs>) -> bool;
         fn nearly_le_tol(&self, other: &Rhs, tol: &Tolerance<Self, Rhs>) -> bool;
@@ -81,3 +100,18 @@ macro_rules! inner_value {
         }
     }};
 }
+
+/// Expected result of the comparison `fn` of two values without compared fields.
+/// Like values of `()` they are equal, so only the strict comparisons are false.
+#[macro_export]
+macro_rules! empty_cmp {
+    (lt) => {
+        false
+    };
+    (gt) => {
+        false
+    };
+    ($fn: ident) => {
+        true
+    };
+}
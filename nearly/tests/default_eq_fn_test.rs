@@ -1,7 +1,7 @@
 use mockall::{mock, predicate::eq, Sequence};
 use nearly::{
-    EpsTolerance, EpsToleranceType, NearlyEq, NearlyEqEps, NearlyEqTol, NearlyEqUlps, Tolerance,
-    UlpsTolerance, UlpsToleranceType,
+    EpsTolerance, EpsToleranceType, NearlyEq, NearlyEqEps, NearlyEqRel, NearlyEqTol, NearlyEqUlps,
+    RelTolerance, RelToleranceType, Tolerance, ToleranceMode, UlpsTolerance, UlpsToleranceType,
 };
 
 #[derive(Debug, PartialEq)]
@@ -20,6 +20,11 @@ mock!(
         const DEFAULT: i32 = 3;
     }
 
+    impl RelTolerance<Rhs> for Lhs {
+        type T = f32;
+        const DEFAULT: f32 = 0.01;
+    }
+
     impl NearlyEqEps<Rhs> for Lhs {
         fn nearly_eq_eps(&self, other: &Rhs, eps: &EpsToleranceType<Self, Rhs>) -> bool;
     }
@@ -28,6 +33,10 @@ mock!(
         fn nearly_eq_ulps(&self, other: &Rhs, ulps: &UlpsToleranceType<Self, Rhs>) -> bool;
     }
 
+    impl NearlyEqRel<Rhs> for Lhs {
+        fn nearly_eq_rel(&self, other: &Rhs, rel: &RelToleranceType<Self, Rhs>) -> bool;
+    }
+
     impl NearlyEqTol<Rhs> for Lhs {}
 
     impl NearlyEq<Rhs> for Lhs {}
@@ -161,6 +170,284 @@ fn nearly_ne_tol() {
     assert!(a.nearly_ne_tol(&b, &Tolerance::<MockLhs, Rhs>::new(0.1, 5)));
 }
 
+#[test]
+fn nearly_eq_tol_rel() {
+    let mut seq = Sequence::new();
+
+    let mut a = MockLhs::new();
+    let b = Rhs(5);
+
+    a.expect_nearly_eq_eps()
+        .with(eq(Rhs(5)), eq(0.1))
+        .times(1)
+        .in_sequence(&mut seq)
+        .return_const(true);
+    a.expect_nearly_eq_ulps().times(0);
+    a.expect_nearly_eq_rel().times(0);
+    assert!(a.nearly_eq_tol(&b, &Tolerance::<MockLhs, Rhs>::new(0.1, 5).with_rel(0.2)));
+
+    a.checkpoint();
+
+    a.expect_nearly_eq_eps()
+        .with(eq(Rhs(5)), eq(0.1))
+        .times(1)
+        .in_sequence(&mut seq)
+        .return_const(false);
+    a.expect_nearly_eq_ulps()
+        .with(eq(Rhs(5)), eq(5))
+        .times(1)
+        .in_sequence(&mut seq)
+        .return_const(false);
+    a.expect_nearly_eq_rel()
+        .with(eq(Rhs(5)), eq(0.2))
+        .times(1)
+        .in_sequence(&mut seq)
+        .return_const(true);
+    assert!(a.nearly_eq_tol(&b, &Tolerance::<MockLhs, Rhs>::new(0.1, 5).with_rel(0.2)));
+
+    a.checkpoint();
+
+    a.expect_nearly_eq_eps()
+        .with(eq(Rhs(5)), eq(0.1))
+        .times(1)
+        .in_sequence(&mut seq)
+        .return_const(false);
+    a.expect_nearly_eq_ulps()
+        .with(eq(Rhs(5)), eq(5))
+        .times(1)
+        .in_sequence(&mut seq)
+        .return_const(false);
+    a.expect_nearly_eq_rel()
+        .with(eq(Rhs(5)), eq(0.2))
+        .times(1)
+        .in_sequence(&mut seq)
+        .return_const(false);
+    assert!(!a.nearly_eq_tol(&b, &Tolerance::<MockLhs, Rhs>::new(0.1, 5).with_rel(0.2)));
+}
+
+#[test]
+fn nearly_ne_tol_rel() {
+    let mut seq = Sequence::new();
+
+    let mut a = MockLhs::new();
+    let b = Rhs(5);
+
+    a.expect_nearly_eq_eps()
+        .with(eq(Rhs(5)), eq(0.1))
+        .times(1)
+        .in_sequence(&mut seq)
+        .return_const(true);
+    a.expect_nearly_eq_ulps().times(0);
+    a.expect_nearly_eq_rel().times(0);
+    assert!(!a.nearly_ne_tol(&b, &Tolerance::<MockLhs, Rhs>::new(0.1, 5).with_rel(0.2)));
+
+    a.checkpoint();
+
+    a.expect_nearly_eq_eps()
+        .with(eq(Rhs(5)), eq(0.1))
+        .times(1)
+        .in_sequence(&mut seq)
+        .return_const(false);
+    a.expect_nearly_eq_ulps()
+        .with(eq(Rhs(5)), eq(5))
+        .times(1)
+        .in_sequence(&mut seq)
+        .return_const(false);
+    a.expect_nearly_eq_rel()
+        .with(eq(Rhs(5)), eq(0.2))
+        .times(1)
+        .in_sequence(&mut seq)
+        .return_const(true);
+    assert!(!a.nearly_ne_tol(&b, &Tolerance::<MockLhs, Rhs>::new(0.1, 5).with_rel(0.2)));
+
+    a.checkpoint();
+
+    a.expect_nearly_eq_eps()
+        .with(eq(Rhs(5)), eq(0.1))
+        .times(1)
+        .in_sequence(&mut seq)
+        .return_const(false);
+    a.expect_nearly_eq_ulps()
+        .with(eq(Rhs(5)), eq(5))
+        .times(1)
+        .in_sequence(&mut seq)
+        .return_const(false);
+    a.expect_nearly_eq_rel()
+        .with(eq(Rhs(5)), eq(0.2))
+        .times(1)
+        .in_sequence(&mut seq)
+        .return_const(false);
+    assert!(a.nearly_ne_tol(&b, &Tolerance::<MockLhs, Rhs>::new(0.1, 5).with_rel(0.2)));
+}
+
+#[test]
+fn nearly_eq_tol_all() {
+    let mut seq = Sequence::new();
+
+    let mut a = MockLhs::new();
+    let b = Rhs(5);
+
+    a.expect_nearly_eq_eps()
+        .with(eq(Rhs(5)), eq(0.1))
+        .times(1)
+        .in_sequence(&mut seq)
+        .return_const(false);
+    a.expect_nearly_eq_ulps().times(0);
+    assert!(!a.nearly_eq_tol(
+        &b,
+        &Tolerance::<MockLhs, Rhs>::new(0.1, 5).with_mode(ToleranceMode::All)
+    ));
+
+    a.checkpoint();
+
+    a.expect_nearly_eq_eps()
+        .with(eq(Rhs(5)), eq(0.1))
+        .times(1)
+        .in_sequence(&mut seq)
+        .return_const(true);
+    a.expect_nearly_eq_ulps()
+        .with(eq(Rhs(5)), eq(5))
+        .times(1)
+        .in_sequence(&mut seq)
+        .return_const(true);
+    assert!(a.nearly_eq_tol(
+        &b,
+        &Tolerance::<MockLhs, Rhs>::new(0.1, 5).with_mode(ToleranceMode::All)
+    ));
+
+    a.checkpoint();
+
+    a.expect_nearly_eq_eps()
+        .with(eq(Rhs(5)), eq(0.1))
+        .times(1)
+        .in_sequence(&mut seq)
+        .return_const(true);
+    a.expect_nearly_eq_ulps()
+        .with(eq(Rhs(5)), eq(5))
+        .times(1)
+        .in_sequence(&mut seq)
+        .return_const(true);
+    a.expect_nearly_eq_rel()
+        .with(eq(Rhs(5)), eq(0.2))
+        .times(1)
+        .in_sequence(&mut seq)
+        .return_const(true);
+    assert!(a.nearly_eq_tol(
+        &b,
+        &Tolerance::<MockLhs, Rhs>::new(0.1, 5)
+            .with_rel(0.2)
+            .with_mode(ToleranceMode::All)
+    ));
+
+    a.checkpoint();
+
+    a.expect_nearly_eq_eps()
+        .with(eq(Rhs(5)), eq(0.1))
+        .times(1)
+        .in_sequence(&mut seq)
+        .return_const(true);
+    a.expect_nearly_eq_ulps()
+        .with(eq(Rhs(5)), eq(5))
+        .times(1)
+        .in_sequence(&mut seq)
+        .return_const(true);
+    a.expect_nearly_eq_rel()
+        .with(eq(Rhs(5)), eq(0.2))
+        .times(1)
+        .in_sequence(&mut seq)
+        .return_const(false);
+    assert!(!a.nearly_eq_tol(
+        &b,
+        &Tolerance::<MockLhs, Rhs>::new(0.1, 5)
+            .with_rel(0.2)
+            .with_mode(ToleranceMode::All)
+    ));
+}
+
+#[test]
+fn nearly_ne_tol_all() {
+    let mut seq = Sequence::new();
+
+    let mut a = MockLhs::new();
+    let b = Rhs(5);
+
+    a.expect_nearly_eq_eps()
+        .with(eq(Rhs(5)), eq(0.1))
+        .times(1)
+        .in_sequence(&mut seq)
+        .return_const(false);
+    a.expect_nearly_eq_ulps().times(0);
+    assert!(a.nearly_ne_tol(
+        &b,
+        &Tolerance::<MockLhs, Rhs>::new(0.1, 5).with_mode(ToleranceMode::All)
+    ));
+
+    a.checkpoint();
+
+    a.expect_nearly_eq_eps()
+        .with(eq(Rhs(5)), eq(0.1))
+        .times(1)
+        .in_sequence(&mut seq)
+        .return_const(true);
+    a.expect_nearly_eq_ulps()
+        .with(eq(Rhs(5)), eq(5))
+        .times(1)
+        .in_sequence(&mut seq)
+        .return_const(true);
+    assert!(!a.nearly_ne_tol(
+        &b,
+        &Tolerance::<MockLhs, Rhs>::new(0.1, 5).with_mode(ToleranceMode::All)
+    ));
+
+    a.checkpoint();
+
+    a.expect_nearly_eq_eps()
+        .with(eq(Rhs(5)), eq(0.1))
+        .times(1)
+        .in_sequence(&mut seq)
+        .return_const(true);
+    a.expect_nearly_eq_ulps()
+        .with(eq(Rhs(5)), eq(5))
+        .times(1)
+        .in_sequence(&mut seq)
+        .return_const(true);
+    a.expect_nearly_eq_rel()
+        .with(eq(Rhs(5)), eq(0.2))
+        .times(1)
+        .in_sequence(&mut seq)
+        .return_const(true);
+    assert!(!a.nearly_ne_tol(
+        &b,
+        &Tolerance::<MockLhs, Rhs>::new(0.1, 5)
+            .with_rel(0.2)
+            .with_mode(ToleranceMode::All)
+    ));
+
+    a.checkpoint();
+
+    a.expect_nearly_eq_eps()
+        .with(eq(Rhs(5)), eq(0.1))
+        .times(1)
+        .in_sequence(&mut seq)
+        .return_const(true);
+    a.expect_nearly_eq_ulps()
+        .with(eq(Rhs(5)), eq(5))
+        .times(1)
+        .in_sequence(&mut seq)
+        .return_const(true);
+    a.expect_nearly_eq_rel()
+        .with(eq(Rhs(5)), eq(0.2))
+        .times(1)
+        .in_sequence(&mut seq)
+        .return_const(false);
+    assert!(a.nearly_ne_tol(
+        &b,
+        &Tolerance::<MockLhs, Rhs>::new(0.1, 5)
+            .with_rel(0.2)
+            .with_mode(ToleranceMode::All)
+    ));
+}
+
 #[test]
 fn nearly_eq() {
     let mut seq = Sequence::new();
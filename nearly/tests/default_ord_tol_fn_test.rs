@@ -1,8 +1,10 @@
 use mockall::{mock, predicate::eq, Sequence};
 use nearly::{
-    EpsTolerance, EpsToleranceType, NearlyEq, NearlyEqEps, NearlyEqTol, NearlyEqUlps, NearlyOrd,
-    NearlyOrdEps, NearlyOrdTol, NearlyOrdUlps, Tolerance, UlpsTolerance, UlpsToleranceType,
+    EpsTolerance, EpsToleranceType, NearlyEq, NearlyEqEps, NearlyEqRel, NearlyEqTol, NearlyEqUlps,
+    NearlyOrd, NearlyOrdEps, NearlyOrdRel, NearlyOrdTol, NearlyOrdUlps, RelTolerance,
+    RelToleranceType, Tolerance, ToleranceMode, UlpsTolerance, UlpsToleranceType,
 };
+use std::cmp::Ordering;
 
 #[derive(Debug, PartialEq)]
 pub struct Rhs(pub i32);
@@ -20,6 +22,11 @@ mock!(
         const DEFAULT: i32 = 3;
     }
 
+    impl RelTolerance<Rhs> for Lhs {
+        type T = f32;
+        const DEFAULT: f32 = 0.01;
+    }
+
     impl NearlyEqEps<Rhs> for Lhs {
         fn nearly_eq_eps(&self, other: &Rhs, eps: &EpsToleranceType<Self, Rhs>) -> bool;
     }
@@ -28,6 +35,10 @@ mock!(
         fn nearly_eq_ulps(&self, other: &Rhs, ulps: &UlpsToleranceType<Self, Rhs>) -> bool;
     }
 
+    impl NearlyEqRel<Rhs> for Lhs {
+        fn nearly_eq_rel(&self, other: &Rhs, rel: &RelToleranceType<Self, Rhs>) -> bool;
+    }
+
     impl NearlyEqTol<Rhs> for Lhs {}
 
     impl NearlyEq<Rhs> for Lhs {}
@@ -46,6 +57,13 @@ mock!(
         fn nearly_ge_ulps(&self, other: &Rhs, ulps: &UlpsToleranceType<Self, Rhs>) -> bool;
     }
 
+    impl NearlyOrdRel<Rhs> for Lhs {
+        fn nearly_lt_rel(&self, other: &Rhs, rel: &RelToleranceType<Self, Rhs>) -> bool;
+        fn nearly_le_rel(&self, other: &Rhs, rel: &RelToleranceType<Self, Rhs>) -> bool;
+        fn nearly_gt_rel(&self, other: &Rhs, rel: &RelToleranceType<Self, Rhs>) -> bool;
+        fn nearly_ge_rel(&self, other: &Rhs, rel: &RelToleranceType<Self, Rhs>) -> bool;
+    }
+
     impl NearlyOrdTol<Rhs> for Lhs {}
 
     impl NearlyOrd<Rhs> for Lhs {}
@@ -58,21 +76,15 @@ fn nearly_lt_tol() {
     let mut a = MockLhs::new();
     let b = Rhs(5);
 
+    // Since 0.5.0, `a` is only strict less than `b` in ToleranceMode::Any if it is strict less
+    // based on every component, i.e. not nearly equal based on any of them. Before, a single
+    // component was sufficient, so the values could be nearly equal and less at the same time.
+    // The cases are unchanged, but the comparison now stops at the first false component.
     a.expect_nearly_lt_eps()
         .with(eq(Rhs(5)), eq(0.1))
         .times(1)
         .in_sequence(&mut seq)
         .return_const(true);
-    a.expect_nearly_lt_ulps().times(0);
-    assert!(a.nearly_lt_tol(&b, &Tolerance::<MockLhs, Rhs>::new(0.1, 5)));
-
-    a.checkpoint();
-
-    a.expect_nearly_lt_eps()
-        .with(eq(Rhs(5)), eq(0.1))
-        .times(1)
-        .in_sequence(&mut seq)
-        .return_const(false);
     a.expect_nearly_lt_ulps()
         .with(eq(Rhs(5)), eq(5))
         .times(1)
@@ -87,11 +99,17 @@ fn nearly_lt_tol() {
         .times(1)
         .in_sequence(&mut seq)
         .return_const(false);
-    a.expect_nearly_lt_ulps()
-        .with(eq(Rhs(5)), eq(5))
+    a.expect_nearly_lt_ulps().times(0);
+    assert!(!a.nearly_lt_tol(&b, &Tolerance::<MockLhs, Rhs>::new(0.1, 5)));
+
+    a.checkpoint();
+
+    a.expect_nearly_lt_eps()
+        .with(eq(Rhs(5)), eq(0.1))
         .times(1)
         .in_sequence(&mut seq)
         .return_const(false);
+    a.expect_nearly_lt_ulps().times(0);
     assert!(!a.nearly_lt_tol(&b, &Tolerance::<MockLhs, Rhs>::new(0.1, 5)));
 }
 
@@ -146,21 +164,15 @@ fn nearly_gt_tol() {
     let mut a = MockLhs::new();
     let b = Rhs(5);
 
+    // Since 0.5.0, `a` is only strict greater than `b` in ToleranceMode::Any if it is strict greater
+    // based on every component, i.e. not nearly equal based on any of them. Before, a single
+    // component was sufficient, so the values could be nearly equal and greater at the same time.
+    // The cases are unchanged, but the comparison now stops at the first false component.
     a.expect_nearly_gt_eps()
         .with(eq(Rhs(5)), eq(0.1))
         .times(1)
         .in_sequence(&mut seq)
         .return_const(true);
-    a.expect_nearly_gt_ulps().times(0);
-    assert!(a.nearly_gt_tol(&b, &Tolerance::<MockLhs, Rhs>::new(0.1, 5)));
-
-    a.checkpoint();
-
-    a.expect_nearly_gt_eps()
-        .with(eq(Rhs(5)), eq(0.1))
-        .times(1)
-        .in_sequence(&mut seq)
-        .return_const(false);
     a.expect_nearly_gt_ulps()
         .with(eq(Rhs(5)), eq(5))
         .times(1)
@@ -175,11 +187,17 @@ fn nearly_gt_tol() {
         .times(1)
         .in_sequence(&mut seq)
         .return_const(false);
-    a.expect_nearly_gt_ulps()
-        .with(eq(Rhs(5)), eq(5))
+    a.expect_nearly_gt_ulps().times(0);
+    assert!(!a.nearly_gt_tol(&b, &Tolerance::<MockLhs, Rhs>::new(0.1, 5)));
+
+    a.checkpoint();
+
+    a.expect_nearly_gt_eps()
+        .with(eq(Rhs(5)), eq(0.1))
         .times(1)
         .in_sequence(&mut seq)
         .return_const(false);
+    a.expect_nearly_gt_ulps().times(0);
     assert!(!a.nearly_gt_tol(&b, &Tolerance::<MockLhs, Rhs>::new(0.1, 5)));
 }
 
@@ -228,177 +246,1087 @@ fn nearly_ge_tol() {
 }
 
 #[test]
-fn nearly_lt() {
+fn nearly_lt_tol_rel() {
     let mut seq = Sequence::new();
 
     let mut a = MockLhs::new();
     let b = Rhs(5);
 
+    // Since 0.5.0, `a` is only strict less than `b` in ToleranceMode::Any if it is strict less
+    // based on every component, i.e. not nearly equal based on any of them. Before, a single
+    // component was sufficient, so the values could be nearly equal and less at the same time.
+    // The cases are unchanged, but the comparison now stops at the first false component.
     a.expect_nearly_lt_eps()
-        .with(eq(Rhs(5)), eq(0.01))
+        .with(eq(Rhs(5)), eq(0.1))
         .times(1)
         .in_sequence(&mut seq)
         .return_const(true);
-    a.expect_nearly_lt_ulps().times(0);
-    assert!(a.nearly_lt(&b));
-
-    a.checkpoint();
-
-    a.expect_nearly_lt_eps()
-        .with(eq(Rhs(5)), eq(0.01))
+    a.expect_nearly_lt_ulps()
+        .with(eq(Rhs(5)), eq(5))
         .times(1)
         .in_sequence(&mut seq)
-        .return_const(false);
-    a.expect_nearly_lt_ulps()
-        .with(eq(Rhs(5)), eq(3))
+        .return_const(true);
+    a.expect_nearly_lt_rel()
+        .with(eq(Rhs(5)), eq(0.2))
         .times(1)
         .in_sequence(&mut seq)
         .return_const(true);
-    assert!(a.nearly_lt(&b));
+    assert!(a.nearly_lt_tol(&b, &Tolerance::<MockLhs, Rhs>::new(0.1, 5).with_rel(0.2)));
 
     a.checkpoint();
 
     a.expect_nearly_lt_eps()
-        .with(eq(Rhs(5)), eq(0.01))
+        .with(eq(Rhs(5)), eq(0.1))
         .times(1)
         .in_sequence(&mut seq)
         .return_const(false);
-    a.expect_nearly_lt_ulps()
-        .with(eq(Rhs(5)), eq(3))
+    a.expect_nearly_lt_ulps().times(0);
+    a.expect_nearly_lt_rel().times(0);
+    assert!(!a.nearly_lt_tol(&b, &Tolerance::<MockLhs, Rhs>::new(0.1, 5).with_rel(0.2)));
+
+    a.checkpoint();
+
+    a.expect_nearly_lt_eps()
+        .with(eq(Rhs(5)), eq(0.1))
         .times(1)
         .in_sequence(&mut seq)
         .return_const(false);
-    assert!(!a.nearly_lt(&b));
+    a.expect_nearly_lt_ulps().times(0);
+    a.expect_nearly_lt_rel().times(0);
+    assert!(!a.nearly_lt_tol(&b, &Tolerance::<MockLhs, Rhs>::new(0.1, 5).with_rel(0.2)));
 }
 
 #[test]
-fn nearly_le() {
+fn nearly_le_tol_rel() {
     let mut seq = Sequence::new();
 
     let mut a = MockLhs::new();
     let b = Rhs(5);
 
     a.expect_nearly_le_eps()
-        .with(eq(Rhs(5)), eq(0.01))
+        .with(eq(Rhs(5)), eq(0.1))
         .times(1)
         .in_sequence(&mut seq)
         .return_const(true);
     a.expect_nearly_le_ulps().times(0);
-    assert!(a.nearly_le(&b));
+    a.expect_nearly_le_rel().times(0);
+    assert!(a.nearly_le_tol(&b, &Tolerance::<MockLhs, Rhs>::new(0.1, 5).with_rel(0.2)));
 
     a.checkpoint();
 
     a.expect_nearly_le_eps()
-        .with(eq(Rhs(5)), eq(0.01))
+        .with(eq(Rhs(5)), eq(0.1))
         .times(1)
         .in_sequence(&mut seq)
         .return_const(false);
     a.expect_nearly_le_ulps()
-        .with(eq(Rhs(5)), eq(3))
+        .with(eq(Rhs(5)), eq(5))
+        .times(1)
+        .in_sequence(&mut seq)
+        .return_const(false);
+    a.expect_nearly_le_rel()
+        .with(eq(Rhs(5)), eq(0.2))
         .times(1)
         .in_sequence(&mut seq)
         .return_const(true);
-    assert!(a.nearly_le(&b));
+    assert!(a.nearly_le_tol(&b, &Tolerance::<MockLhs, Rhs>::new(0.1, 5).with_rel(0.2)));
 
     a.checkpoint();
 
     a.expect_nearly_le_eps()
-        .with(eq(Rhs(5)), eq(0.01))
+        .with(eq(Rhs(5)), eq(0.1))
         .times(1)
         .in_sequence(&mut seq)
         .return_const(false);
     a.expect_nearly_le_ulps()
-        .with(eq(Rhs(5)), eq(3))
+        .with(eq(Rhs(5)), eq(5))
         .times(1)
         .in_sequence(&mut seq)
         .return_const(false);
-    assert!(!a.nearly_le(&b));
+    a.expect_nearly_le_rel()
+        .with(eq(Rhs(5)), eq(0.2))
+        .times(1)
+        .in_sequence(&mut seq)
+        .return_const(false);
+    assert!(!a.nearly_le_tol(&b, &Tolerance::<MockLhs, Rhs>::new(0.1, 5).with_rel(0.2)));
 }
 
 #[test]
-fn nearly_gt() {
+fn nearly_gt_tol_rel() {
     let mut seq = Sequence::new();
 
     let mut a = MockLhs::new();
     let b = Rhs(5);
 
+    // Since 0.5.0, `a` is only strict greater than `b` in ToleranceMode::Any if it is strict greater
+    // based on every component, i.e. not nearly equal based on any of them. Before, a single
+    // component was sufficient, so the values could be nearly equal and greater at the same time.
+    // The cases are unchanged, but the comparison now stops at the first false component.
     a.expect_nearly_gt_eps()
-        .with(eq(Rhs(5)), eq(0.01))
+        .with(eq(Rhs(5)), eq(0.1))
         .times(1)
         .in_sequence(&mut seq)
         .return_const(true);
-    a.expect_nearly_gt_ulps().times(0);
-    assert!(a.nearly_gt(&b));
-
-    a.checkpoint();
-
-    a.expect_nearly_gt_eps()
-        .with(eq(Rhs(5)), eq(0.01))
+    a.expect_nearly_gt_ulps()
+        .with(eq(Rhs(5)), eq(5))
         .times(1)
         .in_sequence(&mut seq)
-        .return_const(false);
-    a.expect_nearly_gt_ulps()
-        .with(eq(Rhs(5)), eq(3))
+        .return_const(true);
+    a.expect_nearly_gt_rel()
+        .with(eq(Rhs(5)), eq(0.2))
         .times(1)
         .in_sequence(&mut seq)
         .return_const(true);
-    assert!(a.nearly_gt(&b));
+    assert!(a.nearly_gt_tol(&b, &Tolerance::<MockLhs, Rhs>::new(0.1, 5).with_rel(0.2)));
 
     a.checkpoint();
 
     a.expect_nearly_gt_eps()
-        .with(eq(Rhs(5)), eq(0.01))
+        .with(eq(Rhs(5)), eq(0.1))
         .times(1)
         .in_sequence(&mut seq)
         .return_const(false);
-    a.expect_nearly_gt_ulps()
-        .with(eq(Rhs(5)), eq(3))
+    a.expect_nearly_gt_ulps().times(0);
+    a.expect_nearly_gt_rel().times(0);
+    assert!(!a.nearly_gt_tol(&b, &Tolerance::<MockLhs, Rhs>::new(0.1, 5).with_rel(0.2)));
+
+    a.checkpoint();
+
+    a.expect_nearly_gt_eps()
+        .with(eq(Rhs(5)), eq(0.1))
         .times(1)
         .in_sequence(&mut seq)
         .return_const(false);
-    assert!(!a.nearly_gt(&b));
+    a.expect_nearly_gt_ulps().times(0);
+    a.expect_nearly_gt_rel().times(0);
+    assert!(!a.nearly_gt_tol(&b, &Tolerance::<MockLhs, Rhs>::new(0.1, 5).with_rel(0.2)));
 }
 
 #[test]
-fn nearly_ge() {
+fn nearly_ge_tol_rel() {
     let mut seq = Sequence::new();
 
     let mut a = MockLhs::new();
     let b = Rhs(5);
 
     a.expect_nearly_ge_eps()
-        .with(eq(Rhs(5)), eq(0.01))
+        .with(eq(Rhs(5)), eq(0.1))
         .times(1)
         .in_sequence(&mut seq)
         .return_const(true);
     a.expect_nearly_ge_ulps().times(0);
-    assert!(a.nearly_ge(&b));
+    a.expect_nearly_ge_rel().times(0);
+    assert!(a.nearly_ge_tol(&b, &Tolerance::<MockLhs, Rhs>::new(0.1, 5).with_rel(0.2)));
 
     a.checkpoint();
 
     a.expect_nearly_ge_eps()
-        .with(eq(Rhs(5)), eq(0.01))
+        .with(eq(Rhs(5)), eq(0.1))
         .times(1)
         .in_sequence(&mut seq)
         .return_const(false);
     a.expect_nearly_ge_ulps()
-        .with(eq(Rhs(5)), eq(3))
+        .with(eq(Rhs(5)), eq(5))
+        .times(1)
+        .in_sequence(&mut seq)
+        .return_const(false);
+    a.expect_nearly_ge_rel()
+        .with(eq(Rhs(5)), eq(0.2))
         .times(1)
         .in_sequence(&mut seq)
         .return_const(true);
-    assert!(a.nearly_ge(&b));
+    assert!(a.nearly_ge_tol(&b, &Tolerance::<MockLhs, Rhs>::new(0.1, 5).with_rel(0.2)));
 
     a.checkpoint();
 
     a.expect_nearly_ge_eps()
-        .with(eq(Rhs(5)), eq(0.01))
+        .with(eq(Rhs(5)), eq(0.1))
         .times(1)
         .in_sequence(&mut seq)
         .return_const(false);
     a.expect_nearly_ge_ulps()
-        .with(eq(Rhs(5)), eq(3))
+        .with(eq(Rhs(5)), eq(5))
         .times(1)
         .in_sequence(&mut seq)
         .return_const(false);
-    assert!(!a.nearly_ge(&b));
+    a.expect_nearly_ge_rel()
+        .with(eq(Rhs(5)), eq(0.2))
+        .times(1)
+        .in_sequence(&mut seq)
+        .return_const(false);
+    assert!(!a.nearly_ge_tol(&b, &Tolerance::<MockLhs, Rhs>::new(0.1, 5).with_rel(0.2)));
+}
+
+#[test]
+fn nearly_lt_tol_all() {
+    let mut seq = Sequence::new();
+
+    let mut a = MockLhs::new();
+    let b = Rhs(5);
+
+    a.expect_nearly_lt_eps()
+        .with(eq(Rhs(5)), eq(0.1))
+        .times(1)
+        .in_sequence(&mut seq)
+        .return_const(true);
+    a.expect_nearly_lt_ulps().times(0);
+    assert!(a.nearly_lt_tol(
+        &b,
+        &Tolerance::<MockLhs, Rhs>::new(0.1, 5).with_mode(ToleranceMode::All)
+    ));
+
+    a.checkpoint();
+
+    a.expect_nearly_lt_eps()
+        .with(eq(Rhs(5)), eq(0.1))
+        .times(1)
+        .in_sequence(&mut seq)
+        .return_const(false);
+    a.expect_nearly_lt_ulps()
+        .with(eq(Rhs(5)), eq(5))
+        .times(1)
+        .in_sequence(&mut seq)
+        .return_const(false);
+    assert!(!a.nearly_lt_tol(
+        &b,
+        &Tolerance::<MockLhs, Rhs>::new(0.1, 5).with_mode(ToleranceMode::All)
+    ));
+
+    a.checkpoint();
+
+    a.expect_nearly_lt_eps()
+        .with(eq(Rhs(5)), eq(0.1))
+        .times(1)
+        .in_sequence(&mut seq)
+        .return_const(false);
+    a.expect_nearly_lt_ulps()
+        .with(eq(Rhs(5)), eq(5))
+        .times(1)
+        .in_sequence(&mut seq)
+        .return_const(false);
+    a.expect_nearly_lt_rel()
+        .with(eq(Rhs(5)), eq(0.2))
+        .times(1)
+        .in_sequence(&mut seq)
+        .return_const(false);
+    assert!(!a.nearly_lt_tol(
+        &b,
+        &Tolerance::<MockLhs, Rhs>::new(0.1, 5)
+            .with_rel(0.2)
+            .with_mode(ToleranceMode::All)
+    ));
+
+    a.checkpoint();
+
+    a.expect_nearly_lt_eps()
+        .with(eq(Rhs(5)), eq(0.1))
+        .times(1)
+        .in_sequence(&mut seq)
+        .return_const(false);
+    a.expect_nearly_lt_ulps()
+        .with(eq(Rhs(5)), eq(5))
+        .times(1)
+        .in_sequence(&mut seq)
+        .return_const(false);
+    a.expect_nearly_lt_rel()
+        .with(eq(Rhs(5)), eq(0.2))
+        .times(1)
+        .in_sequence(&mut seq)
+        .return_const(true);
+    assert!(a.nearly_lt_tol(
+        &b,
+        &Tolerance::<MockLhs, Rhs>::new(0.1, 5)
+            .with_rel(0.2)
+            .with_mode(ToleranceMode::All)
+    ));
+}
+
+#[test]
+fn nearly_le_tol_all() {
+    let mut seq = Sequence::new();
+
+    let mut a = MockLhs::new();
+    let b = Rhs(5);
+
+    a.expect_nearly_le_eps()
+        .with(eq(Rhs(5)), eq(0.1))
+        .times(1)
+        .in_sequence(&mut seq)
+        .return_const(false);
+    a.expect_nearly_le_ulps().times(0);
+    assert!(!a.nearly_le_tol(
+        &b,
+        &Tolerance::<MockLhs, Rhs>::new(0.1, 5).with_mode(ToleranceMode::All)
+    ));
+
+    a.checkpoint();
+
+    a.expect_nearly_le_eps()
+        .with(eq(Rhs(5)), eq(0.1))
+        .times(1)
+        .in_sequence(&mut seq)
+        .return_const(true);
+    a.expect_nearly_le_ulps()
+        .with(eq(Rhs(5)), eq(5))
+        .times(1)
+        .in_sequence(&mut seq)
+        .return_const(true);
+    assert!(a.nearly_le_tol(
+        &b,
+        &Tolerance::<MockLhs, Rhs>::new(0.1, 5).with_mode(ToleranceMode::All)
+    ));
+
+    a.checkpoint();
+
+    a.expect_nearly_le_eps()
+        .with(eq(Rhs(5)), eq(0.1))
+        .times(1)
+        .in_sequence(&mut seq)
+        .return_const(true);
+    a.expect_nearly_le_ulps()
+        .with(eq(Rhs(5)), eq(5))
+        .times(1)
+        .in_sequence(&mut seq)
+        .return_const(true);
+    a.expect_nearly_le_rel()
+        .with(eq(Rhs(5)), eq(0.2))
+        .times(1)
+        .in_sequence(&mut seq)
+        .return_const(true);
+    assert!(a.nearly_le_tol(
+        &b,
+        &Tolerance::<MockLhs, Rhs>::new(0.1, 5)
+            .with_rel(0.2)
+            .with_mode(ToleranceMode::All)
+    ));
+
+    a.checkpoint();
+
+    a.expect_nearly_le_eps()
+        .with(eq(Rhs(5)), eq(0.1))
+        .times(1)
+        .in_sequence(&mut seq)
+        .return_const(true);
+    a.expect_nearly_le_ulps()
+        .with(eq(Rhs(5)), eq(5))
+        .times(1)
+        .in_sequence(&mut seq)
+        .return_const(true);
+    a.expect_nearly_le_rel()
+        .with(eq(Rhs(5)), eq(0.2))
+        .times(1)
+        .in_sequence(&mut seq)
+        .return_const(false);
+    assert!(!a.nearly_le_tol(
+        &b,
+        &Tolerance::<MockLhs, Rhs>::new(0.1, 5)
+            .with_rel(0.2)
+            .with_mode(ToleranceMode::All)
+    ));
+}
+
+#[test]
+fn nearly_gt_tol_all() {
+    let mut seq = Sequence::new();
+
+    let mut a = MockLhs::new();
+    let b = Rhs(5);
+
+    a.expect_nearly_gt_eps()
+        .with(eq(Rhs(5)), eq(0.1))
+        .times(1)
+        .in_sequence(&mut seq)
+        .return_const(true);
+    a.expect_nearly_gt_ulps().times(0);
+    assert!(a.nearly_gt_tol(
+        &b,
+        &Tolerance::<MockLhs, Rhs>::new(0.1, 5).with_mode(ToleranceMode::All)
+    ));
+
+    a.checkpoint();
+
+    a.expect_nearly_gt_eps()
+        .with(eq(Rhs(5)), eq(0.1))
+        .times(1)
+        .in_sequence(&mut seq)
+        .return_const(false);
+    a.expect_nearly_gt_ulps()
+        .with(eq(Rhs(5)), eq(5))
+        .times(1)
+        .in_sequence(&mut seq)
+        .return_const(false);
+    assert!(!a.nearly_gt_tol(
+        &b,
+        &Tolerance::<MockLhs, Rhs>::new(0.1, 5).with_mode(ToleranceMode::All)
+    ));
+
+    a.checkpoint();
+
+    a.expect_nearly_gt_eps()
+        .with(eq(Rhs(5)), eq(0.1))
+        .times(1)
+        .in_sequence(&mut seq)
+        .return_const(false);
+    a.expect_nearly_gt_ulps()
+        .with(eq(Rhs(5)), eq(5))
+        .times(1)
+        .in_sequence(&mut seq)
+        .return_const(false);
+    a.expect_nearly_gt_rel()
+        .with(eq(Rhs(5)), eq(0.2))
+        .times(1)
+        .in_sequence(&mut seq)
+        .return_const(false);
+    assert!(!a.nearly_gt_tol(
+        &b,
+        &Tolerance::<MockLhs, Rhs>::new(0.1, 5)
+            .with_rel(0.2)
+            .with_mode(ToleranceMode::All)
+    ));
+
+    a.checkpoint();
+
+    a.expect_nearly_gt_eps()
+        .with(eq(Rhs(5)), eq(0.1))
+        .times(1)
+        .in_sequence(&mut seq)
+        .return_const(false);
+    a.expect_nearly_gt_ulps()
+        .with(eq(Rhs(5)), eq(5))
+        .times(1)
+        .in_sequence(&mut seq)
+        .return_const(false);
+    a.expect_nearly_gt_rel()
+        .with(eq(Rhs(5)), eq(0.2))
+        .times(1)
+        .in_sequence(&mut seq)
+        .return_const(true);
+    assert!(a.nearly_gt_tol(
+        &b,
+        &Tolerance::<MockLhs, Rhs>::new(0.1, 5)
+            .with_rel(0.2)
+            .with_mode(ToleranceMode::All)
+    ));
+}
+
+#[test]
+fn nearly_ge_tol_all() {
+    let mut seq = Sequence::new();
+
+    let mut a = MockLhs::new();
+    let b = Rhs(5);
+
+    a.expect_nearly_ge_eps()
+        .with(eq(Rhs(5)), eq(0.1))
+        .times(1)
+        .in_sequence(&mut seq)
+        .return_const(false);
+    a.expect_nearly_ge_ulps().times(0);
+    assert!(!a.nearly_ge_tol(
+        &b,
+        &Tolerance::<MockLhs, Rhs>::new(0.1, 5).with_mode(ToleranceMode::All)
+    ));
+
+    a.checkpoint();
+
+    a.expect_nearly_ge_eps()
+        .with(eq(Rhs(5)), eq(0.1))
+        .times(1)
+        .in_sequence(&mut seq)
+        .return_const(true);
+    a.expect_nearly_ge_ulps()
+        .with(eq(Rhs(5)), eq(5))
+        .times(1)
+        .in_sequence(&mut seq)
+        .return_const(true);
+    assert!(a.nearly_ge_tol(
+        &b,
+        &Tolerance::<MockLhs, Rhs>::new(0.1, 5).with_mode(ToleranceMode::All)
+    ));
+
+    a.checkpoint();
+
+    a.expect_nearly_ge_eps()
+        .with(eq(Rhs(5)), eq(0.1))
+        .times(1)
+        .in_sequence(&mut seq)
+        .return_const(true);
+    a.expect_nearly_ge_ulps()
+        .with(eq(Rhs(5)), eq(5))
+        .times(1)
+        .in_sequence(&mut seq)
+        .return_const(true);
+    a.expect_nearly_ge_rel()
+        .with(eq(Rhs(5)), eq(0.2))
+        .times(1)
+        .in_sequence(&mut seq)
+        .return_const(true);
+    assert!(a.nearly_ge_tol(
+        &b,
+        &Tolerance::<MockLhs, Rhs>::new(0.1, 5)
+            .with_rel(0.2)
+            .with_mode(ToleranceMode::All)
+    ));
+
+    a.checkpoint();
+
+    a.expect_nearly_ge_eps()
+        .with(eq(Rhs(5)), eq(0.1))
+        .times(1)
+        .in_sequence(&mut seq)
+        .return_const(true);
+    a.expect_nearly_ge_ulps()
+        .with(eq(Rhs(5)), eq(5))
+        .times(1)
+        .in_sequence(&mut seq)
+        .return_const(true);
+    a.expect_nearly_ge_rel()
+        .with(eq(Rhs(5)), eq(0.2))
+        .times(1)
+        .in_sequence(&mut seq)
+        .return_const(false);
+    assert!(!a.nearly_ge_tol(
+        &b,
+        &Tolerance::<MockLhs, Rhs>::new(0.1, 5)
+            .with_rel(0.2)
+            .with_mode(ToleranceMode::All)
+    ));
+}
+
+#[test]
+fn nearly_cmp_eps() {
+    let mut seq = Sequence::new();
+
+    let mut a = MockLhs::new();
+    let b = Rhs(5);
+
+    a.expect_nearly_le_eps()
+        .with(eq(Rhs(5)), eq(0.1))
+        .times(1)
+        .in_sequence(&mut seq)
+        .return_const(true);
+    a.expect_nearly_ge_eps()
+        .with(eq(Rhs(5)), eq(0.1))
+        .times(1)
+        .in_sequence(&mut seq)
+        .return_const(true);
+    assert_eq!(a.nearly_cmp_eps(&b, &0.1), Some(Ordering::Equal));
+
+    a.checkpoint();
+
+    a.expect_nearly_le_eps()
+        .with(eq(Rhs(5)), eq(0.1))
+        .times(1)
+        .in_sequence(&mut seq)
+        .return_const(true);
+    a.expect_nearly_ge_eps()
+        .with(eq(Rhs(5)), eq(0.1))
+        .times(1)
+        .in_sequence(&mut seq)
+        .return_const(false);
+    assert_eq!(a.nearly_cmp_eps(&b, &0.1), Some(Ordering::Less));
+
+    a.checkpoint();
+
+    a.expect_nearly_le_eps()
+        .with(eq(Rhs(5)), eq(0.1))
+        .times(1)
+        .in_sequence(&mut seq)
+        .return_const(false);
+    a.expect_nearly_ge_eps()
+        .with(eq(Rhs(5)), eq(0.1))
+        .times(1)
+        .in_sequence(&mut seq)
+        .return_const(true);
+    assert_eq!(a.nearly_cmp_eps(&b, &0.1), Some(Ordering::Greater));
+
+    a.checkpoint();
+
+    a.expect_nearly_le_eps()
+        .with(eq(Rhs(5)), eq(0.1))
+        .times(1)
+        .in_sequence(&mut seq)
+        .return_const(false);
+    a.expect_nearly_ge_eps()
+        .with(eq(Rhs(5)), eq(0.1))
+        .times(1)
+        .in_sequence(&mut seq)
+        .return_const(false);
+    assert_eq!(a.nearly_cmp_eps(&b, &0.1), None);
+}
+
+#[test]
+fn nearly_cmp_ulps() {
+    let mut seq = Sequence::new();
+
+    let mut a = MockLhs::new();
+    let b = Rhs(5);
+
+    a.expect_nearly_le_ulps()
+        .with(eq(Rhs(5)), eq(5))
+        .times(1)
+        .in_sequence(&mut seq)
+        .return_const(true);
+    a.expect_nearly_ge_ulps()
+        .with(eq(Rhs(5)), eq(5))
+        .times(1)
+        .in_sequence(&mut seq)
+        .return_const(true);
+    assert_eq!(a.nearly_cmp_ulps(&b, &5), Some(Ordering::Equal));
+
+    a.checkpoint();
+
+    a.expect_nearly_le_ulps()
+        .with(eq(Rhs(5)), eq(5))
+        .times(1)
+        .in_sequence(&mut seq)
+        .return_const(true);
+    a.expect_nearly_ge_ulps()
+        .with(eq(Rhs(5)), eq(5))
+        .times(1)
+        .in_sequence(&mut seq)
+        .return_const(false);
+    assert_eq!(a.nearly_cmp_ulps(&b, &5), Some(Ordering::Less));
+
+    a.checkpoint();
+
+    a.expect_nearly_le_ulps()
+        .with(eq(Rhs(5)), eq(5))
+        .times(1)
+        .in_sequence(&mut seq)
+        .return_const(false);
+    a.expect_nearly_ge_ulps()
+        .with(eq(Rhs(5)), eq(5))
+        .times(1)
+        .in_sequence(&mut seq)
+        .return_const(true);
+    assert_eq!(a.nearly_cmp_ulps(&b, &5), Some(Ordering::Greater));
+
+    a.checkpoint();
+
+    a.expect_nearly_le_ulps()
+        .with(eq(Rhs(5)), eq(5))
+        .times(1)
+        .in_sequence(&mut seq)
+        .return_const(false);
+    a.expect_nearly_ge_ulps()
+        .with(eq(Rhs(5)), eq(5))
+        .times(1)
+        .in_sequence(&mut seq)
+        .return_const(false);
+    assert_eq!(a.nearly_cmp_ulps(&b, &5), None);
+}
+
+#[test]
+fn nearly_cmp_rel() {
+    let mut seq = Sequence::new();
+
+    let mut a = MockLhs::new();
+    let b = Rhs(5);
+
+    a.expect_nearly_le_rel()
+        .with(eq(Rhs(5)), eq(0.2))
+        .times(1)
+        .in_sequence(&mut seq)
+        .return_const(true);
+    a.expect_nearly_ge_rel()
+        .with(eq(Rhs(5)), eq(0.2))
+        .times(1)
+        .in_sequence(&mut seq)
+        .return_const(true);
+    assert_eq!(a.nearly_cmp_rel(&b, &0.2), Some(Ordering::Equal));
+
+    a.checkpoint();
+
+    a.expect_nearly_le_rel()
+        .with(eq(Rhs(5)), eq(0.2))
+        .times(1)
+        .in_sequence(&mut seq)
+        .return_const(true);
+    a.expect_nearly_ge_rel()
+        .with(eq(Rhs(5)), eq(0.2))
+        .times(1)
+        .in_sequence(&mut seq)
+        .return_const(false);
+    assert_eq!(a.nearly_cmp_rel(&b, &0.2), Some(Ordering::Less));
+
+    a.checkpoint();
+
+    a.expect_nearly_le_rel()
+        .with(eq(Rhs(5)), eq(0.2))
+        .times(1)
+        .in_sequence(&mut seq)
+        .return_const(false);
+    a.expect_nearly_ge_rel()
+        .with(eq(Rhs(5)), eq(0.2))
+        .times(1)
+        .in_sequence(&mut seq)
+        .return_const(true);
+    assert_eq!(a.nearly_cmp_rel(&b, &0.2), Some(Ordering::Greater));
+
+    a.checkpoint();
+
+    a.expect_nearly_le_rel()
+        .with(eq(Rhs(5)), eq(0.2))
+        .times(1)
+        .in_sequence(&mut seq)
+        .return_const(false);
+    a.expect_nearly_ge_rel()
+        .with(eq(Rhs(5)), eq(0.2))
+        .times(1)
+        .in_sequence(&mut seq)
+        .return_const(false);
+    assert_eq!(a.nearly_cmp_rel(&b, &0.2), None);
+}
+
+#[test]
+fn nearly_cmp_tol() {
+    let mut seq = Sequence::new();
+
+    let mut a = MockLhs::new();
+    let b = Rhs(5);
+
+    a.expect_nearly_le_eps()
+        .with(eq(Rhs(5)), eq(0.1))
+        .times(1)
+        .in_sequence(&mut seq)
+        .return_const(true);
+    a.expect_nearly_ge_eps()
+        .with(eq(Rhs(5)), eq(0.1))
+        .times(1)
+        .in_sequence(&mut seq)
+        .return_const(true);
+    assert_eq!(
+        a.nearly_cmp_tol(&b, &Tolerance::<MockLhs, Rhs>::new(0.1, 5)),
+        Some(Ordering::Equal)
+    );
+
+    a.checkpoint();
+
+    a.expect_nearly_le_eps()
+        .with(eq(Rhs(5)), eq(0.1))
+        .times(1)
+        .in_sequence(&mut seq)
+        .return_const(true);
+    a.expect_nearly_ge_eps()
+        .with(eq(Rhs(5)), eq(0.1))
+        .times(1)
+        .in_sequence(&mut seq)
+        .return_const(false);
+    a.expect_nearly_ge_ulps()
+        .with(eq(Rhs(5)), eq(5))
+        .times(1)
+        .in_sequence(&mut seq)
+        .return_const(false);
+    assert_eq!(
+        a.nearly_cmp_tol(&b, &Tolerance::<MockLhs, Rhs>::new(0.1, 5)),
+        Some(Ordering::Less)
+    );
+
+    a.checkpoint();
+
+    a.expect_nearly_le_eps()
+        .with(eq(Rhs(5)), eq(0.1))
+        .times(1)
+        .in_sequence(&mut seq)
+        .return_const(false);
+    a.expect_nearly_le_ulps()
+        .with(eq(Rhs(5)), eq(5))
+        .times(1)
+        .in_sequence(&mut seq)
+        .return_const(false);
+    a.expect_nearly_ge_eps()
+        .with(eq(Rhs(5)), eq(0.1))
+        .times(1)
+        .in_sequence(&mut seq)
+        .return_const(true);
+    assert_eq!(
+        a.nearly_cmp_tol(&b, &Tolerance::<MockLhs, Rhs>::new(0.1, 5)),
+        Some(Ordering::Greater)
+    );
+
+    a.checkpoint();
+
+    a.expect_nearly_le_eps()
+        .with(eq(Rhs(5)), eq(0.1))
+        .times(1)
+        .in_sequence(&mut seq)
+        .return_const(false);
+    a.expect_nearly_le_ulps()
+        .with(eq(Rhs(5)), eq(5))
+        .times(1)
+        .in_sequence(&mut seq)
+        .return_const(false);
+    a.expect_nearly_ge_eps()
+        .with(eq(Rhs(5)), eq(0.1))
+        .times(1)
+        .in_sequence(&mut seq)
+        .return_const(false);
+    a.expect_nearly_ge_ulps()
+        .with(eq(Rhs(5)), eq(5))
+        .times(1)
+        .in_sequence(&mut seq)
+        .return_const(false);
+    assert_eq!(
+        a.nearly_cmp_tol(&b, &Tolerance::<MockLhs, Rhs>::new(0.1, 5)),
+        None
+    );
+}
+
+#[test]
+fn nearly_lt() {
+    let mut seq = Sequence::new();
+
+    let mut a = MockLhs::new();
+    let b = Rhs(5);
+
+    // Since 0.5.0, `a` is only strict less than `b` in ToleranceMode::Any if it is strict less
+    // based on every component, i.e. not nearly equal based on any of them. Before, a single
+    // component was sufficient, so the values could be nearly equal and less at the same time.
+    // The cases are unchanged, but the comparison now stops at the first false component.
+    a.expect_nearly_lt_eps()
+        .with(eq(Rhs(5)), eq(0.01))
+        .times(1)
+        .in_sequence(&mut seq)
+        .return_const(true);
+    a.expect_nearly_lt_ulps()
+        .with(eq(Rhs(5)), eq(3))
+        .times(1)
+        .in_sequence(&mut seq)
+        .return_const(true);
+    assert!(a.nearly_lt(&b));
+
+    a.checkpoint();
+
+    a.expect_nearly_lt_eps()
+        .with(eq(Rhs(5)), eq(0.01))
+        .times(1)
+        .in_sequence(&mut seq)
+        .return_const(false);
+    a.expect_nearly_lt_ulps().times(0);
+    assert!(!a.nearly_lt(&b));
+
+    a.checkpoint();
+
+    a.expect_nearly_lt_eps()
+        .with(eq(Rhs(5)), eq(0.01))
+        .times(1)
+        .in_sequence(&mut seq)
+        .return_const(false);
+    a.expect_nearly_lt_ulps().times(0);
+    assert!(!a.nearly_lt(&b));
+}
+
+#[test]
+fn nearly_le() {
+    let mut seq = Sequence::new();
+
+    let mut a = MockLhs::new();
+    let b = Rhs(5);
+
+    a.expect_nearly_le_eps()
+        .with(eq(Rhs(5)), eq(0.01))
+        .times(1)
+        .in_sequence(&mut seq)
+        .return_const(true);
+    a.expect_nearly_le_ulps().times(0);
+    assert!(a.nearly_le(&b));
+
+    a.checkpoint();
+
+    a.expect_nearly_le_eps()
+        .with(eq(Rhs(5)), eq(0.01))
+        .times(1)
+        .in_sequence(&mut seq)
+        .return_const(false);
+    a.expect_nearly_le_ulps()
+        .with(eq(Rhs(5)), eq(3))
+        .times(1)
+        .in_sequence(&mut seq)
+        .return_const(true);
+    assert!(a.nearly_le(&b));
+
+    a.checkpoint();
+
+    a.expect_nearly_le_eps()
+        .with(eq(Rhs(5)), eq(0.01))
+        .times(1)
+        .in_sequence(&mut seq)
+        .return_const(false);
+    a.expect_nearly_le_ulps()
+        .with(eq(Rhs(5)), eq(3))
+        .times(1)
+        .in_sequence(&mut seq)
+        .return_const(false);
+    assert!(!a.nearly_le(&b));
+}
+
+#[test]
+fn nearly_gt() {
+    let mut seq = Sequence::new();
+
+    let mut a = MockLhs::new();
+    let b = Rhs(5);
+
+    // Since 0.5.0, `a` is only strict greater than `b` in ToleranceMode::Any if it is strict greater
+    // based on every component, i.e. not nearly equal based on any of them. Before, a single
+    // component was sufficient, so the values could be nearly equal and greater at the same time.
+    // The cases are unchanged, but the comparison now stops at the first false component.
+    a.expect_nearly_gt_eps()
+        .with(eq(Rhs(5)), eq(0.01))
+        .times(1)
+        .in_sequence(&mut seq)
+        .return_const(true);
+    a.expect_nearly_gt_ulps()
+        .with(eq(Rhs(5)), eq(3))
+        .times(1)
+        .in_sequence(&mut seq)
+        .return_const(true);
+    assert!(a.nearly_gt(&b));
+
+    a.checkpoint();
+
+    a.expect_nearly_gt_eps()
+        .with(eq(Rhs(5)), eq(0.01))
+        .times(1)
+        .in_sequence(&mut seq)
+        .return_const(false);
+    a.expect_nearly_gt_ulps().times(0);
+    assert!(!a.nearly_gt(&b));
+
+    a.checkpoint();
+
+    a.expect_nearly_gt_eps()
+        .with(eq(Rhs(5)), eq(0.01))
+        .times(1)
+        .in_sequence(&mut seq)
+        .return_const(false);
+    a.expect_nearly_gt_ulps().times(0);
+    assert!(!a.nearly_gt(&b));
+}
+
+#[test]
+fn nearly_ge() {
+    let mut seq = Sequence::new();
+
+    let mut a = MockLhs::new();
+    let b = Rhs(5);
+
+    a.expect_nearly_ge_eps()
+        .with(eq(Rhs(5)), eq(0.01))
+        .times(1)
+        .in_sequence(&mut seq)
+        .return_const(true);
+    a.expect_nearly_ge_ulps().times(0);
+    assert!(a.nearly_ge(&b));
+
+    a.checkpoint();
+
+    a.expect_nearly_ge_eps()
+        .with(eq(Rhs(5)), eq(0.01))
+        .times(1)
+        .in_sequence(&mut seq)
+        .return_const(false);
+    a.expect_nearly_ge_ulps()
+        .with(eq(Rhs(5)), eq(3))
+        .times(1)
+        .in_sequence(&mut seq)
+        .return_const(true);
+    assert!(a.nearly_ge(&b));
+
+    a.checkpoint();
+
+    a.expect_nearly_ge_eps()
+        .with(eq(Rhs(5)), eq(0.01))
+        .times(1)
+        .in_sequence(&mut seq)
+        .return_const(false);
+    a.expect_nearly_ge_ulps()
+        .with(eq(Rhs(5)), eq(3))
+        .times(1)
+        .in_sequence(&mut seq)
+        .return_const(false);
+    assert!(!a.nearly_ge(&b));
+}
+
+#[test]
+fn nearly_cmp() {
+    let mut seq = Sequence::new();
+
+    let mut a = MockLhs::new();
+    let b = Rhs(5);
+
+    a.expect_nearly_le_eps()
+        .with(eq(Rhs(5)), eq(0.01))
+        .times(1)
+        .in_sequence(&mut seq)
+        .return_const(true);
+    a.expect_nearly_ge_eps()
+        .with(eq(Rhs(5)), eq(0.01))
+        .times(1)
+        .in_sequence(&mut seq)
+        .return_const(true);
+    assert_eq!(a.nearly_cmp(&b), Some(Ordering::Equal));
+
+    a.checkpoint();
+
+    a.expect_nearly_le_eps()
+        .with(eq(Rhs(5)), eq(0.01))
+        .times(1)
+        .in_sequence(&mut seq)
+        .return_const(true);
+    a.expect_nearly_ge_eps()
+        .with(eq(Rhs(5)), eq(0.01))
+        .times(1)
+        .in_sequence(&mut seq)
+        .return_const(false);
+    a.expect_nearly_ge_ulps()
+        .with(eq(Rhs(5)), eq(3))
+        .times(1)
+        .in_sequence(&mut seq)
+        .return_const(false);
+    assert_eq!(a.nearly_cmp(&b), Some(Ordering::Less));
+
+    a.checkpoint();
+
+    a.expect_nearly_le_eps()
+        .with(eq(Rhs(5)), eq(0.01))
+        .times(1)
+        .in_sequence(&mut seq)
+        .return_const(false);
+    a.expect_nearly_le_ulps()
+        .with(eq(Rhs(5)), eq(3))
+        .times(1)
+        .in_sequence(&mut seq)
+        .return_const(false);
+    a.expect_nearly_ge_eps()
+        .with(eq(Rhs(5)), eq(0.01))
+        .times(1)
+        .in_sequence(&mut seq)
+        .return_const(true);
+    assert_eq!(a.nearly_cmp(&b), Some(Ordering::Greater));
+
+    a.checkpoint();
+
+    a.expect_nearly_le_eps()
+        .with(eq(Rhs(5)), eq(0.01))
+        .times(1)
+        .in_sequence(&mut seq)
+        .return_const(false);
+    a.expect_nearly_le_ulps()
+        .with(eq(Rhs(5)), eq(3))
+        .times(1)
+        .in_sequence(&mut seq)
+        .return_const(false);
+    a.expect_nearly_ge_eps()
+        .with(eq(Rhs(5)), eq(0.01))
+        .times(1)
+        .in_sequence(&mut seq)
+        .return_const(false);
+    a.expect_nearly_ge_ulps()
+        .with(eq(Rhs(5)), eq(3))
+        .times(1)
+        .in_sequence(&mut seq)
+        .return_const(false);
+    assert_eq!(a.nearly_cmp(&b), None);
 }
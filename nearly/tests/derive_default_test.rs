@@ -0,0 +1,118 @@
+use nearly::{assert_nearly, EpsTolerance, NearlyEq, NearlyOrd, RelTolerance, UlpsTolerance};
+
+#[derive(NearlyEq, NearlyOrd, Debug)]
+struct Fields {
+    #[nearly(eps = 0.01, ulps = 8)]
+    coarse: f32,
+    fine: f32,
+}
+
+#[derive(NearlyEq, Debug)]
+struct Unnamed(f64, #[nearly(rel = 0.1)] f64);
+
+#[derive(NearlyEq, NearlyOrd, Debug)]
+#[nearly(eps = 0.01, ulps = 8, rel = 0.1)]
+struct Container {
+    x: f32,
+    y: f32,
+}
+
+#[derive(NearlyEq, Debug)]
+#[nearly(eps = (0.01, 0.1))]
+struct Mixed {
+    x: f32,
+    y: f64,
+}
+
+#[derive(NearlyEq, Debug)]
+enum Shape {
+    Circle {
+        #[nearly(eps = 0.1)]
+        radius: f32,
+    },
+    Square(f32),
+}
+
+#[test]
+fn derive_default_field() {
+    assert_eq!(
+        <Fields as EpsTolerance>::DEFAULT,
+        (0.01, <f32 as EpsTolerance>::DEFAULT)
+    );
+    assert_eq!(<Fields as UlpsTolerance>::DEFAULT, (8, 4));
+    assert_eq!(
+        <Fields as RelTolerance>::DEFAULT,
+        (
+            <f32 as RelTolerance>::DEFAULT,
+            <f32 as RelTolerance>::DEFAULT
+        )
+    );
+
+    let a = Fields {
+        coarse: 1.0,
+        fine: 1.0,
+    };
+    let b = Fields {
+        coarse: 1.005,
+        fine: 1.0,
+    };
+    let c = Fields {
+        coarse: 1.0,
+        fine: 1.005,
+    };
+    assert_nearly!(a == b);
+    assert_nearly!(a != c);
+    assert_nearly!(a <= b);
+    assert_nearly!(a >= b);
+}
+
+#[test]
+fn derive_default_unnamed_field() {
+    assert_eq!(
+        <Unnamed as RelTolerance>::DEFAULT,
+        (<f64 as RelTolerance>::DEFAULT, 0.1)
+    );
+    assert_eq!(
+        <Unnamed as EpsTolerance>::DEFAULT,
+        (
+            <f64 as EpsTolerance>::DEFAULT,
+            <f64 as EpsTolerance>::DEFAULT
+        )
+    );
+}
+
+#[test]
+fn derive_default_container() {
+    assert_eq!(<Container as EpsTolerance>::DEFAULT, 0.01);
+    assert_eq!(<Container as UlpsTolerance>::DEFAULT, 8);
+    assert_eq!(<Container as RelTolerance>::DEFAULT, 0.1);
+
+    let a = Container { x: 1.0, y: 2.0 };
+    let b = Container { x: 1.005, y: 2.005 };
+    let c = Container { x: 1.05, y: 2.0 };
+    assert_nearly!(a == b);
+    assert_nearly!(a != c);
+    assert_nearly!(a <= b);
+}
+
+#[test]
+fn derive_default_container_tuple() {
+    assert_eq!(<Mixed as EpsTolerance>::DEFAULT, (0.01, 0.1));
+
+    let a = Mixed { x: 1.0, y: 2.0 };
+    let b = Mixed { x: 1.005, y: 2.05 };
+    assert_nearly!(a == b);
+}
+
+#[test]
+fn derive_default_enum() {
+    assert_eq!(
+        <Shape as EpsTolerance>::DEFAULT,
+        (0.1, <f32 as EpsTolerance>::DEFAULT)
+    );
+
+    let a = Shape::Circle { radius: 1.0 };
+    let b = Shape::Circle { radius: 1.05 };
+    assert_nearly!(a == b);
+    assert_nearly!(Shape::Square(1.0) != Shape::Square(1.05));
+}
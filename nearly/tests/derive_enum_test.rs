@@ -0,0 +1,125 @@
+use nearly::{assert_nearly, NearlyEq, NearlyEqTol, NearlyOrd, NearlyOrdEps, Tolerance};
+
+#[derive(NearlyEq, NearlyOrd, Debug)]
+enum SameType {
+    Point { x: f32, y: f32 },
+    Triple(f32, f32, f32),
+    Single(f32),
+    Empty(),
+    Unit,
+    Other,
+}
+
+#[derive(NearlyEq, NearlyOrd, Debug)]
+enum DifferentType {
+    Named { a: f32, b: f64 },
+    Unnamed(f64, f32),
+    Unit,
+}
+
+#[derive(NearlyEq, NearlyOrd, Debug)]
+enum Fieldless {
+    A,
+    B,
+}
+
+#[derive(NearlyEq, NearlyOrd, Debug)]
+enum SingleVariant {
+    Value { x: f64, y: f64 },
+}
+
+#[test]
+fn derive_enum_named_fields() {
+    let a = SameType::Point { x: 1.0, y: 2.0 };
+    let b = SameType::Point { x: 1.05, y: 2.0 };
+    let c = SameType::Point { x: 1.0, y: 2.5 };
+
+    assert_nearly!(a == b, eps = 0.1);
+    assert_nearly!(a != b, eps = 0.01);
+    assert_nearly!(a != c, eps = 0.1);
+    assert_nearly!(a <= c, eps = 0.1);
+    assert!(!a.nearly_lt_eps(&c, &0.1));
+    assert!(a.nearly_eq_tol(&b, &Tolerance::new(0.1, 0)));
+}
+
+#[test]
+fn derive_enum_multiple_unnamed_fields() {
+    let a = SameType::Triple(1.0, 2.0, 3.0);
+    let b = SameType::Triple(1.05, 2.05, 3.05);
+    let c = SameType::Triple(1.0, 2.0, 3.5);
+
+    assert_nearly!(a == b, eps = 0.1);
+    assert_nearly!(a != c, eps = 0.1);
+    assert_nearly!(a < SameType::Triple(2.0, 3.0, 4.0), eps = 0.1);
+    assert_nearly!(c >= a, ulps = 4);
+}
+
+#[test]
+fn derive_enum_different_variants() {
+    let a = SameType::Point { x: 1.0, y: 2.0 };
+    let b = SameType::Single(1.0);
+    let c = SameType::Triple(1.0, 2.0, 3.0);
+
+    assert_nearly!(a != b, eps = 0.1);
+    assert_nearly!(a != c, eps = 0.1);
+    assert!(!a.nearly_le_eps(&b, &0.1));
+    assert!(!a.nearly_ge_eps(&b, &0.1));
+}
+
+#[test]
+fn derive_enum_unit_variants() {
+    assert_nearly!(SameType::Unit == SameType::Unit);
+    assert_nearly!(SameType::Empty() == SameType::Empty());
+    assert_nearly!(SameType::Unit != SameType::Other);
+    assert_nearly!(SameType::Unit != SameType::Empty());
+    assert!(!SameType::Unit.nearly_le_eps(&SameType::Other, &0.1));
+}
+
+#[test]
+fn derive_enum_unit_variants_ord() {
+    for (a, b) in [
+        (SameType::Unit, SameType::Unit),
+        (SameType::Empty(), SameType::Empty()),
+    ] {
+        assert!(!a.nearly_lt(&b));
+        assert!(!a.nearly_gt(&b));
+        assert!(a.nearly_le(&b));
+        assert!(a.nearly_ge(&b));
+        assert!(!a.nearly_lt_eps(&b, &0.1));
+        assert!(!a.nearly_gt_eps(&b, &0.1));
+        assert_eq!(a.nearly_cmp(&b), Some(std::cmp::Ordering::Equal));
+    }
+    assert!(!Fieldless::A.nearly_lt(&Fieldless::A));
+    assert!(!Fieldless::A.nearly_gt(&Fieldless::A));
+}
+
+#[test]
+fn derive_enum_different_types() {
+    let a = DifferentType::Named { a: 1.0, b: 2.0 };
+    let b = DifferentType::Named { a: 1.05, b: 2.0 };
+    let c = DifferentType::Unnamed(1.0, 2.0);
+    let d = DifferentType::Unnamed(1.0, 2.05);
+
+    assert_nearly!(a == b, eps = (0.1, 0.01, 0.01, 0.01));
+    assert_nearly!(a != b, eps = (0.01, 0.1, 0.1, 0.1));
+    assert_nearly!(c == d, eps = (0.01, 0.01, 0.01, 0.1));
+    assert_nearly!(c != d, eps = (0.1, 0.1, 0.1, 0.01));
+    assert_nearly!(a != c, eps = (0.1, 0.1, 0.1, 0.1));
+    assert_nearly!(DifferentType::Unit == DifferentType::Unit);
+}
+
+#[test]
+fn derive_enum_fieldless() {
+    assert_nearly!(Fieldless::A == Fieldless::A);
+    assert_nearly!(Fieldless::A != Fieldless::B);
+    assert_nearly!(Fieldless::A == Fieldless::A, eps = ());
+}
+
+#[test]
+fn derive_enum_single_variant() {
+    let a = SingleVariant::Value { x: 1.0, y: 2.0 };
+    let b = SingleVariant::Value { x: 1.05, y: 2.05 };
+
+    assert_nearly!(a == b, eps = 0.1);
+    assert_nearly!(a < SingleVariant::Value { x: 2.0, y: 3.0 }, eps = 0.1);
+}
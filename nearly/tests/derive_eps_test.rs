@@ -400,7 +400,7 @@ macro_rules! impl_test {
                 let a = UnitStruct;
                 let b = UnitStruct;
 
-                assert!(a.[<nearly_ $fn _eps>](&b, &()));
+                assert_eq!(a.[<nearly_ $fn _eps>](&b, &()), empty_cmp!($fn));
             }
 
             #[test]
@@ -432,7 +432,7 @@ macro_rules! impl_test {
                     .times(0);
 
                 assert!(!b.[<nearly_ $fn _eps>](&c, &0.1_f32));
-                assert!(c.[<nearly_ $fn _eps>](&d, &0.1_f32));
+                assert_eq!(c.[<nearly_ $fn _eps>](&d, &0.1_f32), empty_cmp!($fn));
                 assert!(!b.[<nearly_ $fn _eps>](&e, &0.1_f32));
                 assert!(!c.[<nearly_ $fn _eps>](&e, &0.1_f32));
 
@@ -483,7 +483,7 @@ macro_rules! impl_test {
                     .times(0);
 
                 assert!(!b.[<nearly_ $fn _eps>](&c, &eps));
-                assert!(c.[<nearly_ $fn _eps>](&d, &eps));
+                assert_eq!(c.[<nearly_ $fn _eps>](&d, &eps), empty_cmp!($fn));
                 assert!(!b.[<nearly_ $fn _eps>](&e, &eps));
                 assert!(!c.[<nearly_ $fn _eps>](&e, &eps));
 
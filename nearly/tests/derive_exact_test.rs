@@ -0,0 +1,153 @@
+use nearly::{assert_nearly, EpsTolerance, NearlyEq, NearlyOrd, NearlyOrdEps, UlpsTolerance};
+
+#[derive(PartialEq, PartialOrd, Debug)]
+enum Kind {
+    Small,
+    Large,
+}
+
+#[derive(NearlyEq, NearlyOrd, Debug)]
+struct Measurement {
+    #[nearly(exact)]
+    count: u32,
+    #[nearly(exact)]
+    kind: Kind,
+    value: f32,
+}
+
+#[derive(NearlyEq, NearlyOrd, Debug)]
+struct Named(#[nearly(exact)] String, f64, f64);
+
+#[derive(NearlyEq, NearlyOrd, Debug)]
+struct Generic<T, U> {
+    #[nearly(exact)]
+    id: T,
+    value: U,
+}
+
+#[derive(NearlyEq, NearlyOrd, Debug)]
+enum Event {
+    Sample {
+        #[nearly(exact)]
+        channel: u8,
+        value: f32,
+    },
+    Marker(#[nearly(exact)] u8),
+}
+
+#[test]
+fn derive_exact_named_fields() {
+    let a = Measurement {
+        count: 3,
+        kind: Kind::Small,
+        value: 1.0,
+    };
+    let b = Measurement {
+        count: 3,
+        kind: Kind::Small,
+        value: 1.05,
+    };
+    let c = Measurement {
+        count: 4,
+        kind: Kind::Small,
+        value: 1.0,
+    };
+    let d = Measurement {
+        count: 3,
+        kind: Kind::Large,
+        value: 1.0,
+    };
+
+    let _: <Measurement as EpsTolerance>::T = 0.1_f32;
+    let _: <Measurement as UlpsTolerance>::T = 4_i32;
+
+    assert_nearly!(a == b, eps = 0.1);
+    assert_nearly!(a != b, eps = 0.01);
+    assert_nearly!(a != c, eps = 0.1);
+    assert_nearly!(a != d, eps = 0.1);
+}
+
+#[test]
+fn derive_exact_ordering() {
+    let a = Measurement {
+        count: 3,
+        kind: Kind::Small,
+        value: 1.0,
+    };
+    let b = Measurement {
+        count: 4,
+        kind: Kind::Large,
+        value: 2.0,
+    };
+    let c = Measurement {
+        count: 3,
+        kind: Kind::Large,
+        value: 2.0,
+    };
+
+    assert_nearly!(a < b, eps = 0.1);
+    assert_nearly!(b > a, eps = 0.1);
+    assert_nearly!(a <= c, eps = 0.1);
+    assert!(!a.nearly_lt_eps(&c, &0.1));
+    assert!(!c.nearly_gt_eps(&a, &0.1));
+    assert!(c.nearly_ge_eps(&a, &0.1));
+}
+
+#[test]
+fn derive_exact_unnamed_fields() {
+    let a = Named(String::from("a"), 1.0, 2.0);
+    let b = Named(String::from("a"), 1.05, 2.05);
+    let c = Named(String::from("b"), 1.0, 2.0);
+
+    let _: <Named as EpsTolerance>::T = 0.1_f64;
+
+    assert_nearly!(a == b, eps = 0.1);
+    assert_nearly!(a != c, eps = 0.1);
+    assert_nearly!(a <= c, eps = 0.1);
+}
+
+#[test]
+fn derive_exact_generic() {
+    let a = Generic {
+        id: 1_i32,
+        value: 1.0_f32,
+    };
+    let b = Generic {
+        id: 1_i32,
+        value: 1.05_f32,
+    };
+    let c = Generic {
+        id: 2_i32,
+        value: 1.0_f32,
+    };
+
+    let _: <Generic<i32, f32> as EpsTolerance>::T = 0.1_f32;
+
+    assert_nearly!(a == b, eps = 0.1);
+    assert_nearly!(a != c, eps = 0.1);
+    assert_nearly!(a <= c, eps = 0.1);
+}
+
+#[test]
+fn derive_exact_enum() {
+    let a = Event::Sample {
+        channel: 1,
+        value: 1.0,
+    };
+    let b = Event::Sample {
+        channel: 1,
+        value: 1.05,
+    };
+    let c = Event::Sample {
+        channel: 2,
+        value: 1.0,
+    };
+
+    let _: <Event as EpsTolerance>::T = 0.1_f32;
+
+    assert_nearly!(a == b, eps = 0.1);
+    assert_nearly!(a != c, eps = 0.1);
+    assert_nearly!(Event::Marker(1) == Event::Marker(1), eps = 0.1);
+    assert_nearly!(Event::Marker(1) != Event::Marker(2), eps = 0.1);
+    assert_nearly!(Event::Marker(1) < Event::Marker(2), eps = 0.1);
+}
@@ -0,0 +1,159 @@
+use nearly::{assert_nearly, NearlyEq, NearlyEqEps, NearlyEqTol, NearlyOrd, Tolerance};
+
+#[derive(NearlyEq, NearlyOrd, Debug)]
+struct Point<T> {
+    x: T,
+    y: T,
+}
+
+#[derive(NearlyEq, NearlyOrd, Debug)]
+struct Pair<T, U>(T, U);
+
+#[derive(NearlyEq, NearlyOrd, Debug)]
+struct WithDefault<T = f32> {
+    x: T,
+    y: f32,
+}
+
+#[derive(NearlyEq, NearlyOrd, Debug)]
+struct Bounded<T>
+where
+    T: Copy,
+{
+    x: T,
+}
+
+#[derive(NearlyEq, NearlyOrd, Debug)]
+struct Fixed<const N: usize> {
+    x: f64,
+}
+
+#[derive(NearlyEq, NearlyOrd, Debug)]
+struct View<'a, T> {
+    x: &'a T,
+    y: &'a T,
+}
+
+#[derive(NearlyEq, NearlyOrd, Debug)]
+enum Shape<T> {
+    Circle(T),
+    Square(T),
+}
+
+#[derive(NearlyEq, NearlyOrd, Debug)]
+struct Nested<T> {
+    a: Point<T>,
+    b: Point<T>,
+}
+
+#[test]
+fn derive_generic_named_struct() {
+    let a = Point { x: 1.0_f32, y: 2.0 };
+    let b = Point {
+        x: 1.0_f32 + f32::EPSILON,
+        y: 2.0,
+    };
+    let c = Point { x: 1.5_f32, y: 2.5 };
+
+    assert_nearly!(a == b);
+    assert_nearly!(a != c);
+    assert_nearly!(a < c);
+    assert_nearly!(a <= b);
+    assert_nearly!(c > a, eps = 0.1);
+    assert_nearly!(c >= a, ulps = 4);
+    assert!(a.nearly_eq_tol(&b, &Tolerance::new(0.0, 4)));
+
+    let a = Point { x: 1.0_f64, y: 2.0 };
+    let b = Point {
+        x: 1.05_f64,
+        y: 2.0,
+    };
+    assert!(a.nearly_eq_eps(&b, &0.1));
+    assert!(!a.nearly_eq_eps(&b, &0.01));
+}
+
+#[test]
+fn derive_generic_unnamed_struct() {
+    let a = Pair(1.0_f32, 2.0_f64);
+    let b = Pair(1.05_f32, 2.05_f64);
+
+    assert_nearly!(a == b, eps = (0.1, 0.1));
+    assert_nearly!(a != b, eps = (0.01, 0.1));
+    assert_nearly!(a < b, eps = (0.01, 0.01));
+}
+
+#[test]
+fn derive_generic_default_type() {
+    let a: WithDefault = WithDefault { x: 1.0, y: 2.0 };
+    let b: WithDefault = WithDefault { x: 1.0, y: 2.05 };
+    assert_nearly!(a == b, eps = (0.1, 0.1));
+
+    let a = WithDefault { x: 1.0_f64, y: 2.0 };
+    let b = WithDefault {
+        x: 1.05_f64,
+        y: 2.0,
+    };
+    assert_nearly!(a == b, eps = (0.1, 0.1));
+}
+
+#[test]
+fn derive_generic_where_clause() {
+    let a = Bounded { x: 1.0_f32 };
+    let b = Bounded { x: 1.05_f32 };
+    assert_nearly!(a == b, eps = 0.1);
+    assert_nearly!(a < b, eps = 0.01);
+}
+
+#[test]
+fn derive_generic_const() {
+    let a = Fixed::<3> { x: 1.0 };
+    let b = Fixed::<3> { x: 1.05 };
+    assert_nearly!(a == b, eps = 0.1);
+    assert_nearly!(a <= b, eps = 0.01);
+}
+
+#[test]
+fn derive_generic_lifetime() {
+    let values = [1.0_f32, 2.0, 2.05];
+    let a = View {
+        x: &values[0],
+        y: &values[1],
+    };
+    let b = View {
+        x: &values[0],
+        y: &values[2],
+    };
+    assert_nearly!(a == b, eps = 0.1);
+    assert_nearly!(a <= b, eps = 0.01);
+}
+
+#[test]
+fn derive_generic_enum() {
+    let a = Shape::Circle(1.0_f64);
+    let b = Shape::Circle(1.05_f64);
+    let c = Shape::Square(1.0_f64);
+
+    assert_nearly!(a == b, eps = 0.1);
+    assert_nearly!(a != c, eps = 0.1);
+    assert_nearly!(a < b, eps = 0.01);
+}
+
+#[test]
+fn derive_generic_nested() {
+    let a = Nested {
+        a: Point { x: 1.0_f32, y: 2.0 },
+        b: Point { x: 3.0_f32, y: 4.0 },
+    };
+    let b = Nested {
+        a: Point {
+            x: 1.05_f32,
+            y: 2.0,
+        },
+        b: Point {
+            x: 3.0_f32,
+            y: 4.05,
+        },
+    };
+    assert_nearly!(a == b, eps = 0.1);
+    assert_nearly!(a != b, eps = 0.01);
+}
@@ -0,0 +1,129 @@
+use nearly::{
+    assert_nearly, EpsTolerance, NearlyEq, NearlyEqEps, NearlyEqTol, NearlyOrd, NearlyOrdEps,
+    RelTolerance, Tolerance, UlpsTolerance,
+};
+
+#[derive(NearlyEq, NearlyOrd, Debug)]
+#[nearly(named_tolerance)]
+struct Point {
+    x: f32,
+    #[nearly(eps = 0.5)]
+    y: f64,
+    #[nearly(skip)]
+    #[allow(dead_code)]
+    label: &'static str,
+}
+
+#[derive(NearlyEq, Debug)]
+#[nearly(named_tolerance)]
+struct Pair(f32, #[nearly(exact)] u8, f32);
+
+#[derive(NearlyEq, Debug)]
+#[nearly(named_tolerance)]
+struct Generic<T> {
+    value: T,
+    scale: f64,
+}
+
+#[derive(NearlyEq, Debug)]
+#[nearly(named_tolerance, eps = SizeEps { width: 0.1, height: 0.2 })]
+struct Size {
+    width: f32,
+    height: f32,
+}
+
+#[test]
+fn derive_named_tolerance_fields() {
+    let eps: PointEps = <Point as EpsTolerance>::DEFAULT;
+    assert_eq!(eps.x, <f32 as EpsTolerance>::DEFAULT);
+    assert_eq!(eps.y, 0.5);
+
+    let ulps = PointUlps::default();
+    assert_eq!(ulps.x, <f32 as UlpsTolerance>::DEFAULT);
+    assert_eq!(ulps.y, <f64 as UlpsTolerance>::DEFAULT);
+
+    let rel: PointRel = <Point as RelTolerance>::DEFAULT;
+    assert_eq!(rel.x, <f32 as RelTolerance>::DEFAULT);
+    assert_eq!(rel.y, <f64 as RelTolerance>::DEFAULT);
+
+    let copy = eps;
+    assert_eq!(copy.y, eps.y);
+    assert_eq!(
+        format!("{:?}", PointEps { x: 0.5, y: 0.25 }),
+        "PointEps { x: 0.5, y: 0.25 }"
+    );
+}
+
+#[test]
+fn derive_named_tolerance_compare() {
+    let a = Point {
+        x: 1.0,
+        y: 2.0,
+        label: "a",
+    };
+    let b = Point {
+        x: 1.05,
+        y: 2.3,
+        label: "b",
+    };
+
+    assert_nearly!(a == b, eps = PointEps { x: 0.1, y: 0.5 });
+    assert_nearly!(a != b, eps = PointEps { x: 0.01, y: 0.5 });
+    assert_nearly!(a != b, eps = PointEps { x: 0.1, y: 0.1 });
+    assert!(a.nearly_eq_eps(&b, &PointEps { x: 0.1, y: 0.5 }));
+    assert!(a.nearly_le_eps(&b, &PointEps { x: 0.01, y: 0.01 }));
+    assert!(a.nearly_eq_tol(
+        &b,
+        &Tolerance::new(PointEps { x: 0.1, y: 0.5 }, PointUlps { x: 0, y: 0 })
+    ));
+}
+
+#[test]
+fn derive_named_tolerance_tuple() {
+    let eps = PairEps(0.1, 0.2);
+    assert_eq!(format!("{:?}", eps), "PairEps(0.1, 0.2)");
+
+    let a = Pair(1.0, 1, 2.0);
+    let b = Pair(1.05, 1, 2.15);
+    assert_nearly!(a == b, eps = eps);
+    assert_nearly!(a != b, eps = PairEps(0.1, 0.1));
+    assert_nearly!(a != Pair(1.0, 2, 2.0), eps = eps);
+}
+
+#[test]
+fn derive_named_tolerance_generic() {
+    let eps: GenericEps<f32> = GenericEps {
+        value: 0.1,
+        scale: 0.01,
+    };
+    let a = Generic {
+        value: 1.0_f32,
+        scale: 1.0,
+    };
+    let b = Generic {
+        value: 1.05_f32,
+        scale: 1.005,
+    };
+    assert_nearly!(a == b, eps = eps);
+    assert_eq!(
+        GenericEps::<f32>::default().value,
+        <f32 as EpsTolerance>::DEFAULT
+    );
+}
+
+#[test]
+fn derive_named_tolerance_container_default() {
+    let eps = SizeEps::default();
+    assert_eq!(eps.width, 0.1);
+    assert_eq!(eps.height, 0.2);
+
+    let a = Size {
+        width: 1.0,
+        height: 1.0,
+    };
+    let b = Size {
+        width: 1.05,
+        height: 1.15,
+    };
+    assert_nearly!(a == b, eps = SizeEps::default());
+}
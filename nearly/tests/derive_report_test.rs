@@ -0,0 +1,288 @@
+#![cfg(feature = "std")]
+
+use nearly::{
+    EpsToleranceType, MismatchKind, NearlyEq, NearlyEqReport, PathSegment, PerElement,
+    RelToleranceType, Tolerance, UlpsToleranceType,
+};
+
+/// Compares values by their magnitude.
+mod magnitude {
+    use super::*;
+    use nearly::{NearlyEqEps, NearlyEqRel, NearlyEqTol, NearlyEqUlps};
+
+    pub fn nearly_eq_eps(a: &f32, b: &f32, eps: &EpsToleranceType<f32>) -> bool {
+        a.abs().nearly_eq_eps(&b.abs(), eps)
+    }
+
+    pub fn nearly_eq_ulps(a: &f32, b: &f32, ulps: &UlpsToleranceType<f32>) -> bool {
+        a.abs().nearly_eq_ulps(&b.abs(), ulps)
+    }
+
+    pub fn nearly_eq_rel(a: &f32, b: &f32, rel: &RelToleranceType<f32>) -> bool {
+        a.abs().nearly_eq_rel(&b.abs(), rel)
+    }
+
+    pub fn nearly_eq_tol(a: &f32, b: &f32, tol: &Tolerance<f32>) -> bool {
+        a.abs().nearly_eq_tol(&b.abs(), tol)
+    }
+}
+
+#[derive(NearlyEq, NearlyEqReport, Debug)]
+struct Point {
+    x: f32,
+    y: f32,
+}
+
+#[derive(NearlyEq, NearlyEqReport, Debug)]
+struct Sample {
+    pos: PerElement<(f32, f64)>,
+    weight: Option<f32>,
+    #[nearly(exact)]
+    id: u8,
+    #[nearly(skip)]
+    #[allow(dead_code)]
+    label: &'static str,
+    #[nearly(with = magnitude)]
+    amplitude: f32,
+}
+
+#[derive(NearlyEq, NearlyEqReport, Debug)]
+struct Pair(f32, f64);
+
+#[derive(NearlyEq, NearlyEqReport, Debug)]
+#[nearly(named_tolerance)]
+struct Named {
+    x: f32,
+    y: f64,
+}
+
+#[derive(NearlyEq, NearlyEqReport, Debug)]
+struct Generic<T> {
+    value: T,
+}
+
+#[derive(NearlyEq, NearlyEqReport, Debug)]
+enum Shape {
+    Circle { radius: f32 },
+    Rect(f32, f32),
+    Empty,
+}
+
+#[derive(NearlyEq, NearlyEqReport, Debug)]
+#[nearly(rhs = PointF64)]
+struct PointF32 {
+    x: f32,
+    y: f32,
+}
+
+#[derive(Debug)]
+struct PointF64 {
+    x: f32,
+    y: f32,
+    #[allow(dead_code)]
+    z: f64,
+}
+
+fn sample() -> Sample {
+    Sample {
+        pos: PerElement((1.0, 2.0)),
+        weight: Some(1.0),
+        id: 1,
+        label: "a",
+        amplitude: 1.0,
+    }
+}
+
+#[test]
+fn derive_report_same_type() {
+    let a = Point { x: 1.0, y: 2.0 };
+    let b = Point { x: 1.05, y: 2.5 };
+
+    assert!(a.nearly_eq_eps_report(&b, &0.6).is_nearly_eq());
+
+    let report = a.nearly_eq_eps_report(&b, &0.1);
+    assert_eq!(report.mismatches().len(), 1);
+    assert_eq!(
+        report.first().unwrap().path.0,
+        vec![PathSegment::Field("y")]
+    );
+    assert_eq!(
+        report.to_string(),
+        "y: 2.0 != 2.5 (diff: 0.5, ulps: 2097152, eps: 0.1)"
+    );
+
+    let report = a.nearly_eq_ulps_report(&b, &4);
+    assert_eq!(report.mismatches().len(), 2);
+    assert_eq!(report.mismatches()[0].path.to_string(), "x");
+    assert_eq!(report.mismatches()[1].path.to_string(), "y");
+
+    let report = a.nearly_eq_tol_report(&b, &Tolerance::new(0.1, 4).with_rel(0.3));
+    assert!(report.is_nearly_eq());
+    assert!(!a.nearly_eq_report(&b).is_nearly_eq());
+    assert!(a.nearly_eq_rel_report(&b, &0.3).is_nearly_eq());
+}
+
+#[test]
+fn derive_report_different_types() {
+    let a = sample();
+    let eps = ((0.1, 0.1), 0.1, 0.1);
+
+    assert!(a.nearly_eq_eps_report(&sample(), &eps).is_nearly_eq());
+
+    let b = Sample {
+        pos: PerElement((1.0, 2.5)),
+        weight: None,
+        id: 2,
+        label: "b",
+        amplitude: -1.5,
+    };
+
+    let report = a.nearly_eq_eps_report(&b, &eps);
+    let lines: Vec<String> = report
+        .mismatches()
+        .iter()
+        .map(|it| it.to_string())
+        .collect();
+    assert_eq!(
+        lines,
+        vec![
+            "pos.1: 2.0 != 2.5 (diff: 0.5, ulps: 1125899906842624, eps: 0.1)",
+            "weight: variant Some != None",
+            "id: not equal",
+            "amplitude: not equal",
+        ]
+    );
+
+    let b = Sample {
+        amplitude: -1.0,
+        ..sample()
+    };
+    assert!(a.nearly_eq_eps_report(&b, &eps).is_nearly_eq());
+
+    let b = Sample {
+        pos: PerElement((1.5, 2.0)),
+        ..sample()
+    };
+    let tol = Tolerance::new(eps, ((0, 0), 0, 0));
+    assert_eq!(
+        a.nearly_eq_tol_report(&b, &tol).to_string(),
+        "pos.0: 1.0 != 1.5 (diff: 0.5, ulps: 4194304, eps: 0.1, ulps: 0)"
+    );
+}
+
+#[test]
+fn derive_report_unnamed() {
+    let a = Pair(1.0, 2.0);
+    let b = Pair(1.5, 2.0);
+
+    let report = a.nearly_eq_eps_report(&b, &(0.1, 0.1));
+    assert_eq!(
+        report.first().unwrap().path.0,
+        vec![PathSegment::Field("0")]
+    );
+    assert_eq!(report.first().unwrap().path.to_string(), "0");
+}
+
+#[test]
+fn derive_report_named_tolerance() {
+    let a = Named { x: 1.0, y: 2.0 };
+    let b = Named { x: 1.0, y: 2.05 };
+
+    let eps = NamedEps { x: 0.1, y: 0.01 };
+    let report = a.nearly_eq_eps_report(&b, &eps);
+    assert_eq!(report.mismatches().len(), 1);
+    assert_eq!(report.first().unwrap().path.to_string(), "y");
+
+    let eps = NamedEps { x: 0.1, y: 0.1 };
+    assert!(a.nearly_eq_eps_report(&b, &eps).is_nearly_eq());
+}
+
+#[test]
+fn derive_report_generic() {
+    let a = Generic { value: 1.0f64 };
+    let b = Generic { value: 1.5f64 };
+
+    assert_eq!(
+        a.nearly_eq_eps_report(&b, &0.1)
+            .first()
+            .unwrap()
+            .path
+            .to_string(),
+        "value"
+    );
+    assert!(a.nearly_eq_eps_report(&b, &0.6).is_nearly_eq());
+}
+
+#[test]
+fn derive_report_enum() {
+    let a = Shape::Rect(1.0, 2.0);
+
+    assert!(a
+        .nearly_eq_eps_report(&Shape::Rect(1.0, 2.05), &0.1)
+        .is_nearly_eq());
+    assert_eq!(
+        a.nearly_eq_eps_report(&Shape::Rect(1.5, 2.0), &0.1)
+            .to_string(),
+        "Rect.0: 1.0 != 1.5 (diff: 0.5, ulps: 4194304, eps: 0.1)"
+    );
+    assert_eq!(
+        a.nearly_eq_eps_report(&Shape::Rect(1.0, 2.5), &0.1)
+            .first()
+            .unwrap()
+            .path
+            .0,
+        vec![PathSegment::Field("Rect"), PathSegment::Field("1")]
+    );
+    assert_eq!(
+        a.nearly_eq_eps_report(&Shape::Empty, &0.1)
+            .first()
+            .unwrap()
+            .kind,
+        MismatchKind::Variant {
+            lhs: "Rect",
+            rhs: "Empty"
+        }
+    );
+
+    let a = Shape::Circle { radius: 1.0 };
+    assert_eq!(
+        a.nearly_eq_eps_report(&Shape::Circle { radius: 2.0 }, &0.1)
+            .first()
+            .unwrap()
+            .path
+            .to_string(),
+        "Circle.radius"
+    );
+    assert!(Shape::Empty
+        .nearly_eq_eps_report(&Shape::Empty, &0.1)
+        .is_nearly_eq());
+}
+
+#[test]
+fn derive_report_rhs() {
+    let a = PointF32 { x: 1.0, y: 2.0 };
+    let b = PointF64 {
+        x: 1.5,
+        y: 2.0,
+        z: 3.0,
+    };
+
+    let report = a.nearly_eq_eps_report(&b, &0.1);
+    assert_eq!(report.first().unwrap().path.to_string(), "x");
+    assert!(a
+        .nearly_eq_eps_report(&PointF32 { x: 1.05, y: 2.0 }, &0.1)
+        .is_nearly_eq());
+}
+
+#[test]
+fn derive_report_nested() {
+    let a = vec![Point { x: 1.0, y: 2.0 }, Point { x: 3.0, y: 4.0 }];
+    let b = vec![Point { x: 1.0, y: 2.0 }, Point { x: 3.0, y: 4.5 }];
+
+    let report = a.nearly_eq_eps_report(&b, &0.1);
+    assert_eq!(
+        report.first().unwrap().path.0,
+        vec![PathSegment::Index(1), PathSegment::Field("y")]
+    );
+    assert_eq!(report.first().unwrap().path.to_string(), "[1].y");
+}
@@ -0,0 +1,229 @@
+use nearly::{
+    assert_nearly, EpsTolerance, EpsToleranceType, NearlyEq, NearlyEqEps, NearlyOrd, NearlyOrdEps,
+    Tolerance,
+};
+
+#[derive(NearlyEq, NearlyOrd, Debug)]
+#[nearly(rhs = PointView<'a>, rhs = PointF64, rhs = PointRef<'a>)]
+struct PointF32 {
+    #[nearly(rhs(PointRef<'a>) = &'a f32)]
+    x: f32,
+    #[nearly(rhs(PointRef<'a>) = &'a f32)]
+    y: f32,
+}
+
+#[derive(Debug)]
+struct PointView<'a> {
+    x: f32,
+    y: f32,
+    #[allow(dead_code)]
+    label: &'a str,
+}
+
+#[derive(Debug)]
+struct PointRef<'a> {
+    x: &'a f32,
+    y: &'a f32,
+}
+
+#[derive(Debug)]
+struct PointF64 {
+    x: f32,
+    y: f32,
+    #[allow(dead_code)]
+    z: f64,
+}
+
+#[derive(NearlyEq, Debug)]
+#[nearly(rhs = Other<T>)]
+struct Generic<T> {
+    value: T,
+    #[nearly(exact)]
+    id: u8,
+    #[nearly(skip)]
+    #[allow(dead_code)]
+    cache: u8,
+}
+
+#[derive(Debug)]
+struct Other<T> {
+    value: T,
+    id: u8,
+}
+
+#[derive(NearlyEq, Debug)]
+#[nearly(rhs = (f32, f64))]
+struct Pair(f32, f64);
+
+#[test]
+fn derive_rhs_with_lifetime() {
+    let a = PointF32 { x: 1.0, y: 2.0 };
+    let b = PointView {
+        x: 1.0,
+        y: 2.0000002,
+        label: "b",
+    };
+    let c = PointView {
+        x: 1.1,
+        y: 2.0,
+        label: "c",
+    };
+
+    assert_eq!(
+        <PointF32 as EpsTolerance<PointView>>::DEFAULT,
+        <PointF32 as EpsTolerance>::DEFAULT
+    );
+    assert_nearly!(a == b);
+    assert_nearly!(a == b, eps = 0.001);
+    assert_nearly!(a == b, ulps = 4);
+    assert_nearly!(a == b, rel = 0.001);
+    assert_nearly!(a == b, tol = Tolerance::new(0.001, 4));
+    assert_nearly!(a != c, eps = 0.001);
+    assert!(a.nearly_eq_eps(&b, &0.001));
+    assert!(a.nearly_le_eps(&c, &0.001));
+}
+
+#[test]
+fn derive_rhs_multiple() {
+    let a = PointF32 { x: 1.0, y: 2.0 };
+    let b = PointF64 {
+        x: 1.0,
+        y: 2.0,
+        z: 3.0,
+    };
+    let c = PointF64 {
+        x: 2.0,
+        y: 3.0,
+        z: 3.0,
+    };
+    assert_nearly!(a == b, eps = 0.001);
+    assert_nearly!(a <= b, eps = 0.001);
+    assert_nearly!(a < c, eps = 0.001);
+    assert_nearly!(a <= c, tol = Tolerance::new(0.001, 4));
+    assert_nearly!(a == PointF32 { x: 1.0, y: 2.0 }, eps = 0.001);
+}
+
+#[test]
+fn derive_rhs_generic() {
+    let a = Generic {
+        value: 1.0_f64,
+        id: 1,
+        cache: 0,
+    };
+    let b = Other { value: 1.05, id: 1 };
+    let c = Other { value: 1.05, id: 2 };
+    assert_nearly!(a == b, eps = 0.1);
+    assert_nearly!(a != c, eps = 0.1);
+    assert_nearly!(a != b, eps = 0.01);
+    assert_eq!(a.cache, 0);
+}
+
+#[test]
+fn derive_rhs_tuple() {
+    let a = Pair(1.0, 2.0);
+    assert_nearly!(a == (1.05, 2.05), eps = (0.1, 0.1));
+    assert_nearly!(a != (1.05, 2.5), eps = (0.1, 0.1));
+}
+
+#[derive(NearlyEq, NearlyOrd, Debug)]
+#[nearly(rhs = SegmentView<'a>)]
+struct Segment {
+    #[nearly(rhs = PointView<'a>)]
+    start: PointF32,
+    #[nearly(rhs = &'a PointF32)]
+    end: PointF32,
+    #[nearly(rhs = &'a f32)]
+    weight: f32,
+}
+
+#[derive(Debug)]
+struct SegmentView<'a> {
+    start: PointView<'a>,
+    end: &'a PointF32,
+    weight: &'a f32,
+}
+
+#[derive(NearlyEq, NearlyOrd, Debug)]
+#[nearly(rhs = Borrowed<'a, T>)]
+struct Owned<T> {
+    #[nearly(rhs = &'a T)]
+    value: T,
+    #[nearly(exact, rhs = &'a u8)]
+    id: u8,
+}
+
+#[derive(Debug)]
+struct Borrowed<'a, T> {
+    value: &'a T,
+    id: &'a u8,
+}
+
+#[test]
+fn derive_rhs_reference_fields() {
+    let a = PointF32 { x: 1.0, y: 2.0 };
+    let (x, y) = (1.0, 2.05);
+    let b = PointRef { x: &x, y: &y };
+
+    assert_eq!(
+        <PointF32 as EpsTolerance<PointRef>>::DEFAULT,
+        <f32 as EpsTolerance>::DEFAULT
+    );
+    assert_nearly!(a == b, eps = 0.1);
+    assert_nearly!(a != b, eps = 0.01);
+    assert_nearly!(a <= b, tol = Tolerance::new(0.01, 4));
+    assert_nearly!(a <= b, ulps = 4);
+    assert_nearly!(a >= b, eps = 0.1);
+}
+
+#[test]
+fn derive_rhs_different_field_types() {
+    let a = Segment {
+        start: PointF32 { x: 1.0, y: 2.0 },
+        end: PointF32 { x: 3.0, y: 4.0 },
+        weight: 0.5,
+    };
+    let end = PointF32 { x: 3.05, y: 4.0 };
+    let weight = 0.55;
+    let b = SegmentView {
+        start: PointView {
+            x: 1.0,
+            y: 2.05,
+            label: "start",
+        },
+        end: &end,
+        weight: &weight,
+    };
+
+    let eps: EpsToleranceType<Segment, SegmentView> = (0.1, 0.1, 0.1);
+    assert!(a.nearly_eq_eps(&b, &eps));
+    assert!(!a.nearly_eq_eps(&b, &(0.1, 0.1, 0.01)));
+    assert!(a.nearly_le_eps(&b, &(0.1, 0.01, 0.01)));
+    assert_nearly!(a == b, eps = (0.1, 0.1, 0.1));
+    assert_nearly!(a != b, eps = (0.01, 0.1, 0.1));
+    assert_nearly!(a == b, tol = Tolerance::new((0.1, 0.1, 0.1), (4, 4, 4)));
+}
+
+#[test]
+fn derive_rhs_generic_reference_fields() {
+    let a = Owned {
+        value: 1.0_f64,
+        id: 1,
+    };
+    let (value, id) = (1.05, 1);
+    let b = Borrowed {
+        value: &value,
+        id: &id,
+    };
+    let other_id = 2;
+    let c = Borrowed {
+        value: &value,
+        id: &other_id,
+    };
+
+    assert_nearly!(a == b, eps = 0.1);
+    assert_nearly!(a != b, eps = 0.01);
+    assert_nearly!(a != c, eps = 0.1);
+    assert_nearly!(a <= c, eps = 0.1);
+    assert_nearly!(a < c, eps = 0.01);
+    assert_nearly!(a <= b, rel = 0.1);
+}
@@ -0,0 +1,136 @@
+use nearly::{assert_nearly, EpsTolerance, NearlyEq, NearlyOrd, NearlyOrdEps, UlpsTolerance};
+
+#[derive(NearlyEq, NearlyOrd, Debug)]
+struct Labeled {
+    #[nearly(skip)]
+    label: String,
+    x: f32,
+    y: f32,
+}
+
+#[derive(NearlyEq, NearlyOrd, Debug)]
+struct Mixed {
+    x: f32,
+    #[nearly(skip)]
+    id: u32,
+    y: f64,
+}
+
+#[derive(NearlyEq, NearlyOrd, Debug)]
+struct Unnamed(#[nearly(skip)] String, f32, #[nearly(skip)] u32, f32);
+
+#[derive(NearlyEq, NearlyOrd, Debug)]
+struct AllSkipped {
+    #[nearly(skip)]
+    id: u32,
+}
+
+#[derive(NearlyEq, NearlyOrd, Debug)]
+enum State {
+    Running {
+        #[nearly(skip)]
+        name: String,
+        t: f64,
+    },
+    Stopped(#[nearly(skip)] u32, f64),
+}
+
+#[test]
+fn derive_skip_named_field() {
+    let a = Labeled {
+        label: String::from("a"),
+        x: 1.0,
+        y: 2.0,
+    };
+    let b = Labeled {
+        label: String::from("b"),
+        x: 1.05,
+        y: 2.0,
+    };
+
+    let _: <Labeled as EpsTolerance>::T = 0.1_f32;
+    let _: <Labeled as UlpsTolerance>::T = 4_i32;
+
+    assert_ne!(a.label, b.label);
+    assert_nearly!(a == b, eps = 0.1);
+    assert_nearly!(a != b, eps = 0.01);
+    assert_nearly!(a <= b, eps = 0.01);
+}
+
+#[test]
+fn derive_skip_named_field_different_types() {
+    let a = Mixed {
+        x: 1.0,
+        id: 1,
+        y: 2.0,
+    };
+    let b = Mixed {
+        x: 1.05,
+        id: 2,
+        y: 2.0,
+    };
+
+    let _: <Mixed as EpsTolerance>::T = (0.1_f32, 0.1_f64);
+
+    assert_ne!(a.id, b.id);
+    assert_nearly!(a == b, eps = (0.1, 0.1));
+    assert_nearly!(a != b, eps = (0.01, 0.1));
+}
+
+#[test]
+fn derive_skip_unnamed_field() {
+    let a = Unnamed(String::from("a"), 1.0, 1, 2.0);
+    let b = Unnamed(String::from("b"), 1.05, 2, 2.05);
+
+    let _: <Unnamed as EpsTolerance>::T = 0.1_f32;
+
+    assert_ne!((&a.0, a.2), (&b.0, b.2));
+    assert_nearly!(a == b, eps = 0.1);
+    assert_nearly!(a != b, eps = 0.01);
+    assert_nearly!(a < b, eps = 0.01);
+}
+
+#[test]
+fn derive_skip_all_fields() {
+    let a = AllSkipped { id: 1 };
+    let b = AllSkipped { id: 2 };
+
+    let _: <AllSkipped as EpsTolerance>::T = ();
+
+    assert_ne!(a.id, b.id);
+    assert_nearly!(a == b);
+    assert_nearly!(a <= b, eps = ());
+    assert!(!a.nearly_lt_eps(&b, &()));
+    assert!(!a.nearly_gt_eps(&b, &()));
+    assert!(!a.nearly_lt(&b));
+    assert!(!a.nearly_gt(&b));
+    assert!(a.nearly_ge(&b));
+}
+
+#[test]
+fn derive_skip_enum_fields() {
+    let a = State::Running {
+        name: String::from("a"),
+        t: 1.0,
+    };
+    let b = State::Running {
+        name: String::from("b"),
+        t: 1.05,
+    };
+    let c = State::Stopped(1, 1.0);
+    let d = State::Stopped(2, 1.05);
+
+    let _: <State as EpsTolerance>::T = 0.1_f64;
+
+    if let (State::Running { name: a_name, .. }, State::Running { name: b_name, .. }) = (&a, &b) {
+        assert_ne!(a_name, b_name);
+    }
+    if let (State::Stopped(c_id, _), State::Stopped(d_id, _)) = (&c, &d) {
+        assert_ne!(c_id, d_id);
+    }
+    assert_nearly!(a == b, eps = 0.1);
+    assert_nearly!(a != b, eps = 0.01);
+    assert_nearly!(c == d, eps = 0.1);
+    assert_nearly!(c < d, eps = 0.01);
+    assert_nearly!(a != c, eps = 0.1);
+}
@@ -396,7 +396,7 @@ macro_rules! impl_test {
                 let a = UnitStruct;
                 let b = UnitStruct;
 
-                assert!(a.[<nearly_ $fn>](&b));
+                assert_eq!(a.[<nearly_ $fn>](&b), empty_cmp!($fn));
             }
 
             #[test]
@@ -428,7 +428,7 @@ macro_rules! impl_test {
                     .times(0);
 
                 assert!(!b.[<nearly_ $fn>](&c));
-                assert!(c.[<nearly_ $fn>](&d));
+                assert_eq!(c.[<nearly_ $fn>](&d), empty_cmp!($fn));
                 assert!(!b.[<nearly_ $fn>](&e));
                 assert!(!c.[<nearly_ $fn>](&e));
 
@@ -477,7 +477,7 @@ macro_rules! impl_test {
                     .times(0);
 
                 assert!(!b.[<nearly_ $fn>](&c));
-                assert!(c.[<nearly_ $fn>](&d));
+                assert_eq!(c.[<nearly_ $fn>](&d), empty_cmp!($fn));
                 assert!(!b.[<nearly_ $fn>](&e));
                 assert!(!c.[<nearly_ $fn>](&e));
 
@@ -503,8 +503,8 @@ macro_rules! impl_test {
 mod nearly_eq {
     use super::*;
     use nearly::{
-        EpsTolerance, EpsToleranceType, NearlyEq, NearlyEqEps, NearlyEqTol, NearlyEqUlps,
-        Tolerance, UlpsTolerance, UlpsToleranceType,
+        EpsTolerance, EpsToleranceType, NearlyEq, NearlyEqEps, NearlyEqRel, NearlyEqTol,
+        NearlyEqUlps, RelTolerance, RelToleranceType, Tolerance, UlpsTolerance, UlpsToleranceType,
     };
 
     mock!(
@@ -520,6 +520,11 @@ mod nearly_eq {
             const DEFAULT: i32 = 7;
         }
 
+        impl RelTolerance for A {
+            type T = f32;
+            const DEFAULT: f32 = 0.5;
+        }
+
         impl NearlyEqEps for A {
             fn nearly_eq_eps(&self, other: &Self, eps: &EpsToleranceType<Self>) -> bool;
         }
@@ -528,6 +533,10 @@ mod nearly_eq {
             fn nearly_eq_ulps(&self, other: &Self, ulps: &UlpsToleranceType<Self>) -> bool;
         }
 
+        impl NearlyEqRel for A {
+            fn nearly_eq_rel(&self, other: &Self, rel: &RelToleranceType<Self>) -> bool;
+        }
+
         impl NearlyEqTol for A {
             fn nearly_eq_tol(&self, other: &Self, tol: &Tolerance<Self>) -> bool;
         }
@@ -550,6 +559,11 @@ mod nearly_eq {
             const DEFAULT: i64 = 13;
         }
 
+        impl RelTolerance for B {
+            type T = f64;
+            const DEFAULT: f64 = 0.05;
+        }
+
         impl NearlyEqEps for B {
             fn nearly_eq_eps(&self, other: &Self, eps: &EpsToleranceType<Self>) -> bool;
         }
@@ -558,6 +572,10 @@ mod nearly_eq {
             fn nearly_eq_ulps(&self, other: &Self, ulps: &UlpsToleranceType<Self>) -> bool;
         }
 
+        impl NearlyEqRel for B {
+            fn nearly_eq_rel(&self, other: &Self, rel: &RelToleranceType<Self>) -> bool;
+        }
+
         impl NearlyEqTol for B {
             fn nearly_eq_tol(&self, other: &Self, tol: &Tolerance<Self>) -> bool;
         }
@@ -619,10 +637,11 @@ mod nearly_eq {
 mod nearly_ord {
     use super::*;
     use nearly::{
-        EpsTolerance, EpsToleranceType, NearlyEq, NearlyEqEps, NearlyEqTol, NearlyEqUlps,
-        NearlyOrd, NearlyOrdEps, NearlyOrdTol, NearlyOrdUlps, Tolerance, UlpsTolerance,
-        UlpsToleranceType,
+        EpsTolerance, EpsToleranceType, NearlyEq, NearlyEqEps, NearlyEqRel, NearlyEqTol,
+        NearlyEqUlps, NearlyOrd, NearlyOrdEps, NearlyOrdRel, NearlyOrdTol, NearlyOrdUlps,
+        RelTolerance, RelToleranceType, Tolerance, UlpsTolerance, UlpsToleranceType,
     };
+    use std::cmp::Ordering;
 
     mock!(
         pub A{}
@@ -637,6 +656,11 @@ mod nearly_ord {
             const DEFAULT: i32 = 7;
         }
 
+        impl RelTolerance for A {
+            type T = f32;
+            const DEFAULT: f32 = 0.5;
+        }
+
         impl NearlyEqEps for A {
             fn nearly_eq_eps(&self, other: &Self, eps: &EpsToleranceType<Self>) -> bool;
         }
@@ -645,6 +669,10 @@ mod nearly_ord {
             fn nearly_eq_ulps(&self, other: &Self, ulps: &UlpsToleranceType<Self>) -> bool;
         }
 
+        impl NearlyEqRel for A {
+            fn nearly_eq_rel(&self, other: &Self, rel: &RelToleranceType<Self>) -> bool;
+        }
+
         impl NearlyEqTol for A {
             fn nearly_eq_tol(&self, other: &Self, tol: &Tolerance<Self>) -> bool;
         }
@@ -667,6 +695,13 @@ mod nearly_ord {
             fn nearly_ge_ulps(&self, other: &Self, ulps: &UlpsToleranceType<Self>) -> bool;
         }
 
+        impl NearlyOrdRel for A {
+            fn nearly_lt_rel(&self, other: &Self, rel: &RelToleranceType<Self>) -> bool;
+            fn nearly_le_rel(&self, other: &Self, rel: &RelToleranceType<Self>) -> bool;
+            fn nearly_gt_rel(&self, other: &Self, rel: &RelToleranceType<Self>) -> bool;
+            fn nearly_ge_rel(&self, other: &Self, rel: &RelToleranceType<Self>) -> bool;
+        }
+
         impl NearlyOrdTol for A {
             fn nearly_lt_tol(&self, other: &Self, tol: &Tolerance<Self>) -> bool;
             fn nearly_le_tol(&self, other: &Self, tol: &Tolerance<Self>) -> bool;
@@ -695,6 +730,11 @@ mod nearly_ord {
             const DEFAULT: i64 = 13;
         }
 
+        impl RelTolerance for B {
+            type T = f64;
+            const DEFAULT: f64 = 0.05;
+        }
+
         impl NearlyEqEps for B {
             fn nearly_eq_eps(&self, other: &Self, eps: &EpsToleranceType<Self>) -> bool;
         }
@@ -703,6 +743,10 @@ mod nearly_ord {
             fn nearly_eq_ulps(&self, other: &Self, ulps: &UlpsToleranceType<Self>) -> bool;
         }
 
+        impl NearlyEqRel for B {
+            fn nearly_eq_rel(&self, other: &Self, rel: &RelToleranceType<Self>) -> bool;
+        }
+
         impl NearlyEqTol for B {
             fn nearly_eq_tol(&self, other: &Self, tol: &Tolerance<Self>) -> bool;
         }
@@ -725,6 +769,13 @@ mod nearly_ord {
             fn nearly_ge_ulps(&self, other: &Self, ulps: &UlpsToleranceType<Self>) -> bool;
         }
 
+        impl NearlyOrdRel for B {
+            fn nearly_lt_rel(&self, other: &Self, rel: &RelToleranceType<Self>) -> bool;
+            fn nearly_le_rel(&self, other: &Self, rel: &RelToleranceType<Self>) -> bool;
+            fn nearly_gt_rel(&self, other: &Self, rel: &RelToleranceType<Self>) -> bool;
+            fn nearly_ge_rel(&self, other: &Self, rel: &RelToleranceType<Self>) -> bool;
+        }
+
         impl NearlyOrdTol for B {
             fn nearly_lt_tol(&self, other: &Self, tol: &Tolerance<Self>) -> bool;
             fn nearly_le_tol(&self, other: &Self, tol: &Tolerance<Self>) -> bool;
@@ -790,4 +841,120 @@ mod nearly_ord {
     impl_test!(le);
     impl_test!(gt);
     impl_test!(ge);
+
+    #[test]
+    fn derive_nearly_cmp_named_struct_same_type() {
+        let mut a = NamedStructSameType {
+            x: MockA::new(),
+            y: MockA::new(),
+            z: MockA::new(),
+        };
+        let b = NamedStructSameType {
+            x: MockA::new(),
+            y: MockA::new(),
+            z: MockA::new(),
+        };
+
+        a.x.expect_nearly_le_tol()
+            .with(always(), eq(Tolerance::<MockA>::new(0.5_f32, 7_i32)))
+            .times(1)
+            .return_const(true);
+        a.y.expect_nearly_le_tol()
+            .with(always(), eq(Tolerance::<MockA>::new(0.5_f32, 7_i32)))
+            .times(1)
+            .return_const(true);
+        a.z.expect_nearly_le_tol()
+            .with(always(), eq(Tolerance::<MockA>::new(0.5_f32, 7_i32)))
+            .times(1)
+            .return_const(true);
+        a.x.expect_nearly_ge_tol()
+            .with(always(), eq(Tolerance::<MockA>::new(0.5_f32, 7_i32)))
+            .times(1)
+            .return_const(true);
+        a.y.expect_nearly_ge_tol()
+            .with(always(), eq(Tolerance::<MockA>::new(0.5_f32, 7_i32)))
+            .times(1)
+            .return_const(true);
+        a.z.expect_nearly_ge_tol()
+            .with(always(), eq(Tolerance::<MockA>::new(0.5_f32, 7_i32)))
+            .times(1)
+            .return_const(true);
+
+        assert_eq!(a.nearly_cmp(&b), Some(Ordering::Equal));
+
+        a.x.checkpoint();
+        a.y.checkpoint();
+        a.z.checkpoint();
+
+        a.x.expect_nearly_le_tol()
+            .with(always(), eq(Tolerance::<MockA>::new(0.5_f32, 7_i32)))
+            .times(1)
+            .return_const(true);
+        a.y.expect_nearly_le_tol()
+            .with(always(), eq(Tolerance::<MockA>::new(0.5_f32, 7_i32)))
+            .times(1)
+            .return_const(true);
+        a.z.expect_nearly_le_tol()
+            .with(always(), eq(Tolerance::<MockA>::new(0.5_f32, 7_i32)))
+            .times(1)
+            .return_const(true);
+        a.x.expect_nearly_ge_tol()
+            .with(always(), eq(Tolerance::<MockA>::new(0.5_f32, 7_i32)))
+            .times(1)
+            .return_const(true);
+        a.y.expect_nearly_ge_tol()
+            .with(always(), eq(Tolerance::<MockA>::new(0.5_f32, 7_i32)))
+            .times(1)
+            .return_const(false);
+        a.z.expect_nearly_ge_tol().times(0);
+
+        assert_eq!(a.nearly_cmp(&b), Some(Ordering::Less));
+
+        a.x.checkpoint();
+        a.y.checkpoint();
+        a.z.checkpoint();
+
+        a.x.expect_nearly_le_tol()
+            .with(always(), eq(Tolerance::<MockA>::new(0.5_f32, 7_i32)))
+            .times(1)
+            .return_const(true);
+        a.y.expect_nearly_le_tol()
+            .with(always(), eq(Tolerance::<MockA>::new(0.5_f32, 7_i32)))
+            .times(1)
+            .return_const(false);
+        a.z.expect_nearly_le_tol().times(0);
+        a.x.expect_nearly_ge_tol()
+            .with(always(), eq(Tolerance::<MockA>::new(0.5_f32, 7_i32)))
+            .times(1)
+            .return_const(true);
+        a.y.expect_nearly_ge_tol()
+            .with(always(), eq(Tolerance::<MockA>::new(0.5_f32, 7_i32)))
+            .times(1)
+            .return_const(true);
+        a.z.expect_nearly_ge_tol()
+            .with(always(), eq(Tolerance::<MockA>::new(0.5_f32, 7_i32)))
+            .times(1)
+            .return_const(true);
+
+        assert_eq!(a.nearly_cmp(&b), Some(Ordering::Greater));
+
+        a.x.checkpoint();
+        a.y.checkpoint();
+        a.z.checkpoint();
+
+        a.x.expect_nearly_le_tol()
+            .with(always(), eq(Tolerance::<MockA>::new(0.5_f32, 7_i32)))
+            .times(1)
+            .return_const(false);
+        a.y.expect_nearly_le_tol().times(0);
+        a.z.expect_nearly_le_tol().times(0);
+        a.x.expect_nearly_ge_tol()
+            .with(always(), eq(Tolerance::<MockA>::new(0.5_f32, 7_i32)))
+            .times(1)
+            .return_const(false);
+        a.y.expect_nearly_ge_tol().times(0);
+        a.z.expect_nearly_ge_tol().times(0);
+
+        assert_eq!(a.nearly_cmp(&b), None);
+    }
 }
@@ -404,7 +404,111 @@ macro_rules! impl_test {
                 let a = UnitStruct;
                 let b = UnitStruct;
 
-                assert!(a.[<nearly_ $fn _tol>](&b, &Tolerance::<UnitStruct>::new((), ())));
+                assert_eq!(a.[<nearly_ $fn _tol>](&b, &Tolerance::<UnitStruct>::new((), ())), empty_cmp!($fn));
+            }
+
+            #[test]
+            fn [<derive_nearly_ $fn _tol_named_struct_same_type_rel_mode>]() {
+                let mut a = NamedStructSameType {
+                    x: MockA::new(),
+                    y: MockA::new(),
+                    z: MockA::new(),
+                };
+                let b = NamedStructSameType {
+                    x: MockA::new(),
+                    y: MockA::new(),
+                    z: MockA::new(),
+                };
+
+                let tol = Tolerance::<NamedStructSameType>::new(0.1_f32, 5_i32)
+                    .with_rel(0.2_f32)
+                    .with_mode(ToleranceMode::All);
+                let field_tol = Tolerance::<MockA>::new(0.1_f32, 5_i32)
+                    .with_rel(0.2_f32)
+                    .with_mode(ToleranceMode::All);
+
+                a.x.[<expect_nearly_ $fn _tol>]()
+                    .with(always(), eq(field_tol))
+                    .times(1)
+                    .return_const(true);
+                a.y.[<expect_nearly_ $fn _tol>]()
+                    .with(always(), eq(field_tol))
+                    .times(1)
+                    .return_const(true);
+                a.z.[<expect_nearly_ $fn _tol>]()
+                    .with(always(), eq(field_tol))
+                    .times(1)
+                    .return_const(true);
+
+                assert!(a.[<nearly_ $fn _tol>](&b, &tol));
+            }
+
+            #[test]
+            fn [<derive_nearly_ $fn _tol_named_struct_different_type_rel_mode>]() {
+                let mut a = NamedStructDifferentType {
+                    x: MockA::new(),
+                    y: MockB::new(),
+                    z: NamedPair {
+                        a: MockA::new(),
+                        b: MockB::new(),
+                    },
+                };
+                let b = NamedStructDifferentType {
+                    x: MockA::new(),
+                    y: MockB::new(),
+                    z: NamedPair {
+                        a: MockA::new(),
+                        b: MockB::new(),
+                    },
+                };
+
+                let eps = (0.1_f32, 0.01_f64, (0.2_f32, 0.02_f64));
+                let ulps = (5_i32, 10_i64, (6_i32, 12_i64));
+                let rel = (0.3_f32, 0.03_f64, (0.4_f32, 0.04_f64));
+                let tol = Tolerance::<NamedStructDifferentType>::new(eps, ulps)
+                    .with_rel(rel)
+                    .with_mode(ToleranceMode::All);
+
+                a.x.[<expect_nearly_ $fn _tol>]()
+                    .with(
+                        always(),
+                        eq(Tolerance::<MockA>::new(0.1_f32, 5_i32)
+                            .with_rel(0.3_f32)
+                            .with_mode(ToleranceMode::All)),
+                    )
+                    .times(1)
+                    .return_const(true);
+                a.y.[<expect_nearly_ $fn _tol>]()
+                    .with(
+                        always(),
+                        eq(Tolerance::<MockB>::new(0.01_f64, 10_i64)
+                            .with_rel(0.03_f64)
+                            .with_mode(ToleranceMode::All)),
+                    )
+                    .times(1)
+                    .return_const(true);
+                a.z.a
+                    .[<expect_nearly_ $fn _tol>]()
+                    .with(
+                        always(),
+                        eq(Tolerance::<MockA>::new(0.2_f32, 6_i32)
+                            .with_rel(0.4_f32)
+                            .with_mode(ToleranceMode::All)),
+                    )
+                    .times(1)
+                    .return_const(true);
+                a.z.b
+                    .[<expect_nearly_ $fn _tol>]()
+                    .with(
+                        always(),
+                        eq(Tolerance::<MockB>::new(0.02_f64, 12_i64)
+                            .with_rel(0.04_f64)
+                            .with_mode(ToleranceMode::All)),
+                    )
+                    .times(1)
+                    .return_const(true);
+
+                assert!(a.[<nearly_ $fn _tol>](&b, &tol));
             }
 
             #[test]
@@ -436,7 +540,7 @@ macro_rules! impl_test {
                     .times(0);
 
                 assert!(!b.[<nearly_ $fn _tol>](&c, &Tolerance::<EnumSameType>::new(0.1_f32, 5_i32)));
-                assert!(c.[<nearly_ $fn _tol>](&d, &Tolerance::<EnumSameType>::new(0.1_f32, 5_i32)));
+                assert_eq!(c.[<nearly_ $fn _tol>](&d, &Tolerance::<EnumSameType>::new(0.1_f32, 5_i32)), empty_cmp!($fn));
                 assert!(!b.[<nearly_ $fn _tol>](&e, &Tolerance::<EnumSameType>::new(0.1_f32, 5_i32)));
                 assert!(!c.[<nearly_ $fn _tol>](&e, &Tolerance::<EnumSameType>::new(0.1_f32, 5_i32)));
 
@@ -489,7 +593,7 @@ macro_rules! impl_test {
                     .times(0);
 
                 assert!(!b.[<nearly_ $fn _tol>](&c, &tol));
-                assert!(c.[<nearly_ $fn _tol>](&d, &tol));
+                assert_eq!(c.[<nearly_ $fn _tol>](&d, &tol), empty_cmp!($fn));
                 assert!(!b.[<nearly_ $fn _tol>](&e, &tol));
                 assert!(!c.[<nearly_ $fn _tol>](&e, &tol));
 
@@ -515,8 +619,8 @@ macro_rules! impl_test {
 mod nearly_eq {
     use super::*;
     use nearly::{
-        EpsTolerance, EpsToleranceType, NearlyEqEps, NearlyEqTol, NearlyEqUlps, Tolerance,
-        UlpsTolerance, UlpsToleranceType,
+        EpsTolerance, EpsToleranceType, NearlyEqEps, NearlyEqRel, NearlyEqTol, NearlyEqUlps,
+        RelTolerance, RelToleranceType, Tolerance, ToleranceMode, UlpsTolerance, UlpsToleranceType,
     };
 
     mock!(
@@ -532,6 +636,11 @@ mod nearly_eq {
             const DEFAULT: i32 = 7;
         }
 
+        impl RelTolerance for A {
+            type T = f32;
+            const DEFAULT: f32 = 0.5;
+        }
+
         impl NearlyEqEps for A {
             fn nearly_eq_eps(&self, other: &Self, eps: &EpsToleranceType<Self>) -> bool;
         }
@@ -540,6 +649,10 @@ mod nearly_eq {
             fn nearly_eq_ulps(&self, other: &Self, ulps: &UlpsToleranceType<Self>) -> bool;
         }
 
+        impl NearlyEqRel for A {
+            fn nearly_eq_rel(&self, other: &Self, rel: &RelToleranceType<Self>) -> bool;
+        }
+
         impl NearlyEqTol for A {
             fn nearly_eq_tol(&self, other: &Self, tol: &Tolerance<Self>) -> bool;
         }
@@ -558,6 +671,11 @@ mod nearly_eq {
             const DEFAULT: i64 = 13;
         }
 
+        impl RelTolerance for B {
+            type T = f64;
+            const DEFAULT: f64 = 0.05;
+        }
+
         impl NearlyEqEps for B {
             fn nearly_eq_eps(&self, other: &Self, eps: &EpsToleranceType<Self>) -> bool;
         }
@@ -566,6 +684,10 @@ mod nearly_eq {
             fn nearly_eq_ulps(&self, other: &Self, ulps: &UlpsToleranceType<Self>) -> bool;
         }
 
+        impl NearlyEqRel for B {
+            fn nearly_eq_rel(&self, other: &Self, rel: &RelToleranceType<Self>) -> bool;
+        }
+
         impl NearlyEqTol for B {
             fn nearly_eq_tol(&self, other: &Self, tol: &Tolerance<Self>) -> bool;
         }
@@ -623,8 +745,9 @@ mod nearly_eq {
 mod nearly_ord {
     use super::*;
     use nearly::{
-        EpsTolerance, EpsToleranceType, NearlyEqEps, NearlyEqTol, NearlyEqUlps, NearlyOrdEps,
-        NearlyOrdTol, NearlyOrdUlps, Tolerance, UlpsTolerance, UlpsToleranceType,
+        EpsTolerance, EpsToleranceType, NearlyEqEps, NearlyEqRel, NearlyEqTol, NearlyEqUlps,
+        NearlyOrdEps, NearlyOrdRel, NearlyOrdTol, NearlyOrdUlps, RelTolerance, RelToleranceType,
+        Tolerance, ToleranceMode, UlpsTolerance, UlpsToleranceType,
     };
 
     mock!(
@@ -640,6 +763,11 @@ mod nearly_ord {
             const DEFAULT: i32 = 7;
         }
 
+        impl RelTolerance for A {
+            type T = f32;
+            const DEFAULT: f32 = 0.5;
+        }
+
         impl NearlyEqEps for A {
             fn nearly_eq_eps(&self, other: &Self, eps: &EpsToleranceType<Self>) -> bool;
         }
@@ -648,6 +776,10 @@ mod nearly_ord {
             fn nearly_eq_ulps(&self, other: &Self, ulps: &UlpsToleranceType<Self>) -> bool;
         }
 
+        impl NearlyEqRel for A {
+            fn nearly_eq_rel(&self, other: &Self, rel: &RelToleranceType<Self>) -> bool;
+        }
+
         impl NearlyEqTol for A {
             fn nearly_eq_tol(&self, other: &Self, tol: &Tolerance<Self>) -> bool;
         }
@@ -666,6 +798,13 @@ mod nearly_ord {
             fn nearly_ge_ulps(&self, other: &Self, ulps: &UlpsToleranceType<Self>) -> bool;
         }
 
+        impl NearlyOrdRel for A {
+            fn nearly_lt_rel(&self, other: &Self, rel: &RelToleranceType<Self>) -> bool;
+            fn nearly_le_rel(&self, other: &Self, rel: &RelToleranceType<Self>) -> bool;
+            fn nearly_gt_rel(&self, other: &Self, rel: &RelToleranceType<Self>) -> bool;
+            fn nearly_ge_rel(&self, other: &Self, rel: &RelToleranceType<Self>) -> bool;
+        }
+
         impl NearlyOrdTol for A {
             fn nearly_lt_tol(&self, other: &Self, tol: &Tolerance<Self>) -> bool;
             fn nearly_le_tol(&self, other: &Self, tol: &Tolerance<Self>) -> bool;
@@ -687,6 +826,11 @@ mod nearly_ord {
             const DEFAULT: i64 = 13;
         }
 
+        impl RelTolerance for B {
+            type T = f64;
+            const DEFAULT: f64 = 0.05;
+        }
+
         impl NearlyEqEps for B {
             fn nearly_eq_eps(&self, other: &Self, eps: &EpsToleranceType<Self>) -> bool;
         }
@@ -695,6 +839,10 @@ mod nearly_ord {
             fn nearly_eq_ulps(&self, other: &Self, ulps: &UlpsToleranceType<Self>) -> bool;
         }
 
+        impl NearlyEqRel for B {
+            fn nearly_eq_rel(&self, other: &Self, rel: &RelToleranceType<Self>) -> bool;
+        }
+
         impl NearlyEqTol for B {
             fn nearly_eq_tol(&self, other: &Self, tol: &Tolerance<Self>) -> bool;
         }
@@ -713,6 +861,13 @@ mod nearly_ord {
             fn nearly_ge_ulps(&self, other: &Self, ulps: &UlpsToleranceType<Self>) -> bool;
         }
 
+        impl NearlyOrdRel for B {
+            fn nearly_lt_rel(&self, other: &Self, rel: &RelToleranceType<Self>) -> bool;
+            fn nearly_le_rel(&self, other: &Self, rel: &RelToleranceType<Self>) -> bool;
+            fn nearly_gt_rel(&self, other: &Self, rel: &RelToleranceType<Self>) -> bool;
+            fn nearly_ge_rel(&self, other: &Self, rel: &RelToleranceType<Self>) -> bool;
+        }
+
         impl NearlyOrdTol for B {
             fn nearly_lt_tol(&self, other: &Self, tol: &Tolerance<Self>) -> bool;
             fn nearly_le_tol(&self, other: &Self, tol: &Tolerance<Self>) -> bool;
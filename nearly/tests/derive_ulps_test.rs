@@ -400,7 +400,7 @@ macro_rules! impl_test {
                 let a = UnitStruct;
                 let b = UnitStruct;
 
-                assert!(a.[<nearly_ $fn _ulps>](&b, &()));
+                assert_eq!(a.[<nearly_ $fn _ulps>](&b, &()), empty_cmp!($fn));
             }
 
             #[test]
@@ -432,7 +432,7 @@ macro_rules! impl_test {
                     .times(0);
 
                 assert!(!b.[<nearly_ $fn _ulps>](&c, &5_i32));
-                assert!(c.[<nearly_ $fn _ulps>](&d, &5_i32));
+                assert_eq!(c.[<nearly_ $fn _ulps>](&d, &5_i32), empty_cmp!($fn));
                 assert!(!b.[<nearly_ $fn _ulps>](&e, &5_i32));
                 assert!(!c.[<nearly_ $fn _ulps>](&e, &5_i32));
 
@@ -483,7 +483,7 @@ macro_rules! impl_test {
                     .times(0);
 
                 assert!(!b.[<nearly_ $fn _ulps>](&c, &ulps));
-                assert!(c.[<nearly_ $fn _ulps>](&d, &ulps));
+                assert_eq!(c.[<nearly_ $fn _ulps>](&d, &ulps), empty_cmp!($fn));
                 assert!(!b.[<nearly_ $fn _ulps>](&e, &ulps));
                 assert!(!c.[<nearly_ $fn _ulps>](&e, &ulps));
 
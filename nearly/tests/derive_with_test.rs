@@ -0,0 +1,160 @@
+use nearly::{
+    assert_nearly, EpsToleranceType, NearlyEq, NearlyOrd, RelToleranceType, Tolerance,
+    UlpsToleranceType,
+};
+
+/// Compares angles in radians modulo 2π.
+mod angle {
+    use super::*;
+    use core::f32::consts::TAU;
+    use nearly::{NearlyEqEps, NearlyEqRel, NearlyEqTol, NearlyEqUlps};
+
+    fn normalized(a: &f32, b: &f32) -> (f32, f32) {
+        let diff = (b - a).rem_euclid(TAU);
+        if diff > TAU / 2.0 {
+            (0.0, diff - TAU)
+        } else {
+            (0.0, diff)
+        }
+    }
+
+    pub fn nearly_eq_eps(a: &f32, b: &f32, eps: &EpsToleranceType<f32>) -> bool {
+        let (a, b) = normalized(a, b);
+        a.nearly_eq_eps(&b, eps)
+    }
+
+    pub fn nearly_eq_ulps(a: &f32, b: &f32, ulps: &UlpsToleranceType<f32>) -> bool {
+        let (a, b) = normalized(a, b);
+        a.nearly_eq_ulps(&b, ulps)
+    }
+
+    pub fn nearly_eq_rel(a: &f32, b: &f32, rel: &RelToleranceType<f32>) -> bool {
+        let (a, b) = normalized(a, b);
+        a.nearly_eq_rel(&b, rel)
+    }
+
+    pub fn nearly_eq_tol(a: &f32, b: &f32, tol: &Tolerance<f32>) -> bool {
+        let (a, b) = normalized(a, b);
+        a.nearly_eq_tol(&b, tol)
+    }
+}
+
+/// Compares values by their magnitude.
+mod magnitude {
+    use super::*;
+    use nearly::{
+        NearlyEqEps, NearlyEqRel, NearlyEqTol, NearlyEqUlps, NearlyOrdEps, NearlyOrdRel,
+        NearlyOrdTol, NearlyOrdUlps,
+    };
+
+    macro_rules! magnitude_fns {
+        ($($fn: ident: $tol: ty),*) => {
+            $(
+                pub fn $fn(a: &f64, b: &f64, tol: &$tol) -> bool {
+                    a.abs().$fn(&b.abs(), tol)
+                }
+            )*
+        };
+    }
+
+    magnitude_fns!(
+        nearly_eq_eps: EpsToleranceType<f64>,
+        nearly_eq_ulps: UlpsToleranceType<f64>,
+        nearly_eq_rel: RelToleranceType<f64>,
+        nearly_eq_tol: Tolerance<f64>,
+        nearly_lt_eps: EpsToleranceType<f64>,
+        nearly_le_eps: EpsToleranceType<f64>,
+        nearly_gt_eps: EpsToleranceType<f64>,
+        nearly_ge_eps: EpsToleranceType<f64>,
+        nearly_lt_ulps: UlpsToleranceType<f64>,
+        nearly_le_ulps: UlpsToleranceType<f64>,
+        nearly_gt_ulps: UlpsToleranceType<f64>,
+        nearly_ge_ulps: UlpsToleranceType<f64>,
+        nearly_lt_rel: RelToleranceType<f64>,
+        nearly_le_rel: RelToleranceType<f64>,
+        nearly_gt_rel: RelToleranceType<f64>,
+        nearly_ge_rel: RelToleranceType<f64>,
+        nearly_lt_tol: Tolerance<f64>,
+        nearly_le_tol: Tolerance<f64>,
+        nearly_gt_tol: Tolerance<f64>,
+        nearly_ge_tol: Tolerance<f64>
+    );
+}
+
+#[derive(NearlyEq, Debug)]
+struct Heading {
+    #[nearly(with = angle)]
+    angle: f32,
+    speed: f32,
+}
+
+#[derive(NearlyEq, NearlyOrd, Debug)]
+struct Magnitude(#[nearly(with = magnitude)] f64);
+
+#[derive(NearlyEq, Debug)]
+enum Rotation {
+    Angle(#[nearly(with = angle)] f32),
+    Turns {
+        #[nearly(with = self::angle)]
+        angle: f32,
+        #[nearly(exact)]
+        count: u8,
+    },
+}
+
+#[test]
+fn derive_with_named_fields() {
+    let a = Heading {
+        angle: 0.0,
+        speed: 1.0,
+    };
+    let b = Heading {
+        angle: core::f32::consts::TAU,
+        speed: 1.0,
+    };
+    let c = Heading {
+        angle: 1.0,
+        speed: 1.0,
+    };
+    let d = Heading {
+        angle: 0.0,
+        speed: 1.1,
+    };
+    assert_nearly!(a == b, eps = 0.0001);
+    assert_nearly!(a == b, ulps = 4);
+    assert_nearly!(a == b, tol = Tolerance::new(0.0001, 4));
+    assert_nearly!(a != c, eps = 0.0001);
+    assert_nearly!(a != d, eps = 0.0001);
+}
+
+#[test]
+fn derive_with_unnamed_fields() {
+    let a = Magnitude(-2.0);
+    let b = Magnitude(2.0);
+    let c = Magnitude(3.0);
+    assert_nearly!(a == b, eps = 0.0001);
+    assert_nearly!(a == b, rel = 0.0001);
+    assert_nearly!(a != c, eps = 0.0001);
+    assert_nearly!(a < c, eps = 0.0001);
+    assert_nearly!(a <= b, ulps = 4);
+    assert_nearly!(c > b, rel = 0.0001);
+    assert_nearly!(c >= a, tol = Tolerance::new(0.0001, 4));
+}
+
+#[test]
+fn derive_with_enum() {
+    let a = Rotation::Angle(-core::f32::consts::PI);
+    let b = Rotation::Angle(core::f32::consts::PI);
+    assert_nearly!(a == b, eps = 0.0001);
+
+    let a = Rotation::Turns {
+        angle: 0.5,
+        count: 2,
+    };
+    let b = Rotation::Turns {
+        angle: 0.5 + 2.0 * core::f32::consts::TAU,
+        count: 2,
+    };
+    assert_nearly!(a == b, eps = 0.0001);
+    assert_nearly!(a != Rotation::Angle(0.5), eps = 0.0001);
+}
@@ -0,0 +1,77 @@
+use nearly::{NearlyIterExt, PerElement, Tolerance};
+
+#[test]
+fn iter_eq_eps() {
+    let a = [1.0f32, 2.0, 3.0];
+    let b = [1.05f32, 2.0, 2.95];
+
+    assert!(a.iter().nearly_eq_iter_eps(b.iter(), &0.1));
+    assert!(!a.iter().nearly_eq_iter_eps(b.iter(), &0.01));
+    assert!(a.into_iter().nearly_eq_iter_eps(b, &0.1));
+    assert!(a
+        .iter()
+        .map(|v| v * 2.0)
+        .nearly_eq_iter_eps(b.iter().map(|v| v * 2.0), &0.2));
+}
+
+#[test]
+fn iter_eq_ulps() {
+    let a = [1.0f64, 2.0, 3.0];
+    let b = [1.0f64, 2.0, 3.0];
+
+    assert!(a.iter().nearly_eq_iter_ulps(b.iter(), &0));
+    assert!(!a.iter().nearly_eq_iter_ulps([1.0, 2.0, 3.1].iter(), &4));
+}
+
+#[test]
+fn iter_eq_rel() {
+    let a = [100.0f64, 200.0];
+    let b = [100.5f64, 201.0];
+
+    assert!(a.iter().nearly_eq_iter_rel(b.iter(), &0.01));
+    assert!(!a.iter().nearly_eq_iter_rel(b.iter(), &0.001));
+}
+
+#[test]
+fn iter_eq_tol() {
+    let a = [1.0f32, 2.0];
+    let b = [1.05f32, 2.0];
+
+    assert!(a
+        .iter()
+        .nearly_eq_iter_tol(b.iter(), &Tolerance::new(0.1, 0)));
+    assert!(!a
+        .iter()
+        .nearly_eq_iter_tol(b.iter(), &Tolerance::new(0.01, 4)));
+    assert!(a.iter().nearly_eq_iter(a.iter()));
+    assert!(!a.iter().nearly_eq_iter(b.iter()));
+}
+
+#[test]
+fn iter_eq_different_length() {
+    let a = [1.0f64, 2.0, 3.0];
+
+    assert!(!a.iter().nearly_eq_iter_eps(a.iter().take(2), &0.1));
+    assert!(!a.iter().take(2).nearly_eq_iter_eps(a.iter(), &0.1));
+    assert!(!a.iter().nearly_eq_iter_eps(a.iter().chain(a.iter()), &0.1));
+    assert!(!a.iter().nearly_eq_iter(core::iter::empty::<&f64>()));
+    assert!(core::iter::empty::<f64>().nearly_eq_iter(core::iter::empty::<f64>()));
+}
+
+#[test]
+fn iter_eq_lazy() {
+    // infinite iterators are compared until the first mismatch
+    let a = core::iter::successors(Some(1.0f64), |v| Some(v * 2.0));
+    let b = (0..).map(|i| if i < 10 { 2.0f64.powi(i) } else { 0.0 });
+
+    assert!(!a.nearly_eq_iter_eps(b, &0.1));
+}
+
+#[test]
+fn iter_eq_tuples() {
+    let a = [PerElement((1.0f32, 2.0f64)), PerElement((3.0, 4.0))];
+    let b = [PerElement((1.05f32, 2.0f64)), PerElement((3.0, 4.05))];
+
+    assert!(a.iter().nearly_eq_iter_eps(b.iter(), &(0.1, 0.1)));
+    assert!(!a.iter().nearly_eq_iter_eps(b.iter(), &(0.1, 0.01)));
+}
@@ -0,0 +1,169 @@
+use nearly::{
+    Lexicographic, NearlyEqEps, NearlyOrd, NearlyOrdEps, NearlyOrdRel, NearlyOrdTol, NearlyOrdUlps,
+    Tolerance,
+};
+use std::cmp::Ordering;
+
+#[test]
+fn lexicographic_cmp_eps_equal() {
+    let a = Lexicographic([1.0_f32, 2.0, 3.0]);
+    let b = Lexicographic([1.05_f32, 1.95, 3.0]);
+    assert_eq!(a.nearly_cmp_eps(&b, &0.1), Some(Ordering::Equal));
+    assert!(a.nearly_eq_eps(&b, &0.1));
+    assert!(a.nearly_le_eps(&b, &0.1));
+    assert!(a.nearly_ge_eps(&b, &0.1));
+    assert!(!a.nearly_lt_eps(&b, &0.1));
+    assert!(!a.nearly_gt_eps(&b, &0.1));
+}
+
+#[test]
+fn lexicographic_cmp_eps_skips_nearly_equal_prefix() {
+    let a = Lexicographic([1.0_f32, 2.05, 3.0]);
+    let b = Lexicographic([1.05_f32, 2.0, 0.0]);
+    assert_eq!(a.nearly_cmp_eps(&b, &0.1), Some(Ordering::Greater));
+    assert_eq!(b.nearly_cmp_eps(&a, &0.1), Some(Ordering::Less));
+    assert!(a.nearly_gt_eps(&b, &0.1));
+    assert!(a.nearly_ge_eps(&b, &0.1));
+    assert!(b.nearly_lt_eps(&a, &0.1));
+    assert!(b.nearly_le_eps(&a, &0.1));
+}
+
+#[test]
+fn lexicographic_cmp_eps_first_difference_decides() {
+    let a = Lexicographic([1.0_f64, 2.0, 9.0]);
+    let b = Lexicographic([1.0_f64, 3.0, 0.0]);
+    assert_eq!(a.nearly_cmp_eps(&b, &0.1), Some(Ordering::Less));
+    assert!(a.nearly_lt_eps(&b, &0.1));
+    assert!(!a.nearly_gt_eps(&b, &0.1));
+
+    // pairwise comparison of the collections is not ordered
+    assert!(!a.0.nearly_lt_eps(&b.0, &0.1));
+    assert!(!a.0.nearly_gt_eps(&b.0, &0.1));
+}
+
+#[test]
+fn lexicographic_cmp_eps_different_length() {
+    let a = Lexicographic(&[1.0_f32, 2.0][..]);
+    let b = Lexicographic(&[1.05_f32, 2.05, 0.0][..]);
+    assert_eq!(a.nearly_cmp_eps(&b, &0.1), Some(Ordering::Less));
+    assert_eq!(b.nearly_cmp_eps(&a, &0.1), Some(Ordering::Greater));
+    assert!(a.nearly_lt_eps(&b, &0.1));
+    assert!(b.nearly_gt_eps(&a, &0.1));
+
+    let c = Lexicographic(&[1.0_f32, 3.0][..]);
+    assert_eq!(b.nearly_cmp_eps(&c, &0.1), Some(Ordering::Less));
+}
+
+#[test]
+fn lexicographic_cmp_eps_empty() {
+    let a: Lexicographic<&[f32]> = Lexicographic(&[]);
+    let b = Lexicographic(&[1.0_f32][..]);
+    assert_eq!(a.nearly_cmp_eps(&a, &0.1), Some(Ordering::Equal));
+    assert_eq!(a.nearly_cmp_eps(&b, &0.1), Some(Ordering::Less));
+    assert_eq!(b.nearly_cmp_eps(&a, &0.1), Some(Ordering::Greater));
+}
+
+#[test]
+fn lexicographic_cmp_eps_nan() {
+    let a = Lexicographic([1.0_f32, f32::NAN, 0.0]);
+    let b = Lexicographic([1.0_f32, 2.0, 3.0]);
+    assert_eq!(a.nearly_cmp_eps(&b, &0.1), None);
+    assert!(!a.nearly_lt_eps(&b, &0.1));
+    assert!(!a.nearly_le_eps(&b, &0.1));
+    assert!(!a.nearly_gt_eps(&b, &0.1));
+    assert!(!a.nearly_ge_eps(&b, &0.1));
+
+    // elements after the first difference are not compared
+    let c = Lexicographic([0.0_f32, f32::NAN, 0.0]);
+    assert_eq!(c.nearly_cmp_eps(&b, &0.1), Some(Ordering::Less));
+}
+
+#[test]
+fn lexicographic_cmp_ulps() {
+    let a = Lexicographic([1.0_f32, 2.0, 3.0]);
+    let b = Lexicographic([1.0_f32 + f32::EPSILON, 2.5, 0.0]);
+    assert_eq!(a.nearly_cmp_ulps(&b, &4), Some(Ordering::Less));
+    assert!(a.nearly_lt_ulps(&b, &4));
+    assert!(b.nearly_gt_ulps(&a, &4));
+}
+
+#[test]
+fn lexicographic_cmp_rel() {
+    let a = Lexicographic([100.0_f64, 5.0]);
+    let b = Lexicographic([100.5_f64, 4.0]);
+    assert_eq!(a.nearly_cmp_rel(&b, &0.01), Some(Ordering::Greater));
+    assert!(a.nearly_gt_rel(&b, &0.01));
+    assert!(b.nearly_lt_rel(&a, &0.01));
+}
+
+#[test]
+fn lexicographic_cmp_tol() {
+    let a = Lexicographic([1.0_f32, 2.0, 3.0]);
+    let b = Lexicographic([1.05_f32, 2.5, 0.0]);
+    let tol = Tolerance::<f32>::new(0.1, 0);
+    assert_eq!(a.nearly_cmp_tol(&b, &tol), Some(Ordering::Less));
+    assert!(a.nearly_lt_tol(&b, &tol));
+    assert!(b.nearly_gt_tol(&a, &tol));
+}
+
+#[test]
+fn lexicographic_cmp_default() {
+    let a = Lexicographic(&[1.0_f64, 2.0][..]);
+    let b = Lexicographic(&[1.0_f64, 2.0, 3.0][..]);
+    assert_eq!(a.nearly_cmp(&b), Some(Ordering::Less));
+    assert!(a.nearly_lt(&b));
+    assert!(b.nearly_gt(&a));
+}
+
+#[test]
+fn lexicographic_sort() {
+    let mut values = vec![
+        vec![1.0_f32, 3.0],
+        vec![1.0, 2.0, 4.0],
+        vec![1.0],
+        vec![0.5, 9.0],
+    ];
+    values.sort_by(|a, b| {
+        Lexicographic(a.as_slice())
+            .nearly_cmp_eps(&Lexicographic(b.as_slice()), &0.1)
+            .unwrap()
+    });
+    assert_eq!(
+        values,
+        vec![
+            vec![0.5, 9.0],
+            vec![1.0],
+            vec![1.0, 2.0, 4.0],
+            vec![1.0, 3.0]
+        ]
+    );
+}
+
+#[cfg(feature = "std")]
+mod std_types {
+    use super::*;
+    use std::collections::{LinkedList, VecDeque};
+
+    #[test]
+    fn lexicographic_cmp_vec_vec_deque() {
+        let a = Lexicographic(vec![1.0_f32, 2.0, 3.0]);
+        let b = Lexicographic(VecDeque::from([1.05_f32, 2.5]));
+        assert_eq!(a.nearly_cmp_eps(&b, &0.1), Some(Ordering::Less));
+        assert_eq!(b.nearly_cmp_eps(&a, &0.1), Some(Ordering::Greater));
+    }
+
+    #[test]
+    fn lexicographic_cmp_vec_array() {
+        let a = Lexicographic(vec![1.0_f32, 2.0]);
+        let b = Lexicographic([1.0_f32, 2.0, 3.0]);
+        assert_eq!(a.nearly_cmp_eps(&b, &0.1), Some(Ordering::Less));
+        assert_eq!(b.nearly_cmp_eps(&a, &0.1), Some(Ordering::Greater));
+    }
+
+    #[test]
+    fn lexicographic_cmp_linked_list() {
+        let a = Lexicographic(LinkedList::from([1.0_f64, 2.0]));
+        let b = Lexicographic(LinkedList::from([1.0_f64, 1.0, 5.0]));
+        assert_eq!(a.nearly_cmp_eps(&b, &0.1), Some(Ordering::Greater));
+    }
+}
@@ -1,5 +1,5 @@
 use mockall::predicate::eq;
-use nearly::{assert_nearly, debug_assert_nearly, nearly, Tolerance};
+use nearly::{assert_nearly, debug_assert_nearly, nearly, Tolerance, ToleranceMode};
 
 mod common;
 use common::{MockLhs, Rhs};
@@ -200,6 +200,246 @@ fn macro_nearly_ne() {
     assert!(!nearly!(a != b));
 }
 
+#[test]
+fn macro_nearly_eq_rel() {
+    let mut a = MockLhs::new();
+    let b = Rhs(5);
+
+    a.expect_nearly_eq_rel()
+        .with(eq(Rhs(5)), eq(0.1))
+        .times(1)
+        .return_const(true);
+    assert!(nearly!(a == b, rel = 0.1));
+
+    a.checkpoint();
+
+    a.expect_nearly_eq_rel()
+        .with(eq(Rhs(5)), eq(0.1))
+        .times(1)
+        .return_const(false);
+    assert!(!nearly!(a == b, rel = 0.1));
+}
+
+#[test]
+fn macro_nearly_ne_rel() {
+    let mut a = MockLhs::new();
+    let b = Rhs(5);
+
+    a.expect_nearly_ne_rel()
+        .with(eq(Rhs(5)), eq(0.1))
+        .times(1)
+        .return_const(true);
+    assert!(nearly!(a != b, rel = 0.1));
+
+    a.checkpoint();
+
+    a.expect_nearly_ne_rel()
+        .with(eq(Rhs(5)), eq(0.1))
+        .times(1)
+        .return_const(false);
+    assert!(!nearly!(a != b, rel = 0.1));
+}
+
+#[test]
+fn macro_nearly_eq_eps_rel() {
+    let mut a = MockLhs::new();
+    let b = Rhs(5);
+
+    a.expect_nearly_eq_eps()
+        .with(eq(Rhs(5)), eq(0.2))
+        .times(1)
+        .return_const(true);
+    a.expect_nearly_eq_rel().times(0);
+    assert!(nearly!(a == b, eps = 0.2, rel = 0.1));
+
+    a.checkpoint();
+
+    a.expect_nearly_eq_eps()
+        .with(eq(Rhs(5)), eq(0.2))
+        .times(1)
+        .return_const(false);
+    a.expect_nearly_eq_rel()
+        .with(eq(Rhs(5)), eq(0.1))
+        .times(1)
+        .return_const(true);
+    assert!(nearly!(a == b, eps = 0.2, rel = 0.1));
+
+    a.checkpoint();
+
+    a.expect_nearly_eq_eps()
+        .with(eq(Rhs(5)), eq(0.2))
+        .times(1)
+        .return_const(false);
+    a.expect_nearly_eq_rel()
+        .with(eq(Rhs(5)), eq(0.1))
+        .times(1)
+        .return_const(false);
+    assert!(!nearly!(a == b, eps = 0.2, rel = 0.1));
+}
+
+#[test]
+fn macro_nearly_eq_ulps_rel() {
+    let mut a = MockLhs::new();
+    let b = Rhs(5);
+
+    a.expect_nearly_eq_ulps()
+        .with(eq(Rhs(5)), eq(7))
+        .times(1)
+        .return_const(true);
+    a.expect_nearly_eq_rel().times(0);
+    assert!(nearly!(a == b, ulps = 7, rel = 0.1));
+
+    a.checkpoint();
+
+    a.expect_nearly_eq_ulps()
+        .with(eq(Rhs(5)), eq(7))
+        .times(1)
+        .return_const(false);
+    a.expect_nearly_eq_rel()
+        .with(eq(Rhs(5)), eq(0.1))
+        .times(1)
+        .return_const(true);
+    assert!(nearly!(a == b, ulps = 7, rel = 0.1));
+
+    a.checkpoint();
+
+    a.expect_nearly_eq_ulps()
+        .with(eq(Rhs(5)), eq(7))
+        .times(1)
+        .return_const(false);
+    a.expect_nearly_eq_rel()
+        .with(eq(Rhs(5)), eq(0.1))
+        .times(1)
+        .return_const(false);
+    assert!(!nearly!(a == b, ulps = 7, rel = 0.1));
+}
+
+#[test]
+fn macro_nearly_eq_tol_rel() {
+    let mut a = MockLhs::new();
+    let b = Rhs(5);
+
+    a.expect_nearly_eq_tol()
+        .with(
+            eq(Rhs(5)),
+            eq(Tolerance::<MockLhs, Rhs>::new(0.2, 7).with_rel(0.1)),
+        )
+        .times(1)
+        .return_const(true);
+    assert!(nearly!(a == b, eps = 0.2, ulps = 7, rel = 0.1));
+
+    a.checkpoint();
+
+    a.expect_nearly_eq_tol()
+        .with(
+            eq(Rhs(5)),
+            eq(Tolerance::<MockLhs, Rhs>::new(0.2, 7).with_rel(0.1)),
+        )
+        .times(1)
+        .return_const(false);
+    assert!(!nearly!(a == b, eps = 0.2, ulps = 7, rel = 0.1));
+}
+
+#[test]
+fn macro_nearly_ne_eps_rel() {
+    let mut a = MockLhs::new();
+    let b = Rhs(5);
+
+    a.expect_nearly_eq_eps()
+        .with(eq(Rhs(5)), eq(0.2))
+        .times(1)
+        .return_const(true);
+    a.expect_nearly_eq_rel().times(0);
+    assert!(!nearly!(a != b, eps = 0.2, rel = 0.1));
+
+    a.checkpoint();
+
+    a.expect_nearly_eq_eps()
+        .with(eq(Rhs(5)), eq(0.2))
+        .times(1)
+        .return_const(false);
+    a.expect_nearly_eq_rel()
+        .with(eq(Rhs(5)), eq(0.1))
+        .times(1)
+        .return_const(true);
+    assert!(!nearly!(a != b, eps = 0.2, rel = 0.1));
+
+    a.checkpoint();
+
+    a.expect_nearly_eq_eps()
+        .with(eq(Rhs(5)), eq(0.2))
+        .times(1)
+        .return_const(false);
+    a.expect_nearly_eq_rel()
+        .with(eq(Rhs(5)), eq(0.1))
+        .times(1)
+        .return_const(false);
+    assert!(nearly!(a != b, eps = 0.2, rel = 0.1));
+}
+
+#[test]
+fn macro_nearly_ne_ulps_rel() {
+    let mut a = MockLhs::new();
+    let b = Rhs(5);
+
+    a.expect_nearly_eq_ulps()
+        .with(eq(Rhs(5)), eq(7))
+        .times(1)
+        .return_const(true);
+    a.expect_nearly_eq_rel().times(0);
+    assert!(!nearly!(a != b, ulps = 7, rel = 0.1));
+
+    a.checkpoint();
+
+    a.expect_nearly_eq_ulps()
+        .with(eq(Rhs(5)), eq(7))
+        .times(1)
+        .return_const(false);
+    a.expect_nearly_eq_rel()
+        .with(eq(Rhs(5)), eq(0.1))
+        .times(1)
+        .return_const(true);
+    assert!(!nearly!(a != b, ulps = 7, rel = 0.1));
+
+    a.checkpoint();
+
+    a.expect_nearly_eq_ulps()
+        .with(eq(Rhs(5)), eq(7))
+        .times(1)
+        .return_const(false);
+    a.expect_nearly_eq_rel()
+        .with(eq(Rhs(5)), eq(0.1))
+        .times(1)
+        .return_const(false);
+    assert!(nearly!(a != b, ulps = 7, rel = 0.1));
+}
+
+#[test]
+fn macro_nearly_ne_tol_rel() {
+    let mut a = MockLhs::new();
+    let b = Rhs(5);
+
+    a.expect_nearly_ne_tol()
+        .with(
+            eq(Rhs(5)),
+            eq(Tolerance::<MockLhs, Rhs>::new(0.2, 7).with_rel(0.1)),
+        )
+        .times(1)
+        .return_const(true);
+    assert!(nearly!(a != b, eps = 0.2, ulps = 7, rel = 0.1));
+
+    a.checkpoint();
+
+    a.expect_nearly_ne_tol()
+        .with(
+            eq(Rhs(5)),
+            eq(Tolerance::<MockLhs, Rhs>::new(0.2, 7).with_rel(0.1)),
+        )
+        .times(1)
+        .return_const(false);
+    assert!(!nearly!(a != b, eps = 0.2, ulps = 7, rel = 0.1));
+}
+
 ////////////////////
 // assert_nearly! //
 ////////////////////
@@ -320,6 +560,36 @@ fn macro_assert_nearly_eq_tol_tuple_panic() {
     assert_nearly!(a == b, eps = 0.15, ulps = 7);
 }
 
+#[test]
+#[should_panic(expected = r#"assertion `nearly (left == right)` failed
+  left: MockLhs
+ right: Rhs(5)
+   eps: 0.1
+  ulps: 5
+   rel: 0.2
+  mode: All"#)]
+fn macro_assert_nearly_eq_tol_mode_panic() {
+    let mut a = MockLhs::new();
+    let b = Rhs(5);
+
+    a.expect_nearly_eq_tol()
+        .with(
+            eq(Rhs(5)),
+            eq(Tolerance::<MockLhs, Rhs>::new(0.1, 5)
+                .with_rel(0.2)
+                .with_mode(ToleranceMode::All)),
+        )
+        .times(1)
+        .return_const(false);
+
+    assert_nearly!(
+        a == b,
+        tol = Tolerance::new(0.1, 5)
+            .with_rel(0.2)
+            .with_mode(ToleranceMode::All)
+    );
+}
+
 #[test]
 fn macro_assert_nearly_eq() {
     let mut a = MockLhs::new();
@@ -498,6 +768,132 @@ fn macro_assert_nearly_ne_panic() {
     assert_nearly!(a != b);
 }
 
+#[test]
+fn macro_assert_nearly_eq_rel() {
+    let mut a = MockLhs::new();
+    let b = Rhs(5);
+
+    a.expect_nearly_eq_rel()
+        .with(eq(Rhs(5)), eq(0.1))
+        .times(1)
+        .return_const(true);
+
+    assert_nearly!(a == b, rel = 0.1);
+}
+
+#[test]
+#[should_panic(expected = r#"assertion `nearly (left == right)` failed
+  left: MockLhs
+ right: Rhs(5)
+   rel: 0.1"#)]
+fn macro_assert_nearly_eq_rel_panic() {
+    let mut a = MockLhs::new();
+    let b = Rhs(5);
+
+    a.expect_nearly_eq_rel()
+        .with(eq(Rhs(5)), eq(0.1))
+        .times(1)
+        .return_const(false);
+
+    assert_nearly!(a == b, rel = 0.1);
+}
+
+#[test]
+fn macro_assert_nearly_ne_rel() {
+    let mut a = MockLhs::new();
+    let b = Rhs(5);
+
+    a.expect_nearly_ne_rel()
+        .with(eq(Rhs(5)), eq(0.1))
+        .times(1)
+        .return_const(true);
+
+    assert_nearly!(a != b, rel = 0.1);
+}
+
+#[test]
+#[should_panic(expected = r#"assertion `nearly (left != right)` failed
+  left: MockLhs
+ right: Rhs(5)
+   rel: 0.1"#)]
+fn macro_assert_nearly_ne_rel_panic() {
+    let mut a = MockLhs::new();
+    let b = Rhs(5);
+
+    a.expect_nearly_ne_rel()
+        .with(eq(Rhs(5)), eq(0.1))
+        .times(1)
+        .return_const(false);
+
+    assert_nearly!(a != b, rel = 0.1);
+}
+
+#[test]
+#[should_panic(expected = r#"assertion `nearly (left == right)` failed
+  left: MockLhs
+ right: Rhs(5)
+   eps: 0.2
+  ulps: 7
+   rel: 0.1"#)]
+fn macro_assert_nearly_eq_tol_rel_panic() {
+    let mut a = MockLhs::new();
+    let b = Rhs(5);
+
+    a.expect_nearly_eq_tol()
+        .with(
+            eq(Rhs(5)),
+            eq(Tolerance::<MockLhs, Rhs>::new(0.2, 7).with_rel(0.1)),
+        )
+        .times(1)
+        .return_const(false);
+
+    assert_nearly!(a == b, eps = 0.2, ulps = 7, rel = 0.1);
+}
+
+#[test]
+#[should_panic(expected = r#"assertion `nearly (left != right)` failed
+  left: MockLhs
+ right: Rhs(5)
+   eps: 0.2
+  ulps: 7
+   rel: 0.1"#)]
+fn macro_assert_nearly_ne_tol_rel_panic() {
+    let mut a = MockLhs::new();
+    let b = Rhs(5);
+
+    a.expect_nearly_ne_tol()
+        .with(
+            eq(Rhs(5)),
+            eq(Tolerance::<MockLhs, Rhs>::new(0.2, 7).with_rel(0.1)),
+        )
+        .times(1)
+        .return_const(false);
+
+    assert_nearly!(a != b, eps = 0.2, ulps = 7, rel = 0.1);
+}
+
+#[test]
+#[should_panic(expected = r#"assertion `nearly (left == right)` failed
+  left: 1.0
+ right: 2.0
+   rel: 0.1 (observed: 0.5)"#)]
+fn macro_assert_nearly_eq_rel_float_panic() {
+    let a: f32 = 1.0;
+    let b: f32 = 2.0;
+    assert_nearly!(a == b, rel = 0.1);
+}
+
+#[test]
+#[should_panic(expected = r#"assertion `nearly (left != right)` failed
+  left: 1.0
+ right: 1.0
+   rel: 0.1 (observed: 0.0)"#)]
+fn macro_assert_nearly_ne_rel_float_panic() {
+    let a: f32 = 1.0;
+    let b: f32 = 1.0;
+    assert_nearly!(a != b, rel = 0.1);
+}
+
 //////////////////////////
 // debug_assert_nearly! //
 //////////////////////////
@@ -895,3 +1291,111 @@ fn macro_debug_assert_nearly_ne() {
     a.expect_nearly_ne().times(0);
     debug_assert_nearly!(a != b);
 }
+
+#[test]
+#[cfg(debug_assertions)]
+fn macro_debug_assert_nearly_eq_rel() {
+    let mut a = MockLhs::new();
+    let b = Rhs(5);
+
+    a.expect_nearly_eq_rel()
+        .with(eq(Rhs(5)), eq(0.1))
+        .times(1)
+        .return_const(true);
+
+    debug_assert_nearly!(a == b, rel = 0.1);
+}
+
+#[test]
+#[cfg(debug_assertions)]
+#[should_panic(expected = r#"assertion `nearly (left == right)` failed
+  left: MockLhs
+ right: Rhs(5)
+   rel: 0.1"#)]
+fn macro_debug_assert_nearly_eq_rel_panic() {
+    let mut a = MockLhs::new();
+    let b = Rhs(5);
+
+    a.expect_nearly_eq_rel()
+        .with(eq(Rhs(5)), eq(0.1))
+        .times(1)
+        .return_const(false);
+
+    debug_assert_nearly!(a == b, rel = 0.1);
+}
+
+#[test]
+#[cfg(not(debug_assertions))]
+fn macro_debug_assert_nearly_eq_rel() {
+    let mut a = MockLhs::new();
+    let b = Rhs(5);
+
+    a.expect_nearly_eq_rel().times(0);
+    debug_assert_nearly!(a == b, rel = 0.1);
+}
+
+#[test]
+#[cfg(debug_assertions)]
+fn macro_debug_assert_nearly_ne_rel() {
+    let mut a = MockLhs::new();
+    let b = Rhs(5);
+
+    a.expect_nearly_ne_rel()
+        .with(eq(Rhs(5)), eq(0.1))
+        .times(1)
+        .return_const(true);
+
+    debug_assert_nearly!(a != b, rel = 0.1);
+}
+
+#[test]
+#[cfg(debug_assertions)]
+#[should_panic(expected = r#"assertion `nearly (left != right)` failed
+  left: MockLhs
+ right: Rhs(5)
+   rel: 0.1"#)]
+fn macro_debug_assert_nearly_ne_rel_panic() {
+    let mut a = MockLhs::new();
+    let b = Rhs(5);
+
+    a.expect_nearly_ne_rel()
+        .with(eq(Rhs(5)), eq(0.1))
+        .times(1)
+        .return_const(false);
+
+    debug_assert_nearly!(a != b, rel = 0.1);
+}
+
+#[test]
+#[cfg(not(debug_assertions))]
+fn macro_debug_assert_nearly_ne_rel() {
+    let mut a = MockLhs::new();
+    let b = Rhs(5);
+
+    a.expect_nearly_ne_rel().times(0);
+    debug_assert_nearly!(a != b, rel = 0.1);
+}
+
+#[test]
+#[cfg(debug_assertions)]
+#[should_panic(expected = r#"assertion `nearly (left == right)` failed
+  left: 1.0
+ right: 2.0
+   rel: 0.1 (observed: 0.5)"#)]
+fn macro_debug_assert_nearly_eq_rel_float_panic() {
+    let a: f32 = 1.0;
+    let b: f32 = 2.0;
+    debug_assert_nearly!(a == b, rel = 0.1);
+}
+
+#[test]
+#[cfg(debug_assertions)]
+#[should_panic(expected = r#"assertion `nearly (left != right)` failed
+  left: 1.0
+ right: 1.0
+   rel: 0.1 (observed: 0.0)"#)]
+fn macro_debug_assert_nearly_ne_rel_float_panic() {
+    let a: f32 = 1.0;
+    let b: f32 = 1.0;
+    debug_assert_nearly!(a != b, rel = 0.1);
+}
@@ -1,5 +1,5 @@
 use mockall::predicate::eq;
-use nearly::{assert_nearly, debug_assert_nearly, nearly, Tolerance};
+use nearly::{assert_nearly, debug_assert_nearly, nearly, Tolerance, ToleranceMode};
 
 mod common;
 use common::{MockLhs, Rhs};
@@ -392,6 +392,532 @@ fn macro_nearly_ge() {
     assert!(!nearly!(a >= b));
 }
 
+#[test]
+fn macro_nearly_lt_rel() {
+    let mut a = MockLhs::new();
+    let b = Rhs(5);
+
+    a.expect_nearly_lt_rel()
+        .with(eq(Rhs(5)), eq(0.1))
+        .times(1)
+        .return_const(true);
+    assert!(nearly!(a < b, rel = 0.1));
+
+    a.checkpoint();
+
+    a.expect_nearly_lt_rel()
+        .with(eq(Rhs(5)), eq(0.1))
+        .times(1)
+        .return_const(false);
+    assert!(!nearly!(a < b, rel = 0.1));
+}
+
+#[test]
+fn macro_nearly_le_rel() {
+    let mut a = MockLhs::new();
+    let b = Rhs(5);
+
+    a.expect_nearly_le_rel()
+        .with(eq(Rhs(5)), eq(0.1))
+        .times(1)
+        .return_const(true);
+    assert!(nearly!(a <= b, rel = 0.1));
+
+    a.checkpoint();
+
+    a.expect_nearly_le_rel()
+        .with(eq(Rhs(5)), eq(0.1))
+        .times(1)
+        .return_const(false);
+    assert!(!nearly!(a <= b, rel = 0.1));
+}
+
+#[test]
+fn macro_nearly_gt_rel() {
+    let mut a = MockLhs::new();
+    let b = Rhs(5);
+
+    a.expect_nearly_gt_rel()
+        .with(eq(Rhs(5)), eq(0.1))
+        .times(1)
+        .return_const(true);
+    assert!(nearly!(a > b, rel = 0.1));
+
+    a.checkpoint();
+
+    a.expect_nearly_gt_rel()
+        .with(eq(Rhs(5)), eq(0.1))
+        .times(1)
+        .return_const(false);
+    assert!(!nearly!(a > b, rel = 0.1));
+}
+
+#[test]
+fn macro_nearly_ge_rel() {
+    let mut a = MockLhs::new();
+    let b = Rhs(5);
+
+    a.expect_nearly_ge_rel()
+        .with(eq(Rhs(5)), eq(0.1))
+        .times(1)
+        .return_const(true);
+    assert!(nearly!(a >= b, rel = 0.1));
+
+    a.checkpoint();
+
+    a.expect_nearly_ge_rel()
+        .with(eq(Rhs(5)), eq(0.1))
+        .times(1)
+        .return_const(false);
+    assert!(!nearly!(a >= b, rel = 0.1));
+}
+
+#[test]
+fn macro_nearly_lt_eps_rel() {
+    let mut a = MockLhs::new();
+    let b = Rhs(5);
+
+    a.expect_nearly_lt_eps()
+        .with(eq(Rhs(5)), eq(0.2))
+        .times(1)
+        .return_const(true);
+    a.expect_nearly_lt_rel()
+        .with(eq(Rhs(5)), eq(0.1))
+        .times(1)
+        .return_const(true);
+    assert!(nearly!(a < b, eps = 0.2, rel = 0.1));
+
+    a.checkpoint();
+
+    a.expect_nearly_lt_eps()
+        .with(eq(Rhs(5)), eq(0.2))
+        .times(1)
+        .return_const(false);
+    a.expect_nearly_lt_rel().times(0);
+    assert!(!nearly!(a < b, eps = 0.2, rel = 0.1));
+
+    a.checkpoint();
+
+    a.expect_nearly_lt_eps()
+        .with(eq(Rhs(5)), eq(0.2))
+        .times(1)
+        .return_const(true);
+    a.expect_nearly_lt_rel()
+        .with(eq(Rhs(5)), eq(0.1))
+        .times(1)
+        .return_const(false);
+    assert!(!nearly!(a < b, eps = 0.2, rel = 0.1));
+}
+
+#[test]
+fn macro_nearly_lt_ulps_rel() {
+    let mut a = MockLhs::new();
+    let b = Rhs(5);
+
+    a.expect_nearly_lt_ulps()
+        .with(eq(Rhs(5)), eq(7))
+        .times(1)
+        .return_const(true);
+    a.expect_nearly_lt_rel()
+        .with(eq(Rhs(5)), eq(0.1))
+        .times(1)
+        .return_const(true);
+    assert!(nearly!(a < b, ulps = 7, rel = 0.1));
+
+    a.checkpoint();
+
+    a.expect_nearly_lt_ulps()
+        .with(eq(Rhs(5)), eq(7))
+        .times(1)
+        .return_const(false);
+    a.expect_nearly_lt_rel().times(0);
+    assert!(!nearly!(a < b, ulps = 7, rel = 0.1));
+
+    a.checkpoint();
+
+    a.expect_nearly_lt_ulps()
+        .with(eq(Rhs(5)), eq(7))
+        .times(1)
+        .return_const(true);
+    a.expect_nearly_lt_rel()
+        .with(eq(Rhs(5)), eq(0.1))
+        .times(1)
+        .return_const(false);
+    assert!(!nearly!(a < b, ulps = 7, rel = 0.1));
+}
+
+#[test]
+fn macro_nearly_lt_tol_rel() {
+    let mut a = MockLhs::new();
+    let b = Rhs(5);
+
+    a.expect_nearly_lt_tol()
+        .with(
+            eq(Rhs(5)),
+            eq(Tolerance::<MockLhs, Rhs>::new(0.2, 7).with_rel(0.1)),
+        )
+        .times(1)
+        .return_const(true);
+    assert!(nearly!(a < b, eps = 0.2, ulps = 7, rel = 0.1));
+
+    a.checkpoint();
+
+    a.expect_nearly_lt_tol()
+        .with(
+            eq(Rhs(5)),
+            eq(Tolerance::<MockLhs, Rhs>::new(0.2, 7).with_rel(0.1)),
+        )
+        .times(1)
+        .return_const(false);
+    assert!(!nearly!(a < b, eps = 0.2, ulps = 7, rel = 0.1));
+}
+
+#[test]
+fn macro_nearly_le_eps_rel() {
+    let mut a = MockLhs::new();
+    let b = Rhs(5);
+
+    a.expect_nearly_le_eps()
+        .with(eq(Rhs(5)), eq(0.2))
+        .times(1)
+        .return_const(true);
+    a.expect_nearly_le_rel().times(0);
+    assert!(nearly!(a <= b, eps = 0.2, rel = 0.1));
+
+    a.checkpoint();
+
+    a.expect_nearly_le_eps()
+        .with(eq(Rhs(5)), eq(0.2))
+        .times(1)
+        .return_const(false);
+    a.expect_nearly_le_rel()
+        .with(eq(Rhs(5)), eq(0.1))
+        .times(1)
+        .return_const(true);
+    assert!(nearly!(a <= b, eps = 0.2, rel = 0.1));
+
+    a.checkpoint();
+
+    a.expect_nearly_le_eps()
+        .with(eq(Rhs(5)), eq(0.2))
+        .times(1)
+        .return_const(false);
+    a.expect_nearly_le_rel()
+        .with(eq(Rhs(5)), eq(0.1))
+        .times(1)
+        .return_const(false);
+    assert!(!nearly!(a <= b, eps = 0.2, rel = 0.1));
+}
+
+#[test]
+fn macro_nearly_le_ulps_rel() {
+    let mut a = MockLhs::new();
+    let b = Rhs(5);
+
+    a.expect_nearly_le_ulps()
+        .with(eq(Rhs(5)), eq(7))
+        .times(1)
+        .return_const(true);
+    a.expect_nearly_le_rel().times(0);
+    assert!(nearly!(a <= b, ulps = 7, rel = 0.1));
+
+    a.checkpoint();
+
+    a.expect_nearly_le_ulps()
+        .with(eq(Rhs(5)), eq(7))
+        .times(1)
+        .return_const(false);
+    a.expect_nearly_le_rel()
+        .with(eq(Rhs(5)), eq(0.1))
+        .times(1)
+        .return_const(true);
+    assert!(nearly!(a <= b, ulps = 7, rel = 0.1));
+
+    a.checkpoint();
+
+    a.expect_nearly_le_ulps()
+        .with(eq(Rhs(5)), eq(7))
+        .times(1)
+        .return_const(false);
+    a.expect_nearly_le_rel()
+        .with(eq(Rhs(5)), eq(0.1))
+        .times(1)
+        .return_const(false);
+    assert!(!nearly!(a <= b, ulps = 7, rel = 0.1));
+}
+
+#[test]
+fn macro_nearly_le_tol_rel() {
+    let mut a = MockLhs::new();
+    let b = Rhs(5);
+
+    a.expect_nearly_le_tol()
+        .with(
+            eq(Rhs(5)),
+            eq(Tolerance::<MockLhs, Rhs>::new(0.2, 7).with_rel(0.1)),
+        )
+        .times(1)
+        .return_const(true);
+    assert!(nearly!(a <= b, eps = 0.2, ulps = 7, rel = 0.1));
+
+    a.checkpoint();
+
+    a.expect_nearly_le_tol()
+        .with(
+            eq(Rhs(5)),
+            eq(Tolerance::<MockLhs, Rhs>::new(0.2, 7).with_rel(0.1)),
+        )
+        .times(1)
+        .return_const(false);
+    assert!(!nearly!(a <= b, eps = 0.2, ulps = 7, rel = 0.1));
+}
+
+#[test]
+fn macro_nearly_gt_eps_rel() {
+    let mut a = MockLhs::new();
+    let b = Rhs(5);
+
+    a.expect_nearly_gt_eps()
+        .with(eq(Rhs(5)), eq(0.2))
+        .times(1)
+        .return_const(true);
+    a.expect_nearly_gt_rel()
+        .with(eq(Rhs(5)), eq(0.1))
+        .times(1)
+        .return_const(true);
+    assert!(nearly!(a > b, eps = 0.2, rel = 0.1));
+
+    a.checkpoint();
+
+    a.expect_nearly_gt_eps()
+        .with(eq(Rhs(5)), eq(0.2))
+        .times(1)
+        .return_const(false);
+    a.expect_nearly_gt_rel().times(0);
+    assert!(!nearly!(a > b, eps = 0.2, rel = 0.1));
+
+    a.checkpoint();
+
+    a.expect_nearly_gt_eps()
+        .with(eq(Rhs(5)), eq(0.2))
+        .times(1)
+        .return_const(true);
+    a.expect_nearly_gt_rel()
+        .with(eq(Rhs(5)), eq(0.1))
+        .times(1)
+        .return_const(false);
+    assert!(!nearly!(a > b, eps = 0.2, rel = 0.1));
+}
+
+#[test]
+fn macro_nearly_gt_ulps_rel() {
+    let mut a = MockLhs::new();
+    let b = Rhs(5);
+
+    a.expect_nearly_gt_ulps()
+        .with(eq(Rhs(5)), eq(7))
+        .times(1)
+        .return_const(true);
+    a.expect_nearly_gt_rel()
+        .with(eq(Rhs(5)), eq(0.1))
+        .times(1)
+        .return_const(true);
+    assert!(nearly!(a > b, ulps = 7, rel = 0.1));
+
+    a.checkpoint();
+
+    a.expect_nearly_gt_ulps()
+        .with(eq(Rhs(5)), eq(7))
+        .times(1)
+        .return_const(false);
+    a.expect_nearly_gt_rel().times(0);
+    assert!(!nearly!(a > b, ulps = 7, rel = 0.1));
+
+    a.checkpoint();
+
+    a.expect_nearly_gt_ulps()
+        .with(eq(Rhs(5)), eq(7))
+        .times(1)
+        .return_const(true);
+    a.expect_nearly_gt_rel()
+        .with(eq(Rhs(5)), eq(0.1))
+        .times(1)
+        .return_const(false);
+    assert!(!nearly!(a > b, ulps = 7, rel = 0.1));
+}
+
+#[test]
+fn macro_nearly_gt_tol_rel() {
+    let mut a = MockLhs::new();
+    let b = Rhs(5);
+
+    a.expect_nearly_gt_tol()
+        .with(
+            eq(Rhs(5)),
+            eq(Tolerance::<MockLhs, Rhs>::new(0.2, 7).with_rel(0.1)),
+        )
+        .times(1)
+        .return_const(true);
+    assert!(nearly!(a > b, eps = 0.2, ulps = 7, rel = 0.1));
+
+    a.checkpoint();
+
+    a.expect_nearly_gt_tol()
+        .with(
+            eq(Rhs(5)),
+            eq(Tolerance::<MockLhs, Rhs>::new(0.2, 7).with_rel(0.1)),
+        )
+        .times(1)
+        .return_const(false);
+    assert!(!nearly!(a > b, eps = 0.2, ulps = 7, rel = 0.1));
+}
+
+#[test]
+fn macro_nearly_ge_eps_rel() {
+    let mut a = MockLhs::new();
+    let b = Rhs(5);
+
+    a.expect_nearly_ge_eps()
+        .with(eq(Rhs(5)), eq(0.2))
+        .times(1)
+        .return_const(true);
+    a.expect_nearly_ge_rel().times(0);
+    assert!(nearly!(a >= b, eps = 0.2, rel = 0.1));
+
+    a.checkpoint();
+
+    a.expect_nearly_ge_eps()
+        .with(eq(Rhs(5)), eq(0.2))
+        .times(1)
+        .return_const(false);
+    a.expect_nearly_ge_rel()
+        .with(eq(Rhs(5)), eq(0.1))
+        .times(1)
+        .return_const(true);
+    assert!(nearly!(a >= b, eps = 0.2, rel = 0.1));
+
+    a.checkpoint();
+
+    a.expect_nearly_ge_eps()
+        .with(eq(Rhs(5)), eq(0.2))
+        .times(1)
+        .return_const(false);
+    a.expect_nearly_ge_rel()
+        .with(eq(Rhs(5)), eq(0.1))
+        .times(1)
+        .return_const(false);
+    assert!(!nearly!(a >= b, eps = 0.2, rel = 0.1));
+}
+
+#[test]
+fn macro_nearly_ge_ulps_rel() {
+    let mut a = MockLhs::new();
+    let b = Rhs(5);
+
+    a.expect_nearly_ge_ulps()
+        .with(eq(Rhs(5)), eq(7))
+        .times(1)
+        .return_const(true);
+    a.expect_nearly_ge_rel().times(0);
+    assert!(nearly!(a >= b, ulps = 7, rel = 0.1));
+
+    a.checkpoint();
+
+    a.expect_nearly_ge_ulps()
+        .with(eq(Rhs(5)), eq(7))
+        .times(1)
+        .return_const(false);
+    a.expect_nearly_ge_rel()
+        .with(eq(Rhs(5)), eq(0.1))
+        .times(1)
+        .return_const(true);
+    assert!(nearly!(a >= b, ulps = 7, rel = 0.1));
+
+    a.checkpoint();
+
+    a.expect_nearly_ge_ulps()
+        .with(eq(Rhs(5)), eq(7))
+        .times(1)
+        .return_const(false);
+    a.expect_nearly_ge_rel()
+        .with(eq(Rhs(5)), eq(0.1))
+        .times(1)
+        .return_const(false);
+    assert!(!nearly!(a >= b, ulps = 7, rel = 0.1));
+}
+
+#[test]
+fn macro_nearly_ge_tol_rel() {
+    let mut a = MockLhs::new();
+    let b = Rhs(5);
+
+    a.expect_nearly_ge_tol()
+        .with(
+            eq(Rhs(5)),
+            eq(Tolerance::<MockLhs, Rhs>::new(0.2, 7).with_rel(0.1)),
+        )
+        .times(1)
+        .return_const(true);
+    assert!(nearly!(a >= b, eps = 0.2, ulps = 7, rel = 0.1));
+
+    a.checkpoint();
+
+    a.expect_nearly_ge_tol()
+        .with(
+            eq(Rhs(5)),
+            eq(Tolerance::<MockLhs, Rhs>::new(0.2, 7).with_rel(0.1)),
+        )
+        .times(1)
+        .return_const(false);
+    assert!(!nearly!(a >= b, eps = 0.2, ulps = 7, rel = 0.1));
+}
+
+macro_rules! assert_combined_rel_trichotomy {
+    ($a: expr, $b: expr, $($tol: tt)*) => {
+        let eq = nearly!($a == $b, $($tol)*);
+        let lt = nearly!($a < $b, $($tol)*);
+        let gt = nearly!($a > $b, $($tol)*);
+        assert_eq!(
+            [eq, lt, gt].iter().filter(|cmp| **cmp).count(),
+            1,
+            "a: {:?}, b: {:?}, eq: {}, lt: {}, gt: {}",
+            $a,
+            $b,
+            eq,
+            lt,
+            gt
+        );
+        assert_eq!(nearly!($a <= $b, $($tol)*), lt || eq);
+        assert_eq!(nearly!($a >= $b, $($tol)*), gt || eq);
+    };
+}
+
+#[test]
+fn macro_nearly_ord_combined_rel_trichotomy() {
+    let values = [
+        1.0,
+        1.0 + 4.0 * f64::EPSILON,
+        1.0 + 5e-5,
+        1.0 + 1e-3,
+        1.5,
+        2.5,
+        -1.0,
+        0.0,
+        -0.0,
+        f64::MIN_POSITIVE,
+        f64::INFINITY,
+        f64::NEG_INFINITY,
+    ];
+
+    for a in values {
+        for b in values {
+            assert_combined_rel_trichotomy!(a, b, eps = 1.0, rel = 1e-4);
+            assert_combined_rel_trichotomy!(a, b, ulps = 8, rel = 1e-2);
+            assert_combined_rel_trichotomy!(a, b, eps = 1e-3, ulps = 1, rel = 1e-4);
+        }
+    }
+}
+
 ////////////////////
 // assert_nearly! //
 ////////////////////
@@ -692,6 +1218,36 @@ fn macro_assert_nearly_lt_tol_tuple_panic() {
     assert_nearly!(a < b, eps = 0.15, ulps = 7);
 }
 
+#[test]
+#[should_panic(expected = r#"assertion `nearly (left < right)` failed
+  left: MockLhs
+ right: Rhs(5)
+   eps: 0.1
+  ulps: 5
+   rel: 0.2
+  mode: All"#)]
+fn macro_assert_nearly_lt_tol_mode_panic() {
+    let mut a = MockLhs::new();
+    let b = Rhs(5);
+
+    a.expect_nearly_lt_tol()
+        .with(
+            eq(Rhs(5)),
+            eq(Tolerance::<MockLhs, Rhs>::new(0.1, 5)
+                .with_rel(0.2)
+                .with_mode(ToleranceMode::All)),
+        )
+        .times(1)
+        .return_const(false);
+
+    assert_nearly!(
+        a < b,
+        tol = Tolerance::new(0.1, 5)
+            .with_rel(0.2)
+            .with_mode(ToleranceMode::All)
+    );
+}
+
 #[test]
 fn macro_assert_nearly_le_tol() {
     let mut a = MockLhs::new();
@@ -748,6 +1304,36 @@ fn macro_assert_nearly_le_tol_tuple_panic() {
     assert_nearly!(a <= b, eps = 0.15, ulps = 7);
 }
 
+#[test]
+#[should_panic(expected = r#"assertion `nearly (left <= right)` failed
+  left: MockLhs
+ right: Rhs(5)
+   eps: 0.1
+  ulps: 5
+   rel: 0.2
+  mode: All"#)]
+fn macro_assert_nearly_le_tol_mode_panic() {
+    let mut a = MockLhs::new();
+    let b = Rhs(5);
+
+    a.expect_nearly_le_tol()
+        .with(
+            eq(Rhs(5)),
+            eq(Tolerance::<MockLhs, Rhs>::new(0.1, 5)
+                .with_rel(0.2)
+                .with_mode(ToleranceMode::All)),
+        )
+        .times(1)
+        .return_const(false);
+
+    assert_nearly!(
+        a <= b,
+        tol = Tolerance::new(0.1, 5)
+            .with_rel(0.2)
+            .with_mode(ToleranceMode::All)
+    );
+}
+
 #[test]
 fn macro_assert_nearly_gt_tol() {
     let mut a = MockLhs::new();
@@ -804,6 +1390,36 @@ fn macro_assert_nearly_gt_tol_tuple_panic() {
     assert_nearly!(a > b, eps = 0.15, ulps = 7);
 }
 
+#[test]
+#[should_panic(expected = r#"assertion `nearly (left > right)` failed
+  left: MockLhs
+ right: Rhs(5)
+   eps: 0.1
+  ulps: 5
+   rel: 0.2
+  mode: All"#)]
+fn macro_assert_nearly_gt_tol_mode_panic() {
+    let mut a = MockLhs::new();
+    let b = Rhs(5);
+
+    a.expect_nearly_gt_tol()
+        .with(
+            eq(Rhs(5)),
+            eq(Tolerance::<MockLhs, Rhs>::new(0.1, 5)
+                .with_rel(0.2)
+                .with_mode(ToleranceMode::All)),
+        )
+        .times(1)
+        .return_const(false);
+
+    assert_nearly!(
+        a > b,
+        tol = Tolerance::new(0.1, 5)
+            .with_rel(0.2)
+            .with_mode(ToleranceMode::All)
+    );
+}
+
 #[test]
 fn macro_assert_nearly_ge_tol() {
     let mut a = MockLhs::new();
@@ -825,163 +1441,445 @@ fn macro_assert_nearly_ge_tol() {
 }
 
 #[test]
-#[should_panic(expected = r#"assertion `nearly (left >= right)` failed
+#[should_panic(expected = r#"assertion `nearly (left >= right)` failed
+  left: MockLhs
+ right: Rhs(5)
+   eps: 0.1
+  ulps: 5"#)]
+fn macro_assert_nearly_ge_tol_panic() {
+    let mut a = MockLhs::new();
+    let b = Rhs(5);
+
+    a.expect_nearly_ge_tol()
+        .with(eq(Rhs(5)), eq(Tolerance::<MockLhs, Rhs>::new(0.1, 5)))
+        .times(1)
+        .return_const(false);
+
+    assert_nearly!(a >= b, tol = Tolerance::new(0.1, 5));
+}
+
+#[test]
+#[should_panic(expected = r#"assertion `nearly (left >= right)` failed
+  left: MockLhs
+ right: Rhs(5)
+   eps: 0.15
+  ulps: 7"#)]
+fn macro_assert_nearly_ge_tol_tuple_panic() {
+    let mut a = MockLhs::new();
+    let b = Rhs(5);
+
+    a.expect_nearly_ge_tol()
+        .with(eq(Rhs(5)), eq(Tolerance::<MockLhs, Rhs>::new(0.15, 7)))
+        .times(1)
+        .return_const(false);
+
+    assert_nearly!(a >= b, eps = 0.15, ulps = 7);
+}
+
+#[test]
+#[should_panic(expected = r#"assertion `nearly (left >= right)` failed
+  left: MockLhs
+ right: Rhs(5)
+   eps: 0.1
+  ulps: 5
+   rel: 0.2
+  mode: All"#)]
+fn macro_assert_nearly_ge_tol_mode_panic() {
+    let mut a = MockLhs::new();
+    let b = Rhs(5);
+
+    a.expect_nearly_ge_tol()
+        .with(
+            eq(Rhs(5)),
+            eq(Tolerance::<MockLhs, Rhs>::new(0.1, 5)
+                .with_rel(0.2)
+                .with_mode(ToleranceMode::All)),
+        )
+        .times(1)
+        .return_const(false);
+
+    assert_nearly!(
+        a >= b,
+        tol = Tolerance::new(0.1, 5)
+            .with_rel(0.2)
+            .with_mode(ToleranceMode::All)
+    );
+}
+
+#[test]
+fn macro_assert_nearly_lt() {
+    let mut a = MockLhs::new();
+    let b = Rhs(5);
+
+    a.expect_nearly_lt()
+        .with(eq(Rhs(5)))
+        .times(1)
+        .return_const(true);
+
+    assert_nearly!(a < b);
+}
+
+#[test]
+#[should_panic(expected = r#"assertion `nearly (left < right)` failed
+  left: MockLhs
+ right: Rhs(5)
+   eps: 0.01
+  ulps: 3"#)]
+fn macro_assert_nearly_lt_panic() {
+    let mut a = MockLhs::new();
+    let b = Rhs(5);
+
+    a.expect_nearly_lt()
+        .with(eq(Rhs(5)))
+        .times(1)
+        .return_const(false);
+
+    assert_nearly!(a < b);
+}
+
+#[test]
+fn macro_assert_nearly_le() {
+    let mut a = MockLhs::new();
+    let b = Rhs(5);
+
+    a.expect_nearly_le()
+        .with(eq(Rhs(5)))
+        .times(1)
+        .return_const(true);
+
+    assert_nearly!(a <= b);
+}
+
+#[test]
+#[should_panic(expected = r#"assertion `nearly (left <= right)` failed
+  left: MockLhs
+ right: Rhs(5)
+   eps: 0.01
+  ulps: 3"#)]
+fn macro_assert_nearly_le_panic() {
+    let mut a = MockLhs::new();
+    let b = Rhs(5);
+
+    a.expect_nearly_le()
+        .with(eq(Rhs(5)))
+        .times(1)
+        .return_const(false);
+
+    assert_nearly!(a <= b);
+}
+
+#[test]
+fn macro_assert_nearly_gt() {
+    let mut a = MockLhs::new();
+    let b = Rhs(5);
+
+    a.expect_nearly_gt()
+        .with(eq(Rhs(5)))
+        .times(1)
+        .return_const(true);
+
+    assert_nearly!(a > b);
+}
+
+#[test]
+#[should_panic(expected = r#"assertion `nearly (left > right)` failed
+  left: MockLhs
+ right: Rhs(5)
+   eps: 0.01
+  ulps: 3"#)]
+fn macro_assert_nearly_gt_panic() {
+    let mut a = MockLhs::new();
+    let b = Rhs(5);
+
+    a.expect_nearly_gt()
+        .with(eq(Rhs(5)))
+        .times(1)
+        .return_const(false);
+
+    assert_nearly!(a > b);
+}
+
+#[test]
+fn macro_assert_nearly_ge() {
+    let mut a = MockLhs::new();
+    let b = Rhs(5);
+
+    a.expect_nearly_ge()
+        .with(eq(Rhs(5)))
+        .times(1)
+        .return_const(true);
+
+    assert_nearly!(a >= b);
+}
+
+#[test]
+#[should_panic(expected = r#"assertion `nearly (left >= right)` failed
+  left: MockLhs
+ right: Rhs(5)
+   eps: 0.01
+  ulps: 3"#)]
+fn macro_assert_nearly_ge_panic() {
+    let mut a = MockLhs::new();
+    let b = Rhs(5);
+
+    a.expect_nearly_ge()
+        .with(eq(Rhs(5)))
+        .times(1)
+        .return_const(false);
+
+    assert_nearly!(a >= b);
+}
+
+#[test]
+fn macro_assert_nearly_lt_rel() {
+    let mut a = MockLhs::new();
+    let b = Rhs(5);
+
+    a.expect_nearly_lt_rel()
+        .with(eq(Rhs(5)), eq(0.1))
+        .times(1)
+        .return_const(true);
+
+    assert_nearly!(a < b, rel = 0.1);
+}
+
+#[test]
+#[should_panic(expected = r#"assertion `nearly (left < right)` failed
   left: MockLhs
  right: Rhs(5)
-   eps: 0.1
-  ulps: 5"#)]
-fn macro_assert_nearly_ge_tol_panic() {
+   rel: 0.1"#)]
+fn macro_assert_nearly_lt_rel_panic() {
     let mut a = MockLhs::new();
     let b = Rhs(5);
 
-    a.expect_nearly_ge_tol()
-        .with(eq(Rhs(5)), eq(Tolerance::<MockLhs, Rhs>::new(0.1, 5)))
+    a.expect_nearly_lt_rel()
+        .with(eq(Rhs(5)), eq(0.1))
         .times(1)
         .return_const(false);
 
-    assert_nearly!(a >= b, tol = Tolerance::new(0.1, 5));
+    assert_nearly!(a < b, rel = 0.1);
 }
 
 #[test]
-#[should_panic(expected = r#"assertion `nearly (left >= right)` failed
+fn macro_assert_nearly_le_rel() {
+    let mut a = MockLhs::new();
+    let b = Rhs(5);
+
+    a.expect_nearly_le_rel()
+        .with(eq(Rhs(5)), eq(0.1))
+        .times(1)
+        .return_const(true);
+
+    assert_nearly!(a <= b, rel = 0.1);
+}
+
+#[test]
+#[should_panic(expected = r#"assertion `nearly (left <= right)` failed
   left: MockLhs
  right: Rhs(5)
-   eps: 0.15
-  ulps: 7"#)]
-fn macro_assert_nearly_ge_tol_tuple_panic() {
+   rel: 0.1"#)]
+fn macro_assert_nearly_le_rel_panic() {
     let mut a = MockLhs::new();
     let b = Rhs(5);
 
-    a.expect_nearly_ge_tol()
-        .with(eq(Rhs(5)), eq(Tolerance::<MockLhs, Rhs>::new(0.15, 7)))
+    a.expect_nearly_le_rel()
+        .with(eq(Rhs(5)), eq(0.1))
         .times(1)
         .return_const(false);
 
-    assert_nearly!(a >= b, eps = 0.15, ulps = 7);
+    assert_nearly!(a <= b, rel = 0.1);
 }
 
 #[test]
-fn macro_assert_nearly_lt() {
+fn macro_assert_nearly_gt_rel() {
     let mut a = MockLhs::new();
     let b = Rhs(5);
 
-    a.expect_nearly_lt()
-        .with(eq(Rhs(5)))
+    a.expect_nearly_gt_rel()
+        .with(eq(Rhs(5)), eq(0.1))
         .times(1)
         .return_const(true);
 
-    assert_nearly!(a < b);
+    assert_nearly!(a > b, rel = 0.1);
 }
 
 #[test]
-#[should_panic(expected = r#"assertion `nearly (left < right)` failed
+#[should_panic(expected = r#"assertion `nearly (left > right)` failed
   left: MockLhs
  right: Rhs(5)
-   eps: 0.01
-  ulps: 3"#)]
-fn macro_assert_nearly_lt_panic() {
+   rel: 0.1"#)]
+fn macro_assert_nearly_gt_rel_panic() {
     let mut a = MockLhs::new();
     let b = Rhs(5);
 
-    a.expect_nearly_lt()
-        .with(eq(Rhs(5)))
+    a.expect_nearly_gt_rel()
+        .with(eq(Rhs(5)), eq(0.1))
         .times(1)
         .return_const(false);
 
-    assert_nearly!(a < b);
+    assert_nearly!(a > b, rel = 0.1);
 }
 
 #[test]
-fn macro_assert_nearly_le() {
+fn macro_assert_nearly_ge_rel() {
     let mut a = MockLhs::new();
     let b = Rhs(5);
 
-    a.expect_nearly_le()
-        .with(eq(Rhs(5)))
+    a.expect_nearly_ge_rel()
+        .with(eq(Rhs(5)), eq(0.1))
         .times(1)
         .return_const(true);
 
-    assert_nearly!(a <= b);
+    assert_nearly!(a >= b, rel = 0.1);
 }
 
 #[test]
-#[should_panic(expected = r#"assertion `nearly (left <= right)` failed
+#[should_panic(expected = r#"assertion `nearly (left >= right)` failed
   left: MockLhs
  right: Rhs(5)
-   eps: 0.01
-  ulps: 3"#)]
-fn macro_assert_nearly_le_panic() {
+   rel: 0.1"#)]
+fn macro_assert_nearly_ge_rel_panic() {
     let mut a = MockLhs::new();
     let b = Rhs(5);
 
-    a.expect_nearly_le()
-        .with(eq(Rhs(5)))
+    a.expect_nearly_ge_rel()
+        .with(eq(Rhs(5)), eq(0.1))
         .times(1)
         .return_const(false);
 
-    assert_nearly!(a <= b);
+    assert_nearly!(a >= b, rel = 0.1);
 }
 
 #[test]
-fn macro_assert_nearly_gt() {
+#[should_panic(expected = r#"assertion `nearly (left < right)` failed
+  left: MockLhs
+ right: Rhs(5)
+   eps: 0.2
+  ulps: 7
+   rel: 0.1"#)]
+fn macro_assert_nearly_lt_tol_rel_panic() {
     let mut a = MockLhs::new();
     let b = Rhs(5);
 
-    a.expect_nearly_gt()
-        .with(eq(Rhs(5)))
+    a.expect_nearly_lt_tol()
+        .with(
+            eq(Rhs(5)),
+            eq(Tolerance::<MockLhs, Rhs>::new(0.2, 7).with_rel(0.1)),
+        )
         .times(1)
-        .return_const(true);
+        .return_const(false);
 
-    assert_nearly!(a > b);
+    assert_nearly!(a < b, eps = 0.2, ulps = 7, rel = 0.1);
 }
 
 #[test]
-#[should_panic(expected = r#"assertion `nearly (left > right)` failed
+#[should_panic(expected = r#"assertion `nearly (left <= right)` failed
   left: MockLhs
  right: Rhs(5)
-   eps: 0.01
-  ulps: 3"#)]
-fn macro_assert_nearly_gt_panic() {
+   eps: 0.2
+  ulps: 7
+   rel: 0.1"#)]
+fn macro_assert_nearly_le_tol_rel_panic() {
     let mut a = MockLhs::new();
     let b = Rhs(5);
 
-    a.expect_nearly_gt()
-        .with(eq(Rhs(5)))
+    a.expect_nearly_le_tol()
+        .with(
+            eq(Rhs(5)),
+            eq(Tolerance::<MockLhs, Rhs>::new(0.2, 7).with_rel(0.1)),
+        )
         .times(1)
         .return_const(false);
 
-    assert_nearly!(a > b);
+    assert_nearly!(a <= b, eps = 0.2, ulps = 7, rel = 0.1);
 }
 
 #[test]
-fn macro_assert_nearly_ge() {
+#[should_panic(expected = r#"assertion `nearly (left > right)` failed
+  left: MockLhs
+ right: Rhs(5)
+   eps: 0.2
+  ulps: 7
+   rel: 0.1"#)]
+fn macro_assert_nearly_gt_tol_rel_panic() {
     let mut a = MockLhs::new();
     let b = Rhs(5);
 
-    a.expect_nearly_ge()
-        .with(eq(Rhs(5)))
+    a.expect_nearly_gt_tol()
+        .with(
+            eq(Rhs(5)),
+            eq(Tolerance::<MockLhs, Rhs>::new(0.2, 7).with_rel(0.1)),
+        )
         .times(1)
-        .return_const(true);
+        .return_const(false);
 
-    assert_nearly!(a >= b);
+    assert_nearly!(a > b, eps = 0.2, ulps = 7, rel = 0.1);
 }
 
 #[test]
 #[should_panic(expected = r#"assertion `nearly (left >= right)` failed
   left: MockLhs
  right: Rhs(5)
-   eps: 0.01
-  ulps: 3"#)]
-fn macro_assert_nearly_ge_panic() {
+   eps: 0.2
+  ulps: 7
+   rel: 0.1"#)]
+fn macro_assert_nearly_ge_tol_rel_panic() {
     let mut a = MockLhs::new();
     let b = Rhs(5);
 
-    a.expect_nearly_ge()
-        .with(eq(Rhs(5)))
+    a.expect_nearly_ge_tol()
+        .with(
+            eq(Rhs(5)),
+            eq(Tolerance::<MockLhs, Rhs>::new(0.2, 7).with_rel(0.1)),
+        )
         .times(1)
         .return_const(false);
 
-    assert_nearly!(a >= b);
+    assert_nearly!(a >= b, eps = 0.2, ulps = 7, rel = 0.1);
+}
+
+#[test]
+#[should_panic(expected = r#"assertion `nearly (left < right)` failed
+  left: 2.0
+ right: 1.0
+   rel: 0.1 (observed: 0.5)"#)]
+fn macro_assert_nearly_lt_rel_float_panic() {
+    let a: f32 = 2.0;
+    let b: f32 = 1.0;
+    assert_nearly!(a < b, rel = 0.1);
+}
+
+#[test]
+#[should_panic(expected = r#"assertion `nearly (left <= right)` failed
+  left: 2.0
+ right: 1.0
+   rel: 0.1 (observed: 0.5)"#)]
+fn macro_assert_nearly_le_rel_float_panic() {
+    let a: f32 = 2.0;
+    let b: f32 = 1.0;
+    assert_nearly!(a <= b, rel = 0.1);
+}
+
+#[test]
+#[should_panic(expected = r#"assertion `nearly (left > right)` failed
+  left: 1.0
+ right: 2.0
+   rel: 0.1 (observed: 0.5)"#)]
+fn macro_assert_nearly_gt_rel_float_panic() {
+    let a: f32 = 1.0;
+    let b: f32 = 2.0;
+    assert_nearly!(a > b, rel = 0.1);
+}
+
+#[test]
+#[should_panic(expected = r#"assertion `nearly (left >= right)` failed
+  left: 1.0
+ right: 2.0
+   rel: 0.1 (observed: 0.5)"#)]
+fn macro_assert_nearly_ge_rel_float_panic() {
+    let a: f32 = 1.0;
+    let b: f32 = 2.0;
+    assert_nearly!(a >= b, rel = 0.1);
 }
 
 //////////////////////////
@@ -1775,3 +2673,219 @@ fn macro_debug_assert_nearly_ge() {
     a.expect_nearly_ge().times(0);
     debug_assert_nearly!(a >= b);
 }
+
+#[test]
+#[cfg(debug_assertions)]
+fn macro_debug_assert_nearly_lt_rel() {
+    let mut a = MockLhs::new();
+    let b = Rhs(5);
+
+    a.expect_nearly_lt_rel()
+        .with(eq(Rhs(5)), eq(0.1))
+        .times(1)
+        .return_const(true);
+
+    debug_assert_nearly!(a < b, rel = 0.1);
+}
+
+#[test]
+#[cfg(debug_assertions)]
+#[should_panic(expected = r#"assertion `nearly (left < right)` failed
+  left: MockLhs
+ right: Rhs(5)
+   rel: 0.1"#)]
+fn macro_debug_assert_nearly_lt_rel_panic() {
+    let mut a = MockLhs::new();
+    let b = Rhs(5);
+
+    a.expect_nearly_lt_rel()
+        .with(eq(Rhs(5)), eq(0.1))
+        .times(1)
+        .return_const(false);
+
+    debug_assert_nearly!(a < b, rel = 0.1);
+}
+
+#[test]
+#[cfg(not(debug_assertions))]
+fn macro_debug_assert_nearly_lt_rel() {
+    let mut a = MockLhs::new();
+    let b = Rhs(5);
+
+    a.expect_nearly_lt_rel().times(0);
+    debug_assert_nearly!(a < b, rel = 0.1);
+}
+
+#[test]
+#[cfg(debug_assertions)]
+fn macro_debug_assert_nearly_le_rel() {
+    let mut a = MockLhs::new();
+    let b = Rhs(5);
+
+    a.expect_nearly_le_rel()
+        .with(eq(Rhs(5)), eq(0.1))
+        .times(1)
+        .return_const(true);
+
+    debug_assert_nearly!(a <= b, rel = 0.1);
+}
+
+#[test]
+#[cfg(debug_assertions)]
+#[should_panic(expected = r#"assertion `nearly (left <= right)` failed
+  left: MockLhs
+ right: Rhs(5)
+   rel: 0.1"#)]
+fn macro_debug_assert_nearly_le_rel_panic() {
+    let mut a = MockLhs::new();
+    let b = Rhs(5);
+
+    a.expect_nearly_le_rel()
+        .with(eq(Rhs(5)), eq(0.1))
+        .times(1)
+        .return_const(false);
+
+    debug_assert_nearly!(a <= b, rel = 0.1);
+}
+
+#[test]
+#[cfg(not(debug_assertions))]
+fn macro_debug_assert_nearly_le_rel() {
+    let mut a = MockLhs::new();
+    let b = Rhs(5);
+
+    a.expect_nearly_le_rel().times(0);
+    debug_assert_nearly!(a <= b, rel = 0.1);
+}
+
+#[test]
+#[cfg(debug_assertions)]
+fn macro_debug_assert_nearly_gt_rel() {
+    let mut a = MockLhs::new();
+    let b = Rhs(5);
+
+    a.expect_nearly_gt_rel()
+        .with(eq(Rhs(5)), eq(0.1))
+        .times(1)
+        .return_const(true);
+
+    debug_assert_nearly!(a > b, rel = 0.1);
+}
+
+#[test]
+#[cfg(debug_assertions)]
+#[should_panic(expected = r#"assertion `nearly (left > right)` failed
+  left: MockLhs
+ right: Rhs(5)
+   rel: 0.1"#)]
+fn macro_debug_assert_nearly_gt_rel_panic() {
+    let mut a = MockLhs::new();
+    let b = Rhs(5);
+
+    a.expect_nearly_gt_rel()
+        .with(eq(Rhs(5)), eq(0.1))
+        .times(1)
+        .return_const(false);
+
+    debug_assert_nearly!(a > b, rel = 0.1);
+}
+
+#[test]
+#[cfg(not(debug_assertions))]
+fn macro_debug_assert_nearly_gt_rel() {
+    let mut a = MockLhs::new();
+    let b = Rhs(5);
+
+    a.expect_nearly_gt_rel().times(0);
+    debug_assert_nearly!(a > b, rel = 0.1);
+}
+
+#[test]
+#[cfg(debug_assertions)]
+fn macro_debug_assert_nearly_ge_rel() {
+    let mut a = MockLhs::new();
+    let b = Rhs(5);
+
+    a.expect_nearly_ge_rel()
+        .with(eq(Rhs(5)), eq(0.1))
+        .times(1)
+        .return_const(true);
+
+    debug_assert_nearly!(a >= b, rel = 0.1);
+}
+
+#[test]
+#[cfg(debug_assertions)]
+#[should_panic(expected = r#"assertion `nearly (left >= right)` failed
+  left: MockLhs
+ right: Rhs(5)
+   rel: 0.1"#)]
+fn macro_debug_assert_nearly_ge_rel_panic() {
+    let mut a = MockLhs::new();
+    let b = Rhs(5);
+
+    a.expect_nearly_ge_rel()
+        .with(eq(Rhs(5)), eq(0.1))
+        .times(1)
+        .return_const(false);
+
+    debug_assert_nearly!(a >= b, rel = 0.1);
+}
+
+#[test]
+#[cfg(not(debug_assertions))]
+fn macro_debug_assert_nearly_ge_rel() {
+    let mut a = MockLhs::new();
+    let b = Rhs(5);
+
+    a.expect_nearly_ge_rel().times(0);
+    debug_assert_nearly!(a >= b, rel = 0.1);
+}
+
+#[test]
+#[cfg(debug_assertions)]
+#[should_panic(expected = r#"assertion `nearly (left < right)` failed
+  left: 2.0
+ right: 1.0
+   rel: 0.1 (observed: 0.5)"#)]
+fn macro_debug_assert_nearly_lt_rel_float_panic() {
+    let a: f32 = 2.0;
+    let b: f32 = 1.0;
+    debug_assert_nearly!(a < b, rel = 0.1);
+}
+
+#[test]
+#[cfg(debug_assertions)]
+#[should_panic(expected = r#"assertion `nearly (left <= right)` failed
+  left: 2.0
+ right: 1.0
+   rel: 0.1 (observed: 0.5)"#)]
+fn macro_debug_assert_nearly_le_rel_float_panic() {
+    let a: f32 = 2.0;
+    let b: f32 = 1.0;
+    debug_assert_nearly!(a <= b, rel = 0.1);
+}
+
+#[test]
+#[cfg(debug_assertions)]
+#[should_panic(expected = r#"assertion `nearly (left > right)` failed
+  left: 1.0
+ right: 2.0
+   rel: 0.1 (observed: 0.5)"#)]
+fn macro_debug_assert_nearly_gt_rel_float_panic() {
+    let a: f32 = 1.0;
+    let b: f32 = 2.0;
+    debug_assert_nearly!(a > b, rel = 0.1);
+}
+
+#[test]
+#[cfg(debug_assertions)]
+#[should_panic(expected = r#"assertion `nearly (left >= right)` failed
+  left: 1.0
+ right: 2.0
+   rel: 0.1 (observed: 0.5)"#)]
+fn macro_debug_assert_nearly_ge_rel_float_panic() {
+    let a: f32 = 1.0;
+    let b: f32 = 2.0;
+    debug_assert_nearly!(a >= b, rel = 0.1);
+}
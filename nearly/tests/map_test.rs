@@ -2,10 +2,13 @@
 
 use mockall::predicate::eq;
 use nearly::{
-    NearlyEqEps, NearlyEqTol, NearlyEqUlps, NearlyOrdEps, NearlyOrdTol, NearlyOrdUlps, Tolerance,
+    NearlyEqEps, NearlyEqRel, NearlyEqTol, NearlyEqUlps, NearlyOrdEps, NearlyOrdRel, NearlyOrdTol,
+    NearlyOrdUlps, Tolerance,
 };
 use paste::paste;
+use std::collections::hash_map::DefaultHasher;
 use std::collections::{BTreeMap, HashMap};
+use std::hash::BuildHasherDefault;
 
 mod common;
 use common::{MockLhs, Rhs};
@@ -165,6 +168,69 @@ macro_rules! impl_test_fn {
                 assert!(!a.[<nearly_ $fn _eps>](&b, &0.1));
             }
 
+            #[test]
+            fn [<nearly_ $fn _rel_ $lhs _ $rhs>]() {
+                let mut a: lhs_type!($lhs) = lhs_value!($lhs);
+                let b: rhs_type!($rhs)  = rhs_value!($rhs);
+
+                get_element!(a, 0).[<expect_nearly_ $fn _rel>]()
+                    .with(eq(Rhs(3)), eq(0.1))
+                    .times(1)
+                    .return_const(true);
+                get_element!(a, 1).[<expect_nearly_ $fn _rel>]()
+                    .with(eq(Rhs(7)), eq(0.1))
+                    .times(1)
+                    .return_const(true);
+                get_element!(a, 2).[<expect_nearly_ $fn _rel>]()
+                    .with(eq(Rhs(11)), eq(0.1))
+                    .times(1)
+                    .return_const(true);
+
+                assert!(a.[<nearly_ $fn _rel>](&b, &0.1));
+
+                checkpoint!(a);
+                get_element!(a, 0).[<expect_nearly_ $fn _rel>]()
+                    .with(eq(Rhs(3)), eq(0.1))
+                    .times(1)
+                    .return_const(false);
+                get_element!(a, 1).[<expect_nearly_ $fn _rel>]()
+                    .with(eq(Rhs(7)), eq(0.1))
+                    .return_const(true);
+                get_element!(a, 2).[<expect_nearly_ $fn _rel>]()
+                    .with(eq(Rhs(11)), eq(0.1))
+                    .return_const(true);
+
+                assert!(!a.[<nearly_ $fn _rel>](&b, &0.1));
+
+                checkpoint!(a);
+                get_element!(a, 0).[<expect_nearly_ $fn _rel>]()
+                    .with(eq(Rhs(3)), eq(0.1))
+                    .return_const(true);
+                get_element!(a, 1).[<expect_nearly_ $fn _rel>]()
+                    .with(eq(Rhs(7)), eq(0.1))
+                    .times(1)
+                    .return_const(false);
+                get_element!(a, 2).[<expect_nearly_ $fn _rel>]()
+                    .with(eq(Rhs(11)), eq(0.1))
+                    .return_const(true);
+
+                assert!(!a.[<nearly_ $fn _rel>](&b, &0.1));
+
+                checkpoint!(a);
+                get_element!(a, 0).[<expect_nearly_ $fn _rel>]()
+                    .with(eq(Rhs(3)), eq(0.1))
+                    .return_const(true);
+                get_element!(a, 1).[<expect_nearly_ $fn _rel>]()
+                    .with(eq(Rhs(7)), eq(0.1))
+                    .return_const(true);
+                get_element!(a, 2).[<expect_nearly_ $fn _rel>]()
+                    .with(eq(Rhs(11)), eq(0.1))
+                    .times(1)
+                    .return_const(false);
+
+                assert!(!a.[<nearly_ $fn _rel>](&b, &0.1));
+            }
+
             #[test]
             fn [<nearly_ $fn _ulps_ $lhs _ $rhs>]() {
                 #[allow(unused_mut)]
@@ -319,6 +385,33 @@ macro_rules! impl_test_fn {
                 }
             }
 
+            #[test]
+            fn [<nearly_ $fn _rel_different_length_ $lhs _ $rhs>]() {
+                {
+                    let mut a: lhs_type!($lhs) = lhs_value!($lhs);
+                    let b: rhs_type!($rhs)  = rhs_value_short!($rhs);
+
+                    assert!(a.len() > b.len());
+
+                    get_element!(a, 0).[<expect_nearly_ $fn _rel>]().times(0);
+                    get_element!(a, 1).[<expect_nearly_ $fn _rel>]().times(0);
+                    get_element!(a, 2).[<expect_nearly_ $fn _rel>]().times(0);
+
+                    assert!(!a.[<nearly_ $fn _rel>](&b, &0.1));
+                }
+                {
+                    let mut a: lhs_type!($lhs) = lhs_value_short!($lhs);
+                    let b: rhs_type!($rhs)  = rhs_value!($rhs);
+
+                    assert!(a.len() < b.len());
+
+                    get_element!(a, 0).[<expect_nearly_ $fn _rel>]().times(0);
+                    get_element!(a, 1).[<expect_nearly_ $fn _rel>]().times(0);
+
+                    assert!(!a.[<nearly_ $fn _rel>](&b, &0.1));
+                }
+            }
+
             #[test]
             fn [<nearly_ $fn _ulps_different_length_ $lhs _ $rhs>]() {
                 {
@@ -378,3 +471,24 @@ macro_rules! impl_test_fn {
 
 impl_test!(btree_map, btree_map);
 impl_test!(hashmap, hashmap);
+impl_test!(btree_map, hashmap);
+impl_test!(hashmap, btree_map);
+
+#[test]
+fn nearly_hashmap_different_hashers() {
+    let a: HashMap<i32, f32> = HashMap::from([(0, 1.0), (1, 2.0), (2, 3.0)]);
+    let mut b: HashMap<i32, f32, BuildHasherDefault<DefaultHasher>> = HashMap::default();
+    b.extend([(0, 1.05), (1, 2.05), (2, 3.05)]);
+
+    assert!(a.nearly_eq_eps(&b, &0.1));
+    assert!(b.nearly_eq_eps(&a, &0.1));
+    assert!(!a.nearly_eq_eps(&b, &0.01));
+    assert!(a.nearly_lt_eps(&b, &0.01));
+    assert!(b.nearly_gt_eps(&a, &0.01));
+    assert!(a.nearly_eq_tol(&b, &Tolerance::new(0.1, 0)));
+
+    b.remove(&2);
+    b.insert(3, 3.0);
+    assert!(!a.nearly_eq_eps(&b, &0.1));
+    assert!(!b.nearly_eq_eps(&a, &0.1));
+}
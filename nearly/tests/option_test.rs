@@ -0,0 +1,115 @@
+use nearly::{
+    assert_nearly, nearly, EpsTolerance, NearlyEq, NearlyEqEps, NearlyEqRel, NearlyEqTol,
+    NearlyEqUlps, NearlyOrd, NearlyOrdEps, NearlyOrdRel, NearlyOrdTol, NearlyOrdUlps, Tolerance,
+};
+
+#[test]
+fn option_tolerance() {
+    assert_eq!(
+        <Option<f32> as EpsTolerance>::DEFAULT,
+        <f32 as EpsTolerance>::DEFAULT
+    );
+    assert_eq!(
+        <Option<f64> as EpsTolerance>::DEFAULT,
+        <f64 as EpsTolerance>::DEFAULT
+    );
+}
+
+#[test]
+fn option_eq() {
+    let a: Option<f32> = Some(1.0);
+    let b: Option<f32> = Some(1.05);
+    let none: Option<f32> = None;
+
+    assert!(a.nearly_eq_eps(&b, &0.1));
+    assert!(!a.nearly_eq_eps(&b, &0.01));
+    assert!(a.nearly_eq_ulps(&a, &0));
+    assert!(!a.nearly_eq_ulps(&b, &4));
+    assert!(a.nearly_eq_rel(&b, &0.1));
+    assert!(!a.nearly_eq_rel(&b, &0.01));
+    assert!(a.nearly_eq_tol(&b, &Tolerance::new(0.1, 0)));
+    assert!(a.nearly_eq(&Some(1.0)));
+    assert!(!a.nearly_eq(&b));
+
+    assert!(none.nearly_eq_eps(&none, &0.1));
+    assert!(none.nearly_eq(&None::<f32>));
+    assert!(!a.nearly_eq_eps(&none, &0.1));
+    assert!(!none.nearly_eq_eps(&a, &0.1));
+    assert!(!a.nearly_eq(&none));
+    assert!(!none.nearly_eq(&a));
+}
+
+#[test]
+fn option_eq_ref() {
+    let values = [1.0f64, 2.0];
+
+    assert_nearly!(values.first() == Some(1.0));
+    assert_nearly!(Some(2.0) == values.last());
+    assert_nearly!(values.first() == values.first());
+    assert_nearly!(values.get(2) == None::<f64>, eps = 0.1);
+    assert_nearly!(values.get(1) != Some(2.1), eps = 0.01);
+    assert!(nearly!(values.get(1) == Some(2.05), eps = 0.1));
+}
+
+#[test]
+fn option_ord() {
+    let a: Option<f64> = Some(1.0);
+    let b: Option<f64> = Some(1.5);
+    let none: Option<f64> = None;
+
+    assert!(a.nearly_lt_eps(&b, &0.1));
+    assert!(a.nearly_le_eps(&b, &0.1));
+    assert!(!a.nearly_gt_eps(&b, &0.1));
+    assert!(!a.nearly_ge_eps(&b, &0.1));
+    assert!(a.nearly_le_ulps(&a, &0));
+    assert!(b.nearly_gt_rel(&a, &0.1));
+    assert!(b.nearly_ge_tol(&a, &Tolerance::new(0.1, 4)));
+
+    assert!(none.nearly_lt_eps(&a, &0.1));
+    assert!(none.nearly_le_eps(&a, &0.1));
+    assert!(!none.nearly_gt_eps(&a, &0.1));
+    assert!(!none.nearly_ge_eps(&a, &0.1));
+
+    assert!(!a.nearly_lt_ulps(&none, &4));
+    assert!(!a.nearly_le_ulps(&none, &4));
+    assert!(a.nearly_gt_ulps(&none, &4));
+    assert!(a.nearly_ge_ulps(&none, &4));
+
+    assert!(!none.nearly_lt_rel(&none, &0.1));
+    assert!(none.nearly_le_rel(&none, &0.1));
+    assert!(!none.nearly_gt_tol(&none, &Tolerance::new(0.1, 4)));
+    assert!(none.nearly_ge_tol(&none, &Tolerance::new(0.1, 4)));
+
+    assert_nearly!(a < b, eps = 0.1);
+    assert_nearly!(a <= Some(1.05), eps = 0.1);
+    assert_nearly!(none < a);
+    assert_nearly!(a > none);
+}
+
+#[derive(Debug, NearlyEq, NearlyOrd)]
+struct Measurement {
+    value: f64,
+    uncertainty: Option<f64>,
+}
+
+#[test]
+fn option_derive() {
+    let a = Measurement {
+        value: 1.0,
+        uncertainty: Some(0.1),
+    };
+    let b = Measurement {
+        value: 1.0,
+        uncertainty: Some(0.15),
+    };
+    let c = Measurement {
+        value: 1.0,
+        uncertainty: None,
+    };
+
+    assert_nearly!(a == b, eps = (0.1, 0.1));
+    assert_nearly!(a != b, eps = (0.01, 0.01));
+    assert_nearly!(a != c, eps = (0.1, 0.1));
+    assert_nearly!(c == c);
+    assert_nearly!(c <= a, eps = (0.1, 0.1));
+}
@@ -0,0 +1,115 @@
+use nearly::{
+    assert_nearly, nearly, EpsTolerance, NearlyEq, NearlyEqEps, NearlyEqRel, NearlyEqUlps,
+    NearlyOrdEps, NearlyOrdTol, PerElement, RelTolerance, Tolerance, UlpsTolerance,
+};
+
+#[test]
+fn per_element_tolerance() {
+    assert_eq!(
+        <PerElement<(f32, f64)> as EpsTolerance>::DEFAULT,
+        (
+            <f32 as EpsTolerance>::DEFAULT,
+            <f64 as EpsTolerance>::DEFAULT
+        )
+    );
+    assert_eq!(
+        <PerElement<(f32, f64, f32)> as UlpsTolerance>::DEFAULT,
+        (
+            <f32 as UlpsTolerance>::DEFAULT,
+            <f64 as UlpsTolerance>::DEFAULT,
+            <f32 as UlpsTolerance>::DEFAULT
+        )
+    );
+    assert_eq!(
+        <PerElement<(f64,)> as RelTolerance>::DEFAULT,
+        (<f64 as RelTolerance>::DEFAULT,)
+    );
+}
+
+#[test]
+fn per_element_eq() {
+    let a = PerElement((1.0f32, 2.0f64));
+    let b = PerElement((1.05f32, 2.0f64));
+
+    assert!(a.nearly_eq_eps(&b, &(0.1, 0.1)));
+    assert!(!a.nearly_eq_eps(&b, &(0.01, 0.1)));
+    assert!(a.nearly_eq_ulps(&a, &(0, 0)));
+    assert!(!a.nearly_eq_ulps(&b, &(4, 4)));
+    assert!(a.nearly_eq_rel(&b, &(0.1, 0.0)));
+    assert!(!a.nearly_eq_rel(&b, &(0.01, 0.0)));
+    assert!(a.nearly_eq(&PerElement((1.0, 2.0))));
+    assert!(!a.nearly_eq(&b));
+
+    assert_nearly!(a == b, eps = (0.1, 0.1));
+    assert_nearly!(a != b, eps = (0.01, 0.1), ulps = (4, 4));
+    assert_nearly!(a == b, tol = Tolerance::new((0.1, 0.1), (0, 0)));
+}
+
+#[test]
+fn per_element_ord() {
+    let a = PerElement((1.0f32, 2.0f64));
+    let b = PerElement((1.5f32, 2.5f64));
+
+    assert!(a.nearly_lt_eps(&b, &(0.1, 0.1)));
+    assert!(a.nearly_le_eps(&b, &(0.1, 0.1)));
+    assert!(!a.nearly_gt_eps(&b, &(0.1, 0.1)));
+    assert!(b.nearly_ge_tol(&a, &Tolerance::new((0.1, 0.1), (4, 4))));
+
+    assert_nearly!(a < b, eps = (0.1, 0.1));
+    assert_nearly!(a <= PerElement((1.05, 2.0)), eps = (0.1, 0.1));
+}
+
+#[test]
+fn per_element_tol_rel() {
+    let a = PerElement((100.0f32, 2.0f64));
+    let b = PerElement((101.0f32, 2.0f64));
+
+    let tol = Tolerance::<PerElement<(f32, f64)>>::new((0.1, 0.1), (0, 0)).with_rel((0.1, 0.1));
+    assert_nearly!(a == b, tol = tol);
+    let tol = Tolerance::<PerElement<(f32, f64)>>::new((0.1, 0.1), (0, 0)).with_rel((0.001, 0.1));
+    assert_nearly!(a != b, tol = tol);
+}
+
+#[cfg(feature = "std")]
+#[test]
+fn per_element_collection_element() {
+    let a = PerElement((1.0f32, vec![2.0f64, 3.0]));
+    let b = PerElement((1.05f32, vec![2.0f64, 3.05]));
+
+    assert!(nearly!(a == b, eps = (0.1, 0.1)));
+    assert!(!nearly!(a == b, eps = (0.1, 0.01)));
+
+    let a = vec![PerElement((1.0f32, 2.0f64)), PerElement((3.0, 4.0))];
+    let b = vec![PerElement((1.05f32, 2.0f64)), PerElement((3.0, 4.05))];
+    assert!(nearly!(a == b, eps = (0.1, 0.1)));
+    assert!(!nearly!(a == b, eps = (0.01, 0.1)));
+}
+
+#[test]
+fn per_element_nested() {
+    let a = PerElement((PerElement((1.0f32, 2.0f64)), 3.0f32));
+    let b = PerElement((PerElement((1.05f32, 2.0f64)), 3.05f32));
+
+    assert!(nearly!(a == b, eps = ((0.1, 0.1), 0.1)));
+    assert!(!nearly!(a == b, eps = ((0.1, 0.1), 0.01)));
+}
+
+#[test]
+fn per_element_derive_field() {
+    #[derive(NearlyEq, Debug)]
+    struct Sample {
+        pos: PerElement<(f32, f64)>,
+        weight: f32,
+    }
+
+    let a = Sample {
+        pos: PerElement((1.0, 2.0)),
+        weight: 1.0,
+    };
+    let b = Sample {
+        pos: PerElement((1.05, 2.0)),
+        weight: 1.0,
+    };
+    assert_nearly!(a == b, eps = ((0.1, 0.1), 0.1));
+    assert_nearly!(a != b, eps = ((0.01, 0.1), 0.1));
+}
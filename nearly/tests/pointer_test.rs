@@ -2,7 +2,8 @@
 
 use mockall::predicate::eq;
 use nearly::{
-    NearlyEqEps, NearlyEqTol, NearlyEqUlps, NearlyOrdEps, NearlyOrdTol, NearlyOrdUlps, Tolerance,
+    NearlyEqEps, NearlyEqRel, NearlyEqTol, NearlyEqUlps, NearlyOrdEps, NearlyOrdRel, NearlyOrdTol,
+    NearlyOrdUlps, Tolerance,
 };
 use paste::paste;
 use std::boxed::Box;
@@ -97,6 +98,32 @@ macro_rules! impl_test_fn {
                 }
             }
 
+            #[test]
+            fn [<nearly_ $fn _rel_ $ptr>]() {
+                let b: rhs_type!($ptr) = rhs_value!($ptr);
+
+                {
+                    let mut a_val = MockLhs::new();
+                    a_val.[<expect_nearly_ $fn _rel>]()
+                        .with(eq(Rhs(5)), eq(0.1))
+                        .times(1)
+                        .return_const(true);
+                    let a: lhs_type!($ptr) = lhs_value!(a_val, $ptr);
+
+                    assert!(a.[<nearly_ $fn _rel>](&b, &0.1));
+                }
+                {
+                    let mut a_val = MockLhs::new();
+                    a_val.[<expect_nearly_ $fn _rel>]()
+                        .with(eq(Rhs(5)), eq(0.1))
+                        .times(1)
+                        .return_const(false);
+                    let a: lhs_type!($ptr) = lhs_value!(a_val, $ptr);
+
+                    assert!(!a.[<nearly_ $fn _rel>](&b, &0.1));
+                }
+            }
+
             #[test]
             fn [<nearly_ $fn _ulps_ $ptr>]() {
                 let b: rhs_type!($ptr) = rhs_value!($ptr);
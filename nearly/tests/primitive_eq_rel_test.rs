@@ -0,0 +1,413 @@
+use nearly::NearlyEqRel;
+
+///////////////////
+// nearly_eq_rel //
+///////////////////
+
+#[test]
+fn nearly_eq_rel_zero_f32() {
+    let a: f32 = 0.0;
+    let b: f32 = -0.0;
+    assert_eq!(a, b);
+    assert!(a.nearly_eq_rel(&b, &0.0));
+    assert!(b.nearly_eq_rel(&a, &0.0));
+}
+
+#[test]
+fn nearly_eq_rel_zero_f64() {
+    let a: f64 = 0.0;
+    let b: f64 = -0.0;
+    assert_eq!(a, b);
+    assert!(a.nearly_eq_rel(&b, &0.0));
+    assert!(b.nearly_eq_rel(&a, &0.0));
+}
+
+#[test]
+fn nearly_eq_rel_same_f32() {
+    let a: f32 = 1.0;
+    let b: f32 = 1.0;
+    assert_eq!(a, b);
+    assert!(a.nearly_eq_rel(&b, &0.0));
+    assert!(b.nearly_eq_rel(&a, &0.0));
+}
+
+#[test]
+fn nearly_eq_rel_same_f64() {
+    let a: f64 = 1.0;
+    let b: f64 = 1.0;
+    assert_eq!(a, b);
+    assert!(a.nearly_eq_rel(&b, &0.0));
+    assert!(b.nearly_eq_rel(&a, &0.0));
+}
+
+#[test]
+fn nearly_eq_rel_different_f32() {
+    let a: f32 = 1.0;
+    let b: f32 = 1.0000008;
+    assert_ne!(a, b);
+
+    assert!(!a.nearly_eq_rel(&b, &0.0000007));
+    assert!(!b.nearly_eq_rel(&a, &0.0000007));
+
+    assert!(a.nearly_eq_rel(&b, &0.0000009));
+    assert!(b.nearly_eq_rel(&a, &0.0000009));
+
+    assert!(a.nearly_eq_rel(&b, &0.0000011));
+    assert!(b.nearly_eq_rel(&a, &0.0000011));
+}
+
+#[test]
+fn nearly_eq_rel_different_f64() {
+    let a: f64 = 1.0;
+    let b: f64 = 1.0000000000003;
+    assert_ne!(a, b);
+
+    assert!(!a.nearly_eq_rel(&b, &0.0000000000002));
+    assert!(!b.nearly_eq_rel(&a, &0.0000000000002));
+
+    assert!(a.nearly_eq_rel(&b, &0.0000000000003));
+    assert!(b.nearly_eq_rel(&a, &0.0000000000003));
+
+    assert!(a.nearly_eq_rel(&b, &0.0000000000004));
+    assert!(b.nearly_eq_rel(&a, &0.0000000000004));
+}
+
+#[test]
+fn nearly_eq_rel_different_sign_f32() {
+    let a: f32 = 0.01;
+    let b: f32 = -0.01;
+
+    assert!(!a.nearly_eq_rel(&b, &1.9));
+    assert!(!b.nearly_eq_rel(&a, &1.9));
+
+    assert!(a.nearly_eq_rel(&b, &2.0));
+    assert!(b.nearly_eq_rel(&a, &2.0));
+}
+
+#[test]
+fn nearly_eq_rel_different_sign_f64() {
+    let a: f64 = 0.01;
+    let b: f64 = -0.01;
+
+    assert!(!a.nearly_eq_rel(&b, &1.9));
+    assert!(!b.nearly_eq_rel(&a, &1.9));
+
+    assert!(a.nearly_eq_rel(&b, &2.0));
+    assert!(b.nearly_eq_rel(&a, &2.0));
+}
+
+#[test]
+fn nearly_eq_rel_sum_f32() {
+    let mut a: f32 = 0.0;
+    for _i in 0..10 {
+        a += 0.1;
+    }
+    let b: f32 = 1.0;
+
+    assert_ne!(a, b);
+    assert!(a.nearly_eq_rel(&b, &0.000001));
+    assert!(b.nearly_eq_rel(&a, &0.000001));
+}
+
+#[test]
+fn nearly_eq_rel_sum_f64() {
+    let mut a: f64 = 0.0;
+    for _i in 0..10 {
+        a += 0.1;
+    }
+    let b: f64 = 1.0;
+
+    assert_ne!(a, b);
+    assert!(a.nearly_eq_rel(&b, &0.000000000000001));
+    assert!(b.nearly_eq_rel(&a, &0.000000000000001));
+}
+
+#[test]
+fn nearly_eq_rel_magnitude_f32() {
+    let a: f32 = 1000000000.0;
+    let b: f32 = 1000100000.0;
+
+    assert!(!a.nearly_eq_rel(&b, &0.00009));
+    assert!(!b.nearly_eq_rel(&a, &0.00009));
+
+    assert!(a.nearly_eq_rel(&b, &0.00011));
+    assert!(b.nearly_eq_rel(&a, &0.00011));
+
+    let a: f32 = 0.000000001;
+    let b: f32 = 0.0000000010001;
+
+    assert!(!a.nearly_eq_rel(&b, &0.00009));
+    assert!(!b.nearly_eq_rel(&a, &0.00009));
+
+    assert!(a.nearly_eq_rel(&b, &0.00011));
+    assert!(b.nearly_eq_rel(&a, &0.00011));
+}
+
+#[test]
+fn nearly_eq_rel_magnitude_f64() {
+    let a: f64 = 1000000000.0;
+    let b: f64 = 1000100000.0;
+
+    assert!(!a.nearly_eq_rel(&b, &0.00009));
+    assert!(!b.nearly_eq_rel(&a, &0.00009));
+
+    assert!(a.nearly_eq_rel(&b, &0.00011));
+    assert!(b.nearly_eq_rel(&a, &0.00011));
+
+    let a: f64 = 0.000000001;
+    let b: f64 = 0.0000000010001;
+
+    assert!(!a.nearly_eq_rel(&b, &0.00009));
+    assert!(!b.nearly_eq_rel(&a, &0.00009));
+
+    assert!(a.nearly_eq_rel(&b, &0.00011));
+    assert!(b.nearly_eq_rel(&a, &0.00011));
+}
+
+#[test]
+fn nearly_eq_rel_inf_f32() {
+    let a: f32 = 1.0;
+    let b: f32 = f32::INFINITY;
+    assert!(!a.nearly_eq_rel(&b, &f32::MAX));
+    assert!(!b.nearly_eq_rel(&a, &f32::MAX));
+
+    assert!(f32::INFINITY.nearly_eq_rel(&f32::INFINITY, &0.0));
+    assert!((-f32::INFINITY).nearly_eq_rel(&-f32::INFINITY, &0.0));
+    assert!(!f32::INFINITY.nearly_eq_rel(&-f32::INFINITY, &f32::MAX));
+    assert!(!(-f32::INFINITY).nearly_eq_rel(&f32::INFINITY, &f32::MAX));
+}
+
+#[test]
+fn nearly_eq_rel_inf_f64() {
+    let a: f64 = 1.0;
+    let b: f64 = f64::INFINITY;
+    assert!(!a.nearly_eq_rel(&b, &f64::MAX));
+    assert!(!b.nearly_eq_rel(&a, &f64::MAX));
+
+    assert!(f64::INFINITY.nearly_eq_rel(&f64::INFINITY, &0.0));
+    assert!((-f64::INFINITY).nearly_eq_rel(&-f64::INFINITY, &0.0));
+    assert!(!f64::INFINITY.nearly_eq_rel(&-f64::INFINITY, &f64::MAX));
+    assert!(!(-f64::INFINITY).nearly_eq_rel(&f64::INFINITY, &f64::MAX));
+}
+
+#[test]
+fn nearly_eq_rel_nan_f32() {
+    assert!(!f32::NAN.nearly_eq_rel(&f32::NAN, &f32::MAX));
+    assert!(!f32::NAN.nearly_eq_rel(&-f32::NAN, &f32::MAX));
+    assert!(!(-f32::NAN).nearly_eq_rel(&f32::NAN, &f32::MAX));
+    assert!(!(-f32::NAN).nearly_eq_rel(&-f32::NAN, &f32::MAX));
+}
+
+#[test]
+fn nearly_eq_rel_nan_f64() {
+    assert!(!f64::NAN.nearly_eq_rel(&f64::NAN, &f64::MAX));
+    assert!(!f64::NAN.nearly_eq_rel(&-f64::NAN, &f64::MAX));
+    assert!(!(-f64::NAN).nearly_eq_rel(&f64::NAN, &f64::MAX));
+    assert!(!(-f64::NAN).nearly_eq_rel(&-f64::NAN, &f64::MAX));
+}
+
+///////////////////
+// nearly_ne_rel //
+///////////////////
+
+#[test]
+fn nearly_ne_rel_zero_f32() {
+    let a: f32 = 0.0;
+    let b: f32 = -0.0;
+    assert_eq!(a, b);
+    assert!(!a.nearly_ne_rel(&b, &0.0));
+    assert!(!b.nearly_ne_rel(&a, &0.0));
+}
+
+#[test]
+fn nearly_ne_rel_zero_f64() {
+    let a: f64 = 0.0;
+    let b: f64 = -0.0;
+    assert_eq!(a, b);
+    assert!(!a.nearly_ne_rel(&b, &0.0));
+    assert!(!b.nearly_ne_rel(&a, &0.0));
+}
+
+#[test]
+fn nearly_ne_rel_same_f32() {
+    let a: f32 = 1.0;
+    let b: f32 = 1.0;
+    assert_eq!(a, b);
+    assert!(!a.nearly_ne_rel(&b, &0.0));
+    assert!(!b.nearly_ne_rel(&a, &0.0));
+}
+
+#[test]
+fn nearly_ne_rel_same_f64() {
+    let a: f64 = 1.0;
+    let b: f64 = 1.0;
+    assert_eq!(a, b);
+    assert!(!a.nearly_ne_rel(&b, &0.0));
+    assert!(!b.nearly_ne_rel(&a, &0.0));
+}
+
+#[test]
+fn nearly_ne_rel_different_f32() {
+    let a: f32 = 1.0;
+    let b: f32 = 1.0000008;
+    assert_ne!(a, b);
+
+    assert!(a.nearly_ne_rel(&b, &0.0000007));
+    assert!(b.nearly_ne_rel(&a, &0.0000007));
+
+    assert!(!a.nearly_ne_rel(&b, &0.0000009));
+    assert!(!b.nearly_ne_rel(&a, &0.0000009));
+
+    assert!(!a.nearly_ne_rel(&b, &0.0000011));
+    assert!(!b.nearly_ne_rel(&a, &0.0000011));
+}
+
+#[test]
+fn nearly_ne_rel_different_f64() {
+    let a: f64 = 1.0;
+    let b: f64 = 1.0000000000003;
+    assert_ne!(a, b);
+
+    assert!(a.nearly_ne_rel(&b, &0.0000000000002));
+    assert!(b.nearly_ne_rel(&a, &0.0000000000002));
+
+    assert!(!a.nearly_ne_rel(&b, &0.0000000000003));
+    assert!(!b.nearly_ne_rel(&a, &0.0000000000003));
+
+    assert!(!a.nearly_ne_rel(&b, &0.0000000000004));
+    assert!(!b.nearly_ne_rel(&a, &0.0000000000004));
+}
+
+#[test]
+fn nearly_ne_rel_different_sign_f32() {
+    let a: f32 = 0.01;
+    let b: f32 = -0.01;
+
+    assert!(a.nearly_ne_rel(&b, &1.9));
+    assert!(b.nearly_ne_rel(&a, &1.9));
+
+    assert!(!a.nearly_ne_rel(&b, &2.0));
+    assert!(!b.nearly_ne_rel(&a, &2.0));
+}
+
+#[test]
+fn nearly_ne_rel_different_sign_f64() {
+    let a: f64 = 0.01;
+    let b: f64 = -0.01;
+
+    assert!(a.nearly_ne_rel(&b, &1.9));
+    assert!(b.nearly_ne_rel(&a, &1.9));
+
+    assert!(!a.nearly_ne_rel(&b, &2.0));
+    assert!(!b.nearly_ne_rel(&a, &2.0));
+}
+
+#[test]
+fn nearly_ne_rel_sum_f32() {
+    let mut a: f32 = 0.0;
+    for _i in 0..10 {
+        a += 0.1;
+    }
+    let b: f32 = 1.0;
+
+    assert_ne!(a, b);
+    assert!(!a.nearly_ne_rel(&b, &0.000001));
+    assert!(!b.nearly_ne_rel(&a, &0.000001));
+}
+
+#[test]
+fn nearly_ne_rel_sum_f64() {
+    let mut a: f64 = 0.0;
+    for _i in 0..10 {
+        a += 0.1;
+    }
+    let b: f64 = 1.0;
+
+    assert_ne!(a, b);
+    assert!(!a.nearly_ne_rel(&b, &0.000000000000001));
+    assert!(!b.nearly_ne_rel(&a, &0.000000000000001));
+}
+
+#[test]
+fn nearly_ne_rel_magnitude_f32() {
+    let a: f32 = 1000000000.0;
+    let b: f32 = 1000100000.0;
+
+    assert!(a.nearly_ne_rel(&b, &0.00009));
+    assert!(b.nearly_ne_rel(&a, &0.00009));
+
+    assert!(!a.nearly_ne_rel(&b, &0.00011));
+    assert!(!b.nearly_ne_rel(&a, &0.00011));
+
+    let a: f32 = 0.000000001;
+    let b: f32 = 0.0000000010001;
+
+    assert!(a.nearly_ne_rel(&b, &0.00009));
+    assert!(b.nearly_ne_rel(&a, &0.00009));
+
+    assert!(!a.nearly_ne_rel(&b, &0.00011));
+    assert!(!b.nearly_ne_rel(&a, &0.00011));
+}
+
+#[test]
+fn nearly_ne_rel_magnitude_f64() {
+    let a: f64 = 1000000000.0;
+    let b: f64 = 1000100000.0;
+
+    assert!(a.nearly_ne_rel(&b, &0.00009));
+    assert!(b.nearly_ne_rel(&a, &0.00009));
+
+    assert!(!a.nearly_ne_rel(&b, &0.00011));
+    assert!(!b.nearly_ne_rel(&a, &0.00011));
+
+    let a: f64 = 0.000000001;
+    let b: f64 = 0.0000000010001;
+
+    assert!(a.nearly_ne_rel(&b, &0.00009));
+    assert!(b.nearly_ne_rel(&a, &0.00009));
+
+    assert!(!a.nearly_ne_rel(&b, &0.00011));
+    assert!(!b.nearly_ne_rel(&a, &0.00011));
+}
+
+#[test]
+fn nearly_ne_rel_inf_f32() {
+    let a: f32 = 1.0;
+    let b: f32 = f32::INFINITY;
+    assert!(a.nearly_ne_rel(&b, &f32::MAX));
+    assert!(b.nearly_ne_rel(&a, &f32::MAX));
+
+    assert!(!f32::INFINITY.nearly_ne_rel(&f32::INFINITY, &0.0));
+    assert!(!(-f32::INFINITY).nearly_ne_rel(&-f32::INFINITY, &0.0));
+    assert!(f32::INFINITY.nearly_ne_rel(&-f32::INFINITY, &f32::MAX));
+    assert!((-f32::INFINITY).nearly_ne_rel(&f32::INFINITY, &f32::MAX));
+}
+
+#[test]
+fn nearly_ne_rel_inf_f64() {
+    let a: f64 = 1.0;
+    let b: f64 = f64::INFINITY;
+    assert!(a.nearly_ne_rel(&b, &f64::MAX));
+    assert!(b.nearly_ne_rel(&a, &f64::MAX));
+
+    assert!(!f64::INFINITY.nearly_ne_rel(&f64::INFINITY, &0.0));
+    assert!(!(-f64::INFINITY).nearly_ne_rel(&-f64::INFINITY, &0.0));
+    assert!(f64::INFINITY.nearly_ne_rel(&-f64::INFINITY, &f64::MAX));
+    assert!((-f64::INFINITY).nearly_ne_rel(&f64::INFINITY, &f64::MAX));
+}
+
+#[test]
+fn nearly_ne_rel_nan_f32() {
+    assert!(f32::NAN.nearly_ne_rel(&f32::NAN, &f32::MAX));
+    assert!(f32::NAN.nearly_ne_rel(&-f32::NAN, &f32::MAX));
+    assert!((-f32::NAN).nearly_ne_rel(&f32::NAN, &f32::MAX));
+    assert!((-f32::NAN).nearly_ne_rel(&-f32::NAN, &f32::MAX));
+}
+
+#[test]
+fn nearly_ne_rel_nan_f64() {
+    assert!(f64::NAN.nearly_ne_rel(&f64::NAN, &f64::MAX));
+    assert!(f64::NAN.nearly_ne_rel(&-f64::NAN, &f64::MAX));
+    assert!((-f64::NAN).nearly_ne_rel(&f64::NAN, &f64::MAX));
+    assert!((-f64::NAN).nearly_ne_rel(&-f64::NAN, &f64::MAX));
+}
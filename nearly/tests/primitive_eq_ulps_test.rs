@@ -1,4 +1,4 @@
-use nearly::NearlyEqUlps;
+use nearly::{NearlyEqUlps, NearlyEqUlpsAcrossZero, Ulps};
 
 ////////////////////
 // nearly_eq_ulps //
@@ -114,6 +114,28 @@ fn nearly_eq_ulps_sum_f64() {
     assert!(b.nearly_eq_ulps(&a, &1));
 }
 
+#[test]
+fn nearly_eq_ulps_boundary_f32() {
+    let a: f32 = 1.0;
+    for ulps in 0..10 {
+        assert!(a.nearly_eq_ulps(&a.step_ulps(ulps), &ulps));
+        assert!(a.nearly_eq_ulps(&a.step_ulps(-ulps), &ulps));
+        assert!(!a.nearly_eq_ulps(&a.step_ulps(ulps + 1), &ulps));
+        assert!(!a.nearly_eq_ulps(&a.step_ulps(-ulps - 1), &ulps));
+    }
+}
+
+#[test]
+fn nearly_eq_ulps_boundary_f64() {
+    let a: f64 = 1.0;
+    for ulps in 0..10 {
+        assert!(a.nearly_eq_ulps(&a.step_ulps(ulps), &ulps));
+        assert!(a.nearly_eq_ulps(&a.step_ulps(-ulps), &ulps));
+        assert!(!a.nearly_eq_ulps(&a.step_ulps(ulps + 1), &ulps));
+        assert!(!a.nearly_eq_ulps(&a.step_ulps(-ulps - 1), &ulps));
+    }
+}
+
 #[test]
 fn nearly_eq_ulps_inf_f32() {
     let a: f32 = 1.0;
@@ -311,3 +333,125 @@ fn nearly_ne_ulps_nan_f64() {
     assert!((-f64::NAN).nearly_ne_ulps(&f64::NAN, &i64::MAX));
     assert!((-f64::NAN).nearly_ne_ulps(&-f64::NAN, &i64::MAX));
 }
+
+////////////////////////////////
+// nearly_eq_ulps_across_zero //
+////////////////////////////////
+
+#[test]
+fn nearly_eq_ulps_across_zero_zero_f32() {
+    let a: f32 = 0.0;
+    let b: f32 = -0.0;
+    assert!(a.nearly_eq_ulps_across_zero(&b, &0));
+    assert!(b.nearly_eq_ulps_across_zero(&a, &0));
+    assert!(!a.nearly_ne_ulps_across_zero(&b, &0));
+    assert!(!b.nearly_ne_ulps_across_zero(&a, &0));
+}
+
+#[test]
+fn nearly_eq_ulps_across_zero_different_f32() {
+    let a: f32 = 1.0;
+    let b: f32 = 1.0000008;
+
+    assert!(!a.nearly_eq_ulps_across_zero(&b, &6));
+    assert!(!b.nearly_eq_ulps_across_zero(&a, &6));
+    assert!(a.nearly_ne_ulps_across_zero(&b, &6));
+
+    assert!(a.nearly_eq_ulps_across_zero(&b, &7));
+    assert!(b.nearly_eq_ulps_across_zero(&a, &7));
+    assert!(!a.nearly_ne_ulps_across_zero(&b, &7));
+}
+
+#[test]
+fn nearly_eq_ulps_across_zero_different_sign_f32() {
+    let a: f32 = 1e-45;
+    let b: f32 = -1e-45;
+
+    assert!(!a.nearly_eq_ulps_across_zero(&b, &1));
+    assert!(!b.nearly_eq_ulps_across_zero(&a, &1));
+    assert!(a.nearly_ne_ulps_across_zero(&b, &1));
+
+    assert!(a.nearly_eq_ulps_across_zero(&b, &2));
+    assert!(b.nearly_eq_ulps_across_zero(&a, &2));
+    assert!(!a.nearly_ne_ulps_across_zero(&b, &2));
+
+    assert!(a.nearly_eq_ulps_across_zero(&0.0, &1));
+    assert!(b.nearly_eq_ulps_across_zero(&0.0, &1));
+    assert!(a.nearly_eq_ulps_across_zero(&-0.0, &1));
+    assert!(b.nearly_eq_ulps_across_zero(&-0.0, &1));
+}
+
+#[test]
+fn nearly_eq_ulps_across_zero_inf_f32() {
+    assert!(f32::INFINITY.nearly_eq_ulps_across_zero(&f32::INFINITY, &0));
+    assert!((-f32::INFINITY).nearly_eq_ulps_across_zero(&-f32::INFINITY, &0));
+    assert!(!f32::INFINITY.nearly_eq_ulps_across_zero(&-f32::INFINITY, &i32::MAX));
+    assert!(!(-f32::INFINITY).nearly_eq_ulps_across_zero(&f32::INFINITY, &i32::MAX));
+    assert!(!f32::MAX.nearly_eq_ulps_across_zero(&f32::MIN, &i32::MAX));
+}
+
+#[test]
+fn nearly_eq_ulps_across_zero_nan_f32() {
+    assert!(!f32::NAN.nearly_eq_ulps_across_zero(&f32::NAN, &i32::MAX));
+    assert!(!f32::NAN.nearly_eq_ulps_across_zero(&1.0, &i32::MAX));
+    assert!(!1.0_f32.nearly_eq_ulps_across_zero(&f32::NAN, &i32::MAX));
+}
+
+#[test]
+fn nearly_eq_ulps_across_zero_zero_f64() {
+    let a: f64 = 0.0;
+    let b: f64 = -0.0;
+    assert!(a.nearly_eq_ulps_across_zero(&b, &0));
+    assert!(b.nearly_eq_ulps_across_zero(&a, &0));
+    assert!(!a.nearly_ne_ulps_across_zero(&b, &0));
+    assert!(!b.nearly_ne_ulps_across_zero(&a, &0));
+}
+
+#[test]
+fn nearly_eq_ulps_across_zero_different_f64() {
+    let a: f64 = 1.0;
+    let b: f64 = 1.0000000000000016;
+
+    assert!(!a.nearly_eq_ulps_across_zero(&b, &6));
+    assert!(!b.nearly_eq_ulps_across_zero(&a, &6));
+    assert!(a.nearly_ne_ulps_across_zero(&b, &6));
+
+    assert!(a.nearly_eq_ulps_across_zero(&b, &7));
+    assert!(b.nearly_eq_ulps_across_zero(&a, &7));
+    assert!(!a.nearly_ne_ulps_across_zero(&b, &7));
+}
+
+#[test]
+fn nearly_eq_ulps_across_zero_different_sign_f64() {
+    let a: f64 = 5e-324;
+    let b: f64 = -5e-324;
+
+    assert!(!a.nearly_eq_ulps_across_zero(&b, &1));
+    assert!(!b.nearly_eq_ulps_across_zero(&a, &1));
+    assert!(a.nearly_ne_ulps_across_zero(&b, &1));
+
+    assert!(a.nearly_eq_ulps_across_zero(&b, &2));
+    assert!(b.nearly_eq_ulps_across_zero(&a, &2));
+    assert!(!a.nearly_ne_ulps_across_zero(&b, &2));
+
+    assert!(a.nearly_eq_ulps_across_zero(&0.0, &1));
+    assert!(b.nearly_eq_ulps_across_zero(&0.0, &1));
+    assert!(a.nearly_eq_ulps_across_zero(&-0.0, &1));
+    assert!(b.nearly_eq_ulps_across_zero(&-0.0, &1));
+}
+
+#[test]
+fn nearly_eq_ulps_across_zero_inf_f64() {
+    assert!(f64::INFINITY.nearly_eq_ulps_across_zero(&f64::INFINITY, &0));
+    assert!((-f64::INFINITY).nearly_eq_ulps_across_zero(&-f64::INFINITY, &0));
+    assert!(!f64::INFINITY.nearly_eq_ulps_across_zero(&-f64::INFINITY, &i64::MAX));
+    assert!(!(-f64::INFINITY).nearly_eq_ulps_across_zero(&f64::INFINITY, &i64::MAX));
+    assert!(!f64::MAX.nearly_eq_ulps_across_zero(&f64::MIN, &i64::MAX));
+}
+
+#[test]
+fn nearly_eq_ulps_across_zero_nan_f64() {
+    assert!(!f64::NAN.nearly_eq_ulps_across_zero(&f64::NAN, &i64::MAX));
+    assert!(!f64::NAN.nearly_eq_ulps_across_zero(&1.0, &i64::MAX));
+    assert!(!1.0_f64.nearly_eq_ulps_across_zero(&f64::NAN, &i64::MAX));
+}
@@ -1,4 +1,5 @@
 use nearly::NearlyOrdEps;
+use std::cmp::Ordering;
 
 ///////////////////
 // nearly_lt_eps //
@@ -631,3 +632,35 @@ fn nearly_ge_eps_nan_f64() {
     assert!(!(-f64::NAN).nearly_ge_eps(&f64::NAN, &0.0));
     assert!(!(-f64::NAN).nearly_ge_eps(&-f64::NAN, &0.0));
 }
+
+////////////////////
+// nearly_cmp_eps //
+////////////////////
+
+#[test]
+fn nearly_cmp_eps_f32() {
+    let a: f32 = 1.0;
+    let b: f32 = 1.05;
+
+    assert_eq!(a.nearly_cmp_eps(&a, &0.01), Some(Ordering::Equal));
+    assert_eq!(a.nearly_cmp_eps(&b, &0.1), Some(Ordering::Equal));
+    assert_eq!(b.nearly_cmp_eps(&a, &0.1), Some(Ordering::Equal));
+    assert_eq!(a.nearly_cmp_eps(&b, &0.01), Some(Ordering::Less));
+    assert_eq!(b.nearly_cmp_eps(&a, &0.01), Some(Ordering::Greater));
+    assert_eq!(a.nearly_cmp_eps(&f32::NAN, &0.1), None);
+    assert_eq!(f32::NAN.nearly_cmp_eps(&a, &0.1), None);
+}
+
+#[test]
+fn nearly_cmp_eps_f64() {
+    let a: f64 = 1.0;
+    let b: f64 = 1.05;
+
+    assert_eq!(a.nearly_cmp_eps(&a, &0.01), Some(Ordering::Equal));
+    assert_eq!(a.nearly_cmp_eps(&b, &0.1), Some(Ordering::Equal));
+    assert_eq!(b.nearly_cmp_eps(&a, &0.1), Some(Ordering::Equal));
+    assert_eq!(a.nearly_cmp_eps(&b, &0.01), Some(Ordering::Less));
+    assert_eq!(b.nearly_cmp_eps(&a, &0.01), Some(Ordering::Greater));
+    assert_eq!(a.nearly_cmp_eps(&f64::NAN, &0.1), None);
+    assert_eq!(f64::NAN.nearly_cmp_eps(&a, &0.1), None);
+}
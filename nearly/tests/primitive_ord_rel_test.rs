@@ -0,0 +1,666 @@
+use nearly::NearlyOrdRel;
+use std::cmp::Ordering;
+
+///////////////////
+// nearly_lt_rel //
+///////////////////
+
+#[test]
+fn nearly_lt_rel_zero_f32() {
+    let a: f32 = 0.0;
+    let b: f32 = -0.0;
+    assert!(!a.nearly_lt_rel(&b, &0.0));
+    assert!(!b.nearly_lt_rel(&b, &0.0));
+}
+
+#[test]
+fn nearly_lt_rel_zero_f64() {
+    let a: f64 = 0.0;
+    let b: f64 = -0.0;
+    assert!(!a.nearly_lt_rel(&b, &0.0));
+    assert!(!b.nearly_lt_rel(&b, &0.0));
+}
+
+#[test]
+fn nearly_lt_rel_same_f32() {
+    let a: f32 = 1.0;
+    let b: f32 = 1.0;
+    assert!(!a.nearly_lt_rel(&b, &0.0));
+    assert!(!b.nearly_lt_rel(&a, &0.0));
+}
+
+#[test]
+fn nearly_lt_rel_same_f64() {
+    let a: f64 = 1.0;
+    let b: f64 = 1.0;
+    assert!(!a.nearly_lt_rel(&b, &0.0));
+    assert!(!b.nearly_lt_rel(&a, &0.0));
+}
+
+#[test]
+fn nearly_lt_rel_different_f32() {
+    let a: f32 = 1.0;
+    let b: f32 = 1.0000008;
+
+    assert!(a.nearly_lt_rel(&b, &0.0000007));
+    assert!(!b.nearly_lt_rel(&a, &0.0000007));
+
+    assert!(a.nearly_lt_rel(&b, &0.0000008));
+    assert!(!b.nearly_lt_rel(&a, &0.0000008));
+
+    assert!(!a.nearly_lt_rel(&b, &0.0000009));
+    assert!(!b.nearly_lt_rel(&a, &0.0000009));
+}
+
+#[test]
+fn nearly_lt_rel_different_f64() {
+    let a: f64 = 1.0;
+    let b: f64 = 1.0000000000003;
+
+    assert!(a.nearly_lt_rel(&b, &0.0000000000002));
+    assert!(!b.nearly_lt_rel(&a, &0.0000000000002));
+
+    assert!(!a.nearly_lt_rel(&b, &0.0000000000003));
+    assert!(!b.nearly_lt_rel(&a, &0.0000000000003));
+
+    assert!(!a.nearly_lt_rel(&b, &0.0000000000004));
+    assert!(!b.nearly_lt_rel(&a, &0.0000000000004));
+}
+
+#[test]
+fn nearly_lt_rel_different_sign_f32() {
+    let a: f32 = -0.01;
+    let b: f32 = 0.01;
+
+    assert!(a.nearly_lt_rel(&b, &1.9));
+    assert!(!b.nearly_lt_rel(&a, &1.9));
+
+    assert!(!a.nearly_lt_rel(&b, &2.0));
+    assert!(!b.nearly_lt_rel(&a, &2.0));
+}
+
+#[test]
+fn nearly_lt_rel_different_sign_f64() {
+    let a: f64 = -0.01;
+    let b: f64 = 0.01;
+
+    assert!(a.nearly_lt_rel(&b, &1.9));
+    assert!(!b.nearly_lt_rel(&a, &1.9));
+
+    assert!(!a.nearly_lt_rel(&b, &2.0));
+    assert!(!b.nearly_lt_rel(&a, &2.0));
+}
+
+#[test]
+fn nearly_lt_rel_inf_f32() {
+    {
+        let a: f32 = 1.0;
+        let b: f32 = f32::INFINITY;
+        assert!(a.nearly_lt_rel(&b, &f32::MAX));
+        assert!(!b.nearly_lt_rel(&a, &f32::MAX));
+    }
+    {
+        let a: f32 = -f32::INFINITY;
+        let b: f32 = 1.0;
+        assert!(a.nearly_lt_rel(&b, &f32::MAX));
+        assert!(!b.nearly_lt_rel(&a, &f32::MAX));
+    }
+
+    assert!(!f32::INFINITY.nearly_lt_rel(&f32::INFINITY, &0.0));
+    assert!(!(-f32::INFINITY).nearly_lt_rel(&-f32::INFINITY, &0.0));
+    assert!(!f32::INFINITY.nearly_lt_rel(&-f32::INFINITY, &f32::MAX));
+    assert!((-f32::INFINITY).nearly_lt_rel(&f32::INFINITY, &f32::MAX));
+}
+
+#[test]
+fn nearly_lt_rel_inf_f64() {
+    {
+        let a: f64 = 1.0;
+        let b: f64 = f64::INFINITY;
+        assert!(a.nearly_lt_rel(&b, &f64::MAX));
+        assert!(!b.nearly_lt_rel(&a, &f64::MAX));
+    }
+    {
+        let a: f64 = -f64::INFINITY;
+        let b: f64 = 1.0;
+        assert!(a.nearly_lt_rel(&b, &f64::MAX));
+        assert!(!b.nearly_lt_rel(&a, &f64::MAX));
+    }
+
+    assert!(!f64::INFINITY.nearly_lt_rel(&f64::INFINITY, &0.0));
+    assert!(!(-f64::INFINITY).nearly_lt_rel(&-f64::INFINITY, &0.0));
+    assert!(!f64::INFINITY.nearly_lt_rel(&-f64::INFINITY, &f64::MAX));
+    assert!((-f64::INFINITY).nearly_lt_rel(&f64::INFINITY, &f64::MAX));
+}
+
+#[test]
+fn nearly_lt_rel_nan_f32() {
+    let a: f32 = f32::NAN;
+    let b: f32 = 1.0;
+    assert!(!a.nearly_lt_rel(&b, &0.0));
+    assert!(!b.nearly_lt_rel(&a, &0.0));
+
+    assert!(!f32::NAN.nearly_lt_rel(&f32::NAN, &0.0));
+    assert!(!f32::NAN.nearly_lt_rel(&-f32::NAN, &0.0));
+    assert!(!(-f32::NAN).nearly_lt_rel(&f32::NAN, &0.0));
+    assert!(!(-f32::NAN).nearly_lt_rel(&-f32::NAN, &0.0));
+}
+
+#[test]
+fn nearly_lt_rel_nan_f64() {
+    let a: f64 = f64::NAN;
+    let b: f64 = 1.0;
+    assert!(!a.nearly_lt_rel(&b, &0.0));
+    assert!(!b.nearly_lt_rel(&a, &0.0));
+
+    assert!(!f64::NAN.nearly_lt_rel(&f64::NAN, &0.0));
+    assert!(!f64::NAN.nearly_lt_rel(&-f64::NAN, &0.0));
+    assert!(!(-f64::NAN).nearly_lt_rel(&f64::NAN, &0.0));
+    assert!(!(-f64::NAN).nearly_lt_rel(&-f64::NAN, &0.0));
+}
+
+///////////////////
+// nearly_le_rel //
+///////////////////
+
+#[test]
+fn nearly_le_rel_zero_f32() {
+    let a: f32 = 0.0;
+    let b: f32 = -0.0;
+    assert!(a.nearly_le_rel(&b, &0.0));
+    assert!(b.nearly_le_rel(&a, &0.0));
+}
+
+#[test]
+fn nearly_le_rel_zero_f64() {
+    let a: f64 = 0.0;
+    let b: f64 = -0.0;
+    assert!(a.nearly_le_rel(&b, &0.0));
+    assert!(b.nearly_le_rel(&a, &0.0));
+}
+
+#[test]
+fn nearly_le_rel_same_f32() {
+    let a: f32 = 1.0;
+    let b: f32 = 1.0;
+    assert!(a.nearly_le_rel(&b, &0.0));
+    assert!(b.nearly_le_rel(&a, &0.0));
+}
+
+#[test]
+fn nearly_le_rel_same_f64() {
+    let a: f64 = 1.0;
+    let b: f64 = 1.0;
+    assert!(a.nearly_le_rel(&b, &0.0));
+    assert!(b.nearly_le_rel(&a, &0.0));
+}
+
+#[test]
+fn nearly_le_rel_different_f32() {
+    let a: f32 = 1.0;
+    let b: f32 = 1.0000008;
+
+    assert!(a.nearly_le_rel(&b, &0.0000007));
+    assert!(!b.nearly_le_rel(&a, &0.0000007));
+
+    assert!(a.nearly_le_rel(&b, &0.0000008));
+    assert!(!b.nearly_le_rel(&a, &0.0000008));
+
+    assert!(a.nearly_le_rel(&b, &0.0000009));
+    assert!(b.nearly_le_rel(&a, &0.0000009));
+}
+
+#[test]
+fn nearly_le_rel_different_f64() {
+    let a: f64 = 1.0;
+    let b: f64 = 1.0000000000003;
+
+    assert!(a.nearly_le_rel(&b, &0.0000000000002));
+    assert!(!b.nearly_le_rel(&a, &0.0000000000002));
+
+    assert!(a.nearly_le_rel(&b, &0.0000000000003));
+    assert!(b.nearly_le_rel(&a, &0.0000000000003));
+
+    assert!(a.nearly_le_rel(&b, &0.0000000000004));
+    assert!(b.nearly_le_rel(&a, &0.0000000000004));
+}
+
+#[test]
+fn nearly_le_rel_different_sign_f32() {
+    let a: f32 = -0.01;
+    let b: f32 = 0.01;
+
+    assert!(a.nearly_le_rel(&b, &1.9));
+    assert!(!b.nearly_le_rel(&a, &1.9));
+
+    assert!(a.nearly_le_rel(&b, &2.0));
+    assert!(b.nearly_le_rel(&a, &2.0));
+}
+
+#[test]
+fn nearly_le_rel_different_sign_f64() {
+    let a: f64 = -0.01;
+    let b: f64 = 0.01;
+
+    assert!(a.nearly_le_rel(&b, &1.9));
+    assert!(!b.nearly_le_rel(&a, &1.9));
+
+    assert!(a.nearly_le_rel(&b, &2.0));
+    assert!(b.nearly_le_rel(&a, &2.0));
+}
+
+#[test]
+fn nearly_le_rel_inf_f32() {
+    {
+        let a: f32 = 1.0;
+        let b: f32 = f32::INFINITY;
+        assert!(a.nearly_le_rel(&b, &f32::MAX));
+        assert!(!b.nearly_le_rel(&a, &f32::MAX));
+    }
+    {
+        let a: f32 = -f32::INFINITY;
+        let b: f32 = 1.0;
+        assert!(a.nearly_le_rel(&b, &f32::MAX));
+        assert!(!b.nearly_le_rel(&a, &f32::MAX));
+    }
+
+    assert!(f32::INFINITY.nearly_le_rel(&f32::INFINITY, &0.0));
+    assert!((-f32::INFINITY).nearly_le_rel(&-f32::INFINITY, &0.0));
+    assert!(!f32::INFINITY.nearly_le_rel(&-f32::INFINITY, &f32::MAX));
+    assert!((-f32::INFINITY).nearly_le_rel(&f32::INFINITY, &f32::MAX));
+}
+
+#[test]
+fn nearly_le_rel_inf_f64() {
+    {
+        let a: f64 = 1.0;
+        let b: f64 = f64::INFINITY;
+        assert!(a.nearly_le_rel(&b, &f64::MAX));
+        assert!(!b.nearly_le_rel(&a, &f64::MAX));
+    }
+    {
+        let a: f64 = -f64::INFINITY;
+        let b: f64 = 1.0;
+        assert!(a.nearly_le_rel(&b, &f64::MAX));
+        assert!(!b.nearly_le_rel(&a, &f64::MAX));
+    }
+
+    assert!(f64::INFINITY.nearly_le_rel(&f64::INFINITY, &0.0));
+    assert!((-f64::INFINITY).nearly_le_rel(&-f64::INFINITY, &0.0));
+    assert!(!f64::INFINITY.nearly_le_rel(&-f64::INFINITY, &f64::MAX));
+    assert!((-f64::INFINITY).nearly_le_rel(&f64::INFINITY, &f64::MAX));
+}
+
+#[test]
+fn nearly_le_rel_nan_f32() {
+    let a: f32 = f32::NAN;
+    let b: f32 = 1.0;
+    assert!(!a.nearly_le_rel(&b, &0.0));
+    assert!(!b.nearly_le_rel(&a, &0.0));
+
+    assert!(!f32::NAN.nearly_le_rel(&f32::NAN, &0.0));
+    assert!(!f32::NAN.nearly_le_rel(&-f32::NAN, &0.0));
+    assert!(!(-f32::NAN).nearly_le_rel(&f32::NAN, &0.0));
+    assert!(!(-f32::NAN).nearly_le_rel(&-f32::NAN, &0.0));
+}
+
+#[test]
+fn nearly_le_rel_nan_f64() {
+    let a: f64 = f64::NAN;
+    let b: f64 = 1.0;
+    assert!(!a.nearly_le_rel(&b, &0.0));
+    assert!(!b.nearly_le_rel(&a, &0.0));
+
+    assert!(!f64::NAN.nearly_le_rel(&f64::NAN, &0.0));
+    assert!(!f64::NAN.nearly_le_rel(&-f64::NAN, &0.0));
+    assert!(!(-f64::NAN).nearly_le_rel(&f64::NAN, &0.0));
+    assert!(!(-f64::NAN).nearly_le_rel(&-f64::NAN, &0.0));
+}
+
+///////////////////
+// nearly_gt_rel //
+///////////////////
+
+#[test]
+fn nearly_gt_rel_zero_f32() {
+    let a: f32 = 0.0;
+    let b: f32 = -0.0;
+    assert!(!a.nearly_gt_rel(&b, &0.0));
+    assert!(!b.nearly_gt_rel(&b, &0.0));
+}
+
+#[test]
+fn nearly_gt_rel_zero_f64() {
+    let a: f64 = 0.0;
+    let b: f64 = -0.0;
+    assert!(!a.nearly_gt_rel(&b, &0.0));
+    assert!(!b.nearly_gt_rel(&b, &0.0));
+}
+
+#[test]
+fn nearly_gt_rel_same_f32() {
+    let a: f32 = 1.0;
+    let b: f32 = 1.0;
+    assert!(!a.nearly_gt_rel(&b, &0.0));
+    assert!(!b.nearly_gt_rel(&a, &0.0));
+}
+
+#[test]
+fn nearly_gt_rel_same_f64() {
+    let a: f64 = 1.0;
+    let b: f64 = 1.0;
+    assert!(!a.nearly_gt_rel(&b, &0.0));
+    assert!(!b.nearly_gt_rel(&a, &0.0));
+}
+
+#[test]
+fn nearly_gt_rel_different_f32() {
+    let a: f32 = 1.0000008;
+    let b: f32 = 1.0;
+
+    assert!(a.nearly_gt_rel(&b, &0.0000007));
+    assert!(!b.nearly_gt_rel(&a, &0.0000007));
+
+    assert!(a.nearly_gt_rel(&b, &0.0000008));
+    assert!(!b.nearly_gt_rel(&a, &0.0000008));
+
+    assert!(!a.nearly_gt_rel(&b, &0.0000009));
+    assert!(!b.nearly_gt_rel(&a, &0.0000009));
+}
+
+#[test]
+fn nearly_gt_rel_different_f64() {
+    let a: f64 = 1.0000000000003;
+    let b: f64 = 1.0;
+
+    assert!(a.nearly_gt_rel(&b, &0.0000000000002));
+    assert!(!b.nearly_gt_rel(&a, &0.0000000000002));
+
+    assert!(!a.nearly_gt_rel(&b, &0.0000000000003));
+    assert!(!b.nearly_gt_rel(&a, &0.0000000000003));
+
+    assert!(!a.nearly_gt_rel(&b, &0.0000000000004));
+    assert!(!b.nearly_gt_rel(&a, &0.0000000000004));
+}
+
+#[test]
+fn nearly_gt_rel_different_sign_f32() {
+    let a: f32 = 0.01;
+    let b: f32 = -0.01;
+
+    assert!(a.nearly_gt_rel(&b, &1.9));
+    assert!(!b.nearly_gt_rel(&a, &1.9));
+
+    assert!(!a.nearly_gt_rel(&b, &2.0));
+    assert!(!b.nearly_gt_rel(&a, &2.0));
+}
+
+#[test]
+fn nearly_gt_rel_different_sign_f64() {
+    let a: f64 = 0.01;
+    let b: f64 = -0.01;
+
+    assert!(a.nearly_gt_rel(&b, &1.9));
+    assert!(!b.nearly_gt_rel(&a, &1.9));
+
+    assert!(!a.nearly_gt_rel(&b, &2.0));
+    assert!(!b.nearly_gt_rel(&a, &2.0));
+}
+
+#[test]
+fn nearly_gt_rel_inf_f32() {
+    {
+        let a: f32 = 1.0;
+        let b: f32 = -f32::INFINITY;
+        assert!(a.nearly_gt_rel(&b, &f32::MAX));
+        assert!(!b.nearly_gt_rel(&a, &f32::MAX));
+    }
+    {
+        let a: f32 = f32::INFINITY;
+        let b: f32 = 1.0;
+        assert!(a.nearly_gt_rel(&b, &f32::MAX));
+        assert!(!b.nearly_gt_rel(&a, &f32::MAX));
+    }
+
+    assert!(!f32::INFINITY.nearly_gt_rel(&f32::INFINITY, &0.0));
+    assert!(!(-f32::INFINITY).nearly_gt_rel(&-f32::INFINITY, &0.0));
+    assert!(f32::INFINITY.nearly_gt_rel(&-f32::INFINITY, &f32::MAX));
+    assert!(!(-f32::INFINITY).nearly_gt_rel(&f32::INFINITY, &f32::MAX));
+}
+
+#[test]
+fn nearly_gt_rel_inf_f64() {
+    {
+        let a: f64 = 1.0;
+        let b: f64 = -f64::INFINITY;
+        assert!(a.nearly_gt_rel(&b, &f64::MAX));
+        assert!(!b.nearly_gt_rel(&a, &f64::MAX));
+    }
+    {
+        let a: f64 = f64::INFINITY;
+        let b: f64 = 1.0;
+        assert!(a.nearly_gt_rel(&b, &f64::MAX));
+        assert!(!b.nearly_gt_rel(&a, &f64::MAX));
+    }
+
+    assert!(!f64::INFINITY.nearly_gt_rel(&f64::INFINITY, &0.0));
+    assert!(!(-f64::INFINITY).nearly_gt_rel(&-f64::INFINITY, &0.0));
+    assert!(f64::INFINITY.nearly_gt_rel(&-f64::INFINITY, &f64::MAX));
+    assert!(!(-f64::INFINITY).nearly_gt_rel(&f64::INFINITY, &f64::MAX));
+}
+
+#[test]
+fn nearly_gt_rel_nan_f32() {
+    let a: f32 = 1.0;
+    let b: f32 = f32::NAN;
+    assert!(!a.nearly_gt_rel(&b, &0.0));
+    assert!(!b.nearly_gt_rel(&a, &0.0));
+
+    assert!(!f32::NAN.nearly_gt_rel(&f32::NAN, &0.0));
+    assert!(!f32::NAN.nearly_gt_rel(&-f32::NAN, &0.0));
+    assert!(!(-f32::NAN).nearly_gt_rel(&f32::NAN, &0.0));
+    assert!(!(-f32::NAN).nearly_gt_rel(&-f32::NAN, &0.0));
+}
+
+#[test]
+fn nearly_gt_rel_nan_f64() {
+    let a: f64 = 1.0;
+    let b: f64 = f64::NAN;
+    assert!(!a.nearly_gt_rel(&b, &0.0));
+    assert!(!b.nearly_gt_rel(&a, &0.0));
+
+    assert!(!f64::NAN.nearly_gt_rel(&f64::NAN, &0.0));
+    assert!(!f64::NAN.nearly_gt_rel(&-f64::NAN, &0.0));
+    assert!(!(-f64::NAN).nearly_gt_rel(&f64::NAN, &0.0));
+    assert!(!(-f64::NAN).nearly_gt_rel(&-f64::NAN, &0.0));
+}
+
+///////////////////
+// nearly_ge_rel //
+///////////////////
+
+#[test]
+fn nearly_ge_rel_zero_f32() {
+    let a: f32 = 0.0;
+    let b: f32 = -0.0;
+    assert!(a.nearly_ge_rel(&b, &0.0));
+    assert!(b.nearly_ge_rel(&a, &0.0));
+}
+
+#[test]
+fn nearly_ge_rel_zero_f64() {
+    let a: f64 = 0.0;
+    let b: f64 = -0.0;
+    assert!(a.nearly_ge_rel(&b, &0.0));
+    assert!(b.nearly_ge_rel(&a, &0.0));
+}
+
+#[test]
+fn nearly_ge_rel_same_f32() {
+    let a: f32 = 1.0;
+    let b: f32 = 1.0;
+    assert!(a.nearly_ge_rel(&b, &0.0));
+    assert!(b.nearly_ge_rel(&a, &0.0));
+}
+
+#[test]
+fn nearly_ge_rel_same_f64() {
+    let a: f64 = 1.0;
+    let b: f64 = 1.0;
+    assert!(a.nearly_ge_rel(&b, &0.0));
+    assert!(b.nearly_ge_rel(&a, &0.0));
+}
+
+#[test]
+fn nearly_ge_rel_different_f32() {
+    let a: f32 = 1.0000008;
+    let b: f32 = 1.0;
+
+    assert!(a.nearly_ge_rel(&b, &0.0000007));
+    assert!(!b.nearly_ge_rel(&a, &0.0000007));
+
+    assert!(a.nearly_ge_rel(&b, &0.0000008));
+    assert!(!b.nearly_ge_rel(&a, &0.0000008));
+
+    assert!(a.nearly_ge_rel(&b, &0.0000009));
+    assert!(b.nearly_ge_rel(&a, &0.0000009));
+}
+
+#[test]
+fn nearly_ge_rel_different_f64() {
+    let a: f64 = 1.0000000000003;
+    let b: f64 = 1.0;
+
+    assert!(a.nearly_ge_rel(&b, &0.0000000000002));
+    assert!(!b.nearly_ge_rel(&a, &0.0000000000002));
+
+    assert!(a.nearly_ge_rel(&b, &0.0000000000003));
+    assert!(b.nearly_ge_rel(&a, &0.0000000000003));
+
+    assert!(a.nearly_ge_rel(&b, &0.0000000000004));
+    assert!(b.nearly_ge_rel(&a, &0.0000000000004));
+}
+
+#[test]
+fn nearly_ge_rel_different_sign_f32() {
+    let a: f32 = 0.01;
+    let b: f32 = -0.01;
+
+    assert!(a.nearly_ge_rel(&b, &1.9));
+    assert!(!b.nearly_ge_rel(&a, &1.9));
+
+    assert!(a.nearly_ge_rel(&b, &2.0));
+    assert!(b.nearly_ge_rel(&a, &2.0));
+}
+
+#[test]
+fn nearly_ge_rel_different_sign_f64() {
+    let a: f64 = 0.01;
+    let b: f64 = -0.01;
+
+    assert!(a.nearly_ge_rel(&b, &1.9));
+    assert!(!b.nearly_ge_rel(&a, &1.9));
+
+    assert!(a.nearly_ge_rel(&b, &2.0));
+    assert!(b.nearly_ge_rel(&a, &2.0));
+}
+
+#[test]
+fn nearly_ge_rel_inf_f32() {
+    {
+        let a: f32 = 1.0;
+        let b: f32 = -f32::INFINITY;
+        assert!(a.nearly_ge_rel(&b, &f32::MAX));
+        assert!(!b.nearly_ge_rel(&a, &f32::MAX));
+    }
+    {
+        let a: f32 = f32::INFINITY;
+        let b: f32 = 1.0;
+        assert!(a.nearly_ge_rel(&b, &f32::MAX));
+        assert!(!b.nearly_ge_rel(&a, &f32::MAX));
+    }
+
+    assert!(f32::INFINITY.nearly_ge_rel(&f32::INFINITY, &0.0));
+    assert!((-f32::INFINITY).nearly_ge_rel(&-f32::INFINITY, &0.0));
+    assert!(f32::INFINITY.nearly_ge_rel(&-f32::INFINITY, &f32::MAX));
+    assert!(!(-f32::INFINITY).nearly_ge_rel(&f32::INFINITY, &f32::MAX));
+}
+
+#[test]
+fn nearly_ge_rel_inf_f64() {
+    {
+        let a: f64 = 1.0;
+        let b: f64 = -f64::INFINITY;
+        assert!(a.nearly_ge_rel(&b, &f64::MAX));
+        assert!(!b.nearly_ge_rel(&a, &f64::MAX));
+    }
+    {
+        let a: f64 = f64::INFINITY;
+        let b: f64 = 1.0;
+        assert!(a.nearly_ge_rel(&b, &f64::MAX));
+        assert!(!b.nearly_ge_rel(&a, &f64::MAX));
+    }
+
+    assert!(f64::INFINITY.nearly_ge_rel(&f64::INFINITY, &0.0));
+    assert!((-f64::INFINITY).nearly_ge_rel(&-f64::INFINITY, &0.0));
+    assert!(f64::INFINITY.nearly_ge_rel(&-f64::INFINITY, &f64::MAX));
+    assert!(!(-f64::INFINITY).nearly_ge_rel(&f64::INFINITY, &f64::MAX));
+}
+
+#[test]
+fn nearly_ge_rel_nan_f32() {
+    let a: f32 = f32::NAN;
+    let b: f32 = 1.0;
+    assert!(!a.nearly_ge_rel(&b, &0.0));
+    assert!(!b.nearly_ge_rel(&a, &0.0));
+
+    assert!(!f32::NAN.nearly_ge_rel(&f32::NAN, &0.0));
+    assert!(!f32::NAN.nearly_ge_rel(&-f32::NAN, &0.0));
+    assert!(!(-f32::NAN).nearly_ge_rel(&f32::NAN, &0.0));
+    assert!(!(-f32::NAN).nearly_ge_rel(&-f32::NAN, &0.0));
+}
+
+#[test]
+fn nearly_ge_rel_nan_f64() {
+    let a: f64 = f64::NAN;
+    let b: f64 = 1.0;
+    assert!(!a.nearly_ge_rel(&b, &0.0));
+    assert!(!b.nearly_ge_rel(&a, &0.0));
+
+    assert!(!f64::NAN.nearly_ge_rel(&f64::NAN, &0.0));
+    assert!(!f64::NAN.nearly_ge_rel(&-f64::NAN, &0.0));
+    assert!(!(-f64::NAN).nearly_ge_rel(&f64::NAN, &0.0));
+    assert!(!(-f64::NAN).nearly_ge_rel(&-f64::NAN, &0.0));
+}
+
+////////////////////
+// nearly_cmp_rel //
+////////////////////
+
+#[test]
+fn nearly_cmp_rel_f32() {
+    let a: f32 = 100.0;
+    let b: f32 = 101.0;
+
+    assert_eq!(a.nearly_cmp_rel(&a, &0.001), Some(Ordering::Equal));
+    assert_eq!(a.nearly_cmp_rel(&b, &0.01), Some(Ordering::Equal));
+    assert_eq!(b.nearly_cmp_rel(&a, &0.01), Some(Ordering::Equal));
+    assert_eq!(a.nearly_cmp_rel(&b, &0.001), Some(Ordering::Less));
+    assert_eq!(b.nearly_cmp_rel(&a, &0.001), Some(Ordering::Greater));
+    assert_eq!(a.nearly_cmp_rel(&f32::NAN, &0.01), None);
+    assert_eq!(f32::NAN.nearly_cmp_rel(&a, &0.01), None);
+}
+
+#[test]
+fn nearly_cmp_rel_f64() {
+    let a: f64 = 100.0;
+    let b: f64 = 101.0;
+
+    assert_eq!(a.nearly_cmp_rel(&a, &0.001), Some(Ordering::Equal));
+    assert_eq!(a.nearly_cmp_rel(&b, &0.01), Some(Ordering::Equal));
+    assert_eq!(b.nearly_cmp_rel(&a, &0.01), Some(Ordering::Equal));
+    assert_eq!(a.nearly_cmp_rel(&b, &0.001), Some(Ordering::Less));
+    assert_eq!(b.nearly_cmp_rel(&a, &0.001), Some(Ordering::Greater));
+    assert_eq!(a.nearly_cmp_rel(&f64::NAN, &0.01), None);
+    assert_eq!(f64::NAN.nearly_cmp_rel(&a, &0.01), None);
+}
@@ -0,0 +1,98 @@
+use nearly::{NearlyEqTol, NearlyOrdTol, Tolerance, ToleranceMode};
+use std::cmp::Ordering;
+
+macro_rules! assert_trichotomy {
+    ($a: expr, $b: expr, $tol: expr) => {
+        let (a, b, tol) = ($a, $b, $tol);
+        let eq = a.nearly_eq_tol(&b, &tol);
+        let lt = a.nearly_lt_tol(&b, &tol);
+        let gt = a.nearly_gt_tol(&b, &tol);
+        assert_eq!(
+            [eq, lt, gt].iter().filter(|it| **it).count(),
+            1,
+            "a: {:?}, b: {:?}, tol: {:?}, eq: {}, lt: {}, gt: {}",
+            a,
+            b,
+            tol,
+            eq,
+            lt,
+            gt
+        );
+
+        assert_eq!(a.nearly_le_tol(&b, &tol), lt || eq);
+        assert_eq!(a.nearly_ge_tol(&b, &tol), gt || eq);
+
+        let expected = match (lt, eq) {
+            (true, _) => Ordering::Less,
+            (_, true) => Ordering::Equal,
+            _ => Ordering::Greater,
+        };
+        assert_eq!(a.nearly_cmp_tol(&b, &tol), Some(expected));
+    };
+}
+
+macro_rules! trichotomy_test {
+    ($name: ident, $float: ty) => {
+        #[test]
+        fn $name() {
+            let one: $float = 1.0;
+            let values = [
+                one,
+                one + <$float>::EPSILON,
+                one + 4.0 * <$float>::EPSILON,
+                one + 0.0005,
+                one + 0.002,
+                one + 0.5,
+                -one,
+                0.0,
+                -0.0,
+                <$float>::MIN_POSITIVE,
+                <$float>::INFINITY,
+                <$float>::NEG_INFINITY,
+            ];
+            let tolerances = [
+                Tolerance::<$float>::new(1e-3, 1),
+                Tolerance::<$float>::new(1e-3, 1).with_rel(1e-2),
+                Tolerance::<$float>::new(0.0, 8).with_rel(1e-4),
+            ];
+
+            for tol in tolerances {
+                for mode in [ToleranceMode::Any, ToleranceMode::All] {
+                    for a in values {
+                        for b in values {
+                            assert_trichotomy!(a, b, tol.with_mode(mode));
+                        }
+                    }
+                }
+            }
+        }
+    };
+}
+
+trichotomy_test!(nearly_ord_tol_trichotomy_f32, f32);
+trichotomy_test!(nearly_ord_tol_trichotomy_f64, f64);
+
+#[test]
+fn nearly_ord_tol_any_eq() {
+    let tol = Tolerance::<f64>::new(1e-3, 1);
+    let a: f64 = 1.0;
+    let b: f64 = 1.0 + 4.0 * f64::EPSILON;
+
+    assert!(a.nearly_eq_tol(&b, &tol));
+    assert!(!a.nearly_lt_tol(&b, &tol));
+    assert!(!b.nearly_gt_tol(&a, &tol));
+    assert_eq!(a.nearly_cmp_tol(&b, &tol), Some(Ordering::Equal));
+}
+
+#[test]
+fn nearly_ord_tol_all_neither_eq() {
+    let tol = Tolerance::<f64>::new(1e-3, 1).with_mode(ToleranceMode::All);
+    let a: f64 = 1.0;
+    let b: f64 = 1.0 + 4.0 * f64::EPSILON;
+
+    assert!(!a.nearly_eq_tol(&b, &tol));
+    assert!(a.nearly_lt_tol(&b, &tol));
+    assert!(!a.nearly_gt_tol(&b, &tol));
+    assert!(b.nearly_gt_tol(&a, &tol));
+    assert_eq!(a.nearly_cmp_tol(&b, &tol), Some(Ordering::Less));
+}
@@ -1,4 +1,5 @@
 use nearly::NearlyOrdUlps;
+use std::cmp::Ordering;
 
 ////////////////////
 // nearly_lt_ulps //
@@ -631,3 +632,35 @@ fn nearly_ge_ulps_nan_f64() {
     assert!(!(-f64::NAN).nearly_ge_ulps(&f64::NAN, &0));
     assert!(!(-f64::NAN).nearly_ge_ulps(&-f64::NAN, &0));
 }
+
+/////////////////////
+// nearly_cmp_ulps //
+/////////////////////
+
+#[test]
+fn nearly_cmp_ulps_f32() {
+    let a: f32 = 1.0;
+    let b: f32 = 1.0000008;
+
+    assert_eq!(a.nearly_cmp_ulps(&a, &6), Some(Ordering::Equal));
+    assert_eq!(a.nearly_cmp_ulps(&b, &7), Some(Ordering::Equal));
+    assert_eq!(b.nearly_cmp_ulps(&a, &7), Some(Ordering::Equal));
+    assert_eq!(a.nearly_cmp_ulps(&b, &6), Some(Ordering::Less));
+    assert_eq!(b.nearly_cmp_ulps(&a, &6), Some(Ordering::Greater));
+    assert_eq!(a.nearly_cmp_ulps(&f32::NAN, &7), None);
+    assert_eq!(f32::NAN.nearly_cmp_ulps(&a, &7), None);
+}
+
+#[test]
+fn nearly_cmp_ulps_f64() {
+    let a: f64 = 1.0;
+    let b: f64 = 1.0000000000000016;
+
+    assert_eq!(a.nearly_cmp_ulps(&a, &6), Some(Ordering::Equal));
+    assert_eq!(a.nearly_cmp_ulps(&b, &7), Some(Ordering::Equal));
+    assert_eq!(b.nearly_cmp_ulps(&a, &7), Some(Ordering::Equal));
+    assert_eq!(a.nearly_cmp_ulps(&b, &6), Some(Ordering::Less));
+    assert_eq!(b.nearly_cmp_ulps(&a, &6), Some(Ordering::Greater));
+    assert_eq!(a.nearly_cmp_ulps(&f64::NAN, &7), None);
+    assert_eq!(f64::NAN.nearly_cmp_ulps(&a, &7), None);
+}
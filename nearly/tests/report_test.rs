@@ -0,0 +1,338 @@
+#![cfg(feature = "std")]
+
+use nearly::{
+    FloatValue, Mismatch, MismatchKind, NearlyEqReport, Path, PathSegment, PerElement, Report,
+    ReportTolerance, Tolerance,
+};
+use std::collections::hash_map::DefaultHasher;
+use std::collections::{BTreeMap, HashMap};
+use std::hash::BuildHasherDefault;
+
+fn path(segments: &[PathSegment]) -> Path {
+    Path(segments.to_vec())
+}
+
+#[test]
+fn report_primitive_match() {
+    let a: f32 = 1.0;
+    let b: f32 = 1.05;
+
+    assert!(a.nearly_eq_eps_report(&b, &0.1).is_nearly_eq());
+    assert!(a.nearly_eq_ulps_report(&a, &0).is_nearly_eq());
+    assert!(a.nearly_eq_rel_report(&b, &0.1).is_nearly_eq());
+    assert!(a
+        .nearly_eq_tol_report(&b, &Tolerance::new(0.1, 0))
+        .is_nearly_eq());
+    assert!(a.nearly_eq_report(&a).is_nearly_eq());
+    assert_eq!(a.nearly_eq_report(&a).first(), None);
+}
+
+#[test]
+fn report_primitive_mismatch_f32() {
+    let a: f32 = 1.0;
+    let b: f32 = 1.5;
+
+    let report = a.nearly_eq_eps_report(&b, &0.1);
+    assert!(!report.is_nearly_eq());
+    assert_eq!(
+        report.mismatches(),
+        &[Mismatch {
+            path: Path::default(),
+            kind: MismatchKind::Value {
+                lhs: FloatValue::F32(1.0),
+                rhs: FloatValue::F32(1.5),
+                diff: FloatValue::F32(0.5),
+                ulps: Some(1 << 22),
+                tolerance: "eps: 0.1".to_string(),
+            },
+        }]
+    );
+    assert_eq!(
+        report.to_string(),
+        "1.0 != 1.5 (diff: 0.5, ulps: 4194304, eps: 0.1)"
+    );
+}
+
+#[test]
+fn report_primitive_mismatch_f64() {
+    let a: f64 = 1.0;
+    let b = a + 3.0 * f64::EPSILON;
+
+    let report = a.nearly_eq_ulps_report(&b, &2);
+    match &report.first().unwrap().kind {
+        MismatchKind::Value {
+            ulps, tolerance, ..
+        } => {
+            assert_eq!(*ulps, Some(3));
+            assert_eq!(tolerance, "ulps: 2");
+        }
+        kind => panic!("unexpected mismatch {:?}", kind),
+    }
+    assert!(a.nearly_eq_ulps_report(&b, &3).is_nearly_eq());
+}
+
+#[test]
+fn report_primitive_nan() {
+    let report = f64::NAN.nearly_eq_rel_report(&1.0, &0.1);
+
+    match &report.first().unwrap().kind {
+        MismatchKind::Value { ulps, .. } => assert_eq!(*ulps, None),
+        kind => panic!("unexpected mismatch {:?}", kind),
+    }
+    assert_eq!(
+        report.to_string(),
+        "NaN != 1.0 (diff: NaN, ulps: -, rel: 0.1)"
+    );
+}
+
+#[test]
+fn report_tolerance() {
+    let tol = Tolerance::<f32>::new(0.1, 4);
+    assert_eq!(ReportTolerance::Tol(tol).to_string(), "eps: 0.1, ulps: 4");
+
+    let tol = tol.with_rel(0.01).with_mode(nearly::ToleranceMode::All);
+    assert_eq!(
+        ReportTolerance::Tol(tol).to_string(),
+        "eps: 0.1, ulps: 4, rel: 0.01, mode: all"
+    );
+    assert!(ReportTolerance::<f32>::Eps(0.1).nearly_eq(&1.0f32, &1.05f32));
+    assert!(!ReportTolerance::<f32>::Ulps(4).nearly_eq(&1.0f32, &1.05f32));
+
+    let report = 1.0f32.nearly_eq_tol_report(&1.5, &tol);
+    assert_eq!(
+        report.to_string(),
+        "1.0 != 1.5 (diff: 0.5, ulps: 4194304, eps: 0.1, ulps: 4, rel: 0.01, mode: all)"
+    );
+}
+
+#[test]
+fn report_collection() {
+    let a = [1.0f64, 2.0, 3.0, 4.0];
+    let b = [1.0f64, 2.5, 3.0, 4.5];
+
+    let report = a.nearly_eq_eps_report(&b, &0.1);
+    let paths: Vec<&Path> = report.mismatches().iter().map(|it| &it.path).collect();
+    assert_eq!(
+        paths,
+        vec![
+            &path(&[PathSegment::Index(1)]),
+            &path(&[PathSegment::Index(3)])
+        ]
+    );
+    assert_eq!(report.first().unwrap().path.to_string(), "[1]");
+    assert!(a.nearly_eq_eps_report(&b, &0.6).is_nearly_eq());
+
+    let a = vec![1.0f32; 10_000];
+    let mut b = a.clone();
+    b[9_876] = 2.0;
+    let report = a.nearly_eq_report(&b);
+    assert_eq!(report.mismatches().len(), 1);
+    assert_eq!(
+        report.first().unwrap().path.0,
+        vec![PathSegment::Index(9_876)]
+    );
+}
+
+#[test]
+fn report_collection_length() {
+    let a = vec![1.0f32, 2.0, 3.0];
+    let b: &[f32] = &[1.0, 2.0];
+
+    let report = a.nearly_eq_eps_report(&b, &0.1);
+    assert_eq!(
+        report.mismatches(),
+        &[Mismatch {
+            path: Path::default(),
+            kind: MismatchKind::Length { lhs: 3, rhs: 2 },
+        }]
+    );
+    assert_eq!(report.to_string(), "length 3 != 2");
+}
+
+#[test]
+fn report_nested_collection() {
+    let a = PerElement((1.0f32, vec![2.0f32, 3.0]));
+    let b = PerElement((1.0f32, vec![2.0f32]));
+
+    let report = a.nearly_eq_eps_report(&b, &(0.1, 0.1));
+    assert_eq!(report.to_string(), "1: length 2 != 1");
+
+    let a = vec![PerElement((1.0f32, 2.0f64)), PerElement((3.0, 4.0))];
+    let b = vec![PerElement((1.0f32, 2.0f64)), PerElement((3.0, 4.5))];
+
+    let report = a.nearly_eq_eps_report(&b, &(0.1, 0.1));
+    assert_eq!(report.first().unwrap().path.to_string(), "[1].1");
+}
+
+#[test]
+fn report_hashmap() {
+    let a: HashMap<&str, f32> = [("a", 1.0), ("b", 2.0), ("c", 3.0)].into();
+    let b: HashMap<&str, f32> = [("a", 1.0), ("b", 2.5), ("d", 3.0)].into();
+
+    let report = a.nearly_eq_eps_report(&b, &0.1);
+    let mut lines: Vec<String> = report
+        .mismatches()
+        .iter()
+        .map(|it| it.to_string())
+        .collect();
+    lines.sort();
+    assert_eq!(
+        lines,
+        vec![
+            "[\"b\"]: 2.0 != 2.5 (diff: 0.5, ulps: 2097152, eps: 0.1)",
+            "[\"c\"]: only in left",
+            "[\"d\"]: only in right",
+        ]
+    );
+}
+
+#[test]
+fn report_btree_map() {
+    let a: BTreeMap<u8, f64> = [(1, 1.0), (2, 2.0), (3, 3.0)].into();
+    let b: BTreeMap<u8, f64> = [(1, 1.5), (2, 2.0)].into();
+
+    let report = a.nearly_eq_eps_report(&b, &0.1);
+    assert_eq!(
+        report
+            .mismatches()
+            .iter()
+            .map(|it| &it.path)
+            .collect::<Vec<_>>(),
+        vec![
+            &path(&[PathSegment::Key("1".to_string())]),
+            &path(&[PathSegment::Key("3".to_string())])
+        ]
+    );
+    assert_eq!(report.mismatches()[1].kind, MismatchKind::OnlyInLhs);
+
+    let b: HashMap<u8, f64> = [(1, 1.0), (2, 2.0), (3, 3.0)].into();
+    assert!(a.nearly_eq_eps_report(&b, &0.1).is_nearly_eq());
+    assert!(b.nearly_eq_eps_report(&a, &0.1).is_nearly_eq());
+}
+
+#[test]
+fn report_hashmap_different_hashers() {
+    let a: HashMap<u8, f32> = [(1, 1.0), (2, 2.0)].into();
+    let mut b: HashMap<u8, f32, BuildHasherDefault<DefaultHasher>> = HashMap::default();
+    b.extend([(1, 1.0), (2, 2.5)]);
+
+    let report = a.nearly_eq_eps_report(&b, &0.1);
+    assert_eq!(report.mismatches().len(), 1);
+    assert_eq!(
+        report.first().unwrap().path,
+        path(&[PathSegment::Key("2".to_string())])
+    );
+}
+
+#[test]
+fn report_tuple() {
+    let a: (f32, f32, f32) = (1.0, 2.0, 3.0);
+    let b: (f32, f32, f32) = (1.05, 2.5, 3.5);
+
+    let report = a.nearly_eq_eps_report(&b, &0.1);
+    assert_eq!(report.mismatches().len(), 2);
+    assert_eq!(report.mismatches()[0].path.to_string(), "1");
+    assert_eq!(report.mismatches()[1].path.to_string(), "2");
+}
+
+#[test]
+fn report_per_element() {
+    let a = PerElement((1.0f32, 2.0f64, [3.0f32, 4.0]));
+    let b = PerElement((1.05f32, 2.5f64, [3.0f32, 4.5]));
+
+    let report = a.nearly_eq_eps_report(&b, &(0.1, 0.1, 0.1));
+    assert_eq!(report.mismatches().len(), 2);
+    assert_eq!(report.mismatches()[0].path.to_string(), "1");
+    assert_eq!(report.mismatches()[1].path.to_string(), "2[1]");
+
+    let report = a.nearly_eq_tol_report(&b, &Tolerance::new((0.01, 1.0, 1.0), (0, 0, 0)));
+    assert_eq!(report.mismatches().len(), 1);
+    assert_eq!(
+        report.to_string(),
+        "0: 1.0 != 1.05 (diff: 0.049999952, ulps: 419430, eps: 0.01, ulps: 0)"
+    );
+}
+
+#[test]
+fn report_option() {
+    let a: Option<f32> = Some(1.0);
+
+    assert!(a.nearly_eq_eps_report(&Some(1.05), &0.1).is_nearly_eq());
+    assert!(None::<f32>
+        .nearly_eq_eps_report(&None::<f32>, &0.1)
+        .is_nearly_eq());
+    assert_eq!(
+        a.nearly_eq_eps_report(&Some(1.5), &0.1).to_string(),
+        "1.0 != 1.5 (diff: 0.5, ulps: 4194304, eps: 0.1)"
+    );
+    assert_eq!(
+        a.nearly_eq_eps_report(&None::<f32>, &0.1)
+            .first()
+            .unwrap()
+            .kind,
+        MismatchKind::Variant {
+            lhs: "Some",
+            rhs: "None"
+        }
+    );
+
+    let a = PerElement((1.0f64, None::<f64>));
+    let b = PerElement((1.0f64, Some(2.0f64)));
+    assert_eq!(
+        a.nearly_eq_eps_report(&b, &(0.1, 0.1)).to_string(),
+        "1: variant None != Some"
+    );
+}
+
+#[test]
+fn report_consistent_with_nearly_eq() {
+    use nearly::NearlyEqTol;
+
+    let a = vec![
+        PerElement((1.0f32, 2.0f64)),
+        PerElement((3.0, 4.0)),
+        PerElement((5.0, 6.0)),
+    ];
+    let b = vec![
+        PerElement((1.0f32, 2.0f64)),
+        PerElement((3.0, 4.000001)),
+        PerElement((5.0, 6.0)),
+    ];
+
+    for eps in [(0.0, 0.0), (1e-7, 1e-7), (1e-5, 1e-5)] {
+        for ulps in [(0, 0), (4, 4), (1 << 20, 1 << 20)] {
+            let tol = Tolerance::new(eps, ulps);
+            assert_eq!(
+                a.nearly_eq_tol(&b, &tol),
+                a.nearly_eq_tol_report(&b, &tol).is_nearly_eq()
+            );
+        }
+    }
+}
+
+#[test]
+fn report_path() {
+    let path = Path(vec![
+        PathSegment::Field("points"),
+        PathSegment::Index(2),
+        PathSegment::Key("\"x\"".to_string()),
+        PathSegment::Field("0"),
+    ]);
+    assert_eq!(path.to_string(), "points[2][\"x\"].0");
+    assert_eq!(Path::default().to_string(), "");
+}
+
+#[test]
+fn report_manual() {
+    let mut report = Report::new();
+    report.field("a", |report| {
+        report.index(1, |report| report.push(MismatchKind::NotEqual));
+        report.key(&"k", |report| report.push(MismatchKind::OnlyInRhs));
+    });
+    report.push(MismatchKind::NotEqual);
+
+    assert_eq!(
+        report.to_string(),
+        "a[1]: not equal\na[\"k\"]: only in right\nnot equal"
+    );
+}
@@ -0,0 +1,71 @@
+use nearly::{
+    assert_nearly, EpsTolerance, NearlyEq, NearlyEqEps, NearlyEqRel, NearlyEqTol, NearlyEqUlps,
+    NearlyOrdEps, NearlyOrdRel, NearlyOrdTol, NearlyOrdUlps, Tolerance,
+};
+
+#[test]
+fn result_tolerance() {
+    assert_eq!(
+        <Result<f32, u8> as EpsTolerance>::DEFAULT,
+        <f32 as EpsTolerance>::DEFAULT
+    );
+}
+
+#[test]
+fn result_eq() {
+    let a: Result<f32, u8> = Ok(1.0);
+    let b: Result<f32, u8> = Ok(1.05);
+    let err: Result<f32, u8> = Err(1);
+
+    assert!(a.nearly_eq_eps(&b, &0.1));
+    assert!(!a.nearly_eq_eps(&b, &0.01));
+    assert!(a.nearly_eq_ulps(&a, &0));
+    assert!(!a.nearly_eq_ulps(&b, &4));
+    assert!(a.nearly_eq_rel(&b, &0.1));
+    assert!(!a.nearly_eq_rel(&b, &0.01));
+    assert!(a.nearly_eq_tol(&b, &Tolerance::new(0.1, 0)));
+    assert!(a.nearly_eq(&Ok(1.0)));
+    assert!(!a.nearly_eq(&b));
+
+    assert!(err.nearly_eq_eps(&Err(1), &0.1));
+    assert!(!err.nearly_eq_eps(&Err(2), &0.1));
+    assert!(err.nearly_eq(&Err(1)));
+    assert!(!a.nearly_eq_eps(&err, &0.1));
+    assert!(!err.nearly_eq_eps(&a, &0.1));
+
+    assert_nearly!(a == b, eps = 0.1);
+    assert_nearly!(a != err);
+}
+
+#[test]
+fn result_ord() {
+    let a: Result<f64, u8> = Ok(1.0);
+    let b: Result<f64, u8> = Ok(1.5);
+    let err: Result<f64, u8> = Err(1);
+
+    assert!(a.nearly_lt_eps(&b, &0.1));
+    assert!(a.nearly_le_eps(&b, &0.1));
+    assert!(!a.nearly_gt_eps(&b, &0.1));
+    assert!(!a.nearly_ge_eps(&b, &0.1));
+    assert!(a.nearly_le_ulps(&a, &0));
+    assert!(b.nearly_gt_rel(&a, &0.1));
+    assert!(b.nearly_ge_tol(&a, &Tolerance::new(0.1, 4)));
+
+    assert!(a.nearly_lt_eps(&err, &0.1));
+    assert!(a.nearly_le_eps(&err, &0.1));
+    assert!(!a.nearly_gt_eps(&err, &0.1));
+    assert!(!a.nearly_ge_eps(&err, &0.1));
+
+    assert!(!err.nearly_lt_ulps(&a, &4));
+    assert!(!err.nearly_le_ulps(&a, &4));
+    assert!(err.nearly_gt_ulps(&a, &4));
+    assert!(err.nearly_ge_ulps(&a, &4));
+
+    assert!(err.nearly_lt_rel(&Err(2), &0.1));
+    assert!(err.nearly_le_rel(&Err(1), &0.1));
+    assert!(!err.nearly_gt_tol(&Err(1), &Tolerance::new(0.1, 4)));
+    assert!(err.nearly_ge_tol(&Err(1), &Tolerance::new(0.1, 4)));
+
+    assert_nearly!(a < b, eps = 0.1);
+    assert_nearly!(a < err);
+}
@@ -0,0 +1,86 @@
+#![cfg(feature = "std")]
+
+use nearly::{
+    assert_nearly, NearlyEq, NearlyEqEps, NearlyEqRel, NearlyEqTol, NearlyEqUlps, Tolerance,
+};
+use std::cmp::Ordering;
+use std::collections::{BTreeSet, HashSet};
+use std::hash::{Hash, Hasher};
+
+/// An eigenvalue that is hashed and ordered by its exact value.
+#[derive(Debug, Clone, Copy, NearlyEq)]
+struct Eigenvalue(f64);
+
+impl PartialEq for Eigenvalue {
+    fn eq(&self, other: &Self) -> bool {
+        self.cmp(other) == Ordering::Equal
+    }
+}
+
+impl Eq for Eigenvalue {}
+
+impl PartialOrd for Eigenvalue {
+    fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+        Some(self.cmp(other))
+    }
+}
+
+impl Ord for Eigenvalue {
+    fn cmp(&self, other: &Self) -> Ordering {
+        self.0.total_cmp(&other.0)
+    }
+}
+
+impl Hash for Eigenvalue {
+    fn hash<H: Hasher>(&self, state: &mut H) {
+        self.0.to_bits().hash(state);
+    }
+}
+
+fn hash_set(values: &[f64]) -> HashSet<Eigenvalue> {
+    values.iter().copied().map(Eigenvalue).collect()
+}
+
+fn btree_set(values: &[f64]) -> BTreeSet<Eigenvalue> {
+    values.iter().copied().map(Eigenvalue).collect()
+}
+
+#[test]
+fn hash_set_eq() {
+    let a = hash_set(&[1.0, 2.0, 3.0]);
+    let b = hash_set(&[3.0, 2.05, 1.0]);
+
+    assert!(a.nearly_eq_eps(&b, &0.1));
+    assert!(!a.nearly_eq_eps(&b, &0.01));
+    assert!(a.nearly_eq_ulps(&hash_set(&[3.0, 2.0, 1.0]), &0));
+    assert!(!a.nearly_eq_ulps(&b, &4));
+    assert!(a.nearly_eq_rel(&b, &0.1));
+    assert!(a.nearly_eq_tol(&b, &Tolerance::new(0.1, 0)));
+    assert!(!a.nearly_eq(&b));
+    assert!(!a.nearly_eq_eps(&hash_set(&[1.0, 2.0]), &0.1));
+
+    assert_nearly!(a == b, eps = 0.1);
+}
+
+#[test]
+fn btree_set_eq() {
+    let a = btree_set(&[1.1, 1.0, 2.0]);
+    let b = btree_set(&[1.05, 1.15, 2.0]);
+
+    assert!(a.nearly_eq_eps(&b, &0.1));
+    assert!(!a.nearly_eq_eps(&b, &0.01));
+    assert!(!b.nearly_eq_eps(&btree_set(&[1.0, 1.02, 2.0]), &0.1));
+
+    assert_nearly!(a == b, eps = 0.1);
+    assert_nearly!(a != b, eps = 0.01);
+}
+
+#[test]
+fn mixed_set_eq() {
+    let a = hash_set(&[1.0, 2.0]);
+    let b = btree_set(&[2.0, 1.05]);
+
+    assert!(a.nearly_eq_eps(&b, &0.1));
+    assert!(b.nearly_eq_eps(&a, &0.1));
+    assert!(!a.nearly_eq_eps(&b, &0.01));
+}
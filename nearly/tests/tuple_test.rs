@@ -1,7 +1,8 @@
 use mockall::predicate::eq;
 use mockall::Sequence;
 use nearly::{
-    NearlyEqEps, NearlyEqTol, NearlyEqUlps, NearlyOrdEps, NearlyOrdTol, NearlyOrdUlps, Tolerance,
+    NearlyEqEps, NearlyEqRel, NearlyEqTol, NearlyEqUlps, NearlyOrdEps, NearlyOrdRel, NearlyOrdTol,
+    NearlyOrdUlps, Tolerance,
 };
 use paste::paste;
 
@@ -952,4 +953,5 @@ macro_rules! impl_test_fn {
 
 impl_test!(eps, 0.1);
 impl_test!(ulps, 5);
+impl_test!(rel, 0.01);
 impl_test!(tol, Tolerance::<MockLhs, Rhs>::new(0.1, 5));
@@ -0,0 +1,127 @@
+use nearly::{
+    assert_nearly, nearly, NearlyEq, NearlyEqTol, NearlyOrd, NearlyOrdTol, Tolerance, ToleranceMode,
+};
+use std::cmp::Ordering;
+
+#[derive(NearlyEq, NearlyOrd, Debug)]
+struct Point {
+    x: f32,
+    y: f32,
+}
+
+macro_rules! impl_test_float {
+    ($float: ty, $ulps: ty) => {
+        paste::item! {
+            #[test]
+            fn [<nearly_eq_tol_ulps_across_zero_ $float>]() {
+                let a: $float = <$float>::from_bits(1);
+                let b: $float = -<$float>::from_bits(1);
+                let tol = Tolerance::<$float>::new(0.0, 2);
+
+                assert!(!a.nearly_eq_tol(&b, &tol));
+                assert!(a.nearly_eq_tol(&b, &tol.with_ulps_across_zero(true)));
+                assert!(b.nearly_eq_tol(&a, &tol.with_ulps_across_zero(true)));
+                assert!(!a.nearly_eq_tol(&b, &tol.with_ulps(1).with_ulps_across_zero(true)));
+            }
+
+            #[test]
+            fn [<nearly_ord_tol_ulps_across_zero_ $float>]() {
+                let a: $float = <$float>::from_bits(1);
+                let b: $float = -<$float>::from_bits(1);
+                let tol = Tolerance::<$float>::new(0.0, 2);
+
+                assert!(b.nearly_lt_tol(&a, &tol));
+                assert!(a.nearly_gt_tol(&b, &tol));
+                assert_eq!(b.nearly_cmp_tol(&a, &tol), Some(Ordering::Less));
+
+                let tol = tol.with_ulps_across_zero(true);
+                assert!(!b.nearly_lt_tol(&a, &tol));
+                assert!(b.nearly_le_tol(&a, &tol));
+                assert!(!a.nearly_gt_tol(&b, &tol));
+                assert!(a.nearly_ge_tol(&b, &tol));
+                assert_eq!(b.nearly_cmp_tol(&a, &tol), Some(Ordering::Equal));
+            }
+
+            #[test]
+            fn [<nearly_tol_ulps_across_zero_mode_ $float>]() {
+                let a: $float = <$float>::from_bits(1);
+                let b: $float = -<$float>::from_bits(1);
+                let tol = Tolerance::<$float>::new(1.0, 2).with_ulps_across_zero(true);
+
+                assert!(a.nearly_eq_tol(&b, &tol.with_mode(ToleranceMode::All)));
+                assert!(!a.nearly_eq_tol(
+                    &b,
+                    &tol.with_ulps(1).with_mode(ToleranceMode::All)
+                ));
+                assert!(a.nearly_eq_tol(&b, &tol.with_ulps(1)));
+            }
+
+            #[test]
+            fn [<nearly_tol_ulps_across_zero_nan_ $float>]() {
+                let tol = Tolerance::<$float>::new(0.0, <$ulps>::MAX).with_ulps_across_zero(true);
+
+                assert!(!<$float>::NAN.nearly_eq_tol(&<$float>::NAN, &tol));
+                assert!(!<$float>::NAN.nearly_eq_tol(&0.0, &tol));
+                assert_eq!(<$float>::NAN.nearly_cmp_tol(&0.0, &tol), None);
+            }
+        }
+    };
+}
+
+impl_test_float!(f32, i32);
+impl_test_float!(f64, i64);
+
+#[test]
+fn nearly_tol_ulps_across_zero_collection() {
+    let tol = Tolerance::<f32>::new(0.0, 2);
+
+    let a = [f32::from_bits(1); 3];
+    let b = [-f32::from_bits(1); 3];
+    assert!(!a.nearly_eq_tol(&b, &tol));
+    assert!(a.nearly_eq_tol(&b, &tol.with_ulps_across_zero(true)));
+
+    let a = Some(f32::from_bits(1));
+    let b = Some(-f32::from_bits(1));
+    assert!(!a.nearly_eq_tol(&b, &tol));
+    assert!(a.nearly_eq_tol(&b, &tol.with_ulps_across_zero(true)));
+}
+
+#[test]
+#[cfg(feature = "std")]
+fn nearly_tol_ulps_across_zero_vec() {
+    let a = vec![1.0f32, f32::from_bits(1)];
+    let b = vec![1.0f32, -f32::from_bits(1)];
+    let tol = Tolerance::<f32>::new(0.0, 2);
+
+    assert!(!a.nearly_eq_tol(&b, &tol));
+    assert!(a.nearly_eq_tol(&b, &tol.with_ulps_across_zero(true)));
+}
+
+#[test]
+fn nearly_tol_ulps_across_zero_derive() {
+    let a = Point {
+        x: 1.0,
+        y: f32::from_bits(1),
+    };
+    let b = Point {
+        x: 1.0,
+        y: -f32::from_bits(1),
+    };
+    let tol = Tolerance::<Point>::new(0.0, 2);
+
+    assert!(!a.nearly_eq_tol(&b, &tol));
+    assert!(a.nearly_eq_tol(&b, &tol.with_ulps_across_zero(true)));
+    assert!(!a.nearly_gt_tol(&b, &tol.with_ulps_across_zero(true)));
+    assert!(a.nearly_ge_tol(&b, &tol.with_ulps_across_zero(true)));
+}
+
+#[test]
+fn nearly_tol_ulps_across_zero_macro() {
+    let a = [f64::from_bits(1), 0.0];
+    let b = [-f64::from_bits(1), -0.0];
+    let tol = Tolerance::<f64>::new(0.0, 2);
+
+    assert!(!nearly!(a == b, tol = tol));
+    assert!(nearly!(a == b, tol = tol.with_ulps_across_zero(true)));
+    assert_nearly!(a == b, tol = tol.with_ulps_across_zero(true));
+}
@@ -1,8 +1,8 @@
 #![allow(clippy::let_unit_value)]
 
 use nearly::{
-    NearlyEq, NearlyEqEps, NearlyEqTol, NearlyEqUlps, NearlyOrd, NearlyOrdEps, NearlyOrdTol,
-    NearlyOrdUlps, Tolerance,
+    NearlyEq, NearlyEqEps, NearlyEqRel, NearlyEqTol, NearlyEqUlps, NearlyOrd, NearlyOrdEps,
+    NearlyOrdRel, NearlyOrdTol, NearlyOrdUlps, Tolerance,
 };
 
 #[test]
@@ -12,6 +12,7 @@ fn nearly_eq_unit() {
 
     assert!(a.nearly_eq_eps(&b, &()));
     assert!(a.nearly_eq_ulps(&b, &()));
+    assert!(a.nearly_eq_rel(&b, &()));
     assert!(a.nearly_eq_tol(&b, &Tolerance::<()>::new((), ())));
     assert!(a.nearly_eq(&b));
 }
@@ -23,6 +24,7 @@ fn nearly_ne_unit() {
 
     assert!(!a.nearly_ne_eps(&b, &()));
     assert!(!a.nearly_ne_ulps(&b, &()));
+    assert!(!a.nearly_ne_rel(&b, &()));
     assert!(!a.nearly_ne_tol(&b, &Tolerance::<()>::new((), ())));
     assert!(!a.nearly_ne(&b));
 }
@@ -34,6 +36,7 @@ fn nearly_lt_unit() {
 
     assert!(!a.nearly_lt_eps(&b, &()));
     assert!(!a.nearly_lt_ulps(&b, &()));
+    assert!(!a.nearly_lt_rel(&b, &()));
     assert!(!a.nearly_lt_tol(&b, &Tolerance::<()>::new((), ())));
     assert!(!a.nearly_lt(&b));
 }
@@ -45,6 +48,7 @@ fn nearly_le_unit() {
 
     assert!(a.nearly_le_eps(&b, &()));
     assert!(a.nearly_le_ulps(&b, &()));
+    assert!(a.nearly_le_rel(&b, &()));
     assert!(a.nearly_le_tol(&b, &Tolerance::<()>::new((), ())));
     assert!(a.nearly_le(&b));
 }
@@ -56,6 +60,7 @@ fn nearly_gt_unit() {
 
     assert!(!a.nearly_gt_eps(&b, &()));
     assert!(!a.nearly_gt_ulps(&b, &()));
+    assert!(!a.nearly_gt_rel(&b, &()));
     assert!(!a.nearly_gt_tol(&b, &Tolerance::<()>::new((), ())));
     assert!(!a.nearly_gt(&b));
 }
@@ -67,6 +72,7 @@ fn nearly_ge_unit() {
 
     assert!(a.nearly_ge_eps(&b, &()));
     assert!(a.nearly_ge_ulps(&b, &()));
+    assert!(a.nearly_ge_rel(&b, &()));
     assert!(a.nearly_ge_tol(&b, &Tolerance::<()>::new((), ())));
     assert!(a.nearly_ge(&b));
 }
@@ -0,0 +1,118 @@
+#![cfg(feature = "std")]
+
+use nearly::{
+    assert_nearly, nearly, NearlyEq, NearlyEqEps, NearlyEqRel, NearlyEqTol, NearlyEqUlps,
+    Tolerance, Unordered,
+};
+use std::collections::{LinkedList, VecDeque};
+
+#[test]
+fn unordered_eq_eps() {
+    let a = Unordered([1.0f32, 2.0, 3.0]);
+    let b = Unordered([3.05f32, 1.0, 1.95]);
+
+    assert!(a.nearly_eq_eps(&b, &0.1));
+    assert!(!a.nearly_eq_eps(&b, &0.01));
+    assert!(!a.0.nearly_eq_eps(&b.0, &0.1));
+}
+
+#[test]
+fn unordered_eq_ulps() {
+    let a = Unordered(vec![1.0f64, 2.0, 3.0]);
+    let b = Unordered(vec![3.0f64, 1.0, 2.0]);
+
+    assert!(a.nearly_eq_ulps(&b, &0));
+    assert!(!a.nearly_eq_ulps(&Unordered(vec![3.0, 1.0, 2.5]), &4));
+}
+
+#[test]
+fn unordered_eq_rel() {
+    let a = Unordered(VecDeque::from(vec![100.0f64, 200.0]));
+    let b = Unordered(VecDeque::from(vec![201.0f64, 100.5]));
+
+    assert!(a.nearly_eq_rel(&b, &0.01));
+    assert!(!a.nearly_eq_rel(&b, &0.001));
+}
+
+#[test]
+fn unordered_eq_tol() {
+    let a = Unordered(LinkedList::from([1.0f32, 2.0]));
+    let b = Unordered(LinkedList::from([2.0f32, 1.05]));
+
+    assert!(a.nearly_eq_tol(&b, &Tolerance::new(0.1, 0)));
+    assert!(!a.nearly_eq_tol(&b, &Tolerance::new(0.01, 4)));
+    assert!(a.nearly_eq(&Unordered(LinkedList::from([2.0, 1.0]))));
+}
+
+#[test]
+fn unordered_eq_different_length() {
+    let a = Unordered(&[1.0f64, 2.0][..]);
+    let b = Unordered(&[2.0f64, 1.0, 1.0][..]);
+
+    assert!(!a.nearly_eq_eps(&b, &0.1));
+    assert!(!b.nearly_eq_eps(&a, &0.1));
+    assert!(Unordered(&[] as &[f64]).nearly_eq_eps(&Unordered(&[][..]), &0.1));
+}
+
+#[test]
+fn unordered_eq_duplicates() {
+    let a = Unordered([1.0f64, 1.0, 2.0]);
+    let b = Unordered([1.0f64, 2.0, 2.0]);
+
+    assert!(!a.nearly_eq_eps(&b, &0.1));
+    assert!(a.nearly_eq_eps(&Unordered([1.0, 2.0, 1.0]), &0.1));
+}
+
+#[test]
+fn unordered_eq_requires_rematching() {
+    // a greedy matching would match 1.1 with 1.05 and leave no match for 1.0
+    let a = Unordered([1.1f64, 1.0, 2.0]);
+    let b = Unordered([2.0f64, 1.05, 1.15]);
+
+    assert!(a.nearly_eq_eps(&b, &0.1));
+    assert!(b.nearly_eq_eps(&a, &0.1));
+
+    // 1.0 and 1.02 are both only nearly equal to 1.05
+    let c = Unordered([1.0f64, 1.02, 2.0]);
+    assert!(!c.nearly_eq_eps(&b, &0.1));
+}
+
+#[test]
+fn unordered_eq_mixed_collections() {
+    let a = Unordered(vec![1.0f64, 2.0]);
+    let b = Unordered([2.0f64, 1.0]);
+    let c = Unordered(VecDeque::from(vec![2.0f64, 1.0]));
+
+    assert_nearly!(a == b, eps = 0.01);
+    assert_nearly!(b == a, eps = 0.01);
+    assert_nearly!(a == c, eps = 0.01);
+    assert_nearly!(c == b, eps = 0.01);
+    assert!(nearly!(a != Unordered([2.0, 1.5]), eps = 0.1));
+}
+
+#[test]
+fn unordered_eq_many_identical() {
+    let a = Unordered(vec![0.0f64; 4000]);
+
+    assert!(a.nearly_eq_eps(&a, &0.1));
+    assert!(a.nearly_eq_ulps(&a, &0));
+
+    let mut b = a.clone();
+    b.0[1234] = 1.0;
+    assert!(!a.nearly_eq_eps(&b, &0.1));
+}
+
+#[test]
+fn unordered_eq_long_augmenting_path() {
+    // 1.0 .. 2999.0 are matched greedily with 0.5 .. 2998.5, so matching 0.0 requires to
+    // rematch all of them
+    let n = 3000;
+    let a: Vec<f64> = (1..n).chain(0..1).map(f64::from).collect();
+    let b: Vec<f64> = (0..n).map(|i| f64::from(i) + 0.5).collect();
+
+    assert!(Unordered(a.clone()).nearly_eq_eps(&Unordered(b.clone()), &0.6));
+    assert!(Unordered(b).nearly_eq_eps(&Unordered(a.clone()), &0.6));
+
+    let c: Vec<f64> = (0..n).map(|i| f64::from(i) + 0.7).collect();
+    assert!(!Unordered(a).nearly_eq_eps(&Unordered(c), &0.6));
+}
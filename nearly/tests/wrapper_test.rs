@@ -0,0 +1,160 @@
+use nearly::{DefaultTolerance, NanFirst, NanLast, Nearly, Tolerance, ToleranceProvider};
+use std::cmp::Ordering;
+
+struct Coarse;
+
+impl ToleranceProvider<f32> for Coarse {
+    fn tolerance() -> Tolerance<f32> {
+        Tolerance::new(0.1, 0)
+    }
+}
+
+impl ToleranceProvider<f64> for Coarse {
+    fn tolerance() -> Tolerance<f64> {
+        Tolerance::new(0.1, 0)
+    }
+}
+
+#[test]
+fn nearly_new_get_into_inner() {
+    let a = Nearly::<f32>::new(1.5);
+    assert_eq!(*a.get(), 1.5);
+    assert_eq!(*a, 1.5);
+    assert_eq!(a.into_inner(), 1.5);
+
+    let b: Nearly<f32> = 2.5.into();
+    assert_eq!(b.into_inner(), 2.5);
+    assert_eq!(format!("{:?}", b), "Nearly(2.5)");
+}
+
+#[test]
+fn nearly_partial_eq_default_tolerance() {
+    let a = Nearly::<f32>::new(1.0);
+    let b = Nearly::<f32>::new(1.0 + f32::EPSILON);
+    let c = Nearly::<f32>::new(1.1);
+    assert!(a == b);
+    assert!(a != c);
+    assert!(Nearly::<f32>::new(f32::NAN) != Nearly::new(f32::NAN));
+}
+
+#[test]
+fn nearly_partial_eq_provided_tolerance() {
+    let a = Nearly::<f32, Coarse>::new(1.0);
+    let b = Nearly::<f32, Coarse>::new(1.05);
+    let c = Nearly::<f32, Coarse>::new(1.2);
+    assert!(a == b);
+    assert!(a != c);
+}
+
+#[test]
+fn nearly_partial_ord() {
+    let a = Nearly::<f64, Coarse>::new(1.0);
+    let b = Nearly::<f64, Coarse>::new(1.05);
+    let c = Nearly::<f64, Coarse>::new(1.2);
+    let nan = Nearly::<f64, Coarse>::new(f64::NAN);
+
+    assert_eq!(a.partial_cmp(&b), Some(Ordering::Equal));
+    assert_eq!(a.partial_cmp(&c), Some(Ordering::Less));
+    assert_eq!(c.partial_cmp(&a), Some(Ordering::Greater));
+    assert_eq!(a.partial_cmp(&nan), None);
+
+    assert!(a <= b);
+    assert!(a >= b);
+    assert!(a < c);
+    assert!(c > a);
+    assert!(!a.lt(&nan));
+    assert!(!a.le(&nan));
+    assert!(!a.gt(&nan));
+    assert!(!a.ge(&nan));
+}
+
+#[test]
+fn nearly_sort_by() {
+    let mut values = vec![3.0_f32, 1.0, 2.0, 1.05];
+    values.sort_by(|a, b| {
+        Nearly::<f32, Coarse>::new(*a)
+            .partial_cmp(&Nearly::new(*b))
+            .unwrap()
+    });
+    values.dedup_by(|a, b| Nearly::<f32, Coarse>::new(*a) == Nearly::new(*b));
+    assert_eq!(values, vec![1.0, 2.0, 3.0]);
+}
+
+#[test]
+fn nearly_binary_search_by() {
+    let values = [1.0_f32, 2.0, 3.0, 4.0];
+    let search = |x: f32| {
+        values.binary_search_by(|v| {
+            Nearly::<f32, Coarse>::new(*v)
+                .partial_cmp(&Nearly::new(x))
+                .unwrap()
+        })
+    };
+    assert_eq!(search(3.05), Ok(2));
+    assert_eq!(search(2.5), Err(2));
+}
+
+#[test]
+fn nearly_ord_nan_first() {
+    type Key = Nearly<f32, Coarse, NanFirst>;
+
+    assert_eq!(Key::new(f32::NAN).cmp(&Key::new(f32::NAN)), Ordering::Equal);
+    assert_eq!(Key::new(f32::NAN).cmp(&Key::new(1.0)), Ordering::Less);
+    assert_eq!(Key::new(1.0).cmp(&Key::new(f32::NAN)), Ordering::Greater);
+    assert_eq!(
+        Key::new(f32::NAN).cmp(&Key::new(f32::NEG_INFINITY)),
+        Ordering::Less
+    );
+    assert_eq!(Key::new(1.0).cmp(&Key::new(1.05)), Ordering::Equal);
+    assert_eq!(Key::new(1.0).cmp(&Key::new(1.2)), Ordering::Less);
+    assert!(Key::new(f32::NAN) == Key::new(f32::NAN));
+
+    let mut values = [3.0, f32::NAN, 1.0, 2.0];
+    values.sort_by_key(|v| Key::new(*v));
+    assert!(values[0].is_nan());
+    assert_eq!(values[1..], [1.0, 2.0, 3.0]);
+}
+
+#[test]
+fn nearly_ord_nan_last() {
+    type Key = Nearly<f64, Coarse, NanLast>;
+
+    assert_eq!(Key::new(f64::NAN).cmp(&Key::new(f64::NAN)), Ordering::Equal);
+    assert_eq!(Key::new(f64::NAN).cmp(&Key::new(1.0)), Ordering::Greater);
+    assert_eq!(Key::new(1.0).cmp(&Key::new(f64::NAN)), Ordering::Less);
+    assert_eq!(
+        Key::new(f64::NAN).cmp(&Key::new(f64::INFINITY)),
+        Ordering::Greater
+    );
+    assert_eq!(Key::new(1.0).cmp(&Key::new(1.05)), Ordering::Equal);
+    assert_eq!(Key::new(1.2).cmp(&Key::new(1.0)), Ordering::Greater);
+
+    let mut values = [3.0, f64::NAN, 1.0, 2.0];
+    values.sort_by_key(|v| Key::new(*v));
+    assert_eq!(values[..3], [1.0, 2.0, 3.0]);
+    assert!(values[3].is_nan());
+}
+
+#[test]
+fn nearly_btree_set() {
+    use std::collections::BTreeSet;
+
+    let set: BTreeSet<Nearly<f64, Coarse, NanLast>> = [1.0, 1.05, 2.0, f64::NAN, f64::NAN]
+        .into_iter()
+        .map(Nearly::new)
+        .collect();
+    assert_eq!(set.len(), 3);
+    assert!(set.contains(&Nearly::new(1.02)));
+    assert!(set.contains(&Nearly::new(f64::NAN)));
+    assert!(!set.contains(&Nearly::new(1.5)));
+}
+
+#[test]
+fn nearly_btree_map_default_tolerance() {
+    use std::collections::BTreeMap;
+
+    let mut map = BTreeMap::<Nearly<f32, DefaultTolerance, NanFirst>, &str>::new();
+    map.insert(Nearly::new(0.1 + 0.2), "a");
+    assert_eq!(map.get(&Nearly::new(0.3)), Some(&"a"));
+    assert_eq!(map.get(&Nearly::new(0.4)), None);
+}